@@ -163,7 +163,7 @@ class CoinUpdate() {
 
                 val activityUtxo = "${utxo!!.txHash}:${utxo!!.txPos}"
 
-                val activity = createActivity(activityUtxo, utxo!!.value.toUInt(), "deposit")
+                val activity = createActivity(activityUtxo, utxo!!.value.toULong(), "deposit")
 
                 depositResult = DepositResult(activity, backupTx)
             }
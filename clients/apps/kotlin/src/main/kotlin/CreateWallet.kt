@@ -76,6 +76,9 @@ class CreateWallet : CliktCommand(help = "Creates a new wallet") {
         val wallet = Wallet(
             walletName,
             mnemonic!!,
+            null,
+            DerivationScheme.BIP44,
+            0u,
             "0.0.1",
             clientConfig.statechainEntity,
             clientConfig.electrumServer,
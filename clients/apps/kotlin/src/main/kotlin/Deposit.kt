@@ -20,7 +20,7 @@ class Deposit: CliktCommand(help = "Generates a new deposit address", name = "ne
 
     private val walletName: String by argument(help = "Name of the wallet to create")
 
-    private val amount: UInt by argument(help = "Statecoin amount").convert { it.toUInt() }
+    private val amount: ULong by argument(help = "Statecoin amount").convert { it.toULong() }
 
     private val appContext: AppContext by lazy {
         requireNotNull(currentContext.findObject() as? AppContext) {
@@ -56,7 +56,7 @@ class Deposit: CliktCommand(help = "Generates a new deposit address", name = "ne
         appContext.sqliteManager.updateWallet(wallet)
     }
 
-    private suspend fun execute(wallet: Wallet, token: Token, amount: UInt) {
+    private suspend fun execute(wallet: Wallet, token: Token, amount: ULong) {
 
         val coin = getNewCoin(wallet)
         wallet.coins = wallet.coins.plus(coin)
@@ -64,7 +64,7 @@ suspend fun completeWithdraw(clientConfig: ClientConfig, statechainId: String, s
      httpClient.close()
 }
 
-fun createActivity(utxo: String, amount: UInt, action: String): Activity {
+fun createActivity(utxo: String, amount: ULong, action: String): Activity {
     val date = ZonedDateTime.now() // This will get the current date and time in UTC
     val isoString = date.format(DateTimeFormatter.ISO_ZONED_DATE_TIME) // Converts the date to an ISO 8601 string
 
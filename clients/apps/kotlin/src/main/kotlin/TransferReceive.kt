@@ -43,25 +43,21 @@ class TransferReceive: CliktCommand(help = "Retrieve coins from server") {
                 setBody(transferReceiverRequestPayload)
             }
 
-            if (response.status == HttpStatusCode.BadRequest) {
+            if (response.status != HttpStatusCode.OK) {
 
-                val transferReceiverErrorResponsePayload : TransferReceiverErrorResponsePayload = response.body()
+                val apiError = decodeApiError(response.status.value.toUShort(), response.bodyAsText())
 
-                if (transferReceiverErrorResponsePayload.code == TransferReceiverError.EXPIRED_BATCH_TIME_ERROR) {
-                    throw Exception(transferReceiverErrorResponsePayload.message)
-                } else if (transferReceiverErrorResponsePayload.code == TransferReceiverError.STATECOIN_BATCH_LOCKED_ERROR) {
+                if (apiError.code == ApiError.STATECOIN_BATCH_LOCKED_ERROR) {
                     println("Statecoin batch still locked. Waiting until expiration or unlock.")
                     delay(5000)
                     continue
                 }
-            }
 
-            if (response.status == HttpStatusCode.OK) {
-                val transferReceiverPostResponsePayload : TransferReceiverPostResponsePayload = response.body()
-                return transferReceiverPostResponsePayload.serverPubkey
-            } else {
-                throw Exception("Failed to update transfer message")
+                throw Exception("Failed to update transfer message: ${apiError.message}")
             }
+
+            val transferReceiverPostResponsePayload : TransferReceiverPostResponsePayload = response.body()
+            return transferReceiverPostResponsePayload.serverPubkey
         }
     }
 
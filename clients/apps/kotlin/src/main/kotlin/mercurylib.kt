@@ -3,7 +3,6 @@
 
 @file:Suppress("NAME_SHADOWING")
 
-
 // Common helper code.
 //
 // Ideally this would live in a separate .kt file where it can be unittested etc
@@ -16,11 +15,12 @@
 // compile the Rust component. The easiest way to ensure this is to bundle the Kotlin
 // helpers directly inline like we're doing here.
 
-import com.sun.jna.Callback
 import com.sun.jna.Library
+import com.sun.jna.IntegerType
 import com.sun.jna.Native
 import com.sun.jna.Pointer
 import com.sun.jna.Structure
+import com.sun.jna.Callback
 import com.sun.jna.ptr.*
 import kotlinx.serialization.Serializable
 import kotlinx.serialization.SerialName
@@ -28,8 +28,8 @@ import java.nio.ByteBuffer
 import java.nio.ByteOrder
 import java.nio.CharBuffer
 import java.nio.charset.CodingErrorAction
-import java.util.concurrent.ConcurrentHashMap
 import java.util.concurrent.atomic.AtomicLong
+import java.util.concurrent.ConcurrentHashMap
 
 // This is a helper for safely working with byte buffers returned from the Rust code.
 // A rust-owned buffer is represented by its capacity, its current length, and a
@@ -40,37 +40,29 @@ open class RustBuffer : Structure() {
     // Note: `capacity` and `len` are actually `ULong` values, but JVM only supports signed values.
     // When dealing with these fields, make sure to call `toULong()`.
     @JvmField var capacity: Long = 0
-
     @JvmField var len: Long = 0
-
     @JvmField var data: Pointer? = null
 
-    class ByValue : RustBuffer(), Structure.ByValue
-
-    class ByReference : RustBuffer(), Structure.ByReference
+    class ByValue: RustBuffer(), Structure.ByValue
+    class ByReference: RustBuffer(), Structure.ByReference
 
-    internal fun setValue(other: RustBuffer) {
+   internal fun setValue(other: RustBuffer) {
         capacity = other.capacity
         len = other.len
         data = other.data
     }
 
     companion object {
-        internal fun alloc(size: ULong = 0UL) =
-            uniffiRustCall { status ->
-                // Note: need to convert the size to a `Long` value to make this work with JVM.
-                UniffiLib.INSTANCE.ffi_mercurylib_rustbuffer_alloc(size.toLong(), status)
-            }.also {
-                if (it.data == null) {
-                    throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=$size)")
-                }
-            }
+        internal fun alloc(size: ULong = 0UL) = uniffiRustCall() { status ->
+            // Note: need to convert the size to a `Long` value to make this work with JVM.
+            UniffiLib.INSTANCE.ffi_mercurylib_rustbuffer_alloc(size.toLong(), status)
+        }.also {
+            if(it.data == null) {
+               throw RuntimeException("RustBuffer.alloc() returned null data pointer (size=${size})")
+           }
+        }
 
-        internal fun create(
-            capacity: ULong,
-            len: ULong,
-            data: Pointer?,
-        ): RustBuffer.ByValue {
+        internal fun create(capacity: ULong, len: ULong, data: Pointer?): RustBuffer.ByValue {
             var buf = RustBuffer.ByValue()
             buf.capacity = capacity.toLong()
             buf.len = len.toLong()
@@ -78,10 +70,9 @@ open class RustBuffer : Structure() {
             return buf
         }
 
-        internal fun free(buf: RustBuffer.ByValue) =
-            uniffiRustCall { status ->
-                UniffiLib.INSTANCE.ffi_mercurylib_rustbuffer_free(buf, status)
-            }
+        internal fun free(buf: RustBuffer.ByValue) = uniffiRustCall() { status ->
+            UniffiLib.INSTANCE.ffi_mercurylib_rustbuffer_free(buf, status)
+        }
     }
 
     @Suppress("TooGenericExceptionThrown")
@@ -132,12 +123,10 @@ class RustBufferByReference : ByReference(16) {
 @Structure.FieldOrder("len", "data")
 open class ForeignBytes : Structure() {
     @JvmField var len: Int = 0
-
     @JvmField var data: Pointer? = null
 
     class ByValue : ForeignBytes(), Structure.ByValue
 }
-
 // The FfiConverter interface handles converter types to and from the FFI
 //
 // All implementing objects should be public to support external types.  When a
@@ -163,10 +152,7 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun allocationSize(value: KotlinType): ULong
 
     // Write a Kotlin type to a `ByteBuffer`
-    fun write(
-        value: KotlinType,
-        buf: ByteBuffer,
-    )
+    fun write(value: KotlinType, buf: ByteBuffer)
 
     // Lower a value into a `RustBuffer`
     //
@@ -177,10 +163,9 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun lowerIntoRustBuffer(value: KotlinType): RustBuffer.ByValue {
         val rbuf = RustBuffer.alloc(allocationSize(value))
         try {
-            val bbuf =
-                rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
-                    it.order(ByteOrder.BIG_ENDIAN)
-                }
+            val bbuf = rbuf.data!!.getByteBuffer(0, rbuf.capacity).also {
+                it.order(ByteOrder.BIG_ENDIAN)
+            }
             write(value, bbuf)
             rbuf.writeField("len", bbuf.position().toLong())
             return rbuf
@@ -197,11 +182,11 @@ public interface FfiConverter<KotlinType, FfiType> {
     fun liftFromRustBuffer(rbuf: RustBuffer.ByValue): KotlinType {
         val byteBuf = rbuf.asByteBuffer()!!
         try {
-            val item = read(byteBuf)
-            if (byteBuf.hasRemaining()) {
-                throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
-            }
-            return item
+           val item = read(byteBuf)
+           if (byteBuf.hasRemaining()) {
+               throw RuntimeException("junk remaining in buffer after lifting, something is very wrong!!")
+           }
+           return item
         } finally {
             RustBuffer.free(rbuf)
         }
@@ -209,9 +194,8 @@ public interface FfiConverter<KotlinType, FfiType> {
 }
 
 // FfiConverter that uses `RustBuffer` as the FfiType
-public interface FfiConverterRustBuffer<KotlinType> : FfiConverter<KotlinType, RustBuffer.ByValue> {
+public interface FfiConverterRustBuffer<KotlinType>: FfiConverter<KotlinType, RustBuffer.ByValue> {
     override fun lift(value: RustBuffer.ByValue) = liftFromRustBuffer(value)
-
     override fun lower(value: KotlinType) = lowerIntoRustBuffer(value)
 }
 // A handful of classes and functions to support the generated data structures.
@@ -224,10 +208,9 @@ internal const val UNIFFI_CALL_UNEXPECTED_ERROR = 2.toByte()
 @Structure.FieldOrder("code", "error_buf")
 internal open class UniffiRustCallStatus : Structure() {
     @JvmField var code: Byte = 0
-
     @JvmField var error_buf: RustBuffer.ByValue = RustBuffer.ByValue()
 
-    class ByValue : UniffiRustCallStatus(), Structure.ByValue
+    class ByValue: UniffiRustCallStatus(), Structure.ByValue
 
     fun isSuccess(): Boolean {
         return code == UNIFFI_CALL_SUCCESS
@@ -242,10 +225,7 @@ internal open class UniffiRustCallStatus : Structure() {
     }
 
     companion object {
-        fun create(
-            code: Byte,
-            errorBuf: RustBuffer.ByValue,
-        ): UniffiRustCallStatus.ByValue {
+        fun create(code: Byte, errorBuf: RustBuffer.ByValue): UniffiRustCallStatus.ByValue {
             val callStatus = UniffiRustCallStatus.ByValue()
             callStatus.code = code
             callStatus.error_buf = errorBuf
@@ -258,7 +238,7 @@ class InternalException(message: String) : Exception(message)
 
 // Each top-level error class has a companion object that can lift the error from the call status's rust buffer
 interface UniffiRustCallStatusErrorHandler<E> {
-    fun lift(error_buf: RustBuffer.ByValue): E
+    fun lift(error_buf: RustBuffer.ByValue): E;
 }
 
 // Helpers for calling Rust
@@ -266,21 +246,15 @@ interface UniffiRustCallStatusErrorHandler<E> {
 // synchronize itself
 
 // Call a rust function that returns a Result<>.  Pass in the Error class companion that corresponds to the Err
-private inline fun <U, E : Exception> uniffiRustCallWithError(
-    errorHandler: UniffiRustCallStatusErrorHandler<E>,
-    callback: (UniffiRustCallStatus) -> U,
-): U {
-    var status = UniffiRustCallStatus()
+private inline fun <U, E: Exception> uniffiRustCallWithError(errorHandler: UniffiRustCallStatusErrorHandler<E>, callback: (UniffiRustCallStatus) -> U): U {
+    var status = UniffiRustCallStatus();
     val return_value = callback(status)
     uniffiCheckCallStatus(errorHandler, status)
     return return_value
 }
 
 // Check UniffiRustCallStatus and throw an error if the call wasn't successful
-private fun <E : Exception> uniffiCheckCallStatus(
-    errorHandler: UniffiRustCallStatusErrorHandler<E>,
-    status: UniffiRustCallStatus,
-) {
+private fun<E: Exception> uniffiCheckCallStatus(errorHandler: UniffiRustCallStatusErrorHandler<E>, status: UniffiRustCallStatus) {
     if (status.isSuccess()) {
         return
     } else if (status.isError()) {
@@ -300,7 +274,7 @@ private fun <E : Exception> uniffiCheckCallStatus(
 }
 
 // UniffiRustCallStatusErrorHandler implementation for times when we don't expect a CALL_ERROR
-object UniffiNullRustCallStatusErrorHandler : UniffiRustCallStatusErrorHandler<InternalException> {
+object UniffiNullRustCallStatusErrorHandler: UniffiRustCallStatusErrorHandler<InternalException> {
     override fun lift(error_buf: RustBuffer.ByValue): InternalException {
         RustBuffer.free(error_buf)
         return InternalException("Unexpected CALL_ERROR")
@@ -309,31 +283,31 @@ object UniffiNullRustCallStatusErrorHandler : UniffiRustCallStatusErrorHandler<I
 
 // Call a rust function that returns a plain value
 private inline fun <U> uniffiRustCall(callback: (UniffiRustCallStatus) -> U): U {
-    return uniffiRustCallWithError(UniffiNullRustCallStatusErrorHandler, callback)
+    return uniffiRustCallWithError(UniffiNullRustCallStatusErrorHandler, callback);
 }
 
-internal inline fun <T> uniffiTraitInterfaceCall(
+internal inline fun<T> uniffiTraitInterfaceCall(
     callStatus: UniffiRustCallStatus,
     makeCall: () -> T,
     writeReturn: (T) -> Unit,
 ) {
     try {
         writeReturn(makeCall())
-    } catch (e: Exception) {
+    } catch(e: Exception) {
         callStatus.code = UNIFFI_CALL_UNEXPECTED_ERROR
         callStatus.error_buf = FfiConverterString.lower(e.toString())
     }
 }
 
-internal inline fun <T, reified E : Throwable> uniffiTraitInterfaceCallWithError(
+internal inline fun<T, reified E: Throwable> uniffiTraitInterfaceCallWithError(
     callStatus: UniffiRustCallStatus,
     makeCall: () -> T,
     writeReturn: (T) -> Unit,
-    lowerError: (E) -> RustBuffer.ByValue,
+    lowerError: (E) -> RustBuffer.ByValue
 ) {
     try {
         writeReturn(makeCall())
-    } catch (e: Exception) {
+    } catch(e: Exception) {
         if (e is E) {
             callStatus.code = UNIFFI_CALL_ERROR
             callStatus.error_buf = lowerError(e)
@@ -343,11 +317,10 @@ internal inline fun <T, reified E : Throwable> uniffiTraitInterfaceCallWithError
         }
     }
 }
-
 // Map handles to objects
 //
 // This is used pass an opaque 64-bit handle representing a foreign object to the Rust code.
-internal class UniffiHandleMap<T : Any> {
+internal class UniffiHandleMap<T: Any> {
     private val map = ConcurrentHashMap<Long, T>()
     private val counter = java.util.concurrent.atomic.AtomicLong(0)
 
@@ -383,26 +356,22 @@ private fun findLibraryName(componentName: String): String {
     return "mercurylib"
 }
 
-private inline fun <reified Lib : Library> loadIndirect(componentName: String): Lib {
+private inline fun <reified Lib : Library> loadIndirect(
+    componentName: String
+): Lib {
     return Native.load<Lib>(findLibraryName(componentName), Lib::class.java)
 }
 
 // Define FFI callback types
 internal interface UniffiRustFutureContinuationCallback : com.sun.jna.Callback {
-    fun callback(
-        `data`: Long,
-        `pollResult`: Byte,
-    )
+    fun callback(`data`: Long,`pollResult`: Byte,)
 }
-
 internal interface UniffiForeignFutureFree : com.sun.jna.Callback {
-    fun callback(`handle`: Long)
+    fun callback(`handle`: Long,)
 }
-
 internal interface UniffiCallbackInterfaceFree : com.sun.jna.Callback {
-    fun callback(`handle`: Long)
+    fun callback(`handle`: Long,)
 }
-
 @Structure.FieldOrder("handle", "free")
 internal open class UniffiForeignFuture(
     @JvmField internal var `handle`: Long = 0.toLong(),
@@ -411,14 +380,14 @@ internal open class UniffiForeignFuture(
     class UniffiByValue(
         `handle`: Long = 0.toLong(),
         `free`: UniffiForeignFutureFree? = null,
-    ) : UniffiForeignFuture(`handle`, `free`), Structure.ByValue
+    ): UniffiForeignFuture(`handle`,`free`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFuture) {
+   internal fun uniffiSetValue(other: UniffiForeignFuture) {
         `handle` = other.`handle`
         `free` = other.`free`
     }
-}
 
+}
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU8(
     @JvmField internal var `returnValue`: Byte = 0.toByte(),
@@ -427,21 +396,17 @@ internal open class UniffiForeignFutureStructU8(
     class UniffiByValue(
         `returnValue`: Byte = 0.toByte(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU8(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructU8(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU8) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU8) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU8 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU8.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU8.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI8(
     @JvmField internal var `returnValue`: Byte = 0.toByte(),
@@ -450,21 +415,17 @@ internal open class UniffiForeignFutureStructI8(
     class UniffiByValue(
         `returnValue`: Byte = 0.toByte(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI8(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructI8(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI8) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI8) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI8 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI8.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI8.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU16(
     @JvmField internal var `returnValue`: Short = 0.toShort(),
@@ -473,21 +434,17 @@ internal open class UniffiForeignFutureStructU16(
     class UniffiByValue(
         `returnValue`: Short = 0.toShort(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU16(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructU16(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU16) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU16) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU16 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU16.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU16.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI16(
     @JvmField internal var `returnValue`: Short = 0.toShort(),
@@ -496,21 +453,17 @@ internal open class UniffiForeignFutureStructI16(
     class UniffiByValue(
         `returnValue`: Short = 0.toShort(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI16(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructI16(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI16) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI16) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI16 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI16.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI16.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU32(
     @JvmField internal var `returnValue`: Int = 0,
@@ -519,21 +472,17 @@ internal open class UniffiForeignFutureStructU32(
     class UniffiByValue(
         `returnValue`: Int = 0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU32(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructU32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI32(
     @JvmField internal var `returnValue`: Int = 0,
@@ -542,21 +491,17 @@ internal open class UniffiForeignFutureStructI32(
     class UniffiByValue(
         `returnValue`: Int = 0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI32(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructI32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructU64(
     @JvmField internal var `returnValue`: Long = 0.toLong(),
@@ -565,21 +510,17 @@ internal open class UniffiForeignFutureStructU64(
     class UniffiByValue(
         `returnValue`: Long = 0.toLong(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructU64(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructU64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructU64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructU64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteU64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructU64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructU64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructI64(
     @JvmField internal var `returnValue`: Long = 0.toLong(),
@@ -588,21 +529,17 @@ internal open class UniffiForeignFutureStructI64(
     class UniffiByValue(
         `returnValue`: Long = 0.toLong(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructI64(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructI64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructI64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructI64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteI64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructI64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructI64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructF32(
     @JvmField internal var `returnValue`: Float = 0.0f,
@@ -611,21 +548,17 @@ internal open class UniffiForeignFutureStructF32(
     class UniffiByValue(
         `returnValue`: Float = 0.0f,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructF32(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructF32(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructF32) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructF32) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteF32 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructF32.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructF32.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructF64(
     @JvmField internal var `returnValue`: Double = 0.0,
@@ -634,21 +567,17 @@ internal open class UniffiForeignFutureStructF64(
     class UniffiByValue(
         `returnValue`: Double = 0.0,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructF64(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructF64(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructF64) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructF64) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteF64 : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructF64.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructF64.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructPointer(
     @JvmField internal var `returnValue`: Pointer = Pointer.NULL,
@@ -657,21 +586,17 @@ internal open class UniffiForeignFutureStructPointer(
     class UniffiByValue(
         `returnValue`: Pointer = Pointer.NULL,
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructPointer(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructPointer(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructPointer) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructPointer) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompletePointer : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructPointer.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructPointer.UniffiByValue,)
 }
-
 @Structure.FieldOrder("returnValue", "callStatus")
 internal open class UniffiForeignFutureStructRustBuffer(
     @JvmField internal var `returnValue`: RustBuffer.ByValue = RustBuffer.ByValue(),
@@ -680,1765 +605,2602 @@ internal open class UniffiForeignFutureStructRustBuffer(
     class UniffiByValue(
         `returnValue`: RustBuffer.ByValue = RustBuffer.ByValue(),
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructRustBuffer(`returnValue`, `callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructRustBuffer(`returnValue`,`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructRustBuffer) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructRustBuffer) {
         `returnValue` = other.`returnValue`
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteRustBuffer : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructRustBuffer.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructRustBuffer.UniffiByValue,)
 }
-
 @Structure.FieldOrder("callStatus")
 internal open class UniffiForeignFutureStructVoid(
     @JvmField internal var `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
 ) : Structure() {
     class UniffiByValue(
         `callStatus`: UniffiRustCallStatus.ByValue = UniffiRustCallStatus.ByValue(),
-    ) : UniffiForeignFutureStructVoid(`callStatus`), Structure.ByValue
+    ): UniffiForeignFutureStructVoid(`callStatus`,), Structure.ByValue
 
-    internal fun uniffiSetValue(other: UniffiForeignFutureStructVoid) {
+   internal fun uniffiSetValue(other: UniffiForeignFutureStructVoid) {
         `callStatus` = other.`callStatus`
     }
-}
 
+}
 internal interface UniffiForeignFutureCompleteVoid : com.sun.jna.Callback {
-    fun callback(
-        `callbackData`: Long,
-        `result`: UniffiForeignFutureStructVoid.UniffiByValue,
-    )
+    fun callback(`callbackData`: Long,`result`: UniffiForeignFutureStructVoid.UniffiByValue,)
 }
 
-// A JNA Library to expose the extern-C FFI definitions.
-// This is an implementation detail which will be called internally by the public API.
 
-internal interface UniffiLib : Library {
-    companion object {
-        internal val INSTANCE: UniffiLib by lazy {
-            loadIndirect<UniffiLib>(componentName = "mercurylib")
-                .also { lib: UniffiLib ->
-                    uniffiCheckContractApiVersion(lib)
-                    uniffiCheckApiChecksums(lib)
-                }
-        }
-    }
 
-    fun uniffi_mercurylib_fn_func_create_aggregated_address(
-        `coin`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_and_commit_nonces(
-        `coin`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_cpfp_tx(
-        `backupTx`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        `toAddress`: RustBuffer.ByValue,
-        `feeRateSatsPerByte`: Long,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_deposit_msg1(
-        `coin`: RustBuffer.ByValue,
-        `tokenId`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_signature(
-        `msg`: RustBuffer.ByValue,
-        `clientPartialSigHex`: RustBuffer.ByValue,
-        `serverPartialSigHex`: RustBuffer.ByValue,
-        `sessionHex`: RustBuffer.ByValue,
-        `outputPubkeyHex`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_transfer_signature(
-        `recipientAddress`: RustBuffer.ByValue,
-        `inputTxid`: RustBuffer.ByValue,
-        `inputVout`: Int,
-        `clientSeckey`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_create_transfer_update_msg(
-        `x1`: RustBuffer.ByValue,
-        `recipientAddress`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        `transferSignature`: RustBuffer.ByValue,
-        `backupTransactions`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_decode_statechain_address(
-        `scAddress`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_duplicate_coin_to_initialized_state(
-        `wallet`: RustBuffer.ByValue,
-        `authPubkey`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_ffi_validate_signature_scheme(
-        `ffiTransferMsg`: RustBuffer.ByValue,
-        `statechainInfo`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `feeRateTolerance`: Int,
-        `currentFeeRateSatsPerByte`: Int,
-        `interval`: Int,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    fun uniffi_mercurylib_fn_func_ffi_verify_transfer_signature(
-        `newUserPubkey`: RustBuffer.ByValue,
-        `tx0Outpoint`: RustBuffer.ByValue,
-        `ffiTransferMsg`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun uniffi_mercurylib_fn_func_fii_create_transfer_receiver_request_payload(
-        `statechainInfo`: RustBuffer.ByValue,
-        `ffiTransferMsg`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_fii_decrypt_transfer_msg(
-        `encryptedMessage`: RustBuffer.ByValue,
-        `privateKeyWif`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_fii_validate_tx0_output_pubkey(
-        `enclavePublicKey`: RustBuffer.ByValue,
-        `ffiTransferMsg`: RustBuffer.ByValue,
-        `tx0Outpoint`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun uniffi_mercurylib_fn_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(
-        `ffiTransferMsg`: RustBuffer.ByValue,
-        `clientPubkeyShare`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun uniffi_mercurylib_fn_func_generate_mnemonic(uniffi_out_err: UniffiRustCallStatus): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_blockheight(
-        `bkpTx`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    fun uniffi_mercurylib_fn_func_get_new_coin(
-        `wallet`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_new_key_info(
-        `serverPublicKeyHex`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        `statechainId`: RustBuffer.ByValue,
-        `tx0Outpoint`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_output_address_from_tx0(
-        `tx0Outpoint`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_partial_sig_request(
-        `coin`: RustBuffer.ByValue,
-        `blockHeight`: Int,
-        `initlock`: Int,
-        `interval`: Int,
-        `feeRateSatsPerByte`: Int,
-        `qtBackupTx`: Int,
-        `toAddress`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        `isWithdrawal`: Byte,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_tx0_outpoint(
-        `backupTransactions`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_get_user_backup_address(
-        `coin`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_handle_deposit_msg_1_response(
-        `coin`: RustBuffer.ByValue,
-        `depositMsg1Response`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_is_enclave_pubkey_part_of_coin(
-        `coin`: RustBuffer.ByValue,
-        `enclavePubkey`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun uniffi_mercurylib_fn_func_latest_backup_tx_pays_to_user_pubkey(
-        `backupTxs`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_new_backup_transaction(
-        `encodedUnsignedTx`: RustBuffer.ByValue,
-        `signatureHex`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_sign_message(
-        `message`: RustBuffer.ByValue,
-        `coin`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun uniffi_mercurylib_fn_func_validate_address(
-        `address`: RustBuffer.ByValue,
-        `network`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun uniffi_mercurylib_fn_func_verify_blinded_musig_scheme(
-        `backupTx`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `statechainInfo`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
 
-    fun uniffi_mercurylib_fn_func_verify_transaction_signature(
-        `txNHex`: RustBuffer.ByValue,
-        `tx0Hex`: RustBuffer.ByValue,
-        `feeRateTolerance`: Int,
-        `currentFeeRateSatsPerByte`: Int,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
 
-    fun ffi_mercurylib_rustbuffer_alloc(
-        `size`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun ffi_mercurylib_rustbuffer_from_bytes(
-        `bytes`: ForeignBytes.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun ffi_mercurylib_rustbuffer_free(
-        `buf`: RustBuffer.ByValue,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
 
-    fun ffi_mercurylib_rustbuffer_reserve(
-        `buf`: RustBuffer.ByValue,
-        `additional`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun ffi_mercurylib_rust_future_poll_u8(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_u8(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_u8(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_u8(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun ffi_mercurylib_rust_future_poll_i8(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_i8(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_i8(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_i8(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Byte
 
-    fun ffi_mercurylib_rust_future_poll_u16(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_u16(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_u16(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_u16(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Short
 
-    fun ffi_mercurylib_rust_future_poll_i16(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_i16(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_i16(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_i16(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Short
 
-    fun ffi_mercurylib_rust_future_poll_u32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_u32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_u32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_u32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    fun ffi_mercurylib_rust_future_poll_i32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_i32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_i32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_i32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Int
 
-    fun ffi_mercurylib_rust_future_poll_u64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_u64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_u64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_u64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Long
 
-    fun ffi_mercurylib_rust_future_poll_i64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_i64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_i64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_i64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Long
 
-    fun ffi_mercurylib_rust_future_poll_f32(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_f32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_f32(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_f32(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Float
 
-    fun ffi_mercurylib_rust_future_poll_f64(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_f64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_f64(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_f64(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Double
 
-    fun ffi_mercurylib_rust_future_poll_pointer(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_pointer(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_pointer(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_pointer(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Pointer
 
-    fun ffi_mercurylib_rust_future_poll_rust_buffer(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_rust_buffer(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_rust_buffer(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_rust_buffer(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): RustBuffer.ByValue
 
-    fun ffi_mercurylib_rust_future_poll_void(
-        `handle`: Long,
-        `callback`: UniffiRustFutureContinuationCallback,
-        `callbackData`: Long,
-    ): Unit
 
-    fun ffi_mercurylib_rust_future_cancel_void(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_free_void(`handle`: Long): Unit
 
-    fun ffi_mercurylib_rust_future_complete_void(
-        `handle`: Long,
-        uniffi_out_err: UniffiRustCallStatus,
-    ): Unit
 
-    fun uniffi_mercurylib_checksum_func_create_aggregated_address(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_and_commit_nonces(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_cpfp_tx(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_deposit_msg1(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_signature(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_transfer_signature(): Short
 
-    fun uniffi_mercurylib_checksum_func_create_transfer_update_msg(): Short
 
-    fun uniffi_mercurylib_checksum_func_decode_statechain_address(): Short
 
-    fun uniffi_mercurylib_checksum_func_duplicate_coin_to_initialized_state(): Short
 
-    fun uniffi_mercurylib_checksum_func_ffi_validate_signature_scheme(): Short
 
-    fun uniffi_mercurylib_checksum_func_ffi_verify_transfer_signature(): Short
 
-    fun uniffi_mercurylib_checksum_func_fii_create_transfer_receiver_request_payload(): Short
 
-    fun uniffi_mercurylib_checksum_func_fii_decrypt_transfer_msg(): Short
 
-    fun uniffi_mercurylib_checksum_func_fii_validate_tx0_output_pubkey(): Short
 
-    fun uniffi_mercurylib_checksum_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(): Short
 
-    fun uniffi_mercurylib_checksum_func_generate_mnemonic(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_blockheight(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_new_coin(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_new_key_info(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_output_address_from_tx0(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_partial_sig_request(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_tx0_outpoint(): Short
 
-    fun uniffi_mercurylib_checksum_func_get_user_backup_address(): Short
 
-    fun uniffi_mercurylib_checksum_func_handle_deposit_msg_1_response(): Short
 
-    fun uniffi_mercurylib_checksum_func_is_enclave_pubkey_part_of_coin(): Short
 
-    fun uniffi_mercurylib_checksum_func_latest_backup_tx_pays_to_user_pubkey(): Short
 
-    fun uniffi_mercurylib_checksum_func_new_backup_transaction(): Short
 
-    fun uniffi_mercurylib_checksum_func_sign_message(): Short
 
-    fun uniffi_mercurylib_checksum_func_validate_address(): Short
 
-    fun uniffi_mercurylib_checksum_func_verify_blinded_musig_scheme(): Short
 
-    fun uniffi_mercurylib_checksum_func_verify_transaction_signature(): Short
 
-    fun ffi_mercurylib_uniffi_contract_version(): Int
-}
 
-private fun uniffiCheckContractApiVersion(lib: UniffiLib) {
-    // Get the bindings contract version from our ComponentInterface
-    val bindings_contract_version = 26
-    // Get the scaffolding contract version by calling the into the dylib
-    val scaffolding_contract_version = lib.ffi_mercurylib_uniffi_contract_version()
-    if (bindings_contract_version != scaffolding_contract_version) {
-        throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
-    }
-}
 
-@Suppress("UNUSED_PARAMETER")
-private fun uniffiCheckApiChecksums(lib: UniffiLib) {
-    if (lib.uniffi_mercurylib_checksum_func_create_aggregated_address() != 44269.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_and_commit_nonces() != 16584.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_cpfp_tx() != 63811.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_deposit_msg1() != 9767.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_signature() != 53021.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_transfer_signature() != 61677.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_create_transfer_update_msg() != 6918.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_decode_statechain_address() != 7125.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_duplicate_coin_to_initialized_state() != 30591.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_ffi_validate_signature_scheme() != 26248.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_ffi_verify_transfer_signature() != 18534.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_fii_create_transfer_receiver_request_payload() != 58308.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_fii_decrypt_transfer_msg() != 44515.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_fii_validate_tx0_output_pubkey() != 51706.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_fii_verify_latest_backup_tx_pays_to_user_pubkey() != 46083.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_generate_mnemonic() != 62910.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_blockheight() != 5222.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_new_coin() != 45841.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_new_key_info() != 64987.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_output_address_from_tx0() != 62309.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_partial_sig_request() != 13111.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_tx0_outpoint() != 21467.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_get_user_backup_address() != 29075.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_handle_deposit_msg_1_response() != 64110.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_is_enclave_pubkey_part_of_coin() != 37041.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_latest_backup_tx_pays_to_user_pubkey() != 19689.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_new_backup_transaction() != 56642.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_sign_message() != 9994.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_validate_address() != 16334.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_verify_blinded_musig_scheme() != 42963.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-    if (lib.uniffi_mercurylib_checksum_func_verify_transaction_signature() != 32006.toShort()) {
-        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
-    }
-}
 
-// Async support
 
-// Public interface members begin here.
 
-// Interface implemented by anything that can contain an object reference.
-//
-// Such types expose a `destroy()` method that must be called to cleanly
-// dispose of the contained objects. Failure to call this method may result
-// in memory leaks.
-//
-// The easiest way to ensure this method is called is to use the `.use`
-// helper method to execute a block and destroy the object at the end.
-interface Disposable {
-    fun destroy()
 
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+
+// A JNA Library to expose the extern-C FFI definitions.
+// This is an implementation detail which will be called internally by the public API.
+
+internal interface UniffiLib : Library {
     companion object {
-        fun destroy(vararg args: Any?) {
-            args.filterIsInstance<Disposable>()
-                .forEach(Disposable::destroy)
+        internal val INSTANCE: UniffiLib by lazy {
+            loadIndirect<UniffiLib>(componentName = "mercurylib")
+            .also { lib: UniffiLib ->
+                uniffiCheckContractApiVersion(lib)
+                uniffiCheckApiChecksums(lib)
+                }
         }
+        
+    }
+
+    fun uniffi_mercurylib_fn_func_add_server_partial_sig_to_psbt(`coin`: RustBuffer.ByValue,`psbt`: RustBuffer.ByValue,`serverPartialSigHex`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_aggregated_address(`coin`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_and_commit_nonces(`coin`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_backup_tx_psbt(`coin`: RustBuffer.ByValue,`blockHeight`: Int,`initlock`: Int,`interval`: Int,`feeRateSatsPerByte`: Double,`qtBackupTx`: Int,`toAddress`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,`isWithdrawal`: Byte,`anchorType`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_cpfp_package_tx(`parents`: RustBuffer.ByValue,`extraInputs`: RustBuffer.ByValue,`toAddress`: RustBuffer.ByValue,`feeRateSatsPerByte`: Double,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_cpfp_tx(`backupTx`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,`toAddress`: RustBuffer.ByValue,`feeRateSatsPerByte`: Double,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_deposit_msg1(`coin`: RustBuffer.ByValue,`tokenId`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_ownership_proof(`coin`: RustBuffer.ByValue,`challenge`: RustBuffer.ByValue,`timestamp`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_signature(`msg`: RustBuffer.ByValue,`clientPartialSigHex`: RustBuffer.ByValue,`serverPartialSigHex`: RustBuffer.ByValue,`sessionHex`: RustBuffer.ByValue,`outputPubkeyHex`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_statechain_lookup_request(`coin`: RustBuffer.ByValue,`timestamp`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_transfer_signature(`recipientAddress`: RustBuffer.ByValue,`inputTxid`: RustBuffer.ByValue,`inputVout`: Int,`clientSeckey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_transfer_update_msg(`x1`: RustBuffer.ByValue,`recipientAddress`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,`transferSignature`: RustBuffer.ByValue,`backupTransactions`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_watchtower_package(`coin`: RustBuffer.ByValue,`backupTransactions`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_create_withdrawal_psbt(`coin`: RustBuffer.ByValue,`blockHeight`: Int,`feeRateSatsPerByte`: Double,`outputs`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_decode_api_error(`statusCode`: Short,`body`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_decode_statechain_address(`scAddress`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_duplicate_coin_to_initialized_state(`wallet`: RustBuffer.ByValue,`authPubkey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_export_watchtower_package(`package`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_ffi_verify_transfer_signature(`newUserPubkey`: RustBuffer.ByValue,`tx0Outpoint`: RustBuffer.ByValue,`ffiTransferMsg`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_fii_create_transfer_receiver_request_payload(`statechainInfo`: RustBuffer.ByValue,`ffiTransferMsg`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_fii_decrypt_transfer_msg(`encryptedMessage`: RustBuffer.ByValue,`privateKeyWif`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_fii_validate_tx0_output_pubkey(`enclavePublicKey`: RustBuffer.ByValue,`ffiTransferMsg`: RustBuffer.ByValue,`tx0Outpoint`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(`ffiTransferMsg`: RustBuffer.ByValue,`clientPubkeyShare`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_finalize_psbt(`psbt`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_generate_mnemonic(uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_amount_from_tx0(`tx0Hex`: RustBuffer.ByValue,`tx0Outpoint`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Long
+    fun uniffi_mercurylib_fn_func_get_blockheight(`bkpTx`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Int
+    fun uniffi_mercurylib_fn_func_get_coin_at_index(`wallet`: RustBuffer.ByValue,`addressIndex`: Int,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_coin_descriptor(`coin`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_new_coin(`wallet`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_new_coin_with_address_options(`wallet`: RustBuffer.ByValue,`addressOptions`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_new_key_info(`serverPublicKeyHex`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,`statechainId`: RustBuffer.ByValue,`tx0Outpoint`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_output_address_from_tx0(`tx0Outpoint`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_partial_sig_request(`coin`: RustBuffer.ByValue,`blockHeight`: Int,`initlock`: Int,`interval`: Int,`feeRateSatsPerByte`: Double,`qtBackupTx`: Int,`toAddress`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,`isWithdrawal`: Byte,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_partial_sig_request_from_psbt(`coin`: RustBuffer.ByValue,`psbt`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_partial_sig_request_with_anchor(`coin`: RustBuffer.ByValue,`blockHeight`: Int,`initlock`: Int,`interval`: Int,`feeRateSatsPerByte`: Double,`qtBackupTx`: Int,`toAddress`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,`isWithdrawal`: Byte,`anchorType`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_previous_outpoint(`backupTx`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_signature_scheme_validation_report(`backupTransactions`: RustBuffer.ByValue,`statechainInfo`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`currentBlockheight`: Int,`feeRateTolerance`: Double,`currentFeeRateSatsPerByte`: Double,`lockheightInit`: Int,`interval`: Int,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_tx0_outpoint(`backupTransactions`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_user_backup_address(`coin`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_wallet_descriptors(`wallet`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_get_withdrawal_partial_sig_request(`coin`: RustBuffer.ByValue,`blockHeight`: Int,`feeRateSatsPerByte`: Double,`outputs`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_handle_deposit_msg_1_response(`coin`: RustBuffer.ByValue,`depositMsg1Response`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_import_watchtower_package(`packageJson`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_is_enclave_pubkey_part_of_coin(`coin`: RustBuffer.ByValue,`enclavePubkey`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_latest_backup_tx_pays_to_user_pubkey(`backupTxs`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_new_backup_transaction(`encodedUnsignedTx`: RustBuffer.ByValue,`signatureHex`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_sign_message(`message`: RustBuffer.ByValue,`coin`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_sign_psbt(`coin`: RustBuffer.ByValue,`psbt`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_validate_address(`address`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_validate_address_at(`address`: RustBuffer.ByValue,`network`: RustBuffer.ByValue,`currentTime`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun uniffi_mercurylib_fn_func_validate_signature_scheme(`backupTransactions`: RustBuffer.ByValue,`statechainInfo`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`currentBlockheight`: Int,`feeRateTolerance`: Double,`currentFeeRateSatsPerByte`: Double,`lockheightInit`: Int,`interval`: Int,uniffi_out_err: UniffiRustCallStatus, 
+    ): Int
+    fun uniffi_mercurylib_fn_func_verify_blinded_musig_scheme(`backupTx`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`statechainInfo`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_mercurylib_fn_func_verify_key_rotation(`coin`: RustBuffer.ByValue,`previousServerPubkey`: RustBuffer.ByValue,`keylist`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun uniffi_mercurylib_fn_func_verify_ownership_proof(`proof`: RustBuffer.ByValue,`challenge`: RustBuffer.ByValue,`statechainOwner`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`currentTime`: Long,`maxAge`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_mercurylib_fn_func_verify_statechain_lookup_request(`payload`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_mercurylib_fn_func_verify_transaction_sequence(`txNHex`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_mercurylib_fn_func_verify_transaction_signature(`txNHex`: RustBuffer.ByValue,`tx0Hex`: RustBuffer.ByValue,`feeRateTolerance`: Double,`currentFeeRateSatsPerByte`: Double,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_mercurylib_fn_func_verify_watchtower_package(`package`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun ffi_mercurylib_rustbuffer_alloc(`size`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun ffi_mercurylib_rustbuffer_from_bytes(`bytes`: ForeignBytes.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun ffi_mercurylib_rustbuffer_free(`buf`: RustBuffer.ByValue,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun ffi_mercurylib_rustbuffer_reserve(`buf`: RustBuffer.ByValue,`additional`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun ffi_mercurylib_rust_future_poll_u8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_u8(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_u8(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_u8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun ffi_mercurylib_rust_future_poll_i8(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_i8(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_i8(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_i8(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Byte
+    fun ffi_mercurylib_rust_future_poll_u16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_u16(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_u16(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_u16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Short
+    fun ffi_mercurylib_rust_future_poll_i16(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_i16(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_i16(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_i16(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Short
+    fun ffi_mercurylib_rust_future_poll_u32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_u32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_u32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_u32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Int
+    fun ffi_mercurylib_rust_future_poll_i32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_i32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_i32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_i32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Int
+    fun ffi_mercurylib_rust_future_poll_u64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_u64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_u64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_u64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Long
+    fun ffi_mercurylib_rust_future_poll_i64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_i64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_i64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_i64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Long
+    fun ffi_mercurylib_rust_future_poll_f32(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_f32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_f32(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_f32(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Float
+    fun ffi_mercurylib_rust_future_poll_f64(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_f64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_f64(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_f64(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Double
+    fun ffi_mercurylib_rust_future_poll_pointer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_pointer(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_pointer(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_pointer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Pointer
+    fun ffi_mercurylib_rust_future_poll_rust_buffer(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_rust_buffer(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_rust_buffer(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_rust_buffer(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): RustBuffer.ByValue
+    fun ffi_mercurylib_rust_future_poll_void(`handle`: Long,`callback`: UniffiRustFutureContinuationCallback,`callbackData`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_cancel_void(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_free_void(`handle`: Long,
+    ): Unit
+    fun ffi_mercurylib_rust_future_complete_void(`handle`: Long,uniffi_out_err: UniffiRustCallStatus, 
+    ): Unit
+    fun uniffi_mercurylib_checksum_func_add_server_partial_sig_to_psbt(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_aggregated_address(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_and_commit_nonces(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_backup_tx_psbt(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_cpfp_package_tx(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_cpfp_tx(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_deposit_msg1(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_ownership_proof(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_signature(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_statechain_lookup_request(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_transfer_signature(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_transfer_update_msg(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_watchtower_package(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_create_withdrawal_psbt(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_decode_api_error(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_decode_statechain_address(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_duplicate_coin_to_initialized_state(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_export_watchtower_package(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_ffi_verify_transfer_signature(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_fii_create_transfer_receiver_request_payload(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_fii_decrypt_transfer_msg(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_fii_validate_tx0_output_pubkey(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_finalize_psbt(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_generate_mnemonic(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_amount_from_tx0(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_blockheight(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_coin_at_index(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_coin_descriptor(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_new_coin(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_new_coin_with_address_options(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_new_key_info(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_output_address_from_tx0(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_partial_sig_request(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_partial_sig_request_from_psbt(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_partial_sig_request_with_anchor(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_previous_outpoint(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_signature_scheme_validation_report(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_tx0_outpoint(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_user_backup_address(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_wallet_descriptors(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_get_withdrawal_partial_sig_request(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_handle_deposit_msg_1_response(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_import_watchtower_package(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_is_enclave_pubkey_part_of_coin(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_latest_backup_tx_pays_to_user_pubkey(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_new_backup_transaction(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_sign_message(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_sign_psbt(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_validate_address(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_validate_address_at(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_validate_signature_scheme(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_blinded_musig_scheme(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_key_rotation(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_ownership_proof(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_statechain_lookup_request(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_transaction_sequence(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_transaction_signature(
+    ): Short
+    fun uniffi_mercurylib_checksum_func_verify_watchtower_package(
+    ): Short
+    fun ffi_mercurylib_uniffi_contract_version(
+    ): Int
+    
+}
+
+private fun uniffiCheckContractApiVersion(lib: UniffiLib) {
+    // Get the bindings contract version from our ComponentInterface
+    val bindings_contract_version = 26
+    // Get the scaffolding contract version by calling the into the dylib
+    val scaffolding_contract_version = lib.ffi_mercurylib_uniffi_contract_version()
+    if (bindings_contract_version != scaffolding_contract_version) {
+        throw RuntimeException("UniFFI contract version mismatch: try cleaning and rebuilding your project")
     }
 }
 
-inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
-    try {
-        block(this)
-    } finally {
-        try {
-            // N.B. our implementation is on the nullable type `Disposable?`.
-            this?.destroy()
-        } catch (e: Throwable) {
-            // swallow
-        }
+@Suppress("UNUSED_PARAMETER")
+private fun uniffiCheckApiChecksums(lib: UniffiLib) {
+    if (lib.uniffi_mercurylib_checksum_func_add_server_partial_sig_to_psbt() != 30187.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_aggregated_address() != 44269.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_and_commit_nonces() != 16584.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_backup_tx_psbt() != 25905.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_cpfp_package_tx() != 33437.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_cpfp_tx() != 38451.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_deposit_msg1() != 9767.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_ownership_proof() != 31763.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_signature() != 53021.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_statechain_lookup_request() != 14001.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_transfer_signature() != 61677.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_transfer_update_msg() != 6918.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_watchtower_package() != 39311.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_create_withdrawal_psbt() != 11720.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_decode_api_error() != 4950.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_decode_statechain_address() != 7125.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_duplicate_coin_to_initialized_state() != 30591.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_export_watchtower_package() != 39674.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_ffi_verify_transfer_signature() != 18534.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_fii_create_transfer_receiver_request_payload() != 58308.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_fii_decrypt_transfer_msg() != 44515.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_fii_validate_tx0_output_pubkey() != 51706.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_fii_verify_latest_backup_tx_pays_to_user_pubkey() != 46083.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_finalize_psbt() != 34438.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_generate_mnemonic() != 62910.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_amount_from_tx0() != 16451.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_blockheight() != 5222.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_coin_at_index() != 61254.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_coin_descriptor() != 62524.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_new_coin() != 45841.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_new_coin_with_address_options() != 62986.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_new_key_info() != 64987.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_output_address_from_tx0() != 62309.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_partial_sig_request() != 1183.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_partial_sig_request_from_psbt() != 3655.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_partial_sig_request_with_anchor() != 43168.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_previous_outpoint() != 1788.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_signature_scheme_validation_report() != 12553.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_tx0_outpoint() != 21467.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_user_backup_address() != 29075.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_wallet_descriptors() != 48187.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_get_withdrawal_partial_sig_request() != 1741.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_handle_deposit_msg_1_response() != 64110.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_import_watchtower_package() != 6034.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_is_enclave_pubkey_part_of_coin() != 37041.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_latest_backup_tx_pays_to_user_pubkey() != 19689.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_new_backup_transaction() != 56642.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_sign_message() != 9994.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_sign_psbt() != 48958.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_validate_address() != 16334.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_validate_address_at() != 59420.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_validate_signature_scheme() != 36006.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_blinded_musig_scheme() != 42963.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_key_rotation() != 34647.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_ownership_proof() != 16085.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_statechain_lookup_request() != 16179.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_transaction_sequence() != 43154.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_transaction_signature() != 50583.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+    if (lib.uniffi_mercurylib_checksum_func_verify_watchtower_package() != 39550.toShort()) {
+        throw RuntimeException("UniFFI API checksum mismatch: try cleaning and rebuilding your project")
+    }
+}
+
+// Async support
+
+// Public interface members begin here.
+
+
+// Interface implemented by anything that can contain an object reference.
+//
+// Such types expose a `destroy()` method that must be called to cleanly
+// dispose of the contained objects. Failure to call this method may result
+// in memory leaks.
+//
+// The easiest way to ensure this method is called is to use the `.use`
+// helper method to execute a block and destroy the object at the end.
+interface Disposable {
+    fun destroy()
+    companion object {
+        fun destroy(vararg args: Any?) {
+            args.filterIsInstance<Disposable>()
+                .forEach(Disposable::destroy)
+        }
+    }
+}
+
+inline fun <T : Disposable?, R> T.use(block: (T) -> R) =
+    try {
+        block(this)
+    } finally {
+        try {
+            // N.B. our implementation is on the nullable type `Disposable?`.
+            this?.destroy()
+        } catch (e: Throwable) {
+            // swallow
+        }
+    }
+
+/** Used to instantiate an interface without an actual pointer, for fakes in tests, mostly. */
+object NoPointer
+
+public object FfiConverterUByte: FfiConverter<UByte, Byte> {
+    override fun lift(value: Byte): UByte {
+        return value.toUByte()
+    }
+
+    override fun read(buf: ByteBuffer): UByte {
+        return lift(buf.get())
+    }
+
+    override fun lower(value: UByte): Byte {
+        return value.toByte()
+    }
+
+    override fun allocationSize(value: UByte) = 1UL
+
+    override fun write(value: UByte, buf: ByteBuffer) {
+        buf.put(value.toByte())
+    }
+}
+
+public object FfiConverterUShort: FfiConverter<UShort, Short> {
+    override fun lift(value: Short): UShort {
+        return value.toUShort()
+    }
+
+    override fun read(buf: ByteBuffer): UShort {
+        return lift(buf.getShort())
+    }
+
+    override fun lower(value: UShort): Short {
+        return value.toShort()
+    }
+
+    override fun allocationSize(value: UShort) = 2UL
+
+    override fun write(value: UShort, buf: ByteBuffer) {
+        buf.putShort(value.toShort())
+    }
+}
+
+public object FfiConverterUInt: FfiConverter<UInt, Int> {
+    override fun lift(value: Int): UInt {
+        return value.toUInt()
+    }
+
+    override fun read(buf: ByteBuffer): UInt {
+        return lift(buf.getInt())
+    }
+
+    override fun lower(value: UInt): Int {
+        return value.toInt()
+    }
+
+    override fun allocationSize(value: UInt) = 4UL
+
+    override fun write(value: UInt, buf: ByteBuffer) {
+        buf.putInt(value.toInt())
+    }
+}
+
+public object FfiConverterULong: FfiConverter<ULong, Long> {
+    override fun lift(value: Long): ULong {
+        return value.toULong()
+    }
+
+    override fun read(buf: ByteBuffer): ULong {
+        return lift(buf.getLong())
+    }
+
+    override fun lower(value: ULong): Long {
+        return value.toLong()
+    }
+
+    override fun allocationSize(value: ULong) = 8UL
+
+    override fun write(value: ULong, buf: ByteBuffer) {
+        buf.putLong(value.toLong())
+    }
+}
+
+public object FfiConverterDouble: FfiConverter<Double, Double> {
+    override fun lift(value: Double): Double {
+        return value
+    }
+
+    override fun read(buf: ByteBuffer): Double {
+        return buf.getDouble()
+    }
+
+    override fun lower(value: Double): Double {
+        return value
+    }
+
+    override fun allocationSize(value: Double) = 8UL
+
+    override fun write(value: Double, buf: ByteBuffer) {
+        buf.putDouble(value)
+    }
+}
+
+public object FfiConverterBoolean: FfiConverter<Boolean, Byte> {
+    override fun lift(value: Byte): Boolean {
+        return value.toInt() != 0
+    }
+
+    override fun read(buf: ByteBuffer): Boolean {
+        return lift(buf.get())
+    }
+
+    override fun lower(value: Boolean): Byte {
+        return if (value) 1.toByte() else 0.toByte()
+    }
+
+    override fun allocationSize(value: Boolean) = 1UL
+
+    override fun write(value: Boolean, buf: ByteBuffer) {
+        buf.put(lower(value))
+    }
+}
+
+public object FfiConverterString: FfiConverter<String, RustBuffer.ByValue> {
+    // Note: we don't inherit from FfiConverterRustBuffer, because we use a
+    // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
+    // store our length and avoid writing it out to the buffer.
+    override fun lift(value: RustBuffer.ByValue): String {
+        try {
+            val byteArr = ByteArray(value.len.toInt())
+            value.asByteBuffer()!!.get(byteArr)
+            return byteArr.toString(Charsets.UTF_8)
+        } finally {
+            RustBuffer.free(value)
+        }
+    }
+
+    override fun read(buf: ByteBuffer): String {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr.toString(Charsets.UTF_8)
+    }
+
+    fun toUtf8(value: String): ByteBuffer {
+        // Make sure we don't have invalid UTF-16, check for lone surrogates.
+        return Charsets.UTF_8.newEncoder().run {
+            onMalformedInput(CodingErrorAction.REPORT)
+            encode(CharBuffer.wrap(value))
+        }
+    }
+
+    override fun lower(value: String): RustBuffer.ByValue {
+        val byteBuf = toUtf8(value)
+        // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
+        // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
+        val rbuf = RustBuffer.alloc(byteBuf.limit().toULong())
+        rbuf.asByteBuffer()!!.put(byteBuf)
+        return rbuf
+    }
+
+    // We aren't sure exactly how many bytes our string will be once it's UTF-8
+    // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
+    // enough.
+    override fun allocationSize(value: String): ULong {
+        val sizeForLength = 4UL
+        val sizeForString = value.length.toULong() * 3UL
+        return sizeForLength + sizeForString
+    }
+
+    override fun write(value: String, buf: ByteBuffer) {
+        val byteBuf = toUtf8(value)
+        buf.putInt(byteBuf.limit())
+        buf.put(byteBuf)
+    }
+}
+
+public object FfiConverterByteArray: FfiConverterRustBuffer<ByteArray> {
+    override fun read(buf: ByteBuffer): ByteArray {
+        val len = buf.getInt()
+        val byteArr = ByteArray(len)
+        buf.get(byteArr)
+        return byteArr
+    }
+    override fun allocationSize(value: ByteArray): ULong {
+        return 4UL + value.size.toULong()
+    }
+    override fun write(value: ByteArray, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        buf.put(value)
+    }
+}
+
+
+
+@Serializable
+data class Activity (
+    var `utxo`: kotlin.String, 
+    var `amount`: kotlin.ULong, 
+    var `action`: kotlin.String, 
+    var `date`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeActivity: FfiConverterRustBuffer<Activity> {
+    override fun read(buf: ByteBuffer): Activity {
+        return Activity(
+            FfiConverterString.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: Activity) = (
+            FfiConverterString.allocationSize(value.`utxo`) +
+            FfiConverterULong.allocationSize(value.`amount`) +
+            FfiConverterString.allocationSize(value.`action`) +
+            FfiConverterString.allocationSize(value.`date`)
+    )
+
+    override fun write(value: Activity, buf: ByteBuffer) {
+            FfiConverterString.write(value.`utxo`, buf)
+            FfiConverterULong.write(value.`amount`, buf)
+            FfiConverterString.write(value.`action`, buf)
+            FfiConverterString.write(value.`date`, buf)
+    }
+}
+
+
+
+@Serializable
+data class AggregatedPublicKey (
+	@SerialName("aggregate_pubkey")
+    var `aggregatePubkey`: kotlin.String, 
+	@SerialName("aggregate_address")
+    var `aggregateAddress`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeAggregatedPublicKey: FfiConverterRustBuffer<AggregatedPublicKey> {
+    override fun read(buf: ByteBuffer): AggregatedPublicKey {
+        return AggregatedPublicKey(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: AggregatedPublicKey) = (
+            FfiConverterString.allocationSize(value.`aggregatePubkey`) +
+            FfiConverterString.allocationSize(value.`aggregateAddress`)
+    )
+
+    override fun write(value: AggregatedPublicKey, buf: ByteBuffer) {
+            FfiConverterString.write(value.`aggregatePubkey`, buf)
+            FfiConverterString.write(value.`aggregateAddress`, buf)
+    }
+}
+
+
+
+/**
+ * Body of the server responses for failed requests
+ */
+data class ApiErrorResponsePayload (
+    var `code`: ApiError, 
+    var `message`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeApiErrorResponsePayload: FfiConverterRustBuffer<ApiErrorResponsePayload> {
+    override fun read(buf: ByteBuffer): ApiErrorResponsePayload {
+        return ApiErrorResponsePayload(
+            FfiConverterTypeApiError.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: ApiErrorResponsePayload) = (
+            FfiConverterTypeApiError.allocationSize(value.`code`) +
+            FfiConverterString.allocationSize(value.`message`)
+    )
+
+    override fun write(value: ApiErrorResponsePayload, buf: ByteBuffer) {
+            FfiConverterTypeApiError.write(value.`code`, buf)
+            FfiConverterString.write(value.`message`, buf)
+    }
+}
+
+
+
+@Serializable
+data class BackupTx (
+	@SerialName("tx_n")
+    var `txN`: kotlin.UInt, 
+    var `tx`: kotlin.String, 
+	@SerialName("client_public_nonce")
+    var `clientPublicNonce`: kotlin.String, 
+	@SerialName("server_public_nonce")
+    var `serverPublicNonce`: kotlin.String, 
+	@SerialName("client_public_key")
+    var `clientPublicKey`: kotlin.String, 
+	@SerialName("server_public_key")
+    var `serverPublicKey`: kotlin.String, 
+	@SerialName("blinding_factor")
+    var `blindingFactor`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeBackupTx: FfiConverterRustBuffer<BackupTx> {
+    override fun read(buf: ByteBuffer): BackupTx {
+        return BackupTx(
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: BackupTx) = (
+            FfiConverterUInt.allocationSize(value.`txN`) +
+            FfiConverterString.allocationSize(value.`tx`) +
+            FfiConverterString.allocationSize(value.`clientPublicNonce`) +
+            FfiConverterString.allocationSize(value.`serverPublicNonce`) +
+            FfiConverterString.allocationSize(value.`clientPublicKey`) +
+            FfiConverterString.allocationSize(value.`serverPublicKey`) +
+            FfiConverterString.allocationSize(value.`blindingFactor`)
+    )
+
+    override fun write(value: BackupTx, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`txN`, buf)
+            FfiConverterString.write(value.`tx`, buf)
+            FfiConverterString.write(value.`clientPublicNonce`, buf)
+            FfiConverterString.write(value.`serverPublicNonce`, buf)
+            FfiConverterString.write(value.`clientPublicKey`, buf)
+            FfiConverterString.write(value.`serverPublicKey`, buf)
+            FfiConverterString.write(value.`blindingFactor`, buf)
+    }
+}
+
+
+
+/**
+ * Result of the checks performed on a single backup transaction
+ */
+data class BackupTxValidation (
+    var `txN`: kotlin.UInt, 
+    var `signatureValid`: kotlin.Boolean, 
+    /**
+     * The blinded challenge computed from the transaction matches the one stored by the server
+     */
+    var `challengeValid`: kotlin.Boolean, 
+    var `sequenceValid`: kotlin.Boolean, 
+    /**
+     * Locktime, transaction version and output layout are acceptable
+     */
+    var `locktimeValid`: kotlin.Boolean, 
+    var `reconstructionValid`: kotlin.Boolean, 
+    /**
+     * The locktime is `interval` blocks lower than the one of the previous backup transaction
+     */
+    var `intervalValid`: kotlin.Boolean, 
+    var `lockTime`: kotlin.UInt?, 
+    /**
+     * Measured fee rate in sats/vbyte, if the transaction could be decoded
+     */
+    var `feeRate`: kotlin.Double?, 
+    var `feeRateValid`: kotlin.Boolean, 
+    /**
+     * Absolute fee in sats, if the transaction could be decoded
+     */
+    var `fee`: kotlin.ULong?, 
+    /**
+     * The absolute fee matches the weight of the signed transaction and the backup output is above dust
+     */
+    var `feeValid`: kotlin.Boolean, 
+    /**
+     * One entry per failed check
+     */
+    var `errors`: List<MercuryErrorDetails>
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeBackupTxValidation: FfiConverterRustBuffer<BackupTxValidation> {
+    override fun read(buf: ByteBuffer): BackupTxValidation {
+        return BackupTxValidation(
+            FfiConverterUInt.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalDouble.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterOptionalULong.read(buf),
+            FfiConverterBoolean.read(buf),
+            FfiConverterSequenceTypeMercuryErrorDetails.read(buf),
+        )
     }
 
-/** Used to instantiate an interface without an actual pointer, for fakes in tests, mostly. */
-object NoPointer
+    override fun allocationSize(value: BackupTxValidation) = (
+            FfiConverterUInt.allocationSize(value.`txN`) +
+            FfiConverterBoolean.allocationSize(value.`signatureValid`) +
+            FfiConverterBoolean.allocationSize(value.`challengeValid`) +
+            FfiConverterBoolean.allocationSize(value.`sequenceValid`) +
+            FfiConverterBoolean.allocationSize(value.`locktimeValid`) +
+            FfiConverterBoolean.allocationSize(value.`reconstructionValid`) +
+            FfiConverterBoolean.allocationSize(value.`intervalValid`) +
+            FfiConverterOptionalUInt.allocationSize(value.`lockTime`) +
+            FfiConverterOptionalDouble.allocationSize(value.`feeRate`) +
+            FfiConverterBoolean.allocationSize(value.`feeRateValid`) +
+            FfiConverterOptionalULong.allocationSize(value.`fee`) +
+            FfiConverterBoolean.allocationSize(value.`feeValid`) +
+            FfiConverterSequenceTypeMercuryErrorDetails.allocationSize(value.`errors`)
+    )
 
-public object FfiConverterUByte : FfiConverter<UByte, Byte> {
-    override fun lift(value: Byte): UByte {
-        return value.toUByte()
+    override fun write(value: BackupTxValidation, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`txN`, buf)
+            FfiConverterBoolean.write(value.`signatureValid`, buf)
+            FfiConverterBoolean.write(value.`challengeValid`, buf)
+            FfiConverterBoolean.write(value.`sequenceValid`, buf)
+            FfiConverterBoolean.write(value.`locktimeValid`, buf)
+            FfiConverterBoolean.write(value.`reconstructionValid`, buf)
+            FfiConverterBoolean.write(value.`intervalValid`, buf)
+            FfiConverterOptionalUInt.write(value.`lockTime`, buf)
+            FfiConverterOptionalDouble.write(value.`feeRate`, buf)
+            FfiConverterBoolean.write(value.`feeRateValid`, buf)
+            FfiConverterOptionalULong.write(value.`fee`, buf)
+            FfiConverterBoolean.write(value.`feeValid`, buf)
+            FfiConverterSequenceTypeMercuryErrorDetails.write(value.`errors`, buf)
     }
+}
 
-    override fun read(buf: ByteBuffer): UByte {
-        return lift(buf.get())
-    }
 
-    override fun lower(value: UByte): Byte {
-        return value.toByte()
+
+@Serializable
+data class Coin (
+    var `index`: kotlin.UInt, 
+	@SerialName("user_privkey")
+    var `userPrivkey`: kotlin.String, 
+	@SerialName("user_pubkey")
+    var `userPubkey`: kotlin.String, 
+	@SerialName("auth_privkey")
+    var `authPrivkey`: kotlin.String, 
+	@SerialName("auth_pubkey")
+    var `authPubkey`: kotlin.String, 
+	@SerialName("derivation_path")
+    var `derivationPath`: kotlin.String, 
+    var `fingerprint`: kotlin.String, 
+    /**
+     * The coin address is the user_pubkey || auth_pubkey
+     * Used to transfer the coin to another wallet
+     */
+    var `address`: kotlin.String, 
+    /**
+     * The backup address is the address used in backup transactions
+     * The backup address is the p2tr address of the user_pubkey
+     */
+	@SerialName("backup_address")
+    var `backupAddress`: kotlin.String, 
+	@SerialName("server_pubkey")
+    var `serverPubkey`: kotlin.String?, 
+	@SerialName("aggregated_pubkey")
+    var `aggregatedPubkey`: kotlin.String?, 
+    /**
+     * The aggregated address is the P2TR address from aggregated_pubkey
+     */
+	@SerialName("aggregated_address")
+    var `aggregatedAddress`: kotlin.String?, 
+	@SerialName("utxo_txid")
+    var `utxoTxid`: kotlin.String?, 
+	@SerialName("utxo_vout")
+    var `utxoVout`: kotlin.UInt?, 
+    var `amount`: kotlin.ULong?, 
+	@SerialName("statechain_id")
+    var `statechainId`: kotlin.String?, 
+	@SerialName("signed_statechain_id")
+    var `signedStatechainId`: kotlin.String?, 
+    var `locktime`: kotlin.UInt?, 
+	@SerialName("secret_nonce")
+    var `secretNonce`: kotlin.String?, 
+	@SerialName("public_nonce")
+    var `publicNonce`: kotlin.String?, 
+	@SerialName("blinding_factor")
+    var `blindingFactor`: kotlin.String?, 
+	@SerialName("server_public_nonce")
+    var `serverPublicNonce`: kotlin.String?, 
+	@SerialName("tx_cpfp")
+    var `txCpfp`: kotlin.String?, 
+	@SerialName("tx_withdraw")
+    var `txWithdraw`: kotlin.String?, 
+	@SerialName("withdrawal_address")
+    var `withdrawalAddress`: kotlin.String?, 
+    var `status`: CoinStatus, 
+	@SerialName("duplicate_index")
+    var `duplicateIndex`: kotlin.UInt, 
+    /**
+     * Checks, made when the coin was received, that the server share of the previous owner was rotated
+     */
+	@SerialName("key_rotations")
+    var `keyRotations`: List<KeyRotationEvidence>
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeCoin: FfiConverterRustBuffer<Coin> {
+    override fun read(buf: ByteBuffer): Coin {
+        return Coin(
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalULong.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterTypeCoinStatus.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterSequenceTypeKeyRotationEvidence.read(buf),
+        )
     }
 
-    override fun allocationSize(value: UByte) = 1UL
+    override fun allocationSize(value: Coin) = (
+            FfiConverterUInt.allocationSize(value.`index`) +
+            FfiConverterString.allocationSize(value.`userPrivkey`) +
+            FfiConverterString.allocationSize(value.`userPubkey`) +
+            FfiConverterString.allocationSize(value.`authPrivkey`) +
+            FfiConverterString.allocationSize(value.`authPubkey`) +
+            FfiConverterString.allocationSize(value.`derivationPath`) +
+            FfiConverterString.allocationSize(value.`fingerprint`) +
+            FfiConverterString.allocationSize(value.`address`) +
+            FfiConverterString.allocationSize(value.`backupAddress`) +
+            FfiConverterOptionalString.allocationSize(value.`serverPubkey`) +
+            FfiConverterOptionalString.allocationSize(value.`aggregatedPubkey`) +
+            FfiConverterOptionalString.allocationSize(value.`aggregatedAddress`) +
+            FfiConverterOptionalString.allocationSize(value.`utxoTxid`) +
+            FfiConverterOptionalUInt.allocationSize(value.`utxoVout`) +
+            FfiConverterOptionalULong.allocationSize(value.`amount`) +
+            FfiConverterOptionalString.allocationSize(value.`statechainId`) +
+            FfiConverterOptionalString.allocationSize(value.`signedStatechainId`) +
+            FfiConverterOptionalUInt.allocationSize(value.`locktime`) +
+            FfiConverterOptionalString.allocationSize(value.`secretNonce`) +
+            FfiConverterOptionalString.allocationSize(value.`publicNonce`) +
+            FfiConverterOptionalString.allocationSize(value.`blindingFactor`) +
+            FfiConverterOptionalString.allocationSize(value.`serverPublicNonce`) +
+            FfiConverterOptionalString.allocationSize(value.`txCpfp`) +
+            FfiConverterOptionalString.allocationSize(value.`txWithdraw`) +
+            FfiConverterOptionalString.allocationSize(value.`withdrawalAddress`) +
+            FfiConverterTypeCoinStatus.allocationSize(value.`status`) +
+            FfiConverterUInt.allocationSize(value.`duplicateIndex`) +
+            FfiConverterSequenceTypeKeyRotationEvidence.allocationSize(value.`keyRotations`)
+    )
 
-    override fun write(
-        value: UByte,
-        buf: ByteBuffer,
-    ) {
-        buf.put(value.toByte())
+    override fun write(value: Coin, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`index`, buf)
+            FfiConverterString.write(value.`userPrivkey`, buf)
+            FfiConverterString.write(value.`userPubkey`, buf)
+            FfiConverterString.write(value.`authPrivkey`, buf)
+            FfiConverterString.write(value.`authPubkey`, buf)
+            FfiConverterString.write(value.`derivationPath`, buf)
+            FfiConverterString.write(value.`fingerprint`, buf)
+            FfiConverterString.write(value.`address`, buf)
+            FfiConverterString.write(value.`backupAddress`, buf)
+            FfiConverterOptionalString.write(value.`serverPubkey`, buf)
+            FfiConverterOptionalString.write(value.`aggregatedPubkey`, buf)
+            FfiConverterOptionalString.write(value.`aggregatedAddress`, buf)
+            FfiConverterOptionalString.write(value.`utxoTxid`, buf)
+            FfiConverterOptionalUInt.write(value.`utxoVout`, buf)
+            FfiConverterOptionalULong.write(value.`amount`, buf)
+            FfiConverterOptionalString.write(value.`statechainId`, buf)
+            FfiConverterOptionalString.write(value.`signedStatechainId`, buf)
+            FfiConverterOptionalUInt.write(value.`locktime`, buf)
+            FfiConverterOptionalString.write(value.`secretNonce`, buf)
+            FfiConverterOptionalString.write(value.`publicNonce`, buf)
+            FfiConverterOptionalString.write(value.`blindingFactor`, buf)
+            FfiConverterOptionalString.write(value.`serverPublicNonce`, buf)
+            FfiConverterOptionalString.write(value.`txCpfp`, buf)
+            FfiConverterOptionalString.write(value.`txWithdraw`, buf)
+            FfiConverterOptionalString.write(value.`withdrawalAddress`, buf)
+            FfiConverterTypeCoinStatus.write(value.`status`, buf)
+            FfiConverterUInt.write(value.`duplicateIndex`, buf)
+            FfiConverterSequenceTypeKeyRotationEvidence.write(value.`keyRotations`, buf)
     }
 }
 
-public object FfiConverterUInt : FfiConverter<UInt, Int> {
-    override fun lift(value: Int): UInt {
-        return value.toUInt()
-    }
 
-    override fun read(buf: ByteBuffer): UInt {
-        return lift(buf.getInt())
-    }
 
-    override fun lower(value: UInt): Int {
-        return value.toInt()
+/**
+ * Output descriptor of the backup address of a coin
+ */
+data class CoinDescriptor (
+    var `index`: kotlin.UInt, 
+    var `statechainId`: kotlin.String?, 
+    var `status`: CoinStatus, 
+    var `backupAddress`: kotlin.String, 
+    /**
+     * `tr([fingerprint/86h/coin_type/account/0/index]xonly_pubkey)#checksum`
+     */
+    var `descriptor`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeCoinDescriptor: FfiConverterRustBuffer<CoinDescriptor> {
+    override fun read(buf: ByteBuffer): CoinDescriptor {
+        return CoinDescriptor(
+            FfiConverterUInt.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterTypeCoinStatus.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+        )
     }
 
-    override fun allocationSize(value: UInt) = 4UL
+    override fun allocationSize(value: CoinDescriptor) = (
+            FfiConverterUInt.allocationSize(value.`index`) +
+            FfiConverterOptionalString.allocationSize(value.`statechainId`) +
+            FfiConverterTypeCoinStatus.allocationSize(value.`status`) +
+            FfiConverterString.allocationSize(value.`backupAddress`) +
+            FfiConverterString.allocationSize(value.`descriptor`)
+    )
 
-    override fun write(
-        value: UInt,
-        buf: ByteBuffer,
-    ) {
-        buf.putInt(value.toInt())
+    override fun write(value: CoinDescriptor, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`index`, buf)
+            FfiConverterOptionalString.write(value.`statechainId`, buf)
+            FfiConverterTypeCoinStatus.write(value.`status`, buf)
+            FfiConverterString.write(value.`backupAddress`, buf)
+            FfiConverterString.write(value.`descriptor`, buf)
     }
 }
 
-public object FfiConverterULong : FfiConverter<ULong, Long> {
-    override fun lift(value: Long): ULong {
-        return value.toULong()
-    }
 
-    override fun read(buf: ByteBuffer): ULong {
-        return lift(buf.getLong())
-    }
 
-    override fun lower(value: ULong): Long {
-        return value.toLong()
+data class CoinNonce (
+    var `secretNonce`: kotlin.String, 
+    var `publicNonce`: kotlin.String, 
+    var `blindingFactor`: kotlin.String, 
+    var `signFirstRequestPayload`: SignFirstRequestPayload
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeCoinNonce: FfiConverterRustBuffer<CoinNonce> {
+    override fun read(buf: ByteBuffer): CoinNonce {
+        return CoinNonce(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterTypeSignFirstRequestPayload.read(buf),
+        )
     }
 
-    override fun allocationSize(value: ULong) = 8UL
+    override fun allocationSize(value: CoinNonce) = (
+            FfiConverterString.allocationSize(value.`secretNonce`) +
+            FfiConverterString.allocationSize(value.`publicNonce`) +
+            FfiConverterString.allocationSize(value.`blindingFactor`) +
+            FfiConverterTypeSignFirstRequestPayload.allocationSize(value.`signFirstRequestPayload`)
+    )
 
-    override fun write(
-        value: ULong,
-        buf: ByteBuffer,
-    ) {
-        buf.putLong(value.toLong())
+    override fun write(value: CoinNonce, buf: ByteBuffer) {
+            FfiConverterString.write(value.`secretNonce`, buf)
+            FfiConverterString.write(value.`publicNonce`, buf)
+            FfiConverterString.write(value.`blindingFactor`, buf)
+            FfiConverterTypeSignFirstRequestPayload.write(value.`signFirstRequestPayload`, buf)
     }
 }
 
-public object FfiConverterBoolean : FfiConverter<Boolean, Byte> {
-    override fun lift(value: Byte): Boolean {
-        return value.toInt() != 0
+
+
+class CoinStatusParseError {
+    override fun equals(other: Any?): Boolean {
+        return other is CoinStatusParseError
     }
 
-    override fun read(buf: ByteBuffer): Boolean {
-        return lift(buf.get())
+    override fun hashCode(): Int {
+        return javaClass.hashCode()
     }
 
-    override fun lower(value: Boolean): Byte {
-        return if (value) 1.toByte() else 0.toByte()
+    companion object
+}
+
+public object FfiConverterTypeCoinStatusParseError: FfiConverterRustBuffer<CoinStatusParseError> {
+    override fun read(buf: ByteBuffer): CoinStatusParseError {
+        return CoinStatusParseError()
     }
 
-    override fun allocationSize(value: Boolean) = 1UL
+    override fun allocationSize(value: CoinStatusParseError) = 0UL
 
-    override fun write(
-        value: Boolean,
-        buf: ByteBuffer,
-    ) {
-        buf.put(lower(value))
+    override fun write(value: CoinStatusParseError, buf: ByteBuffer) {
     }
 }
 
-public object FfiConverterString : FfiConverter<String, RustBuffer.ByValue> {
-    // Note: we don't inherit from FfiConverterRustBuffer, because we use a
-    // special encoding when lowering/lifting.  We can use `RustBuffer.len` to
-    // store our length and avoid writing it out to the buffer.
-    override fun lift(value: RustBuffer.ByValue): String {
-        try {
-            val byteArr = ByteArray(value.len.toInt())
-            value.asByteBuffer()!!.get(byteArr)
-            return byteArr.toString(Charsets.UTF_8)
-        } finally {
-            RustBuffer.free(value)
-        }
-    }
 
-    override fun read(buf: ByteBuffer): String {
-        val len = buf.getInt()
-        val byteArr = ByteArray(len)
-        buf.get(byteArr)
-        return byteArr.toString(Charsets.UTF_8)
-    }
 
-    fun toUtf8(value: String): ByteBuffer {
-        // Make sure we don't have invalid UTF-16, check for lone surrogates.
-        return Charsets.UTF_8.newEncoder().run {
-            onMalformedInput(CodingErrorAction.REPORT)
-            encode(CharBuffer.wrap(value))
-        }
+/**
+ * An additional P2TR (key path) UTXO used to fund the child when the backup outputs
+ * alone cannot pay for the target package fee rate.
+ */
+data class CpfpExtraInput (
+    var `txid`: kotlin.String, 
+    var `vout`: kotlin.UInt, 
+    var `amount`: kotlin.ULong, 
+    var `privkeyWif`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeCpfpExtraInput: FfiConverterRustBuffer<CpfpExtraInput> {
+    override fun read(buf: ByteBuffer): CpfpExtraInput {
+        return CpfpExtraInput(
+            FfiConverterString.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterString.read(buf),
+        )
     }
 
-    override fun lower(value: String): RustBuffer.ByValue {
-        val byteBuf = toUtf8(value)
-        // Ideally we'd pass these bytes to `ffi_bytebuffer_from_bytes`, but doing so would require us
-        // to copy them into a JNA `Memory`. So we might as well directly copy them into a `RustBuffer`.
-        val rbuf = RustBuffer.alloc(byteBuf.limit().toULong())
-        rbuf.asByteBuffer()!!.put(byteBuf)
-        return rbuf
+    override fun allocationSize(value: CpfpExtraInput) = (
+            FfiConverterString.allocationSize(value.`txid`) +
+            FfiConverterUInt.allocationSize(value.`vout`) +
+            FfiConverterULong.allocationSize(value.`amount`) +
+            FfiConverterString.allocationSize(value.`privkeyWif`)
+    )
+
+    override fun write(value: CpfpExtraInput, buf: ByteBuffer) {
+            FfiConverterString.write(value.`txid`, buf)
+            FfiConverterUInt.write(value.`vout`, buf)
+            FfiConverterULong.write(value.`amount`, buf)
+            FfiConverterString.write(value.`privkeyWif`, buf)
     }
+}
 
-    // We aren't sure exactly how many bytes our string will be once it's UTF-8
-    // encoded.  Allocate 3 bytes per UTF-16 code unit which will always be
-    // enough.
-    override fun allocationSize(value: String): ULong {
-        val sizeForLength = 4UL
-        val sizeForString = value.length.toULong() * 3UL
-        return sizeForLength + sizeForString
+
+
+/**
+ * A stuck backup transaction to be fee-bumped, together with the coin it belongs to.
+ * The coin is required to know the value of the Tx0 output (and therefore the fee paid by
+ * the backup transaction) and the key that can spend the backup output.
+ */
+data class CpfpParent (
+    var `backupTx`: BackupTx, 
+    var `coin`: Coin
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeCpfpParent: FfiConverterRustBuffer<CpfpParent> {
+    override fun read(buf: ByteBuffer): CpfpParent {
+        return CpfpParent(
+            FfiConverterTypeBackupTx.read(buf),
+            FfiConverterTypeCoin.read(buf),
+        )
     }
 
-    override fun write(
-        value: String,
-        buf: ByteBuffer,
-    ) {
-        val byteBuf = toUtf8(value)
-        buf.putInt(byteBuf.limit())
-        buf.put(byteBuf)
+    override fun allocationSize(value: CpfpParent) = (
+            FfiConverterTypeBackupTx.allocationSize(value.`backupTx`) +
+            FfiConverterTypeCoin.allocationSize(value.`coin`)
+    )
+
+    override fun write(value: CpfpParent, buf: ByteBuffer) {
+            FfiConverterTypeBackupTx.write(value.`backupTx`, buf)
+            FfiConverterTypeCoin.write(value.`coin`, buf)
     }
 }
 
-public object FfiConverterByteArray : FfiConverterRustBuffer<ByteArray> {
-    override fun read(buf: ByteBuffer): ByteArray {
-        val len = buf.getInt()
-        val byteArr = ByteArray(len)
-        buf.get(byteArr)
-        return byteArr
-    }
 
-    override fun allocationSize(value: ByteArray): ULong {
-        return 4UL + value.size.toULong()
+
+data class DecodedScAddress (
+    var `version`: kotlin.UByte, 
+    var `userPubkey`: kotlin.String, 
+    var `authPubkey`: kotlin.String, 
+    var `network`: kotlin.String?, 
+    var `serverHint`: kotlin.String?, 
+    var `expiry`: kotlin.ULong?
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeDecodedSCAddress: FfiConverterRustBuffer<DecodedScAddress> {
+    override fun read(buf: ByteBuffer): DecodedScAddress {
+        return DecodedScAddress(
+            FfiConverterUByte.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalULong.read(buf),
+        )
     }
 
-    override fun write(
-        value: ByteArray,
-        buf: ByteBuffer,
-    ) {
-        buf.putInt(value.size)
-        buf.put(value)
+    override fun allocationSize(value: DecodedScAddress) = (
+            FfiConverterUByte.allocationSize(value.`version`) +
+            FfiConverterString.allocationSize(value.`userPubkey`) +
+            FfiConverterString.allocationSize(value.`authPubkey`) +
+            FfiConverterOptionalString.allocationSize(value.`network`) +
+            FfiConverterOptionalString.allocationSize(value.`serverHint`) +
+            FfiConverterOptionalULong.allocationSize(value.`expiry`)
+    )
+
+    override fun write(value: DecodedScAddress, buf: ByteBuffer) {
+            FfiConverterUByte.write(value.`version`, buf)
+            FfiConverterString.write(value.`userPubkey`, buf)
+            FfiConverterString.write(value.`authPubkey`, buf)
+            FfiConverterOptionalString.write(value.`network`, buf)
+            FfiConverterOptionalString.write(value.`serverHint`, buf)
+            FfiConverterOptionalULong.write(value.`expiry`, buf)
     }
 }
 
+
+
 @Serializable
-data class Activity(
-    var `utxo`: kotlin.String,
-    var `amount`: kotlin.UInt,
-    var `action`: kotlin.String,
-    var `date`: kotlin.String,
+data class DepositInitResult (
+	@SerialName("server_pubkey")
+    var `serverPubkey`: kotlin.String, 
+	@SerialName("statechain_id")
+    var `statechainId`: kotlin.String, 
+	@SerialName("signed_statechain_id")
+    var `signedStatechainId`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeActivity : FfiConverterRustBuffer<Activity> {
-    override fun read(buf: ByteBuffer): Activity {
-        return Activity(
+public object FfiConverterTypeDepositInitResult: FfiConverterRustBuffer<DepositInitResult> {
+    override fun read(buf: ByteBuffer): DepositInitResult {
+        return DepositInitResult(
             FfiConverterString.read(buf),
-            FfiConverterUInt.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: Activity) =
-        (
-            FfiConverterString.allocationSize(value.`utxo`) +
-                FfiConverterUInt.allocationSize(value.`amount`) +
-                FfiConverterString.allocationSize(value.`action`) +
-                FfiConverterString.allocationSize(value.`date`)
-        )
+    override fun allocationSize(value: DepositInitResult) = (
+            FfiConverterString.allocationSize(value.`serverPubkey`) +
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`signedStatechainId`)
+    )
 
-    override fun write(
-        value: Activity,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`utxo`, buf)
-        FfiConverterUInt.write(value.`amount`, buf)
-        FfiConverterString.write(value.`action`, buf)
-        FfiConverterString.write(value.`date`, buf)
+    override fun write(value: DepositInitResult, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubkey`, buf)
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`signedStatechainId`, buf)
     }
 }
 
+
+
 @Serializable
-data class AggregatedPublicKey(
-    var `aggregatePubkey`: kotlin.String,
-    var `aggregateAddress`: kotlin.String,
+data class DepositMsg1 (
+	@SerialName("auth_key")
+    var `authKey`: kotlin.String, 
+	@SerialName("token_id")
+    var `tokenId`: kotlin.String, 
+	@SerialName("signed_token_id")
+    var `signedTokenId`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeAggregatedPublicKey : FfiConverterRustBuffer<AggregatedPublicKey> {
-    override fun read(buf: ByteBuffer): AggregatedPublicKey {
-        return AggregatedPublicKey(
+public object FfiConverterTypeDepositMsg1: FfiConverterRustBuffer<DepositMsg1> {
+    override fun read(buf: ByteBuffer): DepositMsg1 {
+        return DepositMsg1(
+            FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: AggregatedPublicKey) =
-        (
-            FfiConverterString.allocationSize(value.`aggregatePubkey`) +
-                FfiConverterString.allocationSize(value.`aggregateAddress`)
-        )
+    override fun allocationSize(value: DepositMsg1) = (
+            FfiConverterString.allocationSize(value.`authKey`) +
+            FfiConverterString.allocationSize(value.`tokenId`) +
+            FfiConverterString.allocationSize(value.`signedTokenId`)
+    )
 
-    override fun write(
-        value: AggregatedPublicKey,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`aggregatePubkey`, buf)
-        FfiConverterString.write(value.`aggregateAddress`, buf)
+    override fun write(value: DepositMsg1, buf: ByteBuffer) {
+            FfiConverterString.write(value.`authKey`, buf)
+            FfiConverterString.write(value.`tokenId`, buf)
+            FfiConverterString.write(value.`signedTokenId`, buf)
     }
 }
 
+
+
 @Serializable
-data class BackupTx(
-    var `txN`: kotlin.UInt,
-    var `tx`: kotlin.String,
-    var `clientPublicNonce`: kotlin.String,
-    var `serverPublicNonce`: kotlin.String,
-    var `clientPublicKey`: kotlin.String,
-    var `serverPublicKey`: kotlin.String,
-    var `blindingFactor`: kotlin.String,
+data class DepositMsg1Response (
+	@SerialName("server_pubkey")
+    var `serverPubkey`: kotlin.String, 
+	@SerialName("statechain_id")
+    var `statechainId`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeBackupTx : FfiConverterRustBuffer<BackupTx> {
-    override fun read(buf: ByteBuffer): BackupTx {
-        return BackupTx(
-            FfiConverterUInt.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypeDepositMsg1Response: FfiConverterRustBuffer<DepositMsg1Response> {
+    override fun read(buf: ByteBuffer): DepositMsg1Response {
+        return DepositMsg1Response(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: BackupTx) =
-        (
-            FfiConverterUInt.allocationSize(value.`txN`) +
-                FfiConverterString.allocationSize(value.`tx`) +
-                FfiConverterString.allocationSize(value.`clientPublicNonce`) +
-                FfiConverterString.allocationSize(value.`serverPublicNonce`) +
-                FfiConverterString.allocationSize(value.`clientPublicKey`) +
-                FfiConverterString.allocationSize(value.`serverPublicKey`) +
-                FfiConverterString.allocationSize(value.`blindingFactor`)
-        )
+    override fun allocationSize(value: DepositMsg1Response) = (
+            FfiConverterString.allocationSize(value.`serverPubkey`) +
+            FfiConverterString.allocationSize(value.`statechainId`)
+    )
 
-    override fun write(
-        value: BackupTx,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterUInt.write(value.`txN`, buf)
-        FfiConverterString.write(value.`tx`, buf)
-        FfiConverterString.write(value.`clientPublicNonce`, buf)
-        FfiConverterString.write(value.`serverPublicNonce`, buf)
-        FfiConverterString.write(value.`clientPublicKey`, buf)
-        FfiConverterString.write(value.`serverPublicKey`, buf)
-        FfiConverterString.write(value.`blindingFactor`, buf)
+    override fun write(value: DepositMsg1Response, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubkey`, buf)
+            FfiConverterString.write(value.`statechainId`, buf)
     }
 }
 
-@Serializable
-data class Coin(
-    var `index`: kotlin.UInt,
-    var `userPrivkey`: kotlin.String,
-    var `userPubkey`: kotlin.String,
-    var `authPrivkey`: kotlin.String,
-    var `authPubkey`: kotlin.String,
-    var `derivationPath`: kotlin.String,
-    var `fingerprint`: kotlin.String,
+
+
+/**
+ * Additional information about the cause of an error.
+ * All fields are optional because not every error has all of them.
+ */
+data class ErrorContext (
     /**
-     * The coin address is the user_pubkey || auth_pubkey
-     * Used to transfer the coin to another wallet
+     * The offending value (fee rate, locktime, address, network...)
      */
-    var `address`: kotlin.String,
+    var `value`: kotlin.String?, 
     /**
-     * The backup address is the address used in backup transactions
-     * The backup address is the p2tr address of the user_pubkey
+     * The expected value or range
      */
-    var `backupAddress`: kotlin.String,
-    var `serverPubkey`: kotlin.String?,
-    var `aggregatedPubkey`: kotlin.String?,
+    var `expected`: kotlin.String?, 
     /**
-     * The aggregated address is the P2TR address from aggregated_pubkey
+     * The backup transaction that caused the error
      */
-    var `aggregatedAddress`: kotlin.String?,
-    var `utxoTxid`: kotlin.String?,
-    var `utxoVout`: kotlin.UInt?,
-    var `amount`: kotlin.UInt?,
-    var `statechainId`: kotlin.String?,
-    var `signedStatechainId`: kotlin.String?,
-    var `locktime`: kotlin.UInt?,
-    var `secretNonce`: kotlin.String?,
-    var `publicNonce`: kotlin.String?,
-    var `blindingFactor`: kotlin.String?,
-    var `serverPublicNonce`: kotlin.String?,
-    var `txCpfp`: kotlin.String?,
-    var `txWithdraw`: kotlin.String?,
-    var `withdrawalAddress`: kotlin.String?,
-    var `status`: CoinStatus,
+    var `txN`: kotlin.UInt?, 
+    var `statechainId`: kotlin.String?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeCoin : FfiConverterRustBuffer<Coin> {
-    override fun read(buf: ByteBuffer): Coin {
-        return Coin(
-            FfiConverterUInt.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalUInt.read(buf),
-            FfiConverterOptionalUInt.read(buf),
+public object FfiConverterTypeErrorContext: FfiConverterRustBuffer<ErrorContext> {
+    override fun read(buf: ByteBuffer): ErrorContext {
+        return ErrorContext(
             FfiConverterOptionalString.read(buf),
             FfiConverterOptionalString.read(buf),
             FfiConverterOptionalUInt.read(buf),
             FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterOptionalString.read(buf),
-            FfiConverterTypeCoinStatus.read(buf),
         )
     }
 
-    override fun allocationSize(value: Coin) =
-        (
-            FfiConverterUInt.allocationSize(value.`index`) +
-                FfiConverterString.allocationSize(value.`userPrivkey`) +
-                FfiConverterString.allocationSize(value.`userPubkey`) +
-                FfiConverterString.allocationSize(value.`authPrivkey`) +
-                FfiConverterString.allocationSize(value.`authPubkey`) +
-                FfiConverterString.allocationSize(value.`derivationPath`) +
-                FfiConverterString.allocationSize(value.`fingerprint`) +
-                FfiConverterString.allocationSize(value.`address`) +
-                FfiConverterString.allocationSize(value.`backupAddress`) +
-                FfiConverterOptionalString.allocationSize(value.`serverPubkey`) +
-                FfiConverterOptionalString.allocationSize(value.`aggregatedPubkey`) +
-                FfiConverterOptionalString.allocationSize(value.`aggregatedAddress`) +
-                FfiConverterOptionalString.allocationSize(value.`utxoTxid`) +
-                FfiConverterOptionalUInt.allocationSize(value.`utxoVout`) +
-                FfiConverterOptionalUInt.allocationSize(value.`amount`) +
-                FfiConverterOptionalString.allocationSize(value.`statechainId`) +
-                FfiConverterOptionalString.allocationSize(value.`signedStatechainId`) +
-                FfiConverterOptionalUInt.allocationSize(value.`locktime`) +
-                FfiConverterOptionalString.allocationSize(value.`secretNonce`) +
-                FfiConverterOptionalString.allocationSize(value.`publicNonce`) +
-                FfiConverterOptionalString.allocationSize(value.`blindingFactor`) +
-                FfiConverterOptionalString.allocationSize(value.`serverPublicNonce`) +
-                FfiConverterOptionalString.allocationSize(value.`txCpfp`) +
-                FfiConverterOptionalString.allocationSize(value.`txWithdraw`) +
-                FfiConverterOptionalString.allocationSize(value.`withdrawalAddress`) +
-                FfiConverterTypeCoinStatus.allocationSize(value.`status`)
-        )
+    override fun allocationSize(value: ErrorContext) = (
+            FfiConverterOptionalString.allocationSize(value.`value`) +
+            FfiConverterOptionalString.allocationSize(value.`expected`) +
+            FfiConverterOptionalUInt.allocationSize(value.`txN`) +
+            FfiConverterOptionalString.allocationSize(value.`statechainId`)
+    )
 
-    override fun write(
-        value: Coin,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterUInt.write(value.`index`, buf)
-        FfiConverterString.write(value.`userPrivkey`, buf)
-        FfiConverterString.write(value.`userPubkey`, buf)
-        FfiConverterString.write(value.`authPrivkey`, buf)
-        FfiConverterString.write(value.`authPubkey`, buf)
-        FfiConverterString.write(value.`derivationPath`, buf)
-        FfiConverterString.write(value.`fingerprint`, buf)
-        FfiConverterString.write(value.`address`, buf)
-        FfiConverterString.write(value.`backupAddress`, buf)
-        FfiConverterOptionalString.write(value.`serverPubkey`, buf)
-        FfiConverterOptionalString.write(value.`aggregatedPubkey`, buf)
-        FfiConverterOptionalString.write(value.`aggregatedAddress`, buf)
-        FfiConverterOptionalString.write(value.`utxoTxid`, buf)
-        FfiConverterOptionalUInt.write(value.`utxoVout`, buf)
-        FfiConverterOptionalUInt.write(value.`amount`, buf)
-        FfiConverterOptionalString.write(value.`statechainId`, buf)
-        FfiConverterOptionalString.write(value.`signedStatechainId`, buf)
-        FfiConverterOptionalUInt.write(value.`locktime`, buf)
-        FfiConverterOptionalString.write(value.`secretNonce`, buf)
-        FfiConverterOptionalString.write(value.`publicNonce`, buf)
-        FfiConverterOptionalString.write(value.`blindingFactor`, buf)
-        FfiConverterOptionalString.write(value.`serverPublicNonce`, buf)
-        FfiConverterOptionalString.write(value.`txCpfp`, buf)
-        FfiConverterOptionalString.write(value.`txWithdraw`, buf)
-        FfiConverterOptionalString.write(value.`withdrawalAddress`, buf)
-        FfiConverterTypeCoinStatus.write(value.`status`, buf)
-    }
-}
-
-data class CoinNonce(
-    var `secretNonce`: kotlin.String,
-    var `publicNonce`: kotlin.String,
-    var `blindingFactor`: kotlin.String,
-    var `signFirstRequestPayload`: SignFirstRequestPayload,
+    override fun write(value: ErrorContext, buf: ByteBuffer) {
+            FfiConverterOptionalString.write(value.`value`, buf)
+            FfiConverterOptionalString.write(value.`expected`, buf)
+            FfiConverterOptionalUInt.write(value.`txN`, buf)
+            FfiConverterOptionalString.write(value.`statechainId`, buf)
+    }
+}
+
+
+
+data class FfiTransferMsg (
+    var `statechainId`: kotlin.String, 
+    var `transferSignature`: kotlin.String, 
+    var `backupTransactions`: List<BackupTx>, 
+    var `t1`: kotlin.ByteArray, 
+    var `userPublicKey`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeCoinNonce : FfiConverterRustBuffer<CoinNonce> {
-    override fun read(buf: ByteBuffer): CoinNonce {
-        return CoinNonce(
+public object FfiConverterTypeFFITransferMsg: FfiConverterRustBuffer<FfiTransferMsg> {
+    override fun read(buf: ByteBuffer): FfiTransferMsg {
+        return FfiTransferMsg(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
+            FfiConverterSequenceTypeBackupTx.read(buf),
+            FfiConverterByteArray.read(buf),
             FfiConverterString.read(buf),
-            FfiConverterTypeSignFirstRequestPayload.read(buf),
         )
     }
 
-    override fun allocationSize(value: CoinNonce) =
-        (
-            FfiConverterString.allocationSize(value.`secretNonce`) +
-                FfiConverterString.allocationSize(value.`publicNonce`) +
-                FfiConverterString.allocationSize(value.`blindingFactor`) +
-                FfiConverterTypeSignFirstRequestPayload.allocationSize(value.`signFirstRequestPayload`)
-        )
+    override fun allocationSize(value: FfiTransferMsg) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`transferSignature`) +
+            FfiConverterSequenceTypeBackupTx.allocationSize(value.`backupTransactions`) +
+            FfiConverterByteArray.allocationSize(value.`t1`) +
+            FfiConverterString.allocationSize(value.`userPublicKey`)
+    )
 
-    override fun write(
-        value: CoinNonce,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`secretNonce`, buf)
-        FfiConverterString.write(value.`publicNonce`, buf)
-        FfiConverterString.write(value.`blindingFactor`, buf)
-        FfiConverterTypeSignFirstRequestPayload.write(value.`signFirstRequestPayload`, buf)
+    override fun write(value: FfiTransferMsg, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`transferSignature`, buf)
+            FfiConverterSequenceTypeBackupTx.write(value.`backupTransactions`, buf)
+            FfiConverterByteArray.write(value.`t1`, buf)
+            FfiConverterString.write(value.`userPublicKey`, buf)
     }
 }
 
-class CoinStatusParseError {
-    override fun equals(other: Any?): Boolean {
-        return other is CoinStatusParseError
+
+
+@Serializable
+data class GetMsgAddrResponsePayload (
+	@SerialName("list_enc_transfer_msg")
+    var `listEncTransferMsg`: List<kotlin.String>
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeGetMsgAddrResponsePayload: FfiConverterRustBuffer<GetMsgAddrResponsePayload> {
+    override fun read(buf: ByteBuffer): GetMsgAddrResponsePayload {
+        return GetMsgAddrResponsePayload(
+            FfiConverterSequenceString.read(buf),
+        )
     }
 
-    override fun hashCode(): Int {
-        return javaClass.hashCode()
+    override fun allocationSize(value: GetMsgAddrResponsePayload) = (
+            FfiConverterSequenceString.allocationSize(value.`listEncTransferMsg`)
+    )
+
+    override fun write(value: GetMsgAddrResponsePayload, buf: ByteBuffer) {
+            FfiConverterSequenceString.write(value.`listEncTransferMsg`, buf)
     }
+}
+
 
+
+data class InfoConfig (
+    var `initlock`: kotlin.UInt, 
+    var `interval`: kotlin.UInt, 
+    var `feeRateSatsPerByte`: kotlin.Double
+) {
+    
     companion object
 }
 
-public object FfiConverterTypeCoinStatusParseError : FfiConverterRustBuffer<CoinStatusParseError> {
-    override fun read(buf: ByteBuffer): CoinStatusParseError {
-        return CoinStatusParseError()
+public object FfiConverterTypeInfoConfig: FfiConverterRustBuffer<InfoConfig> {
+    override fun read(buf: ByteBuffer): InfoConfig {
+        return InfoConfig(
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterDouble.read(buf),
+        )
     }
 
-    override fun allocationSize(value: CoinStatusParseError) = 0UL
+    override fun allocationSize(value: InfoConfig) = (
+            FfiConverterUInt.allocationSize(value.`initlock`) +
+            FfiConverterUInt.allocationSize(value.`interval`) +
+            FfiConverterDouble.allocationSize(value.`feeRateSatsPerByte`)
+    )
 
-    override fun write(
-        value: CoinStatusParseError,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: InfoConfig, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`initlock`, buf)
+            FfiConverterUInt.write(value.`interval`, buf)
+            FfiConverterDouble.write(value.`feeRateSatsPerByte`, buf)
     }
 }
 
-data class DecodedScAddress(
-    var `version`: kotlin.UByte,
-    var `userPubkey`: kotlin.String,
-    var `authPubkey`: kotlin.String,
+
+
+data class KeyListResponsePayload (
+    var `listKeyinfo`: List<PubKeyInfo>
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeDecodedSCAddress : FfiConverterRustBuffer<DecodedScAddress> {
-    override fun read(buf: ByteBuffer): DecodedScAddress {
-        return DecodedScAddress(
-            FfiConverterUByte.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypeKeyListResponsePayload: FfiConverterRustBuffer<KeyListResponsePayload> {
+    override fun read(buf: ByteBuffer): KeyListResponsePayload {
+        return KeyListResponsePayload(
+            FfiConverterSequenceTypePubKeyInfo.read(buf),
         )
     }
 
-    override fun allocationSize(value: DecodedScAddress) =
-        (
-            FfiConverterUByte.allocationSize(value.`version`) +
-                FfiConverterString.allocationSize(value.`userPubkey`) +
-                FfiConverterString.allocationSize(value.`authPubkey`)
-        )
+    override fun allocationSize(value: KeyListResponsePayload) = (
+            FfiConverterSequenceTypePubKeyInfo.allocationSize(value.`listKeyinfo`)
+    )
 
-    override fun write(
-        value: DecodedScAddress,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterUByte.write(value.`version`, buf)
-        FfiConverterString.write(value.`userPubkey`, buf)
-        FfiConverterString.write(value.`authPubkey`, buf)
+    override fun write(value: KeyListResponsePayload, buf: ByteBuffer) {
+            FfiConverterSequenceTypePubKeyInfo.write(value.`listKeyinfo`, buf)
     }
 }
 
+
+
+/**
+ * Record that the published keylist lists the current server key share of a statechain,
+ * and that the share used for the previous owner is no longer part of the coin.
+ * A check that failed is recorded as well, with its `error` and without a keylist entry.
+ */
 @Serializable
-data class DepositInitResult(
-    var `serverPubkey`: kotlin.String,
-    var `statechainId`: kotlin.String,
-    var `signedStatechainId`: kotlin.String,
+data class KeyRotationEvidence (
+	@SerialName("statechain_id")
+    var `statechainId`: kotlin.String, 
+	@SerialName("previous_server_pubkey")
+    var `previousServerPubkey`: kotlin.String, 
+	@SerialName("current_server_pubkey")
+    var `currentServerPubkey`: kotlin.String, 
+    /**
+     * `tx_n` and `created_at` of the keylist entry of the current server key share
+     */
+	@SerialName("keylist_tx_n")
+    var `keylistTxN`: kotlin.UInt?, 
+	@SerialName("keylist_created_at")
+    var `keylistCreatedAt`: kotlin.String?, 
+    /**
+     * Why the key rotation could not be verified, `None` if it was
+     */
+    var `error`: kotlin.String? = null
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeDepositInitResult : FfiConverterRustBuffer<DepositInitResult> {
-    override fun read(buf: ByteBuffer): DepositInitResult {
-        return DepositInitResult(
+public object FfiConverterTypeKeyRotationEvidence: FfiConverterRustBuffer<KeyRotationEvidence> {
+    override fun read(buf: ByteBuffer): KeyRotationEvidence {
+        return KeyRotationEvidence(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
         )
     }
 
-    override fun allocationSize(value: DepositInitResult) =
-        (
-            FfiConverterString.allocationSize(value.`serverPubkey`) +
-                FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`signedStatechainId`)
-        )
+    override fun allocationSize(value: KeyRotationEvidence) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`previousServerPubkey`) +
+            FfiConverterString.allocationSize(value.`currentServerPubkey`) +
+            FfiConverterOptionalUInt.allocationSize(value.`keylistTxN`) +
+            FfiConverterOptionalString.allocationSize(value.`keylistCreatedAt`) +
+            FfiConverterOptionalString.allocationSize(value.`error`)
+    )
 
-    override fun write(
-        value: DepositInitResult,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubkey`, buf)
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`signedStatechainId`, buf)
+    override fun write(value: KeyRotationEvidence, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`previousServerPubkey`, buf)
+            FfiConverterString.write(value.`currentServerPubkey`, buf)
+            FfiConverterOptionalUInt.write(value.`keylistTxN`, buf)
+            FfiConverterOptionalString.write(value.`keylistCreatedAt`, buf)
+            FfiConverterOptionalString.write(value.`error`, buf)
     }
 }
 
+
+
 @Serializable
-data class DepositMsg1(
-	@SerialName("auth_key")
-    var `authKey`: kotlin.String,
-	@SerialName("token_id")
-    var `tokenId`: kotlin.String,
-	@SerialName("signed_token_id")
-    var `signedTokenId`: kotlin.String,
+data class KeyUpdateResponsePayload (
+	@SerialName("statechain_id")
+    var `statechainId`: kotlin.String, 
+    var `t2`: kotlin.String, 
+    var `x1`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeDepositMsg1 : FfiConverterRustBuffer<DepositMsg1> {
-    override fun read(buf: ByteBuffer): DepositMsg1 {
-        return DepositMsg1(
+public object FfiConverterTypeKeyUpdateResponsePayload: FfiConverterRustBuffer<KeyUpdateResponsePayload> {
+    override fun read(buf: ByteBuffer): KeyUpdateResponsePayload {
+        return KeyUpdateResponsePayload(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: DepositMsg1) =
-        (
-            FfiConverterString.allocationSize(value.`authKey`) +
-                FfiConverterString.allocationSize(value.`tokenId`) +
-                FfiConverterString.allocationSize(value.`signedTokenId`)
-        )
+    override fun allocationSize(value: KeyUpdateResponsePayload) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`t2`) +
+            FfiConverterString.allocationSize(value.`x1`)
+    )
 
-    override fun write(
-        value: DepositMsg1,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`authKey`, buf)
-        FfiConverterString.write(value.`tokenId`, buf)
-        FfiConverterString.write(value.`signedTokenId`, buf)
+    override fun write(value: KeyUpdateResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`t2`, buf)
+            FfiConverterString.write(value.`x1`, buf)
     }
 }
 
-@Serializable
-data class DepositMsg1Response(
-	@SerialName("server_pubkey")
-    var `serverPubkey`: kotlin.String,
-	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+
+
+/**
+ * Flat representation of `MercuryError`, for bindings that cannot
+ * handle enum variants with fields (wasm, logs, JSON APIs)
+ */
+data class MercuryErrorDetails (
+    /**
+     * The name of the `MercuryError` variant, e.g. `FeeTooLow`
+     */
+    var `code`: kotlin.String, 
+    var `message`: kotlin.String, 
+    var `value`: kotlin.String?, 
+    var `expected`: kotlin.String?, 
+    var `txN`: kotlin.UInt?, 
+    var `statechainId`: kotlin.String?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeDepositMsg1Response : FfiConverterRustBuffer<DepositMsg1Response> {
-    override fun read(buf: ByteBuffer): DepositMsg1Response {
-        return DepositMsg1Response(
+public object FfiConverterTypeMercuryErrorDetails: FfiConverterRustBuffer<MercuryErrorDetails> {
+    override fun read(buf: ByteBuffer): MercuryErrorDetails {
+        return MercuryErrorDetails(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterOptionalString.read(buf),
         )
     }
 
-    override fun allocationSize(value: DepositMsg1Response) =
-        (
-            FfiConverterString.allocationSize(value.`serverPubkey`) +
-                FfiConverterString.allocationSize(value.`statechainId`)
+    override fun allocationSize(value: MercuryErrorDetails) = (
+            FfiConverterString.allocationSize(value.`code`) +
+            FfiConverterString.allocationSize(value.`message`) +
+            FfiConverterOptionalString.allocationSize(value.`value`) +
+            FfiConverterOptionalString.allocationSize(value.`expected`) +
+            FfiConverterOptionalUInt.allocationSize(value.`txN`) +
+            FfiConverterOptionalString.allocationSize(value.`statechainId`)
+    )
+
+    override fun write(value: MercuryErrorDetails, buf: ByteBuffer) {
+            FfiConverterString.write(value.`code`, buf)
+            FfiConverterString.write(value.`message`, buf)
+            FfiConverterOptionalString.write(value.`value`, buf)
+            FfiConverterOptionalString.write(value.`expected`, buf)
+            FfiConverterOptionalUInt.write(value.`txN`, buf)
+            FfiConverterOptionalString.write(value.`statechainId`, buf)
+    }
+}
+
+
+
+data class NewKeyInfo (
+    var `aggregatePubkey`: kotlin.String, 
+    var `aggregateAddress`: kotlin.String, 
+    var `signedStatechainId`: kotlin.String, 
+    var `amount`: kotlin.ULong
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeNewKeyInfo: FfiConverterRustBuffer<NewKeyInfo> {
+    override fun read(buf: ByteBuffer): NewKeyInfo {
+        return NewKeyInfo(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterULong.read(buf),
         )
+    }
+
+    override fun allocationSize(value: NewKeyInfo) = (
+            FfiConverterString.allocationSize(value.`aggregatePubkey`) +
+            FfiConverterString.allocationSize(value.`aggregateAddress`) +
+            FfiConverterString.allocationSize(value.`signedStatechainId`) +
+            FfiConverterULong.allocationSize(value.`amount`)
+    )
 
-    override fun write(
-        value: DepositMsg1Response,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubkey`, buf)
-        FfiConverterString.write(value.`statechainId`, buf)
+    override fun write(value: NewKeyInfo, buf: ByteBuffer) {
+            FfiConverterString.write(value.`aggregatePubkey`, buf)
+            FfiConverterString.write(value.`aggregateAddress`, buf)
+            FfiConverterString.write(value.`signedStatechainId`, buf)
+            FfiConverterULong.write(value.`amount`, buf)
     }
 }
 
-data class FfiTransferMsg(
-    var `statechainId`: kotlin.String,
-    var `transferSignature`: kotlin.String,
-    var `backupTransactions`: List<BackupTx>,
-    var `t1`: kotlin.ByteArray,
-    var `userPublicKey`: kotlin.String,
+
+
+/**
+ * Statement that the owner of `auth_pubkey` controls the statecoin of `amount` sats
+ * locked in the Tx0 output `utxo_txid:utxo_vout`, answering `challenge` at `timestamp`.
+ * `user_pubkey` is the owner share of the aggregated key, which the verifier recomputes
+ * with the server share to tie the output to the statechain.
+ */
+data class OwnershipProof (
+    var `statechainId`: kotlin.String, 
+    var `utxoTxid`: kotlin.String, 
+    var `utxoVout`: kotlin.UInt, 
+    var `amount`: kotlin.ULong, 
+    var `userPubkey`: kotlin.String, 
+    /**
+     * Chosen by the verifier, so that the proof cannot be replayed to someone else
+     */
+    var `challenge`: kotlin.String, 
+    /**
+     * Unix time, in seconds, at which the proof was signed
+     */
+    var `timestamp`: kotlin.ULong, 
+    var `authPubkey`: kotlin.String, 
+    var `signature`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeFFITransferMsg : FfiConverterRustBuffer<FfiTransferMsg> {
-    override fun read(buf: ByteBuffer): FfiTransferMsg {
-        return FfiTransferMsg(
+public object FfiConverterTypeOwnershipProof: FfiConverterRustBuffer<OwnershipProof> {
+    override fun read(buf: ByteBuffer): OwnershipProof {
+        return OwnershipProof(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
-            FfiConverterSequenceTypeBackupTx.read(buf),
-            FfiConverterByteArray.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: FfiTransferMsg) =
-        (
+    override fun allocationSize(value: OwnershipProof) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`transferSignature`) +
-                FfiConverterSequenceTypeBackupTx.allocationSize(value.`backupTransactions`) +
-                FfiConverterByteArray.allocationSize(value.`t1`) +
-                FfiConverterString.allocationSize(value.`userPublicKey`)
-        )
+            FfiConverterString.allocationSize(value.`utxoTxid`) +
+            FfiConverterUInt.allocationSize(value.`utxoVout`) +
+            FfiConverterULong.allocationSize(value.`amount`) +
+            FfiConverterString.allocationSize(value.`userPubkey`) +
+            FfiConverterString.allocationSize(value.`challenge`) +
+            FfiConverterULong.allocationSize(value.`timestamp`) +
+            FfiConverterString.allocationSize(value.`authPubkey`) +
+            FfiConverterString.allocationSize(value.`signature`)
+    )
 
-    override fun write(
-        value: FfiTransferMsg,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`transferSignature`, buf)
-        FfiConverterSequenceTypeBackupTx.write(value.`backupTransactions`, buf)
-        FfiConverterByteArray.write(value.`t1`, buf)
-        FfiConverterString.write(value.`userPublicKey`, buf)
+    override fun write(value: OwnershipProof, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`utxoTxid`, buf)
+            FfiConverterUInt.write(value.`utxoVout`, buf)
+            FfiConverterULong.write(value.`amount`, buf)
+            FfiConverterString.write(value.`userPubkey`, buf)
+            FfiConverterString.write(value.`challenge`, buf)
+            FfiConverterULong.write(value.`timestamp`, buf)
+            FfiConverterString.write(value.`authPubkey`, buf)
+            FfiConverterString.write(value.`signature`, buf)
     }
 }
 
-@Serializable
-data class GetMsgAddrResponsePayload(
-	@SerialName("list_enc_transfer_msg")
-    var `listEncTransferMsg`: List<kotlin.String>,
+
+
+data class PartialSignatureMsg1 (
+    var `msg`: kotlin.String, 
+    var `outputPubkey`: kotlin.String, 
+    var `clientPartialSig`: kotlin.String, 
+    var `encodedSession`: kotlin.String, 
+    var `encodedUnsignedTx`: kotlin.String, 
+    var `partialSignatureRequestPayload`: PartialSignatureRequestPayload
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeGetMsgAddrResponsePayload : FfiConverterRustBuffer<GetMsgAddrResponsePayload> {
-    override fun read(buf: ByteBuffer): GetMsgAddrResponsePayload {
-        return GetMsgAddrResponsePayload(
-            FfiConverterSequenceString.read(buf),
+public object FfiConverterTypePartialSignatureMsg1: FfiConverterRustBuffer<PartialSignatureMsg1> {
+    override fun read(buf: ByteBuffer): PartialSignatureMsg1 {
+        return PartialSignatureMsg1(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterTypePartialSignatureRequestPayload.read(buf),
         )
     }
 
-    override fun allocationSize(value: GetMsgAddrResponsePayload) =
-        (
-            FfiConverterSequenceString.allocationSize(value.`listEncTransferMsg`)
-        )
+    override fun allocationSize(value: PartialSignatureMsg1) = (
+            FfiConverterString.allocationSize(value.`msg`) +
+            FfiConverterString.allocationSize(value.`outputPubkey`) +
+            FfiConverterString.allocationSize(value.`clientPartialSig`) +
+            FfiConverterString.allocationSize(value.`encodedSession`) +
+            FfiConverterString.allocationSize(value.`encodedUnsignedTx`) +
+            FfiConverterTypePartialSignatureRequestPayload.allocationSize(value.`partialSignatureRequestPayload`)
+    )
 
-    override fun write(
-        value: GetMsgAddrResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterSequenceString.write(value.`listEncTransferMsg`, buf)
+    override fun write(value: PartialSignatureMsg1, buf: ByteBuffer) {
+            FfiConverterString.write(value.`msg`, buf)
+            FfiConverterString.write(value.`outputPubkey`, buf)
+            FfiConverterString.write(value.`clientPartialSig`, buf)
+            FfiConverterString.write(value.`encodedSession`, buf)
+            FfiConverterString.write(value.`encodedUnsignedTx`, buf)
+            FfiConverterTypePartialSignatureRequestPayload.write(value.`partialSignatureRequestPayload`, buf)
     }
 }
 
-data class InfoConfig(
-    var `initlock`: kotlin.UInt,
-    var `interval`: kotlin.UInt,
-    var `feeRateSatsPerByte`: kotlin.ULong,
+
+
+@Serializable
+data class PartialSignatureRequestPayload (
+	@SerialName("statechain_id")
+    var `statechainId`: kotlin.String, 
+	@SerialName("negate_seckey")
+    var `negateSeckey`: kotlin.UByte, 
+    var `session`: kotlin.String, 
+	@SerialName("signed_statechain_id")
+    var `signedStatechainId`: kotlin.String, 
+	@SerialName("server_pub_nonce")
+    var `serverPubNonce`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeInfoConfig : FfiConverterRustBuffer<InfoConfig> {
-    override fun read(buf: ByteBuffer): InfoConfig {
-        return InfoConfig(
-            FfiConverterUInt.read(buf),
-            FfiConverterUInt.read(buf),
-            FfiConverterULong.read(buf),
+public object FfiConverterTypePartialSignatureRequestPayload: FfiConverterRustBuffer<PartialSignatureRequestPayload> {
+    override fun read(buf: ByteBuffer): PartialSignatureRequestPayload {
+        return PartialSignatureRequestPayload(
+            FfiConverterString.read(buf),
+            FfiConverterUByte.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: InfoConfig) =
-        (
-            FfiConverterUInt.allocationSize(value.`initlock`) +
-                FfiConverterUInt.allocationSize(value.`interval`) +
-                FfiConverterULong.allocationSize(value.`feeRateSatsPerByte`)
-        )
+    override fun allocationSize(value: PartialSignatureRequestPayload) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterUByte.allocationSize(value.`negateSeckey`) +
+            FfiConverterString.allocationSize(value.`session`) +
+            FfiConverterString.allocationSize(value.`signedStatechainId`) +
+            FfiConverterString.allocationSize(value.`serverPubNonce`)
+    )
 
-    override fun write(
-        value: InfoConfig,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterUInt.write(value.`initlock`, buf)
-        FfiConverterUInt.write(value.`interval`, buf)
-        FfiConverterULong.write(value.`feeRateSatsPerByte`, buf)
+    override fun write(value: PartialSignatureRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterUByte.write(value.`negateSeckey`, buf)
+            FfiConverterString.write(value.`session`, buf)
+            FfiConverterString.write(value.`signedStatechainId`, buf)
+            FfiConverterString.write(value.`serverPubNonce`, buf)
     }
 }
 
-data class KeyListResponsePayload(
-    var `listKeyinfo`: List<PubKeyInfo>,
+
+
+@Serializable
+data class PartialSignatureResponsePayload (
+	@SerialName("partial_sig")
+    var `partialSig`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeKeyListResponsePayload : FfiConverterRustBuffer<KeyListResponsePayload> {
-    override fun read(buf: ByteBuffer): KeyListResponsePayload {
-        return KeyListResponsePayload(
-            FfiConverterSequenceTypePubKeyInfo.read(buf),
+public object FfiConverterTypePartialSignatureResponsePayload: FfiConverterRustBuffer<PartialSignatureResponsePayload> {
+    override fun read(buf: ByteBuffer): PartialSignatureResponsePayload {
+        return PartialSignatureResponsePayload(
+            FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: KeyListResponsePayload) =
-        (
-            FfiConverterSequenceTypePubKeyInfo.allocationSize(value.`listKeyinfo`)
-        )
+    override fun allocationSize(value: PartialSignatureResponsePayload) = (
+            FfiConverterString.allocationSize(value.`partialSig`)
+    )
 
-    override fun write(
-        value: KeyListResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterSequenceTypePubKeyInfo.write(value.`listKeyinfo`, buf)
+    override fun write(value: PartialSignatureResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`partialSig`, buf)
     }
 }
 
-@Serializable
-data class KeyUpdateResponsePayload(
-	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
-    var `t2`: kotlin.String,
-    var `x1`: kotlin.String,
+
+
+data class PaymentHashRequestPayload (
+    var `statechainId`: kotlin.String, 
+    var `authSig`: kotlin.String, 
+    var `batchId`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeKeyUpdateResponsePayload : FfiConverterRustBuffer<KeyUpdateResponsePayload> {
-    override fun read(buf: ByteBuffer): KeyUpdateResponsePayload {
-        return KeyUpdateResponsePayload(
+public object FfiConverterTypePaymentHashRequestPayload: FfiConverterRustBuffer<PaymentHashRequestPayload> {
+    override fun read(buf: ByteBuffer): PaymentHashRequestPayload {
+        return PaymentHashRequestPayload(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: KeyUpdateResponsePayload) =
-        (
+    override fun allocationSize(value: PaymentHashRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`t2`) +
-                FfiConverterString.allocationSize(value.`x1`)
-        )
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterString.allocationSize(value.`batchId`)
+    )
 
-    override fun write(
-        value: KeyUpdateResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`t2`, buf)
-        FfiConverterString.write(value.`x1`, buf)
+    override fun write(value: PaymentHashRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterString.write(value.`batchId`, buf)
     }
 }
 
-data class NewKeyInfo(
-    var `aggregatePubkey`: kotlin.String,
-    var `aggregateAddress`: kotlin.String,
-    var `signedStatechainId`: kotlin.String,
-    var `amount`: kotlin.UInt,
+
+
+data class PaymentHashResponsePayload (
+    var `hash`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeNewKeyInfo : FfiConverterRustBuffer<NewKeyInfo> {
-    override fun read(buf: ByteBuffer): NewKeyInfo {
-        return NewKeyInfo(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypePaymentHashResponsePayload: FfiConverterRustBuffer<PaymentHashResponsePayload> {
+    override fun read(buf: ByteBuffer): PaymentHashResponsePayload {
+        return PaymentHashResponsePayload(
             FfiConverterString.read(buf),
-            FfiConverterUInt.read(buf),
         )
     }
 
-    override fun allocationSize(value: NewKeyInfo) =
-        (
-            FfiConverterString.allocationSize(value.`aggregatePubkey`) +
-                FfiConverterString.allocationSize(value.`aggregateAddress`) +
-                FfiConverterString.allocationSize(value.`signedStatechainId`) +
-                FfiConverterUInt.allocationSize(value.`amount`)
-        )
+    override fun allocationSize(value: PaymentHashResponsePayload) = (
+            FfiConverterString.allocationSize(value.`hash`)
+    )
 
-    override fun write(
-        value: NewKeyInfo,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`aggregatePubkey`, buf)
-        FfiConverterString.write(value.`aggregateAddress`, buf)
-        FfiConverterString.write(value.`signedStatechainId`, buf)
-        FfiConverterUInt.write(value.`amount`, buf)
+    override fun write(value: PaymentHashResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`hash`, buf)
     }
 }
 
-data class PartialSignatureMsg1(
-    var `msg`: kotlin.String,
-    var `outputPubkey`: kotlin.String,
-    var `clientPartialSig`: kotlin.String,
-    var `encodedSession`: kotlin.String,
-    var `encodedUnsignedTx`: kotlin.String,
-    var `partialSignatureRequestPayload`: PartialSignatureRequestPayload,
+
+
+/**
+ * A statechain that was transferred or withdrawn by `auth_pubkey`
+ */
+data class PreviousStatechain (
+    var `statechainId`: kotlin.String, 
+    var `withdrawn`: kotlin.Boolean
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypePartialSignatureMsg1 : FfiConverterRustBuffer<PartialSignatureMsg1> {
-    override fun read(buf: ByteBuffer): PartialSignatureMsg1 {
-        return PartialSignatureMsg1(
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypePreviousStatechain: FfiConverterRustBuffer<PreviousStatechain> {
+    override fun read(buf: ByteBuffer): PreviousStatechain {
+        return PreviousStatechain(
             FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
-            FfiConverterTypePartialSignatureRequestPayload.read(buf),
+            FfiConverterBoolean.read(buf),
         )
     }
 
-    override fun allocationSize(value: PartialSignatureMsg1) =
-        (
-            FfiConverterString.allocationSize(value.`msg`) +
-                FfiConverterString.allocationSize(value.`outputPubkey`) +
-                FfiConverterString.allocationSize(value.`clientPartialSig`) +
-                FfiConverterString.allocationSize(value.`encodedSession`) +
-                FfiConverterString.allocationSize(value.`encodedUnsignedTx`) +
-                FfiConverterTypePartialSignatureRequestPayload.allocationSize(value.`partialSignatureRequestPayload`)
-        )
+    override fun allocationSize(value: PreviousStatechain) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterBoolean.allocationSize(value.`withdrawn`)
+    )
 
-    override fun write(
-        value: PartialSignatureMsg1,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`msg`, buf)
-        FfiConverterString.write(value.`outputPubkey`, buf)
-        FfiConverterString.write(value.`clientPartialSig`, buf)
-        FfiConverterString.write(value.`encodedSession`, buf)
-        FfiConverterString.write(value.`encodedUnsignedTx`, buf)
-        FfiConverterTypePartialSignatureRequestPayload.write(value.`partialSignatureRequestPayload`, buf)
+    override fun write(value: PreviousStatechain, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterBoolean.write(value.`withdrawn`, buf)
     }
 }
 
-@Serializable
-data class PartialSignatureRequestPayload(
-	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
-	@SerialName("negate_seckey")
-    var `negateSeckey`: kotlin.UByte,
-    var `session`: kotlin.String,
-	@SerialName("signed_statechain_id")
-    var `signedStatechainId`: kotlin.String,
-	@SerialName("server_pub_nonce")
-    var `serverPubNonce`: kotlin.String,
+
+
+data class PubKeyInfo (
+    var `serverPubkey`: kotlin.String, 
+    var `txN`: kotlin.UInt, 
+    var `createdAt`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypePartialSignatureRequestPayload : FfiConverterRustBuffer<PartialSignatureRequestPayload> {
-    override fun read(buf: ByteBuffer): PartialSignatureRequestPayload {
-        return PartialSignatureRequestPayload(
-            FfiConverterString.read(buf),
-            FfiConverterUByte.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypePubKeyInfo: FfiConverterRustBuffer<PubKeyInfo> {
+    override fun read(buf: ByteBuffer): PubKeyInfo {
+        return PubKeyInfo(
             FfiConverterString.read(buf),
+            FfiConverterUInt.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: PartialSignatureRequestPayload) =
-        (
-            FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterUByte.allocationSize(value.`negateSeckey`) +
-                FfiConverterString.allocationSize(value.`session`) +
-                FfiConverterString.allocationSize(value.`signedStatechainId`) +
-                FfiConverterString.allocationSize(value.`serverPubNonce`)
-        )
+    override fun allocationSize(value: PubKeyInfo) = (
+            FfiConverterString.allocationSize(value.`serverPubkey`) +
+            FfiConverterUInt.allocationSize(value.`txN`) +
+            FfiConverterString.allocationSize(value.`createdAt`)
+    )
 
-    override fun write(
-        value: PartialSignatureRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterUByte.write(value.`negateSeckey`, buf)
-        FfiConverterString.write(value.`session`, buf)
-        FfiConverterString.write(value.`signedStatechainId`, buf)
-        FfiConverterString.write(value.`serverPubNonce`, buf)
+    override fun write(value: PubKeyInfo, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubkey`, buf)
+            FfiConverterUInt.write(value.`txN`, buf)
+            FfiConverterString.write(value.`createdAt`, buf)
     }
 }
 
-@Serializable
-data class PartialSignatureResponsePayload(
-	@SerialName("partial_sig")
-    var `partialSig`: kotlin.String,
+
+
+data class RecoveredStatechain (
+    var `statechainId`: kotlin.String, 
+    var `serverPubkey`: kotlin.String, 
+    /**
+     * The transfer message that gave the statechain to `auth_pubkey`, if it was received by transfer.
+     * It holds the backup transactions of the coin.
+     */
+    var `encTransferMsg`: kotlin.String?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypePartialSignatureResponsePayload : FfiConverterRustBuffer<PartialSignatureResponsePayload> {
-    override fun read(buf: ByteBuffer): PartialSignatureResponsePayload {
-        return PartialSignatureResponsePayload(
+public object FfiConverterTypeRecoveredStatechain: FfiConverterRustBuffer<RecoveredStatechain> {
+    override fun read(buf: ByteBuffer): RecoveredStatechain {
+        return RecoveredStatechain(
             FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
         )
     }
 
-    override fun allocationSize(value: PartialSignatureResponsePayload) =
-        (
-            FfiConverterString.allocationSize(value.`partialSig`)
-        )
+    override fun allocationSize(value: RecoveredStatechain) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`serverPubkey`) +
+            FfiConverterOptionalString.allocationSize(value.`encTransferMsg`)
+    )
 
-    override fun write(
-        value: PartialSignatureResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`partialSig`, buf)
+    override fun write(value: RecoveredStatechain, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`serverPubkey`, buf)
+            FfiConverterOptionalString.write(value.`encTransferMsg`, buf)
     }
 }
 
-data class PubKeyInfo(
-    var `serverPubkey`: kotlin.String,
-    var `txN`: kotlin.UInt,
-    var `updatedAt`: kotlin.String,
+
+
+/**
+ * Optional fields of a v1 statechain address.
+ * Addresses generated with them also carry the network, so that they cannot be used on another test network.
+ */
+data class ScAddressOptions (
+    /**
+     * Statechain entity endpoint (URL or onion address) the sender should use
+     */
+    var `serverHint`: kotlin.String?, 
+    /**
+     * Unix timestamp after which senders reject the address
+     */
+    var `expiry`: kotlin.ULong?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypePubKeyInfo : FfiConverterRustBuffer<PubKeyInfo> {
-    override fun read(buf: ByteBuffer): PubKeyInfo {
-        return PubKeyInfo(
-            FfiConverterString.read(buf),
-            FfiConverterUInt.read(buf),
-            FfiConverterString.read(buf),
+public object FfiConverterTypeScAddressOptions: FfiConverterRustBuffer<ScAddressOptions> {
+    override fun read(buf: ByteBuffer): ScAddressOptions {
+        return ScAddressOptions(
+            FfiConverterOptionalString.read(buf),
+            FfiConverterOptionalULong.read(buf),
         )
     }
 
-    override fun allocationSize(value: PubKeyInfo) =
-        (
-            FfiConverterString.allocationSize(value.`serverPubkey`) +
-                FfiConverterUInt.allocationSize(value.`txN`) +
-                FfiConverterString.allocationSize(value.`updatedAt`)
-        )
+    override fun allocationSize(value: ScAddressOptions) = (
+            FfiConverterOptionalString.allocationSize(value.`serverHint`) +
+            FfiConverterOptionalULong.allocationSize(value.`expiry`)
+    )
 
-    override fun write(
-        value: PubKeyInfo,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubkey`, buf)
-        FfiConverterUInt.write(value.`txN`, buf)
-        FfiConverterString.write(value.`updatedAt`, buf)
+    override fun write(value: ScAddressOptions, buf: ByteBuffer) {
+            FfiConverterOptionalString.write(value.`serverHint`, buf)
+            FfiConverterOptionalULong.write(value.`expiry`, buf)
     }
 }
 
+
+
 @Serializable
-data class ServerConfig(
-    var `initlock`: kotlin.UInt,
-    var `interval`: kotlin.UInt,
+data class ServerConfig (
+    var `initlock`: kotlin.UInt, 
+    var `interval`: kotlin.UInt, 
+    var `batchtimeout`: kotlin.UInt, 
+    var `version`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeServerConfig : FfiConverterRustBuffer<ServerConfig> {
+public object FfiConverterTypeServerConfig: FfiConverterRustBuffer<ServerConfig> {
     override fun read(buf: ByteBuffer): ServerConfig {
         return ServerConfig(
             FfiConverterUInt.read(buf),
             FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: ServerConfig) =
-        (
+    override fun allocationSize(value: ServerConfig) = (
             FfiConverterUInt.allocationSize(value.`initlock`) +
-                FfiConverterUInt.allocationSize(value.`interval`)
-        )
+            FfiConverterUInt.allocationSize(value.`interval`) +
+            FfiConverterUInt.allocationSize(value.`batchtimeout`) +
+            FfiConverterString.allocationSize(value.`version`)
+    )
 
-    override fun write(
-        value: ServerConfig,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterUInt.write(value.`initlock`, buf)
-        FfiConverterUInt.write(value.`interval`, buf)
+    override fun write(value: ServerConfig, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`initlock`, buf)
+            FfiConverterUInt.write(value.`interval`, buf)
+            FfiConverterUInt.write(value.`batchtimeout`, buf)
+            FfiConverterString.write(value.`version`, buf)
     }
 }
 
-data class ServerPublicNonceResponsePayload(
-    var `serverPubnonce`: kotlin.String,
+
+
+data class ServerPublicNonceResponsePayload (
+    var `serverPubnonce`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeServerPublicNonceResponsePayload : FfiConverterRustBuffer<ServerPublicNonceResponsePayload> {
+public object FfiConverterTypeServerPublicNonceResponsePayload: FfiConverterRustBuffer<ServerPublicNonceResponsePayload> {
     override fun read(buf: ByteBuffer): ServerPublicNonceResponsePayload {
         return ServerPublicNonceResponsePayload(
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: ServerPublicNonceResponsePayload) =
-        (
+    override fun allocationSize(value: ServerPublicNonceResponsePayload) = (
             FfiConverterString.allocationSize(value.`serverPubnonce`)
-        )
+    )
 
-    override fun write(
-        value: ServerPublicNonceResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubnonce`, buf)
+    override fun write(value: ServerPublicNonceResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubnonce`, buf)
     }
 }
 
+
+
 @Serializable
-data class Settings(
-    var `network`: kotlin.String,
-    var `blockExplorerUrl`: kotlin.String?,
-    var `torProxyHost`: kotlin.String?,
-    var `torProxyPort`: kotlin.String?,
-    var `torProxyControlPassword`: kotlin.String?,
-    var `torProxyControlPort`: kotlin.String?,
-    var `statechainEntityApi`: kotlin.String,
-    var `torStatechainEntityApi`: kotlin.String?,
-    var `electrumProtocol`: kotlin.String,
-    var `electrumHost`: kotlin.String,
-    var `electrumPort`: kotlin.String,
-    var `electrumType`: kotlin.String,
-    var `notifications`: kotlin.Boolean,
-    var `tutorials`: kotlin.Boolean,
+data class Settings (
+    var `network`: kotlin.String, 
+	@SerialName("block_explorer_url")
+    var `blockExplorerUrl`: kotlin.String?, 
+	@SerialName("tor_proxy_host")
+    var `torProxyHost`: kotlin.String?, 
+	@SerialName("tor_proxy_port")
+    var `torProxyPort`: kotlin.String?, 
+	@SerialName("tor_proxy_control_password")
+    var `torProxyControlPassword`: kotlin.String?, 
+	@SerialName("tor_proxy_control_port")
+    var `torProxyControlPort`: kotlin.String?, 
+	@SerialName("statechain_entity_api")
+    var `statechainEntityApi`: kotlin.String, 
+	@SerialName("tor_statechain_entity_api")
+    var `torStatechainEntityApi`: kotlin.String?, 
+	@SerialName("electrum_protocol")
+    var `electrumProtocol`: kotlin.String, 
+	@SerialName("electrum_host")
+    var `electrumHost`: kotlin.String, 
+	@SerialName("electrum_port")
+    var `electrumPort`: kotlin.String, 
+	@SerialName("electrum_type")
+    var `electrumType`: kotlin.String, 
+    var `notifications`: kotlin.Boolean, 
+    var `tutorials`: kotlin.Boolean
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeSettings : FfiConverterRustBuffer<Settings> {
+public object FfiConverterTypeSettings: FfiConverterRustBuffer<Settings> {
     override fun read(buf: ByteBuffer): Settings {
         return Settings(
             FfiConverterString.read(buf),
@@ -2458,56 +3220,55 @@ public object FfiConverterTypeSettings : FfiConverterRustBuffer<Settings> {
         )
     }
 
-    override fun allocationSize(value: Settings) =
-        (
+    override fun allocationSize(value: Settings) = (
             FfiConverterString.allocationSize(value.`network`) +
-                FfiConverterOptionalString.allocationSize(value.`blockExplorerUrl`) +
-                FfiConverterOptionalString.allocationSize(value.`torProxyHost`) +
-                FfiConverterOptionalString.allocationSize(value.`torProxyPort`) +
-                FfiConverterOptionalString.allocationSize(value.`torProxyControlPassword`) +
-                FfiConverterOptionalString.allocationSize(value.`torProxyControlPort`) +
-                FfiConverterString.allocationSize(value.`statechainEntityApi`) +
-                FfiConverterOptionalString.allocationSize(value.`torStatechainEntityApi`) +
-                FfiConverterString.allocationSize(value.`electrumProtocol`) +
-                FfiConverterString.allocationSize(value.`electrumHost`) +
-                FfiConverterString.allocationSize(value.`electrumPort`) +
-                FfiConverterString.allocationSize(value.`electrumType`) +
-                FfiConverterBoolean.allocationSize(value.`notifications`) +
-                FfiConverterBoolean.allocationSize(value.`tutorials`)
-        )
+            FfiConverterOptionalString.allocationSize(value.`blockExplorerUrl`) +
+            FfiConverterOptionalString.allocationSize(value.`torProxyHost`) +
+            FfiConverterOptionalString.allocationSize(value.`torProxyPort`) +
+            FfiConverterOptionalString.allocationSize(value.`torProxyControlPassword`) +
+            FfiConverterOptionalString.allocationSize(value.`torProxyControlPort`) +
+            FfiConverterString.allocationSize(value.`statechainEntityApi`) +
+            FfiConverterOptionalString.allocationSize(value.`torStatechainEntityApi`) +
+            FfiConverterString.allocationSize(value.`electrumProtocol`) +
+            FfiConverterString.allocationSize(value.`electrumHost`) +
+            FfiConverterString.allocationSize(value.`electrumPort`) +
+            FfiConverterString.allocationSize(value.`electrumType`) +
+            FfiConverterBoolean.allocationSize(value.`notifications`) +
+            FfiConverterBoolean.allocationSize(value.`tutorials`)
+    )
 
-    override fun write(
-        value: Settings,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`network`, buf)
-        FfiConverterOptionalString.write(value.`blockExplorerUrl`, buf)
-        FfiConverterOptionalString.write(value.`torProxyHost`, buf)
-        FfiConverterOptionalString.write(value.`torProxyPort`, buf)
-        FfiConverterOptionalString.write(value.`torProxyControlPassword`, buf)
-        FfiConverterOptionalString.write(value.`torProxyControlPort`, buf)
-        FfiConverterString.write(value.`statechainEntityApi`, buf)
-        FfiConverterOptionalString.write(value.`torStatechainEntityApi`, buf)
-        FfiConverterString.write(value.`electrumProtocol`, buf)
-        FfiConverterString.write(value.`electrumHost`, buf)
-        FfiConverterString.write(value.`electrumPort`, buf)
-        FfiConverterString.write(value.`electrumType`, buf)
-        FfiConverterBoolean.write(value.`notifications`, buf)
-        FfiConverterBoolean.write(value.`tutorials`, buf)
+    override fun write(value: Settings, buf: ByteBuffer) {
+            FfiConverterString.write(value.`network`, buf)
+            FfiConverterOptionalString.write(value.`blockExplorerUrl`, buf)
+            FfiConverterOptionalString.write(value.`torProxyHost`, buf)
+            FfiConverterOptionalString.write(value.`torProxyPort`, buf)
+            FfiConverterOptionalString.write(value.`torProxyControlPassword`, buf)
+            FfiConverterOptionalString.write(value.`torProxyControlPort`, buf)
+            FfiConverterString.write(value.`statechainEntityApi`, buf)
+            FfiConverterOptionalString.write(value.`torStatechainEntityApi`, buf)
+            FfiConverterString.write(value.`electrumProtocol`, buf)
+            FfiConverterString.write(value.`electrumHost`, buf)
+            FfiConverterString.write(value.`electrumPort`, buf)
+            FfiConverterString.write(value.`electrumType`, buf)
+            FfiConverterBoolean.write(value.`notifications`, buf)
+            FfiConverterBoolean.write(value.`tutorials`, buf)
     }
 }
 
+
+
 @Serializable
-data class SignFirstRequestPayload(
+data class SignFirstRequestPayload (
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("signed_statechain_id")
-    var `signedStatechainId`: kotlin.String,
+    var `signedStatechainId`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeSignFirstRequestPayload : FfiConverterRustBuffer<SignFirstRequestPayload> {
+public object FfiConverterTypeSignFirstRequestPayload: FfiConverterRustBuffer<SignFirstRequestPayload> {
     override fun read(buf: ByteBuffer): SignFirstRequestPayload {
         return SignFirstRequestPayload(
             FfiConverterString.read(buf),
@@ -2515,57 +3276,99 @@ public object FfiConverterTypeSignFirstRequestPayload : FfiConverterRustBuffer<S
         )
     }
 
-    override fun allocationSize(value: SignFirstRequestPayload) =
-        (
+    override fun allocationSize(value: SignFirstRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`signedStatechainId`)
-        )
+            FfiConverterString.allocationSize(value.`signedStatechainId`)
+    )
 
-    override fun write(
-        value: SignFirstRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`signedStatechainId`, buf)
+    override fun write(value: SignFirstRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`signedStatechainId`, buf)
     }
 }
 
+
+
 @Serializable
-data class SignFirstResponsePayload(
+data class SignFirstResponsePayload (
 	@SerialName("server_pubnonce")
-    var `serverPubnonce`: kotlin.String,
+    var `serverPubnonce`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeSignFirstResponsePayload : FfiConverterRustBuffer<SignFirstResponsePayload> {
+public object FfiConverterTypeSignFirstResponsePayload: FfiConverterRustBuffer<SignFirstResponsePayload> {
     override fun read(buf: ByteBuffer): SignFirstResponsePayload {
         return SignFirstResponsePayload(
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: SignFirstResponsePayload) =
-        (
+    override fun allocationSize(value: SignFirstResponsePayload) = (
             FfiConverterString.allocationSize(value.`serverPubnonce`)
+    )
+
+    override fun write(value: SignFirstResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubnonce`, buf)
+    }
+}
+
+
+
+data class SignatureSchemeValidationReport (
+    var `isValid`: kotlin.Boolean, 
+    var `backupTxs`: List<BackupTxValidation>, 
+    /**
+     * Locktime of the last backup transaction
+     */
+    var `previousLockTime`: kotlin.UInt?, 
+    var `currentFeeRateSatsPerByte`: kotlin.Double, 
+    var `feeRateTolerance`: kotlin.Double
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeSignatureSchemeValidationReport: FfiConverterRustBuffer<SignatureSchemeValidationReport> {
+    override fun read(buf: ByteBuffer): SignatureSchemeValidationReport {
+        return SignatureSchemeValidationReport(
+            FfiConverterBoolean.read(buf),
+            FfiConverterSequenceTypeBackupTxValidation.read(buf),
+            FfiConverterOptionalUInt.read(buf),
+            FfiConverterDouble.read(buf),
+            FfiConverterDouble.read(buf),
         )
+    }
 
-    override fun write(
-        value: SignFirstResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubnonce`, buf)
+    override fun allocationSize(value: SignatureSchemeValidationReport) = (
+            FfiConverterBoolean.allocationSize(value.`isValid`) +
+            FfiConverterSequenceTypeBackupTxValidation.allocationSize(value.`backupTxs`) +
+            FfiConverterOptionalUInt.allocationSize(value.`previousLockTime`) +
+            FfiConverterDouble.allocationSize(value.`currentFeeRateSatsPerByte`) +
+            FfiConverterDouble.allocationSize(value.`feeRateTolerance`)
+    )
+
+    override fun write(value: SignatureSchemeValidationReport, buf: ByteBuffer) {
+            FfiConverterBoolean.write(value.`isValid`, buf)
+            FfiConverterSequenceTypeBackupTxValidation.write(value.`backupTxs`, buf)
+            FfiConverterOptionalUInt.write(value.`previousLockTime`, buf)
+            FfiConverterDouble.write(value.`currentFeeRateSatsPerByte`, buf)
+            FfiConverterDouble.write(value.`feeRateTolerance`, buf)
     }
 }
 
-data class StatechainBackupTxs(
-    var `statechainId`: kotlin.String,
-    var `backupTxs`: List<BackupTx>,
+
+
+data class StatechainBackupTxs (
+    var `statechainId`: kotlin.String, 
+    var `backupTxs`: List<BackupTx>
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeStatechainBackupTxs : FfiConverterRustBuffer<StatechainBackupTxs> {
+public object FfiConverterTypeStatechainBackupTxs: FfiConverterRustBuffer<StatechainBackupTxs> {
     override fun read(buf: ByteBuffer): StatechainBackupTxs {
         return StatechainBackupTxs(
             FfiConverterString.read(buf),
@@ -2573,35 +3376,34 @@ public object FfiConverterTypeStatechainBackupTxs : FfiConverterRustBuffer<State
         )
     }
 
-    override fun allocationSize(value: StatechainBackupTxs) =
-        (
+    override fun allocationSize(value: StatechainBackupTxs) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterSequenceTypeBackupTx.allocationSize(value.`backupTxs`)
-        )
+            FfiConverterSequenceTypeBackupTx.allocationSize(value.`backupTxs`)
+    )
 
-    override fun write(
-        value: StatechainBackupTxs,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterSequenceTypeBackupTx.write(value.`backupTxs`, buf)
+    override fun write(value: StatechainBackupTxs, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterSequenceTypeBackupTx.write(value.`backupTxs`, buf)
     }
 }
 
+
+
 @Serializable
-data class StatechainInfo(
+data class StatechainInfo (
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("server_pubnonce")
-    var `serverPubnonce`: kotlin.String,
-    var `challenge`: kotlin.String,
+    var `serverPubnonce`: kotlin.String, 
+    var `challenge`: kotlin.String, 
 	@SerialName("tx_n")
-    var `txN`: kotlin.UInt,
+    var `txN`: kotlin.UInt
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeStatechainInfo : FfiConverterRustBuffer<StatechainInfo> {
+public object FfiConverterTypeStatechainInfo: FfiConverterRustBuffer<StatechainInfo> {
     override fun read(buf: ByteBuffer): StatechainInfo {
         return StatechainInfo(
             FfiConverterString.read(buf),
@@ -2611,40 +3413,39 @@ public object FfiConverterTypeStatechainInfo : FfiConverterRustBuffer<Statechain
         )
     }
 
-    override fun allocationSize(value: StatechainInfo) =
-        (
+    override fun allocationSize(value: StatechainInfo) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`serverPubnonce`) +
-                FfiConverterString.allocationSize(value.`challenge`) +
-                FfiConverterUInt.allocationSize(value.`txN`)
-        )
+            FfiConverterString.allocationSize(value.`serverPubnonce`) +
+            FfiConverterString.allocationSize(value.`challenge`) +
+            FfiConverterUInt.allocationSize(value.`txN`)
+    )
 
-    override fun write(
-        value: StatechainInfo,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`serverPubnonce`, buf)
-        FfiConverterString.write(value.`challenge`, buf)
-        FfiConverterUInt.write(value.`txN`, buf)
+    override fun write(value: StatechainInfo, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`serverPubnonce`, buf)
+            FfiConverterString.write(value.`challenge`, buf)
+            FfiConverterUInt.write(value.`txN`, buf)
     }
 }
 
+
+
 @Serializable
-data class StatechainInfoResponsePayload(
+data class StatechainInfoResponsePayload (
 	@SerialName("enclave_public_key")
-    var `enclavePublicKey`: kotlin.String,
+    var `enclavePublicKey`: kotlin.String, 
 	@SerialName("num_sigs")
-    var `numSigs`: kotlin.UInt,
+    var `numSigs`: kotlin.UInt, 
 	@SerialName("statechain_info")
-    var `statechainInfo`: List<StatechainInfo>,
+    var `statechainInfo`: List<StatechainInfo>, 
 	@SerialName("x1_pub")
-    var `x1Pub`: kotlin.String?,
+    var `x1Pub`: kotlin.String?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeStatechainInfoResponsePayload : FfiConverterRustBuffer<StatechainInfoResponsePayload> {
+public object FfiConverterTypeStatechainInfoResponsePayload: FfiConverterRustBuffer<StatechainInfoResponsePayload> {
     override fun read(buf: ByteBuffer): StatechainInfoResponsePayload {
         return StatechainInfoResponsePayload(
             FfiConverterString.read(buf),
@@ -2654,44 +3455,151 @@ public object FfiConverterTypeStatechainInfoResponsePayload : FfiConverterRustBu
         )
     }
 
-    override fun allocationSize(value: StatechainInfoResponsePayload) =
-        (
+    override fun allocationSize(value: StatechainInfoResponsePayload) = (
             FfiConverterString.allocationSize(value.`enclavePublicKey`) +
-                FfiConverterUInt.allocationSize(value.`numSigs`) +
-                FfiConverterSequenceTypeStatechainInfo.allocationSize(value.`statechainInfo`) +
-                FfiConverterOptionalString.allocationSize(value.`x1Pub`)
+            FfiConverterUInt.allocationSize(value.`numSigs`) +
+            FfiConverterSequenceTypeStatechainInfo.allocationSize(value.`statechainInfo`) +
+            FfiConverterOptionalString.allocationSize(value.`x1Pub`)
+    )
+
+    override fun write(value: StatechainInfoResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`enclavePublicKey`, buf)
+            FfiConverterUInt.write(value.`numSigs`, buf)
+            FfiConverterSequenceTypeStatechainInfo.write(value.`statechainInfo`, buf)
+            FfiConverterOptionalString.write(value.`x1Pub`, buf)
+    }
+}
+
+
+
+/**
+ * Asks the server which statechains are owned by `auth_pubkey`.
+ * The request is signed with the auth key, so only its owner can list them.
+ */
+data class StatechainLookupRequestPayload (
+    var `authPubkey`: kotlin.String, 
+    /**
+     * Unix time, in seconds, at which the request was signed
+     */
+    var `timestamp`: kotlin.ULong, 
+    var `signature`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeStatechainLookupRequestPayload: FfiConverterRustBuffer<StatechainLookupRequestPayload> {
+    override fun read(buf: ByteBuffer): StatechainLookupRequestPayload {
+        return StatechainLookupRequestPayload(
+            FfiConverterString.read(buf),
+            FfiConverterULong.read(buf),
+            FfiConverterString.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: StatechainLookupRequestPayload) = (
+            FfiConverterString.allocationSize(value.`authPubkey`) +
+            FfiConverterULong.allocationSize(value.`timestamp`) +
+            FfiConverterString.allocationSize(value.`signature`)
+    )
+
+    override fun write(value: StatechainLookupRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`authPubkey`, buf)
+            FfiConverterULong.write(value.`timestamp`, buf)
+            FfiConverterString.write(value.`signature`, buf)
+    }
+}
+
+
+
+data class StatechainLookupResponsePayload (
+    var `statechains`: List<RecoveredStatechain>, 
+    /**
+     * Statechains owned before by `auth_pubkey`. The key is used even if it owns nothing now.
+     */
+    var `previousStatechains`: List<PreviousStatechain>
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeStatechainLookupResponsePayload: FfiConverterRustBuffer<StatechainLookupResponsePayload> {
+    override fun read(buf: ByteBuffer): StatechainLookupResponsePayload {
+        return StatechainLookupResponsePayload(
+            FfiConverterSequenceTypeRecoveredStatechain.read(buf),
+            FfiConverterSequenceTypePreviousStatechain.read(buf),
+        )
+    }
+
+    override fun allocationSize(value: StatechainLookupResponsePayload) = (
+            FfiConverterSequenceTypeRecoveredStatechain.allocationSize(value.`statechains`) +
+            FfiConverterSequenceTypePreviousStatechain.allocationSize(value.`previousStatechains`)
+    )
+
+    override fun write(value: StatechainLookupResponsePayload, buf: ByteBuffer) {
+            FfiConverterSequenceTypeRecoveredStatechain.write(value.`statechains`, buf)
+            FfiConverterSequenceTypePreviousStatechain.write(value.`previousStatechains`, buf)
+    }
+}
+
+
+
+/**
+ * Current auth key and server key share of a statechain, as known by the server
+ */
+data class StatechainOwnerResponsePayload (
+    var `statechainId`: kotlin.String, 
+    var `authXonlyPubkey`: kotlin.String, 
+    var `serverPublicKey`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeStatechainOwnerResponsePayload: FfiConverterRustBuffer<StatechainOwnerResponsePayload> {
+    override fun read(buf: ByteBuffer): StatechainOwnerResponsePayload {
+        return StatechainOwnerResponsePayload(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
         )
+    }
+
+    override fun allocationSize(value: StatechainOwnerResponsePayload) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`authXonlyPubkey`) +
+            FfiConverterString.allocationSize(value.`serverPublicKey`)
+    )
 
-    override fun write(
-        value: StatechainInfoResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`enclavePublicKey`, buf)
-        FfiConverterUInt.write(value.`numSigs`, buf)
-        FfiConverterSequenceTypeStatechainInfo.write(value.`statechainInfo`, buf)
-        FfiConverterOptionalString.write(value.`x1Pub`, buf)
+    override fun write(value: StatechainOwnerResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authXonlyPubkey`, buf)
+            FfiConverterString.write(value.`serverPublicKey`, buf)
     }
 }
 
+
+
 @Serializable
-data class Token(
+data class Token (
 	@SerialName("btc_payment_address")
-    var `btcPaymentAddress`: kotlin.String,
-    var `fee`: kotlin.String,
+    var `btcPaymentAddress`: kotlin.String, 
+    var `fee`: kotlin.String, 
 	@SerialName("lightning_invoice")
-    var `lightningInvoice`: kotlin.String,
+    var `lightningInvoice`: kotlin.String, 
 	@SerialName("processor_id")
-    var `processorId`: kotlin.String,
+    var `processorId`: kotlin.String, 
 	@SerialName("token_id")
-    var `tokenId`: kotlin.String,
-    var `confirmed`: kotlin.Boolean,
-    var `spent`: kotlin.Boolean,
-    var `expiry`: kotlin.String,
+    var `tokenId`: kotlin.String, 
+    var `confirmed`: kotlin.Boolean, 
+    var `spent`: kotlin.Boolean, 
+    var `expiry`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeToken : FfiConverterRustBuffer<Token> {
+public object FfiConverterTypeToken: FfiConverterRustBuffer<Token> {
     override fun read(buf: ByteBuffer): Token {
         return Token(
             FfiConverterString.read(buf),
@@ -2705,106 +3613,135 @@ public object FfiConverterTypeToken : FfiConverterRustBuffer<Token> {
         )
     }
 
-    override fun allocationSize(value: Token) =
-        (
+    override fun allocationSize(value: Token) = (
             FfiConverterString.allocationSize(value.`btcPaymentAddress`) +
-                FfiConverterString.allocationSize(value.`fee`) +
-                FfiConverterString.allocationSize(value.`lightningInvoice`) +
-                FfiConverterString.allocationSize(value.`processorId`) +
-                FfiConverterString.allocationSize(value.`tokenId`) +
-                FfiConverterBoolean.allocationSize(value.`confirmed`) +
-                FfiConverterBoolean.allocationSize(value.`spent`) +
-                FfiConverterString.allocationSize(value.`expiry`)
-        )
+            FfiConverterString.allocationSize(value.`fee`) +
+            FfiConverterString.allocationSize(value.`lightningInvoice`) +
+            FfiConverterString.allocationSize(value.`processorId`) +
+            FfiConverterString.allocationSize(value.`tokenId`) +
+            FfiConverterBoolean.allocationSize(value.`confirmed`) +
+            FfiConverterBoolean.allocationSize(value.`spent`) +
+            FfiConverterString.allocationSize(value.`expiry`)
+    )
 
-    override fun write(
-        value: Token,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`btcPaymentAddress`, buf)
-        FfiConverterString.write(value.`fee`, buf)
-        FfiConverterString.write(value.`lightningInvoice`, buf)
-        FfiConverterString.write(value.`processorId`, buf)
-        FfiConverterString.write(value.`tokenId`, buf)
-        FfiConverterBoolean.write(value.`confirmed`, buf)
-        FfiConverterBoolean.write(value.`spent`, buf)
-        FfiConverterString.write(value.`expiry`, buf)
+    override fun write(value: Token, buf: ByteBuffer) {
+            FfiConverterString.write(value.`btcPaymentAddress`, buf)
+            FfiConverterString.write(value.`fee`, buf)
+            FfiConverterString.write(value.`lightningInvoice`, buf)
+            FfiConverterString.write(value.`processorId`, buf)
+            FfiConverterString.write(value.`tokenId`, buf)
+            FfiConverterBoolean.write(value.`confirmed`, buf)
+            FfiConverterBoolean.write(value.`spent`, buf)
+            FfiConverterString.write(value.`expiry`, buf)
     }
 }
 
-@Serializable
-data class TransferReceiverErrorResponsePayload(
-    var `code`: TransferReceiverError,
-    var `message`: kotlin.String,
+
+
+data class TransferPreimageRequestPayload (
+    var `statechainId`: kotlin.String, 
+    var `authSig`: kotlin.String, 
+    var `previousUserAuthKey`: kotlin.String, 
+    var `batchId`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferReceiverErrorResponsePayload : FfiConverterRustBuffer<TransferReceiverErrorResponsePayload> {
-    override fun read(buf: ByteBuffer): TransferReceiverErrorResponsePayload {
-        return TransferReceiverErrorResponsePayload(
-            FfiConverterTypeTransferReceiverError.read(buf),
+public object FfiConverterTypeTransferPreimageRequestPayload: FfiConverterRustBuffer<TransferPreimageRequestPayload> {
+    override fun read(buf: ByteBuffer): TransferPreimageRequestPayload {
+        return TransferPreimageRequestPayload(
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: TransferReceiverErrorResponsePayload) =
-        (
-            FfiConverterTypeTransferReceiverError.allocationSize(value.`code`) +
-                FfiConverterString.allocationSize(value.`message`)
+    override fun allocationSize(value: TransferPreimageRequestPayload) = (
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterString.allocationSize(value.`previousUserAuthKey`) +
+            FfiConverterString.allocationSize(value.`batchId`)
+    )
+
+    override fun write(value: TransferPreimageRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterString.write(value.`previousUserAuthKey`, buf)
+            FfiConverterString.write(value.`batchId`, buf)
+    }
+}
+
+
+
+data class TransferPreimageResponsePayload (
+    var `preimage`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeTransferPreimageResponsePayload: FfiConverterRustBuffer<TransferPreimageResponsePayload> {
+    override fun read(buf: ByteBuffer): TransferPreimageResponsePayload {
+        return TransferPreimageResponsePayload(
+            FfiConverterString.read(buf),
         )
+    }
+
+    override fun allocationSize(value: TransferPreimageResponsePayload) = (
+            FfiConverterString.allocationSize(value.`preimage`)
+    )
 
-    override fun write(
-        value: TransferReceiverErrorResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterTypeTransferReceiverError.write(value.`code`, buf)
-        FfiConverterString.write(value.`message`, buf)
+    override fun write(value: TransferPreimageResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`preimage`, buf)
     }
 }
 
+
+
 @Serializable
-data class TransferReceiverPostResponsePayload(
+data class TransferReceiverPostResponsePayload (
 	@SerialName("server_pubkey")
-    var `serverPubkey`: kotlin.String,
+    var `serverPubkey`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferReceiverPostResponsePayload : FfiConverterRustBuffer<TransferReceiverPostResponsePayload> {
+public object FfiConverterTypeTransferReceiverPostResponsePayload: FfiConverterRustBuffer<TransferReceiverPostResponsePayload> {
     override fun read(buf: ByteBuffer): TransferReceiverPostResponsePayload {
         return TransferReceiverPostResponsePayload(
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: TransferReceiverPostResponsePayload) =
-        (
+    override fun allocationSize(value: TransferReceiverPostResponsePayload) = (
             FfiConverterString.allocationSize(value.`serverPubkey`)
-        )
+    )
 
-    override fun write(
-        value: TransferReceiverPostResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`serverPubkey`, buf)
+    override fun write(value: TransferReceiverPostResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`serverPubkey`, buf)
     }
 }
 
+
+
 @Serializable
-data class TransferReceiverRequestPayload(
+data class TransferReceiverRequestPayload (
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("batch_data")
-    var `batchData`: kotlin.String?,
-    var `t2`: kotlin.String,
+    var `batchData`: kotlin.String?, 
+    var `t2`: kotlin.String, 
 	@SerialName("auth_sig")
-    var `authSig`: kotlin.String,
+    var `authSig`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferReceiverRequestPayload : FfiConverterRustBuffer<TransferReceiverRequestPayload> {
+public object FfiConverterTypeTransferReceiverRequestPayload: FfiConverterRustBuffer<TransferReceiverRequestPayload> {
     override fun read(buf: ByteBuffer): TransferReceiverRequestPayload {
         return TransferReceiverRequestPayload(
             FfiConverterString.read(buf),
@@ -2814,40 +3751,39 @@ public object FfiConverterTypeTransferReceiverRequestPayload : FfiConverterRustB
         )
     }
 
-    override fun allocationSize(value: TransferReceiverRequestPayload) =
-        (
+    override fun allocationSize(value: TransferReceiverRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterOptionalString.allocationSize(value.`batchData`) +
-                FfiConverterString.allocationSize(value.`t2`) +
-                FfiConverterString.allocationSize(value.`authSig`)
-        )
+            FfiConverterOptionalString.allocationSize(value.`batchData`) +
+            FfiConverterString.allocationSize(value.`t2`) +
+            FfiConverterString.allocationSize(value.`authSig`)
+    )
 
-    override fun write(
-        value: TransferReceiverRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterOptionalString.write(value.`batchData`, buf)
-        FfiConverterString.write(value.`t2`, buf)
-        FfiConverterString.write(value.`authSig`, buf)
+    override fun write(value: TransferReceiverRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterOptionalString.write(value.`batchData`, buf)
+            FfiConverterString.write(value.`t2`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
     }
 }
 
+
+
 @Serializable
-data class TransferSenderRequestPayload(
+data class TransferSenderRequestPayload (
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("auth_sig")
-    var `authSig`: kotlin.String,
+    var `authSig`: kotlin.String, 
 	@SerialName("new_user_auth_key")
-    var `newUserAuthKey`: kotlin.String,
+    var `newUserAuthKey`: kotlin.String, 
 	@SerialName("batch_id")
-    var `batchId`: kotlin.String?,
+    var `batchId`: kotlin.String?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferSenderRequestPayload : FfiConverterRustBuffer<TransferSenderRequestPayload> {
+public object FfiConverterTypeTransferSenderRequestPayload: FfiConverterRustBuffer<TransferSenderRequestPayload> {
     override fun read(buf: ByteBuffer): TransferSenderRequestPayload {
         return TransferSenderRequestPayload(
             FfiConverterString.read(buf),
@@ -2857,105 +3793,102 @@ public object FfiConverterTypeTransferSenderRequestPayload : FfiConverterRustBuf
         )
     }
 
-    override fun allocationSize(value: TransferSenderRequestPayload) =
-        (
+    override fun allocationSize(value: TransferSenderRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`authSig`) +
-                FfiConverterString.allocationSize(value.`newUserAuthKey`) +
-                FfiConverterOptionalString.allocationSize(value.`batchId`)
-        )
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterString.allocationSize(value.`newUserAuthKey`) +
+            FfiConverterOptionalString.allocationSize(value.`batchId`)
+    )
 
-    override fun write(
-        value: TransferSenderRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`authSig`, buf)
-        FfiConverterString.write(value.`newUserAuthKey`, buf)
-        FfiConverterOptionalString.write(value.`batchId`, buf)
+    override fun write(value: TransferSenderRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterString.write(value.`newUserAuthKey`, buf)
+            FfiConverterOptionalString.write(value.`batchId`, buf)
     }
 }
 
+
+
 @Serializable
-data class TransferSenderResponsePayload(
-    var `x1`: kotlin.String,
+data class TransferSenderResponsePayload (
+    var `x1`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferSenderResponsePayload : FfiConverterRustBuffer<TransferSenderResponsePayload> {
+public object FfiConverterTypeTransferSenderResponsePayload: FfiConverterRustBuffer<TransferSenderResponsePayload> {
     override fun read(buf: ByteBuffer): TransferSenderResponsePayload {
         return TransferSenderResponsePayload(
             FfiConverterString.read(buf),
         )
     }
 
-    override fun allocationSize(value: TransferSenderResponsePayload) =
-        (
+    override fun allocationSize(value: TransferSenderResponsePayload) = (
             FfiConverterString.allocationSize(value.`x1`)
-        )
+    )
 
-    override fun write(
-        value: TransferSenderResponsePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`x1`, buf)
+    override fun write(value: TransferSenderResponsePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`x1`, buf)
     }
 }
 
+
+
 @Serializable
-data class TransferUnlockRequestPayload(
+data class TransferUnlockRequestPayload (
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("auth_sig")
-    var `authSig`: kotlin.String,
+    var `authSig`: kotlin.String, 
 	@SerialName("auth_pub_key")
-    var `authPubKey`: kotlin.String,
+    var `authPubKey`: kotlin.String?
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferUnlockRequestPayload : FfiConverterRustBuffer<TransferUnlockRequestPayload> {
+public object FfiConverterTypeTransferUnlockRequestPayload: FfiConverterRustBuffer<TransferUnlockRequestPayload> {
     override fun read(buf: ByteBuffer): TransferUnlockRequestPayload {
         return TransferUnlockRequestPayload(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
-            FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
         )
     }
 
-    override fun allocationSize(value: TransferUnlockRequestPayload) =
-        (
+    override fun allocationSize(value: TransferUnlockRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`authSig`) +
-                FfiConverterString.allocationSize(value.`authPubKey`)
-        )
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterOptionalString.allocationSize(value.`authPubKey`)
+    )
 
-    override fun write(
-        value: TransferUnlockRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`authSig`, buf)
-        FfiConverterString.write(value.`authPubKey`, buf)
+    override fun write(value: TransferUnlockRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterOptionalString.write(value.`authPubKey`, buf)
     }
 }
 
+
+
 @Serializable
-data class TransferUpdateMsgRequestPayload(
+data class TransferUpdateMsgRequestPayload (
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("auth_sig")
-    var `authSig`: kotlin.String,
+    var `authSig`: kotlin.String, 
 	@SerialName("new_user_auth_key")
-    var `newUserAuthKey`: kotlin.String,
+    var `newUserAuthKey`: kotlin.String, 
 	@SerialName("enc_transfer_msg")
-    var `encTransferMsg`: kotlin.String,
+    var `encTransferMsg`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTransferUpdateMsgRequestPayload : FfiConverterRustBuffer<TransferUpdateMsgRequestPayload> {
+public object FfiConverterTypeTransferUpdateMsgRequestPayload: FfiConverterRustBuffer<TransferUpdateMsgRequestPayload> {
     override fun read(buf: ByteBuffer): TransferUpdateMsgRequestPayload {
         return TransferUpdateMsgRequestPayload(
             FfiConverterString.read(buf),
@@ -2965,33 +3898,32 @@ public object FfiConverterTypeTransferUpdateMsgRequestPayload : FfiConverterRust
         )
     }
 
-    override fun allocationSize(value: TransferUpdateMsgRequestPayload) =
-        (
+    override fun allocationSize(value: TransferUpdateMsgRequestPayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`authSig`) +
-                FfiConverterString.allocationSize(value.`newUserAuthKey`) +
-                FfiConverterString.allocationSize(value.`encTransferMsg`)
-        )
+            FfiConverterString.allocationSize(value.`authSig`) +
+            FfiConverterString.allocationSize(value.`newUserAuthKey`) +
+            FfiConverterString.allocationSize(value.`encTransferMsg`)
+    )
 
-    override fun write(
-        value: TransferUpdateMsgRequestPayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`authSig`, buf)
-        FfiConverterString.write(value.`newUserAuthKey`, buf)
-        FfiConverterString.write(value.`encTransferMsg`, buf)
+    override fun write(value: TransferUpdateMsgRequestPayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`authSig`, buf)
+            FfiConverterString.write(value.`newUserAuthKey`, buf)
+            FfiConverterString.write(value.`encTransferMsg`, buf)
     }
 }
 
-data class TxOutpoint(
-    var `txid`: kotlin.String,
-    var `vout`: kotlin.UInt,
+
+
+data class TxOutpoint (
+    var `txid`: kotlin.String, 
+    var `vout`: kotlin.UInt
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeTxOutpoint : FfiConverterRustBuffer<TxOutpoint> {
+public object FfiConverterTypeTxOutpoint: FfiConverterRustBuffer<TxOutpoint> {
     override fun read(buf: ByteBuffer): TxOutpoint {
         return TxOutpoint(
             FfiConverterString.read(buf),
@@ -2999,45 +3931,62 @@ public object FfiConverterTypeTxOutpoint : FfiConverterRustBuffer<TxOutpoint> {
         )
     }
 
-    override fun allocationSize(value: TxOutpoint) =
-        (
+    override fun allocationSize(value: TxOutpoint) = (
             FfiConverterString.allocationSize(value.`txid`) +
-                FfiConverterUInt.allocationSize(value.`vout`)
-        )
+            FfiConverterUInt.allocationSize(value.`vout`)
+    )
 
-    override fun write(
-        value: TxOutpoint,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`txid`, buf)
-        FfiConverterUInt.write(value.`vout`, buf)
+    override fun write(value: TxOutpoint, buf: ByteBuffer) {
+            FfiConverterString.write(value.`txid`, buf)
+            FfiConverterUInt.write(value.`vout`, buf)
     }
 }
 
+
+
 @Serializable
-data class Wallet(
-    var `name`: kotlin.String,
-    var `mnemonic`: kotlin.String,
-    var `version`: kotlin.String,
-    var `stateEntityEndpoint`: kotlin.String,
-    var `electrumEndpoint`: kotlin.String,
-    var `network`: kotlin.String,
-    var `blockheight`: kotlin.UInt,
-    var `initlock`: kotlin.UInt,
-    var `interval`: kotlin.UInt,
-    var `tokens`: List<Token>,
-    var `activities`: List<Activity>,
-    var `coins`: List<Coin>,
-    var `settings`: Settings,
+data class Wallet (
+    var `name`: kotlin.String, 
+    var `mnemonic`: kotlin.String, 
+    /**
+     * Optional BIP39 passphrase. Clients should store it separately from the mnemonic.
+     */
+    var `passphrase`: kotlin.String?, 
+    /**
+     * Wallets created before the scheme was recorded use `Legacy`
+     */
+	@SerialName("derivation_scheme")
+    var `derivationScheme`: DerivationScheme, 
+    /**
+     * BIP44 account, to keep separate wallets with the same mnemonic
+     */
+    var `account`: kotlin.UInt, 
+    var `version`: kotlin.String, 
+	@SerialName("state_entity_endpoint")
+    var `stateEntityEndpoint`: kotlin.String, 
+	@SerialName("electrum_endpoint")
+    var `electrumEndpoint`: kotlin.String, 
+    var `network`: kotlin.String, 
+    var `blockheight`: kotlin.UInt, 
+    var `initlock`: kotlin.UInt, 
+    var `interval`: kotlin.UInt, 
+    var `tokens`: List<Token>, 
+    var `activities`: List<Activity>, 
+    var `coins`: List<Coin>, 
+    var `settings`: Settings
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeWallet : FfiConverterRustBuffer<Wallet> {
+public object FfiConverterTypeWallet: FfiConverterRustBuffer<Wallet> {
     override fun read(buf: ByteBuffer): Wallet {
         return Wallet(
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
+            FfiConverterOptionalString.read(buf),
+            FfiConverterTypeDerivationScheme.read(buf),
+            FfiConverterUInt.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
             FfiConverterString.read(buf),
@@ -3052,54 +4001,137 @@ public object FfiConverterTypeWallet : FfiConverterRustBuffer<Wallet> {
         )
     }
 
-    override fun allocationSize(value: Wallet) =
-        (
+    override fun allocationSize(value: Wallet) = (
             FfiConverterString.allocationSize(value.`name`) +
-                FfiConverterString.allocationSize(value.`mnemonic`) +
-                FfiConverterString.allocationSize(value.`version`) +
-                FfiConverterString.allocationSize(value.`stateEntityEndpoint`) +
-                FfiConverterString.allocationSize(value.`electrumEndpoint`) +
-                FfiConverterString.allocationSize(value.`network`) +
-                FfiConverterUInt.allocationSize(value.`blockheight`) +
-                FfiConverterUInt.allocationSize(value.`initlock`) +
-                FfiConverterUInt.allocationSize(value.`interval`) +
-                FfiConverterSequenceTypeToken.allocationSize(value.`tokens`) +
-                FfiConverterSequenceTypeActivity.allocationSize(value.`activities`) +
-                FfiConverterSequenceTypeCoin.allocationSize(value.`coins`) +
-                FfiConverterTypeSettings.allocationSize(value.`settings`)
+            FfiConverterString.allocationSize(value.`mnemonic`) +
+            FfiConverterOptionalString.allocationSize(value.`passphrase`) +
+            FfiConverterTypeDerivationScheme.allocationSize(value.`derivationScheme`) +
+            FfiConverterUInt.allocationSize(value.`account`) +
+            FfiConverterString.allocationSize(value.`version`) +
+            FfiConverterString.allocationSize(value.`stateEntityEndpoint`) +
+            FfiConverterString.allocationSize(value.`electrumEndpoint`) +
+            FfiConverterString.allocationSize(value.`network`) +
+            FfiConverterUInt.allocationSize(value.`blockheight`) +
+            FfiConverterUInt.allocationSize(value.`initlock`) +
+            FfiConverterUInt.allocationSize(value.`interval`) +
+            FfiConverterSequenceTypeToken.allocationSize(value.`tokens`) +
+            FfiConverterSequenceTypeActivity.allocationSize(value.`activities`) +
+            FfiConverterSequenceTypeCoin.allocationSize(value.`coins`) +
+            FfiConverterTypeSettings.allocationSize(value.`settings`)
+    )
+
+    override fun write(value: Wallet, buf: ByteBuffer) {
+            FfiConverterString.write(value.`name`, buf)
+            FfiConverterString.write(value.`mnemonic`, buf)
+            FfiConverterOptionalString.write(value.`passphrase`, buf)
+            FfiConverterTypeDerivationScheme.write(value.`derivationScheme`, buf)
+            FfiConverterUInt.write(value.`account`, buf)
+            FfiConverterString.write(value.`version`, buf)
+            FfiConverterString.write(value.`stateEntityEndpoint`, buf)
+            FfiConverterString.write(value.`electrumEndpoint`, buf)
+            FfiConverterString.write(value.`network`, buf)
+            FfiConverterUInt.write(value.`blockheight`, buf)
+            FfiConverterUInt.write(value.`initlock`, buf)
+            FfiConverterUInt.write(value.`interval`, buf)
+            FfiConverterSequenceTypeToken.write(value.`tokens`, buf)
+            FfiConverterSequenceTypeActivity.write(value.`activities`, buf)
+            FfiConverterSequenceTypeCoin.write(value.`coins`, buf)
+            FfiConverterTypeSettings.write(value.`settings`, buf)
+    }
+}
+
+
+
+/**
+ * Everything a third party needs to watch a coin and broadcast
+ * its latest backup transaction on behalf of the owner.
+ * The package is signed with the coin's auth key.
+ */
+data class WatchtowerPackage (
+    var `version`: kotlin.UInt, 
+    var `statechainId`: kotlin.String, 
+    var `tx0Txid`: kotlin.String, 
+    var `tx0Vout`: kotlin.UInt, 
+    var `txN`: kotlin.UInt, 
+    /**
+     * The latest backup transaction, ready to be broadcast
+     */
+    var `backupTx`: kotlin.String, 
+    /**
+     * The block height from which `backup_tx` can be broadcast
+     */
+    var `locktime`: kotlin.UInt, 
+    /**
+     * Txids of the earlier backup transactions of the coin (previous owners)
+     */
+    var `previousTxids`: List<kotlin.String>, 
+    var `authPubkey`: kotlin.String, 
+    /**
+     * Schnorr signature of the package by `auth_pubkey`
+     */
+    var `signature`: kotlin.String
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeWatchtowerPackage: FfiConverterRustBuffer<WatchtowerPackage> {
+    override fun read(buf: ByteBuffer): WatchtowerPackage {
+        return WatchtowerPackage(
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterUInt.read(buf),
+            FfiConverterSequenceString.read(buf),
+            FfiConverterString.read(buf),
+            FfiConverterString.read(buf),
         )
+    }
+
+    override fun allocationSize(value: WatchtowerPackage) = (
+            FfiConverterUInt.allocationSize(value.`version`) +
+            FfiConverterString.allocationSize(value.`statechainId`) +
+            FfiConverterString.allocationSize(value.`tx0Txid`) +
+            FfiConverterUInt.allocationSize(value.`tx0Vout`) +
+            FfiConverterUInt.allocationSize(value.`txN`) +
+            FfiConverterString.allocationSize(value.`backupTx`) +
+            FfiConverterUInt.allocationSize(value.`locktime`) +
+            FfiConverterSequenceString.allocationSize(value.`previousTxids`) +
+            FfiConverterString.allocationSize(value.`authPubkey`) +
+            FfiConverterString.allocationSize(value.`signature`)
+    )
 
-    override fun write(
-        value: Wallet,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`name`, buf)
-        FfiConverterString.write(value.`mnemonic`, buf)
-        FfiConverterString.write(value.`version`, buf)
-        FfiConverterString.write(value.`stateEntityEndpoint`, buf)
-        FfiConverterString.write(value.`electrumEndpoint`, buf)
-        FfiConverterString.write(value.`network`, buf)
-        FfiConverterUInt.write(value.`blockheight`, buf)
-        FfiConverterUInt.write(value.`initlock`, buf)
-        FfiConverterUInt.write(value.`interval`, buf)
-        FfiConverterSequenceTypeToken.write(value.`tokens`, buf)
-        FfiConverterSequenceTypeActivity.write(value.`activities`, buf)
-        FfiConverterSequenceTypeCoin.write(value.`coins`, buf)
-        FfiConverterTypeSettings.write(value.`settings`, buf)
+    override fun write(value: WatchtowerPackage, buf: ByteBuffer) {
+            FfiConverterUInt.write(value.`version`, buf)
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`tx0Txid`, buf)
+            FfiConverterUInt.write(value.`tx0Vout`, buf)
+            FfiConverterUInt.write(value.`txN`, buf)
+            FfiConverterString.write(value.`backupTx`, buf)
+            FfiConverterUInt.write(value.`locktime`, buf)
+            FfiConverterSequenceString.write(value.`previousTxids`, buf)
+            FfiConverterString.write(value.`authPubkey`, buf)
+            FfiConverterString.write(value.`signature`, buf)
     }
 }
 
+
+
 @Serializable
-data class WithdrawCompletePayload(
+data class WithdrawCompletePayload (
 	@SerialName("statechain_id")
-    var `statechainId`: kotlin.String,
+    var `statechainId`: kotlin.String, 
 	@SerialName("signed_statechain_id")
-    var `signedStatechainId`: kotlin.String,
+    var `signedStatechainId`: kotlin.String
 ) {
+    
     companion object
 }
 
-public object FfiConverterTypeWithdrawCompletePayload : FfiConverterRustBuffer<WithdrawCompletePayload> {
+public object FfiConverterTypeWithdrawCompletePayload: FfiConverterRustBuffer<WithdrawCompletePayload> {
     override fun read(buf: ByteBuffer): WithdrawCompletePayload {
         return WithdrawCompletePayload(
             FfiConverterString.read(buf),
@@ -3107,22 +4139,142 @@ public object FfiConverterTypeWithdrawCompletePayload : FfiConverterRustBuffer<W
         )
     }
 
-    override fun allocationSize(value: WithdrawCompletePayload) =
-        (
+    override fun allocationSize(value: WithdrawCompletePayload) = (
             FfiConverterString.allocationSize(value.`statechainId`) +
-                FfiConverterString.allocationSize(value.`signedStatechainId`)
+            FfiConverterString.allocationSize(value.`signedStatechainId`)
+    )
+
+    override fun write(value: WithdrawCompletePayload, buf: ByteBuffer) {
+            FfiConverterString.write(value.`statechainId`, buf)
+            FfiConverterString.write(value.`signedStatechainId`, buf)
+    }
+}
+
+
+
+/**
+ * An output of a withdrawal transaction.
+ * The output without `amount`, if any, receives the coin value left after the other outputs and the fee.
+ */
+data class WithdrawalOutput (
+    var `address`: kotlin.String, 
+    var `amount`: kotlin.ULong?
+) {
+    
+    companion object
+}
+
+public object FfiConverterTypeWithdrawalOutput: FfiConverterRustBuffer<WithdrawalOutput> {
+    override fun read(buf: ByteBuffer): WithdrawalOutput {
+        return WithdrawalOutput(
+            FfiConverterString.read(buf),
+            FfiConverterOptionalULong.read(buf),
         )
+    }
+
+    override fun allocationSize(value: WithdrawalOutput) = (
+            FfiConverterString.allocationSize(value.`address`) +
+            FfiConverterOptionalULong.allocationSize(value.`amount`)
+    )
+
+    override fun write(value: WithdrawalOutput, buf: ByteBuffer) {
+            FfiConverterString.write(value.`address`, buf)
+            FfiConverterOptionalULong.write(value.`amount`, buf)
+    }
+}
+
+
+
+/**
+ * Optional anchor output added to backup transactions so that the owner can
+ * bump the fee (CPFP) when the backup transaction is broadcast.
+ */
+
+enum class AnchorOutputType {
+    
+    /**
+     * Pay-to-Anchor (OP_1 <0x4e73>), spendable by anyone with an empty witness
+     */
+    P2A,
+    /**
+     * P2TR output paying to the same key as the backup output
+     */
+    USER_KEY_P2TR;
+    companion object
+}
+
+
+public object FfiConverterTypeAnchorOutputType: FfiConverterRustBuffer<AnchorOutputType> {
+    override fun read(buf: ByteBuffer) = try {
+        AnchorOutputType.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: AnchorOutputType) = 4UL
+
+    override fun write(value: AnchorOutputType, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * Error returned by the server API.
+ * The variant name is the stable code sent in `ApiErrorResponsePayload`.
+ */
+
+enum class ApiError {
+    
+    BAD_REQUEST,
+    INVALID_AUTH_PUBKEY,
+    EXPIRED_BATCH_TIME_ERROR,
+    TRANSFER_MSG_TOO_LARGE,
+    INVALID_SIGNATURE,
+    REQUEST_EXPIRED,
+    TOKEN_GENERATION_NOT_SUPPORTED,
+    NOT_FOUND,
+    STATECHAIN_NOT_FOUND,
+    TOKEN_NOT_FOUND,
+    TRANSFER_MSG_NOT_FOUND,
+    PREIMAGE_NOT_FOUND,
+    AUTH_KEY_ALREADY_ASSIGNED,
+    TRANSFER_MSG_ALREADY_EXISTS,
+    STATECOIN_BATCH_LOCKED_ERROR,
+    SERVER_NONCE_ALREADY_USED,
+    TOKEN_SPENT,
+    TOKEN_NOT_CONFIRMED,
+    RATE_LIMITED,
+    INTERNAL,
+    UPSTREAM_UNAVAILABLE;
+    companion object
+}
+
 
-    override fun write(
-        value: WithdrawCompletePayload,
-        buf: ByteBuffer,
-    ) {
-        FfiConverterString.write(value.`statechainId`, buf)
-        FfiConverterString.write(value.`signedStatechainId`, buf)
+public object FfiConverterTypeApiError: FfiConverterRustBuffer<ApiError> {
+    override fun read(buf: ByteBuffer) = try {
+        ApiError.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: ApiError) = 4UL
+
+    override fun write(value: ApiError, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
     }
 }
 
+
+
+
+
+
 enum class CoinStatus {
+    
     INITIALISED,
     IN_MEMPOOL,
     UNCONFIRMED,
@@ -3131,314 +4283,750 @@ enum class CoinStatus {
     WITHDRAWING,
     TRANSFERRED,
     WITHDRAWN,
-    ;
-
+    DUPLICATED,
+    INVALIDATED;
     companion object
 }
 
-public object FfiConverterTypeCoinStatus : FfiConverterRustBuffer<CoinStatus> {
-    override fun read(buf: ByteBuffer) =
-        try {
-            CoinStatus.values()[buf.getInt() - 1]
-        } catch (e: IndexOutOfBoundsException) {
-            throw RuntimeException("invalid enum value, something is very wrong!!", e)
-        }
+
+public object FfiConverterTypeCoinStatus: FfiConverterRustBuffer<CoinStatus> {
+    override fun read(buf: ByteBuffer) = try {
+        CoinStatus.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
 
     override fun allocationSize(value: CoinStatus) = 4UL
 
-    override fun write(
-        value: CoinStatus,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: CoinStatus, buf: ByteBuffer) {
+        buf.putInt(value.ordinal + 1)
+    }
+}
+
+
+
+
+
+/**
+ * How the user and auth keys of the coins are derived from the seed
+ */
+
+@Serializable
+enum class DerivationScheme {
+    
+    /**
+     * `m/86h/0h/{account}h` and `m/89h/0h/{account}h` on every network
+     */
+    @SerialName("Legacy") LEGACY,
+    /**
+     * `m/86h/{coin_type}h/{account}h` and `m/89h/{coin_type}h/{account}h`,
+     * where the coin type is 0 on mainnet and 1 on the test networks
+     */
+    @SerialName("Bip44") BIP44;
+    companion object
+}
+
+
+public object FfiConverterTypeDerivationScheme: FfiConverterRustBuffer<DerivationScheme> {
+    override fun read(buf: ByteBuffer) = try {
+        DerivationScheme.values()[buf.getInt() - 1]
+    } catch (e: IndexOutOfBoundsException) {
+        throw RuntimeException("invalid enum value, something is very wrong!!", e)
+    }
+
+    override fun allocationSize(value: DerivationScheme) = 4UL
+
+    override fun write(value: DerivationScheme, buf: ByteBuffer) {
         buf.putInt(value.ordinal + 1)
     }
 }
 
-sealed class MercuryException : Exception() {
-    class Bip39Exception() : MercuryException() {
+
+
+
+
+
+
+sealed class MercuryException: Exception() {
+    
+    class Bip39Exception(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class Bip32Exception() : MercuryException() {
+    
+    class Bip32Exception(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class NetworkConversionException() : MercuryException() {
+    
+    class NetworkConversionException(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class Secp256k1UpstreamException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class Secp256k1UpstreamException() : MercuryException() {
+    
+    class KeyException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class KeyException() : MercuryException() {
+    
+    class Bech32Exception(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class Bech32Exception() : MercuryException() {
+    
+    class HexException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class HexException() : MercuryException() {
+    
+    class LocktimeNotBlockHeightException(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class LocktimeTooLow(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class LocktimeTooHigh(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class TransactionReconstructionException(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class TransactionVersionException(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class TransactionSequenceDifferentThanZeroException(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class BitcoinConsensusEncodeException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class LocktimeNotBlockHeightException() : MercuryException() {
+    
+    class MusigNonceGenException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinConsensusEncodeException() : MercuryException() {
+    
+    class InvalidStatechainAddressException(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class InvalidBitcoinAddressException(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class StatechainAddressMismatchNetworkException(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class BitcoinAddressMismatchNetworkException(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class BitcoinAddressException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class MusigNonceGenException() : MercuryException() {
+    
+    class BitcoinAbsoluteException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class InvalidStatechainAddressException() : MercuryException() {
+    
+    class BitcoinHashHexException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class InvalidBitcoinAddressException() : MercuryException() {
+    
+    class BitcoinPsbtException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class StatechainAddressMismatchNetworkException() : MercuryException() {
+    
+    class SighashTypeParseException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinAddressMismatchNetworkException() : MercuryException() {
+    
+    class BitcoinSighashException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinAddressException() : MercuryException() {
+    
+    class ParseException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinAbsoluteException() : MercuryException() {
+    
+    class MusigSignException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinHashHexException() : MercuryException() {
+    
+    class SchnorrSignatureValidationException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinPsbtException() : MercuryException() {
+    
+    class MoreThanOneInputException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class SighashTypeParseException() : MercuryException() {
+    
+    class UnkownNetwork(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class BitcoinSighashException() : MercuryException() {
+    
+    class BackupTransactionDoesNotPayUser(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class FeeTooHigh(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class FeeTooLow(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class OutOfRangeException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class ParseException() : MercuryException() {
+    
+    class SerdeJsonException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class MusigSignException() : MercuryException() {
+    
+    class SecpException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class NoBackupTransactionFound(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class Tx1HasMoreThanOneInput(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class TxHasMoreThanOneOutput(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class EmptyInput(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class InvalidSignature(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class EmptyWitness(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class EmptyWitnessData(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class IncorrectChallenge(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class InvalidT1(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class IncorrectAggregatedPublicKey(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class T1MustBeExactly32BytesException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class SchnorrSignatureValidationException() : MercuryException() {
+    
+    class NoX1Pub(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class NoAggregatedPubkeyException(
+        ) : MercuryException() {
+        override val message
+            get() = ""
+    }
+    
+    class CoinNotFound(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
+        override val message
+            get() = "context=${ `context` }"
+    }
+    
+    class SignatureSchemeValidationException(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class MoreThanOneInputException() : MercuryException() {
+    
+    class NoPreviousLockTimeException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class UnkownNetwork() : MercuryException() {
+    
+    class CoinAmountNotSet(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class BackupTransactionDoesNotPayUser() : MercuryException() {
+    
+    class InsufficientFundsForFeeBump(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class FeeTooHigh() : MercuryException() {
+    
+    class UnknownAnchorOutputType(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class FeeTooLow() : MercuryException() {
+    
+    class InvalidAnchorOutput(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class OutOfRangeException() : MercuryException() {
+    
+    class EciesDecryptionException(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class SerdeJsonException() : MercuryException() {
+    
+    class InvalidUtf8Exception(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class SecpException() : MercuryException() {
+    
+    class InvalidTransferMsg(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class NoBackupTransactionFound() : MercuryException() {
+    
+    class InvalidOutputIndex(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class Tx1HasMoreThanOneInput() : MercuryException() {
+    
+    class InvalidScriptPubkey(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class InvalidSignature() : MercuryException() {
+    
+    class EmptyOutput(
+        ) : MercuryException() {
         override val message
             get() = ""
     }
-
-    class EmptyWitness() : MercuryException() {
+    
+    class InvalidWatchtowerPackage(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class EmptyWitnessData() : MercuryException() {
+    
+    class InvalidWithdrawalOutputs(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class IncorrectChallenge() : MercuryException() {
+    
+    class AmountBelowDust(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class InvalidT1() : MercuryException() {
+    
+    class InvalidDescriptor(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class IncorrectAggregatedPublicKey() : MercuryException() {
+    
+    class UnknownDerivationScheme(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class T1MustBeExactly32BytesException() : MercuryException() {
+    
+    class InvalidPsbt(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class NoX1Pub() : MercuryException() {
+    
+    class TransferMsgTooLarge(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class NoAggregatedPubkeyException() : MercuryException() {
+    
+    class UnsupportedTransferMsgVersion(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class CoinNotFound() : MercuryException() {
+    
+    class InvalidOwnershipProof(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class SignatureSchemeValidationException() : MercuryException() {
+    
+    class KeyRotationNotVerified(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
-
-    class NoPreviousLockTimeException() : MercuryException() {
+    
+    class StatechainAddressExpired(
+        
+        val `context`: ErrorContext
+        ) : MercuryException() {
         override val message
-            get() = ""
+            get() = "context=${ `context` }"
     }
+    
 
     companion object ErrorHandler : UniffiRustCallStatusErrorHandler<MercuryException> {
         override fun lift(error_buf: RustBuffer.ByValue): MercuryException = FfiConverterTypeMercuryError.lift(error_buf)
     }
+
+    
 }
 
 public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryException> {
     override fun read(buf: ByteBuffer): MercuryException {
-        return when (buf.getInt()) {
+        
+
+        return when(buf.getInt()) {
             1 -> MercuryException.Bip39Exception()
             2 -> MercuryException.Bip32Exception()
-            3 -> MercuryException.NetworkConversionException()
+            3 -> MercuryException.NetworkConversionException(
+                FfiConverterTypeErrorContext.read(buf),
+                )
             4 -> MercuryException.Secp256k1UpstreamException()
             5 -> MercuryException.KeyException()
             6 -> MercuryException.Bech32Exception()
             7 -> MercuryException.HexException()
-            8 -> MercuryException.LocktimeNotBlockHeightException()
-            9 -> MercuryException.BitcoinConsensusEncodeException()
-            10 -> MercuryException.MusigNonceGenException()
-            11 -> MercuryException.InvalidStatechainAddressException()
-            12 -> MercuryException.InvalidBitcoinAddressException()
-            13 -> MercuryException.StatechainAddressMismatchNetworkException()
-            14 -> MercuryException.BitcoinAddressMismatchNetworkException()
-            15 -> MercuryException.BitcoinAddressException()
-            16 -> MercuryException.BitcoinAbsoluteException()
-            17 -> MercuryException.BitcoinHashHexException()
-            18 -> MercuryException.BitcoinPsbtException()
-            19 -> MercuryException.SighashTypeParseException()
-            20 -> MercuryException.BitcoinSighashException()
-            21 -> MercuryException.ParseException()
-            22 -> MercuryException.MusigSignException()
-            23 -> MercuryException.SchnorrSignatureValidationException()
-            24 -> MercuryException.MoreThanOneInputException()
-            25 -> MercuryException.UnkownNetwork()
-            26 -> MercuryException.BackupTransactionDoesNotPayUser()
-            27 -> MercuryException.FeeTooHigh()
-            28 -> MercuryException.FeeTooLow()
-            29 -> MercuryException.OutOfRangeException()
-            30 -> MercuryException.SerdeJsonException()
-            31 -> MercuryException.SecpException()
-            32 -> MercuryException.NoBackupTransactionFound()
-            33 -> MercuryException.Tx1HasMoreThanOneInput()
-            34 -> MercuryException.InvalidSignature()
-            35 -> MercuryException.EmptyWitness()
-            36 -> MercuryException.EmptyWitnessData()
-            37 -> MercuryException.IncorrectChallenge()
-            38 -> MercuryException.InvalidT1()
-            39 -> MercuryException.IncorrectAggregatedPublicKey()
-            40 -> MercuryException.T1MustBeExactly32BytesException()
-            41 -> MercuryException.NoX1Pub()
-            42 -> MercuryException.NoAggregatedPubkeyException()
-            43 -> MercuryException.CoinNotFound()
-            44 -> MercuryException.SignatureSchemeValidationException()
-            45 -> MercuryException.NoPreviousLockTimeException()
+            8 -> MercuryException.LocktimeNotBlockHeightException(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            9 -> MercuryException.LocktimeTooLow(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            10 -> MercuryException.LocktimeTooHigh(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            11 -> MercuryException.TransactionReconstructionException(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            12 -> MercuryException.TransactionVersionException(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            13 -> MercuryException.TransactionSequenceDifferentThanZeroException(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            14 -> MercuryException.BitcoinConsensusEncodeException()
+            15 -> MercuryException.MusigNonceGenException()
+            16 -> MercuryException.InvalidStatechainAddressException(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            17 -> MercuryException.InvalidBitcoinAddressException(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            18 -> MercuryException.StatechainAddressMismatchNetworkException(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            19 -> MercuryException.BitcoinAddressMismatchNetworkException(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            20 -> MercuryException.BitcoinAddressException()
+            21 -> MercuryException.BitcoinAbsoluteException()
+            22 -> MercuryException.BitcoinHashHexException()
+            23 -> MercuryException.BitcoinPsbtException()
+            24 -> MercuryException.SighashTypeParseException()
+            25 -> MercuryException.BitcoinSighashException()
+            26 -> MercuryException.ParseException()
+            27 -> MercuryException.MusigSignException()
+            28 -> MercuryException.SchnorrSignatureValidationException()
+            29 -> MercuryException.MoreThanOneInputException()
+            30 -> MercuryException.UnkownNetwork()
+            31 -> MercuryException.BackupTransactionDoesNotPayUser(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            32 -> MercuryException.FeeTooHigh(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            33 -> MercuryException.FeeTooLow(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            34 -> MercuryException.OutOfRangeException()
+            35 -> MercuryException.SerdeJsonException()
+            36 -> MercuryException.SecpException()
+            37 -> MercuryException.NoBackupTransactionFound(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            38 -> MercuryException.Tx1HasMoreThanOneInput(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            39 -> MercuryException.TxHasMoreThanOneOutput(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            40 -> MercuryException.EmptyInput()
+            41 -> MercuryException.InvalidSignature(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            42 -> MercuryException.EmptyWitness()
+            43 -> MercuryException.EmptyWitnessData()
+            44 -> MercuryException.IncorrectChallenge(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            45 -> MercuryException.InvalidT1()
+            46 -> MercuryException.IncorrectAggregatedPublicKey()
+            47 -> MercuryException.T1MustBeExactly32BytesException()
+            48 -> MercuryException.NoX1Pub()
+            49 -> MercuryException.NoAggregatedPubkeyException()
+            50 -> MercuryException.CoinNotFound(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            51 -> MercuryException.SignatureSchemeValidationException(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            52 -> MercuryException.NoPreviousLockTimeException()
+            53 -> MercuryException.CoinAmountNotSet(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            54 -> MercuryException.InsufficientFundsForFeeBump(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            55 -> MercuryException.UnknownAnchorOutputType(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            56 -> MercuryException.InvalidAnchorOutput(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            57 -> MercuryException.EciesDecryptionException()
+            58 -> MercuryException.InvalidUtf8Exception()
+            59 -> MercuryException.InvalidTransferMsg()
+            60 -> MercuryException.InvalidOutputIndex(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            61 -> MercuryException.InvalidScriptPubkey()
+            62 -> MercuryException.EmptyOutput()
+            63 -> MercuryException.InvalidWatchtowerPackage(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            64 -> MercuryException.InvalidWithdrawalOutputs(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            65 -> MercuryException.AmountBelowDust(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            66 -> MercuryException.InvalidDescriptor(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            67 -> MercuryException.UnknownDerivationScheme(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            68 -> MercuryException.InvalidPsbt(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            69 -> MercuryException.TransferMsgTooLarge(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            70 -> MercuryException.UnsupportedTransferMsgVersion(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            71 -> MercuryException.InvalidOwnershipProof(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            72 -> MercuryException.KeyRotationNotVerified(
+                FfiConverterTypeErrorContext.read(buf),
+                )
+            73 -> MercuryException.StatechainAddressExpired(
+                FfiConverterTypeErrorContext.read(buf),
+                )
             else -> throw RuntimeException("invalid error enum value, something is very wrong!!")
         }
     }
 
     override fun allocationSize(value: MercuryException): ULong {
-        return when (value) {
+        return when(value) {
             is MercuryException.Bip39Exception -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
@@ -3450,6 +5038,7 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             is MercuryException.NetworkConversionException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.Secp256k1UpstreamException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
@@ -3470,6 +5059,32 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             is MercuryException.LocktimeNotBlockHeightException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.LocktimeTooLow -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.LocktimeTooHigh -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.TransactionReconstructionException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.TransactionVersionException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.TransactionSequenceDifferentThanZeroException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.BitcoinConsensusEncodeException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
@@ -3482,18 +5097,22 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             is MercuryException.InvalidStatechainAddressException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.InvalidBitcoinAddressException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.StatechainAddressMismatchNetworkException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.BitcoinAddressMismatchNetworkException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.BitcoinAddressException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
@@ -3542,14 +5161,17 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             is MercuryException.BackupTransactionDoesNotPayUser -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.FeeTooHigh -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.FeeTooLow -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.OutOfRangeException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
@@ -3566,14 +5188,26 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             is MercuryException.NoBackupTransactionFound -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.Tx1HasMoreThanOneInput -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.TxHasMoreThanOneOutput -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.EmptyInput -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
             )
             is MercuryException.InvalidSignature -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.EmptyWitness -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
@@ -3586,6 +5220,7 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             is MercuryException.IncorrectChallenge -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.InvalidT1 -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
@@ -3610,23 +5245,122 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             is MercuryException.CoinNotFound -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.SignatureSchemeValidationException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
             )
             is MercuryException.NoPreviousLockTimeException -> (
                 // Add the size for the Int that specifies the variant plus the size needed for all fields
                 4UL
             )
+            is MercuryException.CoinAmountNotSet -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.InsufficientFundsForFeeBump -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.UnknownAnchorOutputType -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.InvalidAnchorOutput -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.EciesDecryptionException -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.InvalidUtf8Exception -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.InvalidTransferMsg -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.InvalidOutputIndex -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.InvalidScriptPubkey -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.EmptyOutput -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+            )
+            is MercuryException.InvalidWatchtowerPackage -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.InvalidWithdrawalOutputs -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.AmountBelowDust -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.InvalidDescriptor -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.UnknownDerivationScheme -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.InvalidPsbt -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.TransferMsgTooLarge -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.UnsupportedTransferMsgVersion -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.InvalidOwnershipProof -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.KeyRotationNotVerified -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
+            is MercuryException.StatechainAddressExpired -> (
+                // Add the size for the Int that specifies the variant plus the size needed for all fields
+                4UL
+                + FfiConverterTypeErrorContext.allocationSize(value.`context`)
+            )
         }
     }
 
-    override fun write(
-        value: MercuryException,
-        buf: ByteBuffer,
-    ) {
-        when (value) {
+    override fun write(value: MercuryException, buf: ByteBuffer) {
+        when(value) {
             is MercuryException.Bip39Exception -> {
                 buf.putInt(1)
                 Unit
@@ -3637,6 +5371,7 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             }
             is MercuryException.NetworkConversionException -> {
                 buf.putInt(3)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
             is MercuryException.Secp256k1UpstreamException -> {
@@ -3657,190 +5392,313 @@ public object FfiConverterTypeMercuryError : FfiConverterRustBuffer<MercuryExcep
             }
             is MercuryException.LocktimeNotBlockHeightException -> {
                 buf.putInt(8)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.BitcoinConsensusEncodeException -> {
+            is MercuryException.LocktimeTooLow -> {
                 buf.putInt(9)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.MusigNonceGenException -> {
+            is MercuryException.LocktimeTooHigh -> {
                 buf.putInt(10)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.InvalidStatechainAddressException -> {
+            is MercuryException.TransactionReconstructionException -> {
                 buf.putInt(11)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.InvalidBitcoinAddressException -> {
+            is MercuryException.TransactionVersionException -> {
                 buf.putInt(12)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.StatechainAddressMismatchNetworkException -> {
+            is MercuryException.TransactionSequenceDifferentThanZeroException -> {
                 buf.putInt(13)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.BitcoinAddressMismatchNetworkException -> {
+            is MercuryException.BitcoinConsensusEncodeException -> {
                 buf.putInt(14)
                 Unit
             }
-            is MercuryException.BitcoinAddressException -> {
+            is MercuryException.MusigNonceGenException -> {
                 buf.putInt(15)
                 Unit
             }
-            is MercuryException.BitcoinAbsoluteException -> {
+            is MercuryException.InvalidStatechainAddressException -> {
                 buf.putInt(16)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.BitcoinHashHexException -> {
+            is MercuryException.InvalidBitcoinAddressException -> {
                 buf.putInt(17)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.BitcoinPsbtException -> {
+            is MercuryException.StatechainAddressMismatchNetworkException -> {
                 buf.putInt(18)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.SighashTypeParseException -> {
+            is MercuryException.BitcoinAddressMismatchNetworkException -> {
                 buf.putInt(19)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.BitcoinSighashException -> {
+            is MercuryException.BitcoinAddressException -> {
                 buf.putInt(20)
                 Unit
             }
-            is MercuryException.ParseException -> {
+            is MercuryException.BitcoinAbsoluteException -> {
                 buf.putInt(21)
                 Unit
             }
-            is MercuryException.MusigSignException -> {
+            is MercuryException.BitcoinHashHexException -> {
                 buf.putInt(22)
                 Unit
             }
-            is MercuryException.SchnorrSignatureValidationException -> {
+            is MercuryException.BitcoinPsbtException -> {
                 buf.putInt(23)
                 Unit
             }
-            is MercuryException.MoreThanOneInputException -> {
+            is MercuryException.SighashTypeParseException -> {
                 buf.putInt(24)
                 Unit
             }
-            is MercuryException.UnkownNetwork -> {
+            is MercuryException.BitcoinSighashException -> {
                 buf.putInt(25)
                 Unit
             }
-            is MercuryException.BackupTransactionDoesNotPayUser -> {
+            is MercuryException.ParseException -> {
                 buf.putInt(26)
                 Unit
             }
-            is MercuryException.FeeTooHigh -> {
+            is MercuryException.MusigSignException -> {
                 buf.putInt(27)
                 Unit
             }
-            is MercuryException.FeeTooLow -> {
+            is MercuryException.SchnorrSignatureValidationException -> {
                 buf.putInt(28)
                 Unit
             }
-            is MercuryException.OutOfRangeException -> {
+            is MercuryException.MoreThanOneInputException -> {
                 buf.putInt(29)
                 Unit
             }
-            is MercuryException.SerdeJsonException -> {
-                buf.putInt(30)
+            is MercuryException.UnkownNetwork -> {
+                buf.putInt(30)
+                Unit
+            }
+            is MercuryException.BackupTransactionDoesNotPayUser -> {
+                buf.putInt(31)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.FeeTooHigh -> {
+                buf.putInt(32)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.FeeTooLow -> {
+                buf.putInt(33)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.OutOfRangeException -> {
+                buf.putInt(34)
+                Unit
+            }
+            is MercuryException.SerdeJsonException -> {
+                buf.putInt(35)
+                Unit
+            }
+            is MercuryException.SecpException -> {
+                buf.putInt(36)
+                Unit
+            }
+            is MercuryException.NoBackupTransactionFound -> {
+                buf.putInt(37)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.Tx1HasMoreThanOneInput -> {
+                buf.putInt(38)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.TxHasMoreThanOneOutput -> {
+                buf.putInt(39)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.EmptyInput -> {
+                buf.putInt(40)
+                Unit
+            }
+            is MercuryException.InvalidSignature -> {
+                buf.putInt(41)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.EmptyWitness -> {
+                buf.putInt(42)
+                Unit
+            }
+            is MercuryException.EmptyWitnessData -> {
+                buf.putInt(43)
+                Unit
+            }
+            is MercuryException.IncorrectChallenge -> {
+                buf.putInt(44)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.InvalidT1 -> {
+                buf.putInt(45)
+                Unit
+            }
+            is MercuryException.IncorrectAggregatedPublicKey -> {
+                buf.putInt(46)
+                Unit
+            }
+            is MercuryException.T1MustBeExactly32BytesException -> {
+                buf.putInt(47)
+                Unit
+            }
+            is MercuryException.NoX1Pub -> {
+                buf.putInt(48)
+                Unit
+            }
+            is MercuryException.NoAggregatedPubkeyException -> {
+                buf.putInt(49)
+                Unit
+            }
+            is MercuryException.CoinNotFound -> {
+                buf.putInt(50)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.SignatureSchemeValidationException -> {
+                buf.putInt(51)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.NoPreviousLockTimeException -> {
+                buf.putInt(52)
+                Unit
+            }
+            is MercuryException.CoinAmountNotSet -> {
+                buf.putInt(53)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.InsufficientFundsForFeeBump -> {
+                buf.putInt(54)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.UnknownAnchorOutputType -> {
+                buf.putInt(55)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.InvalidAnchorOutput -> {
+                buf.putInt(56)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
+                Unit
+            }
+            is MercuryException.EciesDecryptionException -> {
+                buf.putInt(57)
+                Unit
+            }
+            is MercuryException.InvalidUtf8Exception -> {
+                buf.putInt(58)
                 Unit
             }
-            is MercuryException.SecpException -> {
-                buf.putInt(31)
+            is MercuryException.InvalidTransferMsg -> {
+                buf.putInt(59)
                 Unit
             }
-            is MercuryException.NoBackupTransactionFound -> {
-                buf.putInt(32)
+            is MercuryException.InvalidOutputIndex -> {
+                buf.putInt(60)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.Tx1HasMoreThanOneInput -> {
-                buf.putInt(33)
+            is MercuryException.InvalidScriptPubkey -> {
+                buf.putInt(61)
                 Unit
             }
-            is MercuryException.InvalidSignature -> {
-                buf.putInt(34)
+            is MercuryException.EmptyOutput -> {
+                buf.putInt(62)
                 Unit
             }
-            is MercuryException.EmptyWitness -> {
-                buf.putInt(35)
+            is MercuryException.InvalidWatchtowerPackage -> {
+                buf.putInt(63)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.EmptyWitnessData -> {
-                buf.putInt(36)
+            is MercuryException.InvalidWithdrawalOutputs -> {
+                buf.putInt(64)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.IncorrectChallenge -> {
-                buf.putInt(37)
+            is MercuryException.AmountBelowDust -> {
+                buf.putInt(65)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.InvalidT1 -> {
-                buf.putInt(38)
+            is MercuryException.InvalidDescriptor -> {
+                buf.putInt(66)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.IncorrectAggregatedPublicKey -> {
-                buf.putInt(39)
+            is MercuryException.UnknownDerivationScheme -> {
+                buf.putInt(67)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.T1MustBeExactly32BytesException -> {
-                buf.putInt(40)
+            is MercuryException.InvalidPsbt -> {
+                buf.putInt(68)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.NoX1Pub -> {
-                buf.putInt(41)
+            is MercuryException.TransferMsgTooLarge -> {
+                buf.putInt(69)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.NoAggregatedPubkeyException -> {
-                buf.putInt(42)
+            is MercuryException.UnsupportedTransferMsgVersion -> {
+                buf.putInt(70)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.CoinNotFound -> {
-                buf.putInt(43)
+            is MercuryException.InvalidOwnershipProof -> {
+                buf.putInt(71)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.SignatureSchemeValidationException -> {
-                buf.putInt(44)
+            is MercuryException.KeyRotationNotVerified -> {
+                buf.putInt(72)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
-            is MercuryException.NoPreviousLockTimeException -> {
-                buf.putInt(45)
+            is MercuryException.StatechainAddressExpired -> {
+                buf.putInt(73)
+                FfiConverterTypeErrorContext.write(value.`context`, buf)
                 Unit
             }
         }.let { /* this makes the `when` an expression, which ensures it is exhaustive */ }
     }
-}
-
-@Serializable
-enum class TransferReceiverError {
-	@SerialName("StatecoinBatchLockedError")
-    STATECOIN_BATCH_LOCKED_ERROR,
-	@SerialName("ExpiredBatchTimeError")
-    EXPIRED_BATCH_TIME_ERROR,
-    ;
 
-    companion object
 }
 
-public object FfiConverterTypeTransferReceiverError : FfiConverterRustBuffer<TransferReceiverError> {
-    override fun read(buf: ByteBuffer) =
-        try {
-            TransferReceiverError.values()[buf.getInt() - 1]
-        } catch (e: IndexOutOfBoundsException) {
-            throw RuntimeException("invalid enum value, something is very wrong!!", e)
-        }
 
-    override fun allocationSize(value: TransferReceiverError) = 4UL
 
-    override fun write(
-        value: TransferReceiverError,
-        buf: ByteBuffer,
-    ) {
-        buf.putInt(value.ordinal + 1)
-    }
-}
 
-public object FfiConverterOptionalUInt : FfiConverterRustBuffer<kotlin.UInt?> {
+public object FfiConverterOptionalUInt: FfiConverterRustBuffer<kotlin.UInt?> {
     override fun read(buf: ByteBuffer): kotlin.UInt? {
         if (buf.get().toInt() == 0) {
             return null
@@ -3856,10 +5714,7 @@ public object FfiConverterOptionalUInt : FfiConverterRustBuffer<kotlin.UInt?> {
         }
     }
 
-    override fun write(
-        value: kotlin.UInt?,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: kotlin.UInt?, buf: ByteBuffer) {
         if (value == null) {
             buf.put(0)
         } else {
@@ -3869,7 +5724,68 @@ public object FfiConverterOptionalUInt : FfiConverterRustBuffer<kotlin.UInt?> {
     }
 }
 
-public object FfiConverterOptionalString : FfiConverterRustBuffer<kotlin.String?> {
+
+
+
+public object FfiConverterOptionalULong: FfiConverterRustBuffer<kotlin.ULong?> {
+    override fun read(buf: ByteBuffer): kotlin.ULong? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterULong.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.ULong?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterULong.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.ULong?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterULong.write(value, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterOptionalDouble: FfiConverterRustBuffer<kotlin.Double?> {
+    override fun read(buf: ByteBuffer): kotlin.Double? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterDouble.read(buf)
+    }
+
+    override fun allocationSize(value: kotlin.Double?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterDouble.allocationSize(value)
+        }
+    }
+
+    override fun write(value: kotlin.Double?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterDouble.write(value, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterOptionalString: FfiConverterRustBuffer<kotlin.String?> {
     override fun read(buf: ByteBuffer): kotlin.String? {
         if (buf.get().toInt() == 0) {
             return null
@@ -3885,120 +5801,324 @@ public object FfiConverterOptionalString : FfiConverterRustBuffer<kotlin.String?
         }
     }
 
-    override fun write(
-        value: kotlin.String?,
-        buf: ByteBuffer,
-    ) {
-        if (value == null) {
-            buf.put(0)
-        } else {
-            buf.put(1)
-            FfiConverterString.write(value, buf)
+    override fun write(value: kotlin.String?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterString.write(value, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterOptionalTypeAnchorOutputType: FfiConverterRustBuffer<AnchorOutputType?> {
+    override fun read(buf: ByteBuffer): AnchorOutputType? {
+        if (buf.get().toInt() == 0) {
+            return null
+        }
+        return FfiConverterTypeAnchorOutputType.read(buf)
+    }
+
+    override fun allocationSize(value: AnchorOutputType?): ULong {
+        if (value == null) {
+            return 1UL
+        } else {
+            return 1UL + FfiConverterTypeAnchorOutputType.allocationSize(value)
+        }
+    }
+
+    override fun write(value: AnchorOutputType?, buf: ByteBuffer) {
+        if (value == null) {
+            buf.put(0)
+        } else {
+            buf.put(1)
+            FfiConverterTypeAnchorOutputType.write(value, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceString: FfiConverterRustBuffer<List<kotlin.String>> {
+    override fun read(buf: ByteBuffer): List<kotlin.String> {
+        val len = buf.getInt()
+        return List<kotlin.String>(len) {
+            FfiConverterString.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<kotlin.String>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<kotlin.String>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterString.write(it, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceTypeActivity: FfiConverterRustBuffer<List<Activity>> {
+    override fun read(buf: ByteBuffer): List<Activity> {
+        val len = buf.getInt()
+        return List<Activity>(len) {
+            FfiConverterTypeActivity.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<Activity>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeActivity.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<Activity>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeActivity.write(it, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceTypeBackupTx: FfiConverterRustBuffer<List<BackupTx>> {
+    override fun read(buf: ByteBuffer): List<BackupTx> {
+        val len = buf.getInt()
+        return List<BackupTx>(len) {
+            FfiConverterTypeBackupTx.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<BackupTx>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeBackupTx.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<BackupTx>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeBackupTx.write(it, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceTypeBackupTxValidation: FfiConverterRustBuffer<List<BackupTxValidation>> {
+    override fun read(buf: ByteBuffer): List<BackupTxValidation> {
+        val len = buf.getInt()
+        return List<BackupTxValidation>(len) {
+            FfiConverterTypeBackupTxValidation.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<BackupTxValidation>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeBackupTxValidation.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<BackupTxValidation>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeBackupTxValidation.write(it, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceTypeCoin: FfiConverterRustBuffer<List<Coin>> {
+    override fun read(buf: ByteBuffer): List<Coin> {
+        val len = buf.getInt()
+        return List<Coin>(len) {
+            FfiConverterTypeCoin.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<Coin>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeCoin.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<Coin>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeCoin.write(it, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceTypeCoinDescriptor: FfiConverterRustBuffer<List<CoinDescriptor>> {
+    override fun read(buf: ByteBuffer): List<CoinDescriptor> {
+        val len = buf.getInt()
+        return List<CoinDescriptor>(len) {
+            FfiConverterTypeCoinDescriptor.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<CoinDescriptor>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeCoinDescriptor.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<CoinDescriptor>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeCoinDescriptor.write(it, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceTypeCpfpExtraInput: FfiConverterRustBuffer<List<CpfpExtraInput>> {
+    override fun read(buf: ByteBuffer): List<CpfpExtraInput> {
+        val len = buf.getInt()
+        return List<CpfpExtraInput>(len) {
+            FfiConverterTypeCpfpExtraInput.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<CpfpExtraInput>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeCpfpExtraInput.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<CpfpExtraInput>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeCpfpExtraInput.write(it, buf)
         }
     }
 }
 
-public object FfiConverterSequenceString : FfiConverterRustBuffer<List<kotlin.String>> {
-    override fun read(buf: ByteBuffer): List<kotlin.String> {
+
+
+
+public object FfiConverterSequenceTypeCpfpParent: FfiConverterRustBuffer<List<CpfpParent>> {
+    override fun read(buf: ByteBuffer): List<CpfpParent> {
         val len = buf.getInt()
-        return List<kotlin.String>(len) {
-            FfiConverterString.read(buf)
+        return List<CpfpParent>(len) {
+            FfiConverterTypeCpfpParent.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<kotlin.String>): ULong {
+    override fun allocationSize(value: List<CpfpParent>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterString.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypeCpfpParent.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<kotlin.String>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<CpfpParent>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterString.write(it, buf)
+            FfiConverterTypeCpfpParent.write(it, buf)
         }
     }
 }
 
-public object FfiConverterSequenceTypeActivity : FfiConverterRustBuffer<List<Activity>> {
-    override fun read(buf: ByteBuffer): List<Activity> {
+
+
+
+public object FfiConverterSequenceTypeKeyRotationEvidence: FfiConverterRustBuffer<List<KeyRotationEvidence>> {
+    override fun read(buf: ByteBuffer): List<KeyRotationEvidence> {
         val len = buf.getInt()
-        return List<Activity>(len) {
-            FfiConverterTypeActivity.read(buf)
+        return List<KeyRotationEvidence>(len) {
+            FfiConverterTypeKeyRotationEvidence.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<Activity>): ULong {
+    override fun allocationSize(value: List<KeyRotationEvidence>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterTypeActivity.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypeKeyRotationEvidence.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<Activity>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<KeyRotationEvidence>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterTypeActivity.write(it, buf)
+            FfiConverterTypeKeyRotationEvidence.write(it, buf)
         }
     }
 }
 
-public object FfiConverterSequenceTypeBackupTx : FfiConverterRustBuffer<List<BackupTx>> {
-    override fun read(buf: ByteBuffer): List<BackupTx> {
+
+
+
+public object FfiConverterSequenceTypeMercuryErrorDetails: FfiConverterRustBuffer<List<MercuryErrorDetails>> {
+    override fun read(buf: ByteBuffer): List<MercuryErrorDetails> {
         val len = buf.getInt()
-        return List<BackupTx>(len) {
-            FfiConverterTypeBackupTx.read(buf)
+        return List<MercuryErrorDetails>(len) {
+            FfiConverterTypeMercuryErrorDetails.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<BackupTx>): ULong {
+    override fun allocationSize(value: List<MercuryErrorDetails>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterTypeBackupTx.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypeMercuryErrorDetails.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<BackupTx>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<MercuryErrorDetails>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterTypeBackupTx.write(it, buf)
+            FfiConverterTypeMercuryErrorDetails.write(it, buf)
         }
     }
 }
 
-public object FfiConverterSequenceTypeCoin : FfiConverterRustBuffer<List<Coin>> {
-    override fun read(buf: ByteBuffer): List<Coin> {
+
+
+
+public object FfiConverterSequenceTypePreviousStatechain: FfiConverterRustBuffer<List<PreviousStatechain>> {
+    override fun read(buf: ByteBuffer): List<PreviousStatechain> {
         val len = buf.getInt()
-        return List<Coin>(len) {
-            FfiConverterTypeCoin.read(buf)
+        return List<PreviousStatechain>(len) {
+            FfiConverterTypePreviousStatechain.read(buf)
         }
     }
 
-    override fun allocationSize(value: List<Coin>): ULong {
+    override fun allocationSize(value: List<PreviousStatechain>): ULong {
         val sizeForLength = 4UL
-        val sizeForItems = value.map { FfiConverterTypeCoin.allocationSize(it) }.sum()
+        val sizeForItems = value.map { FfiConverterTypePreviousStatechain.allocationSize(it) }.sum()
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<Coin>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<PreviousStatechain>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
-            FfiConverterTypeCoin.write(it, buf)
+            FfiConverterTypePreviousStatechain.write(it, buf)
         }
     }
 }
 
-public object FfiConverterSequenceTypePubKeyInfo : FfiConverterRustBuffer<List<PubKeyInfo>> {
+
+
+
+public object FfiConverterSequenceTypePubKeyInfo: FfiConverterRustBuffer<List<PubKeyInfo>> {
     override fun read(buf: ByteBuffer): List<PubKeyInfo> {
         val len = buf.getInt()
         return List<PubKeyInfo>(len) {
@@ -4012,10 +6132,7 @@ public object FfiConverterSequenceTypePubKeyInfo : FfiConverterRustBuffer<List<P
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<PubKeyInfo>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<PubKeyInfo>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypePubKeyInfo.write(it, buf)
@@ -4023,7 +6140,35 @@ public object FfiConverterSequenceTypePubKeyInfo : FfiConverterRustBuffer<List<P
     }
 }
 
-public object FfiConverterSequenceTypeStatechainInfo : FfiConverterRustBuffer<List<StatechainInfo>> {
+
+
+
+public object FfiConverterSequenceTypeRecoveredStatechain: FfiConverterRustBuffer<List<RecoveredStatechain>> {
+    override fun read(buf: ByteBuffer): List<RecoveredStatechain> {
+        val len = buf.getInt()
+        return List<RecoveredStatechain>(len) {
+            FfiConverterTypeRecoveredStatechain.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<RecoveredStatechain>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeRecoveredStatechain.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<RecoveredStatechain>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeRecoveredStatechain.write(it, buf)
+        }
+    }
+}
+
+
+
+
+public object FfiConverterSequenceTypeStatechainInfo: FfiConverterRustBuffer<List<StatechainInfo>> {
     override fun read(buf: ByteBuffer): List<StatechainInfo> {
         val len = buf.getInt()
         return List<StatechainInfo>(len) {
@@ -4037,10 +6182,7 @@ public object FfiConverterSequenceTypeStatechainInfo : FfiConverterRustBuffer<Li
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<StatechainInfo>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<StatechainInfo>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeStatechainInfo.write(it, buf)
@@ -4048,7 +6190,10 @@ public object FfiConverterSequenceTypeStatechainInfo : FfiConverterRustBuffer<Li
     }
 }
 
-public object FfiConverterSequenceTypeToken : FfiConverterRustBuffer<List<Token>> {
+
+
+
+public object FfiConverterSequenceTypeToken: FfiConverterRustBuffer<List<Token>> {
     override fun read(buf: ByteBuffer): List<Token> {
         val len = buf.getInt()
         return List<Token>(len) {
@@ -4062,10 +6207,7 @@ public object FfiConverterSequenceTypeToken : FfiConverterRustBuffer<List<Token>
         return sizeForLength + sizeForItems
     }
 
-    override fun write(
-        value: List<Token>,
-        buf: ByteBuffer,
-    ) {
+    override fun write(value: List<Token>, buf: ByteBuffer) {
         buf.putInt(value.size)
         value.iterator().forEach {
             FfiConverterTypeToken.write(it, buf)
@@ -4073,539 +6215,676 @@ public object FfiConverterSequenceTypeToken : FfiConverterRustBuffer<List<Token>
     }
 }
 
-@Throws(MercuryException::class)
-fun `createAggregatedAddress`(
-    `coin`: Coin,
-    `network`: kotlin.String,
-): AggregatedPublicKey {
-    return FfiConverterTypeAggregatedPublicKey.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_aggregated_address(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
-    )
-}
 
-@Throws(MercuryException::class)
-fun `createAndCommitNonces`(`coin`: Coin): CoinNonce {
-    return FfiConverterTypeCoinNonce.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_and_commit_nonces(
-                FfiConverterTypeCoin.lower(`coin`),
-                _status,
-            )
-        },
-    )
-}
 
-@Throws(MercuryException::class)
-fun `createCpfpTx`(
-    `backupTx`: BackupTx,
-    `coin`: Coin,
-    `toAddress`: kotlin.String,
-    `feeRateSatsPerByte`: kotlin.ULong,
-    `network`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_cpfp_tx(
-                FfiConverterTypeBackupTx.lower(`backupTx`),
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`toAddress`),
-                FfiConverterULong.lower(`feeRateSatsPerByte`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
-    )
-}
 
-@Throws(MercuryException::class)
-fun `createDepositMsg1`(
-    `coin`: Coin,
-    `tokenId`: kotlin.String,
-): DepositMsg1 {
-    return FfiConverterTypeDepositMsg1.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_deposit_msg1(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`tokenId`),
-                _status,
-            )
-        },
+public object FfiConverterSequenceTypeWithdrawalOutput: FfiConverterRustBuffer<List<WithdrawalOutput>> {
+    override fun read(buf: ByteBuffer): List<WithdrawalOutput> {
+        val len = buf.getInt()
+        return List<WithdrawalOutput>(len) {
+            FfiConverterTypeWithdrawalOutput.read(buf)
+        }
+    }
+
+    override fun allocationSize(value: List<WithdrawalOutput>): ULong {
+        val sizeForLength = 4UL
+        val sizeForItems = value.map { FfiConverterTypeWithdrawalOutput.allocationSize(it) }.sum()
+        return sizeForLength + sizeForItems
+    }
+
+    override fun write(value: List<WithdrawalOutput>, buf: ByteBuffer) {
+        buf.putInt(value.size)
+        value.iterator().forEach {
+            FfiConverterTypeWithdrawalOutput.write(it, buf)
+        }
+    }
+}
+    @Throws(MercuryException::class) fun `addServerPartialSigToPsbt`(`coin`: Coin, `psbt`: kotlin.String, `serverPartialSigHex`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_add_server_partial_sig_to_psbt(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`psbt`),FfiConverterString.lower(`serverPartialSigHex`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `createAggregatedAddress`(`coin`: Coin, `network`: kotlin.String): AggregatedPublicKey {
+            return FfiConverterTypeAggregatedPublicKey.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_aggregated_address(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `createSignature`(
-    `msg`: kotlin.String,
-    `clientPartialSigHex`: kotlin.String,
-    `serverPartialSigHex`: kotlin.String,
-    `sessionHex`: kotlin.String,
-    `outputPubkeyHex`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_signature(
-                FfiConverterString.lower(`msg`),
-                FfiConverterString.lower(`clientPartialSigHex`),
-                FfiConverterString.lower(`serverPartialSigHex`),
-                FfiConverterString.lower(`sessionHex`),
-                FfiConverterString.lower(`outputPubkeyHex`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `createAndCommitNonces`(`coin`: Coin): CoinNonce {
+            return FfiConverterTypeCoinNonce.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_and_commit_nonces(
+        FfiConverterTypeCoin.lower(`coin`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Base64 PSBT of a backup transaction, with the same parameters as `get_partial_sig_request_with_anchor`
+         */
+    @Throws(MercuryException::class) fun `createBackupTxPsbt`(`coin`: Coin, `blockHeight`: kotlin.UInt, `initlock`: kotlin.UInt, `interval`: kotlin.UInt, `feeRateSatsPerByte`: kotlin.Double, `qtBackupTx`: kotlin.UInt, `toAddress`: kotlin.String, `network`: kotlin.String, `isWithdrawal`: kotlin.Boolean, `anchorType`: AnchorOutputType?): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_backup_tx_psbt(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterUInt.lower(`blockHeight`),FfiConverterUInt.lower(`initlock`),FfiConverterUInt.lower(`interval`),FfiConverterDouble.lower(`feeRateSatsPerByte`),FfiConverterUInt.lower(`qtBackupTx`),FfiConverterString.lower(`toAddress`),FfiConverterString.lower(`network`),FfiConverterBoolean.lower(`isWithdrawal`),FfiConverterOptionalTypeAnchorOutputType.lower(`anchorType`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `createTransferSignature`(
-    `recipientAddress`: kotlin.String,
-    `inputTxid`: kotlin.String,
-    `inputVout`: kotlin.UInt,
-    `clientSeckey`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_transfer_signature(
-                FfiConverterString.lower(`recipientAddress`),
-                FfiConverterString.lower(`inputTxid`),
-                FfiConverterUInt.lower(`inputVout`),
-                FfiConverterString.lower(`clientSeckey`),
-                _status,
-            )
-        },
+        /**
+         * Creates a child transaction that spends the outputs of one or more backup transactions (the parents)
+         * and, optionally, extra wallet inputs. The child fee is calculated so that the whole package
+         * (parents + child) reaches `fee_rate_sats_per_byte`, taking into account the fee already paid by the parents.
+         * If the parents already pay more than the target, the child still pays the target fee rate for its own size.
+         */
+    @Throws(MercuryException::class) fun `createCpfpPackageTx`(`parents`: List<CpfpParent>, `extraInputs`: List<CpfpExtraInput>, `toAddress`: kotlin.String, `feeRateSatsPerByte`: kotlin.Double, `network`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_cpfp_package_tx(
+        FfiConverterSequenceTypeCpfpParent.lower(`parents`),FfiConverterSequenceTypeCpfpExtraInput.lower(`extraInputs`),FfiConverterString.lower(`toAddress`),FfiConverterDouble.lower(`feeRateSatsPerByte`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `createCpfpTx`(`backupTx`: BackupTx, `coin`: Coin, `toAddress`: kotlin.String, `feeRateSatsPerByte`: kotlin.Double, `network`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_cpfp_tx(
+        FfiConverterTypeBackupTx.lower(`backupTx`),FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`toAddress`),FfiConverterDouble.lower(`feeRateSatsPerByte`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `createTransferUpdateMsg`(
-    `x1`: kotlin.String,
-    `recipientAddress`: kotlin.String,
-    `coin`: Coin,
-    `transferSignature`: kotlin.String,
-    `backupTransactions`: List<BackupTx>,
-): TransferUpdateMsgRequestPayload {
-    return FfiConverterTypeTransferUpdateMsgRequestPayload.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_transfer_update_msg(
-                FfiConverterString.lower(`x1`),
-                FfiConverterString.lower(`recipientAddress`),
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`transferSignature`),
-                FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `createDepositMsg1`(`coin`: Coin, `tokenId`: kotlin.String): DepositMsg1 {
+            return FfiConverterTypeDepositMsg1.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_deposit_msg1(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`tokenId`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `createOwnershipProof`(`coin`: Coin, `challenge`: kotlin.String, `timestamp`: kotlin.ULong): OwnershipProof {
+            return FfiConverterTypeOwnershipProof.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_ownership_proof(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`challenge`),FfiConverterULong.lower(`timestamp`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `decodeStatechainAddress`(`scAddress`: kotlin.String): DecodedScAddress {
-    return FfiConverterTypeDecodedSCAddress.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_decode_statechain_address(
-                FfiConverterString.lower(`scAddress`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `createSignature`(`msg`: kotlin.String, `clientPartialSigHex`: kotlin.String, `serverPartialSigHex`: kotlin.String, `sessionHex`: kotlin.String, `outputPubkeyHex`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_signature(
+        FfiConverterString.lower(`msg`),FfiConverterString.lower(`clientPartialSigHex`),FfiConverterString.lower(`serverPartialSigHex`),FfiConverterString.lower(`sessionHex`),FfiConverterString.lower(`outputPubkeyHex`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `createStatechainLookupRequest`(`coin`: Coin, `timestamp`: kotlin.ULong): StatechainLookupRequestPayload {
+            return FfiConverterTypeStatechainLookupRequestPayload.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_statechain_lookup_request(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterULong.lower(`timestamp`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `duplicateCoinToInitializedState`(
-    `wallet`: Wallet,
-    `authPubkey`: kotlin.String,
-): Coin {
-    return FfiConverterTypeCoin.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_duplicate_coin_to_initialized_state(
-                FfiConverterTypeWallet.lower(`wallet`),
-                FfiConverterString.lower(`authPubkey`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `createTransferSignature`(`recipientAddress`: kotlin.String, `inputTxid`: kotlin.String, `inputVout`: kotlin.UInt, `clientSeckey`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_transfer_signature(
+        FfiConverterString.lower(`recipientAddress`),FfiConverterString.lower(`inputTxid`),FfiConverterUInt.lower(`inputVout`),FfiConverterString.lower(`clientSeckey`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `createTransferUpdateMsg`(`x1`: kotlin.String, `recipientAddress`: kotlin.String, `coin`: Coin, `transferSignature`: kotlin.String, `backupTransactions`: List<BackupTx>): TransferUpdateMsgRequestPayload {
+            return FfiConverterTypeTransferUpdateMsgRequestPayload.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_transfer_update_msg(
+        FfiConverterString.lower(`x1`),FfiConverterString.lower(`recipientAddress`),FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`transferSignature`),FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `ffiValidateSignatureScheme`(
-    `ffiTransferMsg`: FfiTransferMsg,
-    `statechainInfo`: StatechainInfoResponsePayload,
-    `tx0Hex`: kotlin.String,
-    `feeRateTolerance`: kotlin.UInt,
-    `currentFeeRateSatsPerByte`: kotlin.UInt,
-    `interval`: kotlin.UInt,
-): kotlin.UInt {
-    return FfiConverterUInt.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_ffi_validate_signature_scheme(
-                FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),
-                FfiConverterTypeStatechainInfoResponsePayload.lower(`statechainInfo`),
-                FfiConverterString.lower(`tx0Hex`),
-                FfiConverterUInt.lower(`feeRateTolerance`),
-                FfiConverterUInt.lower(`currentFeeRateSatsPerByte`),
-                FfiConverterUInt.lower(`interval`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `createWatchtowerPackage`(`coin`: Coin, `backupTransactions`: List<BackupTx>, `network`: kotlin.String): WatchtowerPackage {
+            return FfiConverterTypeWatchtowerPackage.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_watchtower_package(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Base64 PSBT of a withdrawal, with the same parameters as `get_withdrawal_partial_sig_request`
+         */
+    @Throws(MercuryException::class) fun `createWithdrawalPsbt`(`coin`: Coin, `blockHeight`: kotlin.UInt, `feeRateSatsPerByte`: kotlin.Double, `outputs`: List<WithdrawalOutput>, `network`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_create_withdrawal_psbt(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterUInt.lower(`blockHeight`),FfiConverterDouble.lower(`feeRateSatsPerByte`),FfiConverterSequenceTypeWithdrawalOutput.lower(`outputs`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `ffiVerifyTransferSignature`(
-    `newUserPubkey`: kotlin.String,
-    `tx0Outpoint`: TxOutpoint,
-    `ffiTransferMsg`: FfiTransferMsg,
-): kotlin.Boolean {
-    return FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_ffi_verify_transfer_signature(
-                FfiConverterString.lower(`newUserPubkey`),
-                FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),
-                FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),
-                _status,
-            )
-        },
+        /**
+         * Decodes the body of a failed response.
+         * Bodies without a known code, as sent by older servers, get the code matching the HTTP status.
+         */ fun `decodeApiError`(`statusCode`: kotlin.UShort, `body`: kotlin.String): ApiErrorResponsePayload {
+            return FfiConverterTypeApiErrorResponsePayload.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_decode_api_error(
+        FfiConverterUShort.lower(`statusCode`),FfiConverterString.lower(`body`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `decodeStatechainAddress`(`scAddress`: kotlin.String): DecodedScAddress {
+            return FfiConverterTypeDecodedSCAddress.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_decode_statechain_address(
+        FfiConverterString.lower(`scAddress`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `fiiCreateTransferReceiverRequestPayload`(
-    `statechainInfo`: StatechainInfoResponsePayload,
-    `ffiTransferMsg`: FfiTransferMsg,
-    `coin`: Coin,
-): TransferReceiverRequestPayload {
-    return FfiConverterTypeTransferReceiverRequestPayload.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_create_transfer_receiver_request_payload(
-                FfiConverterTypeStatechainInfoResponsePayload.lower(`statechainInfo`),
-                FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),
-                FfiConverterTypeCoin.lower(`coin`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `duplicateCoinToInitializedState`(`wallet`: Wallet, `authPubkey`: kotlin.String): Coin {
+            return FfiConverterTypeCoin.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_duplicate_coin_to_initialized_state(
+        FfiConverterTypeWallet.lower(`wallet`),FfiConverterString.lower(`authPubkey`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `exportWatchtowerPackage`(`package`: WatchtowerPackage): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_export_watchtower_package(
+        FfiConverterTypeWatchtowerPackage.lower(`package`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `fiiDecryptTransferMsg`(
-    `encryptedMessage`: kotlin.String,
-    `privateKeyWif`: kotlin.String,
-): FfiTransferMsg {
-    return FfiConverterTypeFFITransferMsg.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_decrypt_transfer_msg(
-                FfiConverterString.lower(`encryptedMessage`),
-                FfiConverterString.lower(`privateKeyWif`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `ffiVerifyTransferSignature`(`newUserPubkey`: kotlin.String, `tx0Outpoint`: TxOutpoint, `ffiTransferMsg`: FfiTransferMsg): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_ffi_verify_transfer_signature(
+        FfiConverterString.lower(`newUserPubkey`),FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `fiiCreateTransferReceiverRequestPayload`(`statechainInfo`: StatechainInfoResponsePayload, `ffiTransferMsg`: FfiTransferMsg, `coin`: Coin): TransferReceiverRequestPayload {
+            return FfiConverterTypeTransferReceiverRequestPayload.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_create_transfer_receiver_request_payload(
+        FfiConverterTypeStatechainInfoResponsePayload.lower(`statechainInfo`),FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),FfiConverterTypeCoin.lower(`coin`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `fiiValidateTx0OutputPubkey`(
-    `enclavePublicKey`: kotlin.String,
-    `ffiTransferMsg`: FfiTransferMsg,
-    `tx0Outpoint`: TxOutpoint,
-    `tx0Hex`: kotlin.String,
-    `network`: kotlin.String,
-): kotlin.Boolean {
-    return FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_validate_tx0_output_pubkey(
-                FfiConverterString.lower(`enclavePublicKey`),
-                FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),
-                FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),
-                FfiConverterString.lower(`tx0Hex`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `fiiDecryptTransferMsg`(`encryptedMessage`: kotlin.String, `privateKeyWif`: kotlin.String): FfiTransferMsg {
+            return FfiConverterTypeFFITransferMsg.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_decrypt_transfer_msg(
+        FfiConverterString.lower(`encryptedMessage`),FfiConverterString.lower(`privateKeyWif`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `fiiValidateTx0OutputPubkey`(`enclavePublicKey`: kotlin.String, `ffiTransferMsg`: FfiTransferMsg, `tx0Outpoint`: TxOutpoint, `tx0Hex`: kotlin.String, `network`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_validate_tx0_output_pubkey(
+        FfiConverterString.lower(`enclavePublicKey`),FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),FfiConverterString.lower(`tx0Hex`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `fiiVerifyLatestBackupTxPaysToUserPubkey`(
-    `ffiTransferMsg`: FfiTransferMsg,
-    `clientPubkeyShare`: kotlin.String,
-    `network`: kotlin.String,
-): kotlin.Boolean {
-    return FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(
-                FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),
-                FfiConverterString.lower(`clientPubkeyShare`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `fiiVerifyLatestBackupTxPaysToUserPubkey`(`ffiTransferMsg`: FfiTransferMsg, `clientPubkeyShare`: kotlin.String, `network`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_fii_verify_latest_backup_tx_pays_to_user_pubkey(
+        FfiConverterTypeFFITransferMsg.lower(`ffiTransferMsg`),FfiConverterString.lower(`clientPubkeyShare`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Aggregates the partial signatures of the PSBT with `create_signature`
+         * and returns the signed transaction, built by `new_backup_transaction`
+         */
+    @Throws(MercuryException::class) fun `finalizePsbt`(`psbt`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_finalize_psbt(
+        FfiConverterString.lower(`psbt`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `generateMnemonic`(): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_generate_mnemonic(
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `generateMnemonic`(): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_generate_mnemonic(
+        _status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `getAmountFromTx0`(`tx0Hex`: kotlin.String, `tx0Outpoint`: TxOutpoint): kotlin.ULong {
+            return FfiConverterULong.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_amount_from_tx0(
+        FfiConverterString.lower(`tx0Hex`),FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getBlockheight`(`bkpTx`: BackupTx): kotlin.UInt {
-    return FfiConverterUInt.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_blockheight(
-                FfiConverterTypeBackupTx.lower(`bkpTx`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `getBlockheight`(`bkpTx`: BackupTx): kotlin.UInt {
+            return FfiConverterUInt.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_blockheight(
+        FfiConverterTypeBackupTx.lower(`bkpTx`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `getCoinAtIndex`(`wallet`: Wallet, `addressIndex`: kotlin.UInt): Coin {
+            return FfiConverterTypeCoin.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_coin_at_index(
+        FfiConverterTypeWallet.lower(`wallet`),FfiConverterUInt.lower(`addressIndex`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getNewCoin`(`wallet`: Wallet): Coin {
-    return FfiConverterTypeCoin.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_new_coin(
-                FfiConverterTypeWallet.lower(`wallet`),
-                _status,
-            )
-        },
+        /**
+         * Returns the `tr()` descriptor, with key origin, of the backup address of the coin
+         */
+    @Throws(MercuryException::class) fun `getCoinDescriptor`(`coin`: Coin, `network`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_coin_descriptor(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `getNewCoin`(`wallet`: Wallet): Coin {
+            return FfiConverterTypeCoin.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_new_coin(
+        FfiConverterTypeWallet.lower(`wallet`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getNewKeyInfo`(
-    `serverPublicKeyHex`: kotlin.String,
-    `coin`: Coin,
-    `statechainId`: kotlin.String,
-    `tx0Outpoint`: TxOutpoint,
-    `tx0Hex`: kotlin.String,
-    `network`: kotlin.String,
-): NewKeyInfo {
-    return FfiConverterTypeNewKeyInfo.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_new_key_info(
-                FfiConverterString.lower(`serverPublicKeyHex`),
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`statechainId`),
-                FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),
-                FfiConverterString.lower(`tx0Hex`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `getNewCoinWithAddressOptions`(`wallet`: Wallet, `addressOptions`: ScAddressOptions): Coin {
+            return FfiConverterTypeCoin.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_new_coin_with_address_options(
+        FfiConverterTypeWallet.lower(`wallet`),FfiConverterTypeScAddressOptions.lower(`addressOptions`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `getNewKeyInfo`(`serverPublicKeyHex`: kotlin.String, `coin`: Coin, `statechainId`: kotlin.String, `tx0Outpoint`: TxOutpoint, `tx0Hex`: kotlin.String, `network`: kotlin.String): NewKeyInfo {
+            return FfiConverterTypeNewKeyInfo.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_new_key_info(
+        FfiConverterString.lower(`serverPublicKeyHex`),FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`statechainId`),FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),FfiConverterString.lower(`tx0Hex`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getOutputAddressFromTx0`(
-    `tx0Outpoint`: TxOutpoint,
-    `tx0Hex`: kotlin.String,
-    `network`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_output_address_from_tx0(
-                FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),
-                FfiConverterString.lower(`tx0Hex`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `getOutputAddressFromTx0`(`tx0Outpoint`: TxOutpoint, `tx0Hex`: kotlin.String, `network`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_output_address_from_tx0(
+        FfiConverterTypeTxOutpoint.lower(`tx0Outpoint`),FfiConverterString.lower(`tx0Hex`),FfiConverterString.lower(`network`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `getPartialSigRequest`(`coin`: Coin, `blockHeight`: kotlin.UInt, `initlock`: kotlin.UInt, `interval`: kotlin.UInt, `feeRateSatsPerByte`: kotlin.Double, `qtBackupTx`: kotlin.UInt, `toAddress`: kotlin.String, `network`: kotlin.String, `isWithdrawal`: kotlin.Boolean): PartialSignatureMsg1 {
+            return FfiConverterTypePartialSignatureMsg1.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_partial_sig_request(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterUInt.lower(`blockHeight`),FfiConverterUInt.lower(`initlock`),FfiConverterUInt.lower(`interval`),FfiConverterDouble.lower(`feeRateSatsPerByte`),FfiConverterUInt.lower(`qtBackupTx`),FfiConverterString.lower(`toAddress`),FfiConverterString.lower(`network`),FfiConverterBoolean.lower(`isWithdrawal`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getPartialSigRequest`(
-    `coin`: Coin,
-    `blockHeight`: kotlin.UInt,
-    `initlock`: kotlin.UInt,
-    `interval`: kotlin.UInt,
-    `feeRateSatsPerByte`: kotlin.UInt,
-    `qtBackupTx`: kotlin.UInt,
-    `toAddress`: kotlin.String,
-    `network`: kotlin.String,
-    `isWithdrawal`: kotlin.Boolean,
-): PartialSignatureMsg1 {
-    return FfiConverterTypePartialSignatureMsg1.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_partial_sig_request(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterUInt.lower(`blockHeight`),
-                FfiConverterUInt.lower(`initlock`),
-                FfiConverterUInt.lower(`interval`),
-                FfiConverterUInt.lower(`feeRateSatsPerByte`),
-                FfiConverterUInt.lower(`qtBackupTx`),
-                FfiConverterString.lower(`toAddress`),
-                FfiConverterString.lower(`network`),
-                FfiConverterBoolean.lower(`isWithdrawal`),
-                _status,
-            )
-        },
+        /**
+         * The request for the server partial signature of a PSBT signed with `sign_psbt`
+         */
+    @Throws(MercuryException::class) fun `getPartialSigRequestFromPsbt`(`coin`: Coin, `psbt`: kotlin.String): PartialSignatureRequestPayload {
+            return FfiConverterTypePartialSignatureRequestPayload.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_partial_sig_request_from_psbt(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`psbt`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `getPartialSigRequestWithAnchor`(`coin`: Coin, `blockHeight`: kotlin.UInt, `initlock`: kotlin.UInt, `interval`: kotlin.UInt, `feeRateSatsPerByte`: kotlin.Double, `qtBackupTx`: kotlin.UInt, `toAddress`: kotlin.String, `network`: kotlin.String, `isWithdrawal`: kotlin.Boolean, `anchorType`: AnchorOutputType?): PartialSignatureMsg1 {
+            return FfiConverterTypePartialSignatureMsg1.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_partial_sig_request_with_anchor(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterUInt.lower(`blockHeight`),FfiConverterUInt.lower(`initlock`),FfiConverterUInt.lower(`interval`),FfiConverterDouble.lower(`feeRateSatsPerByte`),FfiConverterUInt.lower(`qtBackupTx`),FfiConverterString.lower(`toAddress`),FfiConverterString.lower(`network`),FfiConverterBoolean.lower(`isWithdrawal`),FfiConverterOptionalTypeAnchorOutputType.lower(`anchorType`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getTx0Outpoint`(`backupTransactions`: List<BackupTx>): TxOutpoint {
-    return FfiConverterTypeTxOutpoint.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_tx0_outpoint(
-                FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `getPreviousOutpoint`(`backupTx`: BackupTx): TxOutpoint {
+            return FfiConverterTypeTxOutpoint.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_previous_outpoint(
+        FfiConverterTypeBackupTx.lower(`backupTx`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Same checks as `validate_signature_scheme`, but reports the result of each check for each backup transaction.
+         */ fun `getSignatureSchemeValidationReport`(`backupTransactions`: List<BackupTx>, `statechainInfo`: StatechainInfoResponsePayload, `tx0Hex`: kotlin.String, `currentBlockheight`: kotlin.UInt, `feeRateTolerance`: kotlin.Double, `currentFeeRateSatsPerByte`: kotlin.Double, `lockheightInit`: kotlin.UInt, `interval`: kotlin.UInt): SignatureSchemeValidationReport {
+            return FfiConverterTypeSignatureSchemeValidationReport.lift(
+    uniffiRustCall() { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_signature_scheme_validation_report(
+        FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),FfiConverterTypeStatechainInfoResponsePayload.lower(`statechainInfo`),FfiConverterString.lower(`tx0Hex`),FfiConverterUInt.lower(`currentBlockheight`),FfiConverterDouble.lower(`feeRateTolerance`),FfiConverterDouble.lower(`currentFeeRateSatsPerByte`),FfiConverterUInt.lower(`lockheightInit`),FfiConverterUInt.lower(`interval`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `getUserBackupAddress`(
-    `coin`: Coin,
-    `network`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_user_backup_address(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `getTx0Outpoint`(`backupTransactions`: List<BackupTx>): TxOutpoint {
+            return FfiConverterTypeTxOutpoint.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_tx0_outpoint(
+        FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `getUserBackupAddress`(`coin`: Coin, `network`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_user_backup_address(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `handleDepositMsg1Response`(
-    `coin`: Coin,
-    `depositMsg1Response`: DepositMsg1Response,
-): DepositInitResult {
-    return FfiConverterTypeDepositInitResult.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_handle_deposit_msg_1_response(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterTypeDepositMsg1Response.lower(`depositMsg1Response`),
-                _status,
-            )
-        },
+        /**
+         * Returns the descriptors of the backup addresses of all the coins in the wallet
+         */
+    @Throws(MercuryException::class) fun `getWalletDescriptors`(`wallet`: Wallet): List<CoinDescriptor> {
+            return FfiConverterSequenceTypeCoinDescriptor.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_wallet_descriptors(
+        FfiConverterTypeWallet.lower(`wallet`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Creates the partial signature request of a withdrawal paying to one or more outputs
+         */
+    @Throws(MercuryException::class) fun `getWithdrawalPartialSigRequest`(`coin`: Coin, `blockHeight`: kotlin.UInt, `feeRateSatsPerByte`: kotlin.Double, `outputs`: List<WithdrawalOutput>, `network`: kotlin.String): PartialSignatureMsg1 {
+            return FfiConverterTypePartialSignatureMsg1.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_get_withdrawal_partial_sig_request(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterUInt.lower(`blockHeight`),FfiConverterDouble.lower(`feeRateSatsPerByte`),FfiConverterSequenceTypeWithdrawalOutput.lower(`outputs`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `isEnclavePubkeyPartOfCoin`(
-    `coin`: Coin,
-    `enclavePubkey`: kotlin.String,
-): kotlin.Boolean {
-    return FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_is_enclave_pubkey_part_of_coin(
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`enclavePubkey`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `handleDepositMsg1Response`(`coin`: Coin, `depositMsg1Response`: DepositMsg1Response): DepositInitResult {
+            return FfiConverterTypeDepositInitResult.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_handle_deposit_msg_1_response(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterTypeDepositMsg1Response.lower(`depositMsg1Response`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Parses and verifies a package received from a coin owner
+         */
+    @Throws(MercuryException::class) fun `importWatchtowerPackage`(`packageJson`: kotlin.String): WatchtowerPackage {
+            return FfiConverterTypeWatchtowerPackage.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_import_watchtower_package(
+        FfiConverterString.lower(`packageJson`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `latestBackupTxPaysToUserPubkey`(
-    `backupTxs`: List<BackupTx>,
-    `coin`: Coin,
-    `network`: kotlin.String,
-): BackupTx {
-    return FfiConverterTypeBackupTx.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_latest_backup_tx_pays_to_user_pubkey(
-                FfiConverterSequenceTypeBackupTx.lower(`backupTxs`),
-                FfiConverterTypeCoin.lower(`coin`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `isEnclavePubkeyPartOfCoin`(`coin`: Coin, `enclavePubkey`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_is_enclave_pubkey_part_of_coin(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`enclavePubkey`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `latestBackupTxPaysToUserPubkey`(`backupTxs`: List<BackupTx>, `coin`: Coin, `network`: kotlin.String): BackupTx {
+            return FfiConverterTypeBackupTx.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_latest_backup_tx_pays_to_user_pubkey(
+        FfiConverterSequenceTypeBackupTx.lower(`backupTxs`),FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `newBackupTransaction`(
-    `encodedUnsignedTx`: kotlin.String,
-    `signatureHex`: kotlin.String,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_new_backup_transaction(
-                FfiConverterString.lower(`encodedUnsignedTx`),
-                FfiConverterString.lower(`signatureHex`),
-                _status,
-            )
-        },
+    @Throws(MercuryException::class) fun `newBackupTransaction`(`encodedUnsignedTx`: kotlin.String, `signatureHex`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_new_backup_transaction(
+        FfiConverterString.lower(`encodedUnsignedTx`),FfiConverterString.lower(`signatureHex`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `signMessage`(`message`: kotlin.String, `coin`: Coin): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_sign_message(
+        FfiConverterString.lower(`message`),FfiConverterTypeCoin.lower(`coin`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `signMessage`(
-    `message`: kotlin.String,
-    `coin`: Coin,
-): kotlin.String {
-    return FfiConverterString.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_sign_message(
-                FfiConverterString.lower(`message`),
-                FfiConverterTypeCoin.lower(`coin`),
-                _status,
-            )
-        },
+        /**
+         * Adds the partial signature of the coin owner to the PSBT.
+         * It needs the coin private key, secret nonce and blinding factor.
+         * The secret nonce must not be used again once the PSBT is signed: the caller consumes it first,
+         * as `mercuryrustlib::psbt::sign_psbt` does with the signing nonce table.
+         */
+    @Throws(MercuryException::class) fun `signPsbt`(`coin`: Coin, `psbt`: kotlin.String): kotlin.String {
+            return FfiConverterString.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_sign_psbt(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`psbt`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `validateAddress`(`address`: kotlin.String, `network`: kotlin.String): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_validate_address(
+        FfiConverterString.lower(`address`),FfiConverterString.lower(`network`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `validateAddress`(
-    `address`: kotlin.String,
-    `network`: kotlin.String,
-): kotlin.Boolean {
-    return FfiConverterBoolean.lift(
-        uniffiRustCallWithError(MercuryException) { _status ->
-            UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_validate_address(
-                FfiConverterString.lower(`address`),
-                FfiConverterString.lower(`network`),
-                _status,
-            )
-        },
+        /**
+         * Same as `validate_address`, where `current_time` is the unix timestamp used to reject expired v1 statechain addresses.
+         * For targets without a system clock, such as wasm.
+         */
+    @Throws(MercuryException::class) fun `validateAddressAt`(`address`: kotlin.String, `network`: kotlin.String, `currentTime`: kotlin.ULong): kotlin.Boolean {
+            return FfiConverterBoolean.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_validate_address_at(
+        FfiConverterString.lower(`address`),FfiConverterString.lower(`network`),FfiConverterULong.lower(`currentTime`),_status)
+}
     )
+    }
+    
+
+    @Throws(MercuryException::class) fun `validateSignatureScheme`(`backupTransactions`: List<BackupTx>, `statechainInfo`: StatechainInfoResponsePayload, `tx0Hex`: kotlin.String, `currentBlockheight`: kotlin.UInt, `feeRateTolerance`: kotlin.Double, `currentFeeRateSatsPerByte`: kotlin.Double, `lockheightInit`: kotlin.UInt, `interval`: kotlin.UInt): kotlin.UInt {
+            return FfiConverterUInt.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_validate_signature_scheme(
+        FfiConverterSequenceTypeBackupTx.lower(`backupTransactions`),FfiConverterTypeStatechainInfoResponsePayload.lower(`statechainInfo`),FfiConverterString.lower(`tx0Hex`),FfiConverterUInt.lower(`currentBlockheight`),FfiConverterDouble.lower(`feeRateTolerance`),FfiConverterDouble.lower(`currentFeeRateSatsPerByte`),FfiConverterUInt.lower(`lockheightInit`),FfiConverterUInt.lower(`interval`),_status)
 }
+    )
+    }
+    
 
-@Throws(MercuryException::class)
-fun `verifyBlindedMusigScheme`(
-    `backupTx`: BackupTx,
-    `tx0Hex`: kotlin.String,
-    `statechainInfo`: StatechainInfo,
-) = uniffiRustCallWithError(MercuryException) { _status ->
+    @Throws(MercuryException::class) fun `verifyBlindedMusigScheme`(`backupTx`: BackupTx, `tx0Hex`: kotlin.String, `statechainInfo`: StatechainInfo)
+        = 
+    uniffiRustCallWithError(MercuryException) { _status ->
     UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_blinded_musig_scheme(
-        FfiConverterTypeBackupTx.lower(`backupTx`),
-        FfiConverterString.lower(`tx0Hex`),
-        FfiConverterTypeStatechainInfo.lower(`statechainInfo`),
-        _status,
+        FfiConverterTypeBackupTx.lower(`backupTx`),FfiConverterString.lower(`tx0Hex`),FfiConverterTypeStatechainInfo.lower(`statechainInfo`),_status)
+}
+    
+    
+
+        /**
+         * Checks, after a transfer, that the server key share of the previous owner was replaced.
+         * The current share of the coin must be in the keylist published by the server,
+         * and the previous share must neither be in the keylist nor be part of the coin.
+         */
+    @Throws(MercuryException::class) fun `verifyKeyRotation`(`coin`: Coin, `previousServerPubkey`: kotlin.String, `keylist`: KeyListResponsePayload): KeyRotationEvidence {
+            return FfiConverterTypeKeyRotationEvidence.lift(
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_key_rotation(
+        FfiConverterTypeCoin.lower(`coin`),FfiConverterString.lower(`previousServerPubkey`),FfiConverterTypeKeyListResponsePayload.lower(`keylist`),_status)
+}
     )
+    }
+    
+
+        /**
+         * Checks the proof against the challenge sent to the prover, the current auth key and server key share
+         * of the statechain returned by the server and the Tx0 of the coin, whose output must pay to the key
+         * aggregated from the user and server shares.
+         * Whether the Tx0 output is still unspent must be checked by the caller against the chain.
+         */
+    @Throws(MercuryException::class) fun `verifyOwnershipProof`(`proof`: OwnershipProof, `challenge`: kotlin.String, `statechainOwner`: StatechainOwnerResponsePayload, `tx0Hex`: kotlin.String, `currentTime`: kotlin.ULong, `maxAge`: kotlin.ULong)
+        = 
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_ownership_proof(
+        FfiConverterTypeOwnershipProof.lower(`proof`),FfiConverterString.lower(`challenge`),FfiConverterTypeStatechainOwnerResponsePayload.lower(`statechainOwner`),FfiConverterString.lower(`tx0Hex`),FfiConverterULong.lower(`currentTime`),FfiConverterULong.lower(`maxAge`),_status)
+}
+    
+    
+
+        /**
+         * Checks the signature of the request. The freshness of `timestamp` is checked by the caller.
+         */
+    @Throws(MercuryException::class) fun `verifyStatechainLookupRequest`(`payload`: StatechainLookupRequestPayload)
+        = 
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_statechain_lookup_request(
+        FfiConverterTypeStatechainLookupRequestPayload.lower(`payload`),_status)
+}
+    
+    
+
+    @Throws(MercuryException::class) fun `verifyTransactionSequence`(`txNHex`: kotlin.String)
+        = 
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_transaction_sequence(
+        FfiConverterString.lower(`txNHex`),_status)
+}
+    
+    
+
+    @Throws(MercuryException::class) fun `verifyTransactionSignature`(`txNHex`: kotlin.String, `tx0Hex`: kotlin.String, `feeRateTolerance`: kotlin.Double, `currentFeeRateSatsPerByte`: kotlin.Double)
+        = 
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_transaction_signature(
+        FfiConverterString.lower(`txNHex`),FfiConverterString.lower(`tx0Hex`),FfiConverterDouble.lower(`feeRateTolerance`),FfiConverterDouble.lower(`currentFeeRateSatsPerByte`),_status)
 }
+    
+    
 
-@Throws(MercuryException::class)
-fun `verifyTransactionSignature`(
-    `txNHex`: kotlin.String,
-    `tx0Hex`: kotlin.String,
-    `feeRateTolerance`: kotlin.UInt,
-    `currentFeeRateSatsPerByte`: kotlin.UInt,
-) = uniffiRustCallWithError(MercuryException) { _status ->
-    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_transaction_signature(
-        FfiConverterString.lower(`txNHex`),
-        FfiConverterString.lower(`tx0Hex`),
-        FfiConverterUInt.lower(`feeRateTolerance`),
-        FfiConverterUInt.lower(`currentFeeRateSatsPerByte`),
-        _status,
-    )
+        /**
+         * Checks the signature and the internal consistency of the package.
+         * The caller is responsible for checking that `auth_pubkey` is the expected one.
+         */
+    @Throws(MercuryException::class) fun `verifyWatchtowerPackage`(`package`: WatchtowerPackage)
+        = 
+    uniffiRustCallWithError(MercuryException) { _status ->
+    UniffiLib.INSTANCE.uniffi_mercurylib_fn_func_verify_watchtower_package(
+        FfiConverterTypeWatchtowerPackage.lower(`package`),_status)
 }
+    
+    
+
+
@@ -17,12 +17,55 @@ enum Commands {
     /// Create a wallet
     CreateWallet { 
         /// The name of the wallet to create
-        name: String 
+        name: String,
+        /// Prompt for a BIP39 passphrase
+        #[arg(short='p', long)]
+        passphrase: bool,
+        /// BIP44 account, to keep separate wallets with the same mnemonic
+        #[arg(short='a', long, default_value_t = 0)]
+        account: u32,
     },
+    /// Recover a wallet and its coins from a mnemonic
+    RecoverWallet {
+        /// The name of the recovered wallet
+        name: String,
+        /// Prompt for a BIP39 passphrase
+        #[arg(short='p', long)]
+        passphrase: bool,
+        /// BIP44 account of the wallet
+        #[arg(short='a', long, default_value_t = 0)]
+        account: u32,
+        /// The wallet was created before keys were derived with the network coin type and account
+        #[arg(short='l', long)]
+        legacy: bool,
+        /// Number of consecutive unused addresses after which the scan stops
+        #[arg(short='g', long, default_value_t = mercuryrustlib::DEFAULT_GAP_LIMIT)]
+        gap_limit: u32,
+    },
+    /// Encrypt the secrets of a wallet with a password
+    EncryptWallet { wallet_name: String },
+    /// Change the password of an encrypted wallet
+    ChangeWalletPassword { wallet_name: String },
+    /// Unlock an encrypted wallet, so that the commands that sign with it do not prompt for the password
+    /// until the timeout or lock-wallet
+    #[cfg(unix)]
+    UnlockWallet {
+        wallet_name: String,
+        /// Seconds until the wallet is locked again
+        #[arg(short='t', long, default_value_t = mercuryrustlib::wallet_encryption::DEFAULT_UNLOCK_TIMEOUT)]
+        timeout: u64,
+    },
+    /// Lock a wallet unlocked with unlock-wallet
+    #[cfg(unix)]
+    LockWallet { wallet_name: String },
+    /// Keep the key of an unlocked wallet, read from stdin. Started by unlock-wallet.
+    #[cfg(unix)]
+    #[command(hide = true)]
+    WalletAgent { socket_path: std::path::PathBuf, expires_at: i64 },
     /// Get new token.
     NewToken { },
     /// Get new deposit address. Used to fund a new statecoin.
-    NewDepositAddress { wallet_name: String, token_id: String, amount: u32 },
+    NewDepositAddress { wallet_name: String, token_id: String, amount: u64 },
     /// Broadcast the backup transaction to the network
     BroadcastBackupTransaction { 
         wallet_name: String,
@@ -33,14 +76,20 @@ enum Commands {
     },
     /// Broadcast the backup transaction to the network
     ListStatecoins { wallet_name: String },
+    /// Export the output descriptors of the backup addresses of the wallet coins
+    ExportDescriptors { wallet_name: String },
     /// Withdraw funds from a statechain coin to a bitcoin address
     Withdraw { 
         wallet_name: String, 
         statechain_id: String, 
+        /// Receives the coin amount left after the other outputs and the fee
         to_address: String, 
         /// Transaction fee rate in sats per byte
         fee_rate: Option<f64>,
-        duplicated_index: Option<u32>
+        duplicated_index: Option<u32>,
+        /// Additional output in the format <address>:<amount in sats>. Can be repeated.
+        #[arg(short='o', long="output")]
+        outputs: Vec<String>,
     },
     /// Generate a transfer address to receive funds
     NewTransferAddress { 
@@ -48,6 +97,15 @@ enum Commands {
         /// Generate batch id for atomic transfers
         #[arg(short='b', long)]
         generate_batch_id: bool,
+        /// Generate a v1 address, which can carry a server hint and an expiry
+        #[arg(long)]
+        v1: bool,
+        /// Server URL carried by the v1 address
+        #[arg(long, requires = "v1")]
+        server_hint: Option<String>,
+        /// Seconds until the v1 address expires
+        #[arg(long, requires = "v1")]
+        expires_in: Option<u64>,
     },
     /// Send a statechain coin to a transfer address
     TransferSend {
@@ -81,6 +139,80 @@ enum Commands {
     /// Get the payment hash by batch id
     GetPaymentHash {
         batch_id: String,
+    },
+    /// Sign a proof that the wallet controls a statecoin, answering a challenge chosen by the verifier
+    ProveOwnership {
+        wallet_name: String,
+        statechain_id: String,
+        challenge: String,
+    },
+    /// Verify an ownership proof against the server and the chain
+    VerifyOwnership {
+        /// The proof in JSON, as printed by prove-ownership
+        proof: String,
+        challenge: String,
+    },
+}
+
+/// Reads a line without echoing it to the terminal
+fn read_secret(prompt: &str) -> Result<String> {
+    Ok(rpassword::prompt_password(format!("{}\n", prompt))?)
+}
+
+/// Gets the wallet key from the agent started by unlock-wallet, if it is still running
+#[cfg(unix)]
+async fn unlock_wallet_from_agent(client_config: &mercuryrustlib::client_config::ClientConfig, wallet_name: &str) -> Result<bool> {
+    let socket_path = mercuryrustlib::wallet_agent::get_agent_socket_path(client_config, wallet_name)?;
+    Ok(mercuryrustlib::wallet_agent::unlock_wallet_from_agent(&socket_path, wallet_name).await)
+}
+
+#[cfg(not(unix))]
+async fn unlock_wallet_from_agent(_client_config: &mercuryrustlib::client_config::ClientConfig, _wallet_name: &str) -> Result<bool> {
+    Ok(false)
+}
+
+/// Starts the wallet agent in a new process, which outlives the command and gets the key on its stdin
+#[cfg(unix)]
+async fn start_wallet_agent(socket_path: &std::path::Path, key: &mercuryrustlib::wallet_encryption::WalletKey, expires_at: i64) -> Result<()> {
+    use std::{io::Write, os::unix::process::CommandExt, process::{Command, Stdio}};
+
+    let mut agent = Command::new(std::env::current_exe()?)
+        .arg("wallet-agent")
+        .arg(socket_path)
+        .arg(expires_at.to_string())
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        // not stopped by a Ctrl-C in the terminal
+        .process_group(0)
+        .spawn()?;
+
+    agent.stdin.take().unwrap().write_all(format!("{}\n", hex::encode(key)).as_bytes())?;
+
+    for _ in 0..50 {
+        if socket_path.exists() {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    Err(anyhow::anyhow!("The wallet agent did not start"))
+}
+
+/// Wallet whose secrets the command uses to sign
+fn get_signing_wallet_name(command: &Commands) -> Option<&str> {
+    match command {
+        Commands::NewDepositAddress { wallet_name, .. } |
+        Commands::BroadcastBackupTransaction { wallet_name, .. } |
+        Commands::Withdraw { wallet_name, .. } |
+        Commands::NewTransferAddress { wallet_name, .. } |
+        Commands::TransferSend { wallet_name, .. } |
+        Commands::TransferReceive { wallet_name } |
+        Commands::PaymentHash { wallet_name, .. } |
+        Commands::ConfirmPendingInvoice { wallet_name, .. } |
+        Commands::RetrievePreImage { wallet_name, .. } |
+        Commands::ProveOwnership { wallet_name, .. } => Some(wallet_name),
+        _ => None,
     }
 }
 
@@ -89,17 +221,132 @@ async fn main() -> Result<()> {
     
     let cli = Cli::parse();
 
+    // The agent does not use the configuration, it only serves the key sent by unlock-wallet
+    #[cfg(unix)]
+    if let Commands::WalletAgent { socket_path, expires_at } = &cli.command {
+        let mut key = String::new();
+        std::io::stdin().read_line(&mut key)?;
+
+        let key: mercuryrustlib::wallet_encryption::WalletKey = hex::decode(key.trim())?.try_into()
+            .map_err(|_| anyhow::anyhow!("Invalid wallet key"))?;
+
+        return mercuryrustlib::wallet_agent::run_wallet_agent(socket_path, key, *expires_at).await;
+    }
+
     let client_config = mercuryrustlib::client_config::load().await;
 
+    // The wallet key is only kept in memory, by the agent of an unlocked wallet or for the duration of the command
+    if let Some(wallet_name) = get_signing_wallet_name(&cli.command) {
+        if mercuryrustlib::sqlite_manager::is_wallet_locked(&client_config.pool, wallet_name).await? &&
+            !unlock_wallet_from_agent(&client_config, wallet_name).await? {
+            let password = read_secret("Enter the wallet password:")?;
+            mercuryrustlib::wallet_encryption::unlock_wallet(&client_config.pool, wallet_name, &password, mercuryrustlib::wallet_encryption::DEFAULT_UNLOCK_TIMEOUT).await?;
+        }
+    }
+
     match cli.command {
-        Commands::CreateWallet { name } => {
-            let wallet = mercuryrustlib::wallet::create_wallet(
+        Commands::CreateWallet { name, passphrase, account } => {
+            let passphrase = if passphrase {
+                Some(read_secret("Enter the BIP39 passphrase:")?)
+            } else {
+                None
+            };
+
+            let wallet = mercuryrustlib::wallet::create_wallet_with_options(
                 &name, 
-                &client_config).await?;
+                &client_config,
+                passphrase.as_deref(),
+                account).await?;
 
             mercuryrustlib::sqlite_manager::insert_wallet(&client_config.pool, &wallet).await?;
-            println!("Wallet created: {:?}", wallet);
+            println!("Wallet created: {} ({})", wallet.name, wallet.network);
+        },
+        Commands::RecoverWallet { name, passphrase, account, legacy, gap_limit } => {
+            let mnemonic = read_secret("Enter the mnemonic:")?;
+
+            let passphrase = if passphrase {
+                Some(read_secret("Enter the BIP39 passphrase:")?)
+            } else {
+                None
+            };
+
+            let derivation_scheme = if legacy {
+                mercuryrustlib::DerivationScheme::Legacy
+            } else {
+                mercuryrustlib::DerivationScheme::Bip44
+            };
+
+            let recovery_result = mercuryrustlib::recovery::recover_wallet(
+                &client_config,
+                &name,
+                mnemonic.trim(),
+                passphrase.as_deref(),
+                derivation_scheme,
+                account,
+                gap_limit).await?;
+
+            let obj = json!({
+                "wallet": recovery_result.wallet.name,
+                "coins": recovery_result.wallet.coins.len(),
+                "statechains_without_backup_txs": recovery_result.statechains_without_backup_txs,
+                "recovery_errors": recovery_result.recovery_errors.iter()
+                    .map(|recovery_error| json!({"statechain_id": recovery_error.statechain_id, "error": recovery_error.error.to_string()}))
+                    .collect::<Vec<_>>(),
+            });
+
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
         },
+        Commands::EncryptWallet { wallet_name } => {
+            let password = read_secret("Enter the new wallet password:")?;
+            if password != read_secret("Confirm the new wallet password:")? {
+                return Err(anyhow::anyhow!("Passwords do not match"));
+            }
+
+            mercuryrustlib::wallet_encryption::encrypt_wallet(&client_config.pool, &wallet_name, &password).await?;
+            println!("Wallet {} encrypted", wallet_name);
+        },
+        Commands::ChangeWalletPassword { wallet_name } => {
+            let old_password = read_secret("Enter the current wallet password:")?;
+            let new_password = read_secret("Enter the new wallet password:")?;
+            if new_password != read_secret("Confirm the new wallet password:")? {
+                return Err(anyhow::anyhow!("Passwords do not match"));
+            }
+
+            mercuryrustlib::wallet_encryption::change_wallet_password(&client_config.pool, &wallet_name, &old_password, &new_password).await?;
+
+            // the key kept by the agent is the one of the old password
+            #[cfg(unix)]
+            mercuryrustlib::wallet_agent::lock_wallet_agent(&mercuryrustlib::wallet_agent::get_agent_socket_path(&client_config, &wallet_name)?).await;
+
+            println!("Wallet {} password changed", wallet_name);
+        },
+        #[cfg(unix)]
+        Commands::UnlockWallet { wallet_name, timeout } => {
+            let password = read_secret("Enter the wallet password:")?;
+            mercuryrustlib::wallet_encryption::unlock_wallet(&client_config.pool, &wallet_name, &password, timeout).await?;
+
+            let key = mercuryrustlib::wallet_encryption::get_wallet_key(&wallet_name).unwrap();
+            let expires_at = chrono::Utc::now().timestamp() + timeout as i64;
+
+            // a wallet unlocked again gets a new agent, with the new timeout
+            let socket_path = mercuryrustlib::wallet_agent::get_agent_socket_path(&client_config, &wallet_name)?;
+            mercuryrustlib::wallet_agent::lock_wallet_agent(&socket_path).await;
+
+            start_wallet_agent(&socket_path, &key, expires_at).await?;
+            println!("Wallet {} unlocked for {} seconds", wallet_name, timeout);
+        },
+        #[cfg(unix)]
+        Commands::LockWallet { wallet_name } => {
+            let socket_path = mercuryrustlib::wallet_agent::get_agent_socket_path(&client_config, &wallet_name)?;
+
+            if mercuryrustlib::wallet_agent::lock_wallet_agent(&socket_path).await {
+                println!("Wallet {} locked", wallet_name);
+            } else {
+                println!("Wallet {} is not unlocked", wallet_name);
+            }
+        },
+        #[cfg(unix)]
+        Commands::WalletAgent { .. } => unreachable!(),
         Commands::NewToken { } => {
             let token_response = mercuryrustlib::deposit::get_token(&client_config).await?;
 
@@ -141,12 +388,34 @@ async fn main() -> Result<()> {
             let coins_json_string = serde_json::to_string_pretty(&coins_json).unwrap();
             println!("{}", coins_json_string);
         },
-        Commands::Withdraw { wallet_name, statechain_id, to_address, fee_rate, duplicated_index } => {
+        Commands::ExportDescriptors { wallet_name } => {
+            let wallet = mercuryrustlib::sqlite_manager::get_wallet(&client_config.pool, &wallet_name).await?;
+
+            let descriptors = mercuryrustlib::get_wallet_descriptors(&wallet)?;
+
+            let descriptors_json_string = serde_json::to_string_pretty(&descriptors).unwrap();
+            println!("{}", descriptors_json_string);
+        },
+        Commands::Withdraw { wallet_name, statechain_id, to_address, fee_rate, duplicated_index, outputs } => {
+            let mut withdrawal_outputs = Vec::<mercuryrustlib::WithdrawalOutput>::new();
+            for output in outputs.iter() {
+                let (address, amount) = output.rsplit_once(':')
+                    .ok_or(anyhow::anyhow!("Invalid output {}. The format is <address>:<amount>", output))?;
+                withdrawal_outputs.push(mercuryrustlib::WithdrawalOutput { address: address.to_string(), amount: Some(amount.parse::<u64>()?) });
+            }
+            withdrawal_outputs.push(mercuryrustlib::WithdrawalOutput { address: to_address, amount: None });
+
             mercuryrustlib::coin_status::update_coins(&client_config, &wallet_name).await?;
-            mercuryrustlib::withdraw::execute(&client_config, &wallet_name, &statechain_id, &to_address, fee_rate, duplicated_index).await?;
+            mercuryrustlib::withdraw::execute_with_outputs(&client_config, &wallet_name, &statechain_id, &withdrawal_outputs, fee_rate, duplicated_index).await?;
         },
-        Commands::NewTransferAddress { wallet_name, generate_batch_id } => {
-            let address = mercuryrustlib::transfer_receiver::new_transfer_address(&client_config, &wallet_name).await?;
+        Commands::NewTransferAddress { wallet_name, generate_batch_id, v1, server_hint, expires_in } => {
+
+            let address_options = v1.then(|| mercuryrustlib::ScAddressOptions {
+                server_hint,
+                expiry: expires_in.map(|expires_in| chrono::Utc::now().timestamp() as u64 + expires_in),
+            });
+
+            let address = mercuryrustlib::transfer_receiver::new_transfer_address_with_options(&client_config, &wallet_name, address_options.as_ref()).await?;
 
             let mut obj = json!({"new_transfer_address:": address});
 
@@ -179,6 +448,10 @@ async fn main() -> Result<()> {
                 let transfer_receive_result = mercuryrustlib::transfer_receiver::execute(&client_config, &wallet_name).await?;
                 received_statechain_ids.extend(transfer_receive_result.received_statechain_ids);
 
+                for evidence in transfer_receive_result.unverified_key_rotations {
+                    println!("Key rotation of statechain {} not verified: {}", evidence.statechain_id, evidence.error.unwrap_or_default());
+                }
+
                 if transfer_receive_result.is_there_batch_locked {
                     println!("Statecoin batch still locked. Waiting until expiration or unlock.");
                     thread::sleep(Duration::from_secs(5));
@@ -214,6 +487,20 @@ async fn main() -> Result<()> {
 
             let obj = json!({"payment_hash": payment_hash});
 
+            println!("{}", serde_json::to_string_pretty(&obj).unwrap());
+        },
+        Commands::ProveOwnership { wallet_name, statechain_id, challenge } => {
+            mercuryrustlib::coin_status::update_coins(&client_config, &wallet_name).await?;
+            let proof = mercuryrustlib::ownership::prove_ownership(&client_config, &wallet_name, &statechain_id, &challenge).await?;
+
+            println!("{}", serde_json::to_string_pretty(&proof).unwrap());
+        },
+        Commands::VerifyOwnership { proof, challenge } => {
+            let proof: mercuryrustlib::OwnershipProof = serde_json::from_str(&proof)?;
+            mercuryrustlib::ownership::verify_ownership(&client_config, &proof, &challenge).await?;
+
+            let obj = json!({"valid": true, "statechain_id": proof.statechain_id, "amount": proof.amount});
+
             println!("{}", serde_json::to_string_pretty(&obj).unwrap());
         }
     }
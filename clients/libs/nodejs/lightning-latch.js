@@ -3,6 +3,7 @@ const { v4: uuidv4 } = require('uuid');
 const axios = require('axios').default;
 const { SocksProxyAgent } = require('socks-proxy-agent');
 const { CoinStatus } = require('./coin_enum');
+const utils = require('./utils');
 
 const createPreImage  = async (clientConfig, db, walletName, statechainId) => {
 
@@ -152,10 +153,12 @@ const getPaymentHash = async (clientConfig, batchId) => {
         return response?.data?.hash;
     }
     catch (error) {
-        if (error.response.status == 401) {
+        const apiError = utils.decodeApiError(error);
+
+        if (apiError.code == 'PreimageNotFound') {
             return null;
         } else {
-            throw new Error(`Failed to retrieve payment hash: ${JSON.stringify(error.response.data)}`);
+            throw new Error(`Failed to retrieve payment hash: ${apiError.code} - ${apiError.message}`);
         }
     }
 }
@@ -151,7 +151,12 @@ const processEncryptedMessage = async (clientConfig, electrumClient, db, coin, e
     let clientAuthKey = coin.auth_privkey;
     let newUserPubkey = coin.user_pubkey;
 
-    let transferMsg = mercury_wasm.decryptTransferMsg(encMessage, clientAuthKey);
+    let transferMsg;
+    try {
+        transferMsg = mercury_wasm.decryptTransferMsg(encMessage, clientAuthKey);
+    } catch (error) {
+        throw new Error(`Failed to decrypt transfer message: ${error.message}`);
+    }
 
     let tx0Outpoint = mercury_wasm.getTx0Outpoint(transferMsg.backup_transactions);
 
@@ -356,18 +361,16 @@ const sendTransferReceiverRequestPayload = async (clientConfig, transferReceiver
     }
     catch (error) {
 
-        if (error.response.status == 400) {
-            if (error.response.data.code == 'ExpiredBatchTimeError') {
-                throw new Error(`Failed to update transfer message ${error.response.data.message}`);
-            } else  if (error.response.data.code == 'StatecoinBatchLockedError') {
-                return {
-                    isBatchLocked: true,
-                    serverPubkey: null,
-                };
-            }
-        } else {
-            throw new Error(`Failed to update transfer message ${JSON.stringify(error.response.data)}`);
+        const apiError = utils.decodeApiError(error);
+
+        if (apiError.code == 'StatecoinBatchLockedError') {
+            return {
+                isBatchLocked: true,
+                serverPubkey: null,
+            };
         }
+
+        throw new Error(`Failed to update transfer message: ${apiError.code} - ${apiError.message}`);
     }
 
 }
@@ -83,6 +83,12 @@ const execute = async (clientConfig, electrumClient, db, walletName, statechainI
 
     const block_height = mercury_wasm.getBlockheight(bkp_tx1);
 
+    const addressError = mercury_wasm.getAddressValidationError(toAddress, wallet.network);
+
+    if (addressError) {
+        throw new Error(addressError.message);
+    }
+
     const decodedTransferAddress = mercury_wasm.decodeTransferAddress(toAddress);
     const new_auth_pubkey = decodedTransferAddress.auth_pubkey;
 
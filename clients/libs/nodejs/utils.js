@@ -1,6 +1,7 @@
 const axios = require('axios').default;
 const { SocksProxyAgent } = require('socks-proxy-agent');
 const bitcoinjs_lib = require("bitcoinjs-lib");
+const mercury_wasm = require('mercury-wasm');
 
 const infoConfig = async (clientConfig, ecl) => {
 
@@ -81,7 +82,7 @@ const getStatechainInfo = async (clientConfig, statechainId) => {
         let response = await axios.get(statechainEntityUrl + '/' + path, socksAgent);
         return response.data;
     } catch (error) {
-        if (error.response.status == 404) {
+        if (decodeApiError(error).code == 'StatechainNotFound') {
             return null;
         } else {
             throw error;
@@ -111,4 +112,17 @@ const completeWithdraw = async (clientConfig, statechainId, signedStatechainId)
     await axios.post(url, deleteStatechainPayload, socksAgent);
 }
 
-module.exports = { infoConfig, getNetwork, createActivity, getStatechainInfo, completeWithdraw };
+// Typed error of a failed request to the server, as { code, message }, where the code is an ApiError name.
+// Requests that got no response, e.g. when the server is down, are rethrown.
+const decodeApiError = (error) => {
+
+    if (!error.response) {
+        throw error;
+    }
+
+    const body = typeof error.response.data === 'string' ? error.response.data : JSON.stringify(error.response.data);
+
+    return mercury_wasm.decodeApiError(error.response.status, body);
+}
+
+module.exports = { infoConfig, getNetwork, createActivity, getStatechainInfo, completeWithdraw, decodeApiError };
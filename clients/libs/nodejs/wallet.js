@@ -43,6 +43,8 @@ const createWallet = async (name, clientConfig, electrumClient) => {
     let wallet = {
         name,
         mnemonic,
+        derivation_scheme: "Bip44",
+        account: 0,
         version: "0.1.0",
         state_entity_endpoint: statechainEntityEndpoint,
         electrum_endpoint: electrumEndpoint,
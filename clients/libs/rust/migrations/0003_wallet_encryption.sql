@@ -0,0 +1 @@
+ALTER TABLE wallet ADD COLUMN encryption TEXT;
@@ -0,0 +1,3 @@
+-- Amounts were stored as u32 before version 1 and may have been truncated.
+-- Wallets at version 0 have their coin amounts checked against the chain once.
+ALTER TABLE wallet ADD COLUMN amounts_version INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,11 @@
+-- Lifecycle of the MuSig2 nonces used to sign backup and withdrawal transactions.
+-- The secret nonce stays in the wallet, the public nonce identifies it here.
+-- status is 'created', then either 'consumed' (a partial signature was made) or 'abandoned'.
+CREATE TABLE IF NOT EXISTS signing_nonce (
+    public_nonce TEXT UNIQUE NOT NULL,
+    statechain_id TEXT NOT NULL,
+    status TEXT NOT NULL,
+    msg TEXT,
+    created_at INTEGER NOT NULL,
+    consumed_at INTEGER
+);
@@ -1,11 +1,11 @@
-use crate::{client_config::ClientConfig, sqlite_manager::{get_backup_txs, get_wallet, update_wallet}};
+use crate::{client_config::ClientConfig, sqlite_manager::{get_backup_txs, get_unlocked_wallet, update_wallet}};
 use anyhow::{anyhow, Result};
 use electrum_client::ElectrumApi;
 use mercurylib::wallet::{cpfp_tx, CoinStatus};
 
 pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain_id: &str, to_address: Option<String>, fee_rate: Option<f64>) -> Result<()> {
     
-    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let mut wallet: mercurylib::wallet::Wallet = get_unlocked_wallet(&client_config.pool, &wallet_name).await?;
 
     if to_address.is_some() {
         let to_address = to_address.clone().unwrap();
@@ -4,6 +4,7 @@ use bitcoin::Network;
 use config::Config;
 use sqlx::{Sqlite, migrate::MigrateDatabase, SqlitePool};
 use anyhow::Result;
+use mercurylib::transaction::AnchorOutputType;
 
 /// Config struct storing all StataChain Entity config
 pub struct ClientConfig {
@@ -21,12 +22,16 @@ pub struct ClientConfig {
     pub fee_rate_tolerance: f64,
     /// Confirmation target
     pub confirmation_target: u32,
+    /// Database file
+    pub database_file: String,
     /// Database connection pool
     pub pool: sqlx::Pool<Sqlite>,
     /// Tor SOCKS5 proxy address
     pub tor_proxy: Option<String>,
     /// Confirmation target
     pub max_fee_rate: f64,
+    /// Anchor output added to backup transactions (none, p2a or p2tr)
+    pub anchor_output: Option<AnchorOutputType>,
 }
 
 fn check_and_set_settings() -> String {
@@ -61,6 +66,11 @@ impl ClientConfig {
             Ok(proxy) => Some(proxy.to_string()),
             Err(_) => None,
         };
+
+        let anchor_output = match settings.get_string("anchor_output") {
+            Ok(anchor_output) if anchor_output != "none" => Some(mercurylib::utils::get_anchor_output_type(&anchor_output).expect("Invalid anchor output type")),
+            _ => None,
+        };
         // Open database connection pool
 
         if !Sqlite::database_exists(&database_file).await.unwrap_or(false) {
@@ -99,9 +109,11 @@ impl ClientConfig {
             network,
             fee_rate_tolerance,
             confirmation_target,
+            database_file,
             pool,
             tor_proxy,
-            max_fee_rate
+            max_fee_rate,
+            anchor_output,
         }
     }
 
@@ -2,12 +2,12 @@
 use crate::utils::create_activity;
 use std::str::FromStr;
 
-use bitcoin::Address;
+use bitcoin::{Address, Txid};
 use electrum_client::{ElectrumApi, ListUnspentRes};
-use mercurylib::{utils::is_enclave_pubkey_part_of_coin, wallet::{Activity, BackupTx, Coin, CoinStatus}};
+use mercurylib::{utils::is_enclave_pubkey_part_of_coin, wallet::{Activity, BackupTx, Coin, CoinStatus, Wallet}};
 use anyhow::{anyhow, Result, Ok};
 
-use crate::{client_config::ClientConfig, sqlite_manager::{get_wallet, update_wallet, insert_backup_txs}, deposit::create_tx1};
+use crate::{client_config::ClientConfig, sqlite_manager::{get_unlocked_wallet, get_wallet, get_wallet_amounts_version, insert_backup_txs, is_wallet_locked, set_wallet_amounts_version, update_wallet, WALLET_AMOUNTS_VERSION}, deposit::create_tx1};
 
 struct DepositResult {
     activity: Activity,
@@ -30,8 +30,10 @@ async fn check_deposit(client_config: &ClientConfig, coin: &mut Coin, wallet_net
 
     let utxo_list =  client_config.electrum_client.script_list_unspent(&address.script_pubkey())?;
 
+    // the amount of a deposit is unknown if the coin was recovered before being funded
     for unspent in utxo_list {
-        if unspent.value == coin.amount.unwrap() as u64 {
+        if coin.amount.map_or(true, |amount| unspent.value == amount) {
+            coin.amount = Some(unspent.value);
             utxo = Some(unspent);
             break;
         }
@@ -72,7 +74,7 @@ async fn check_deposit(client_config: &ClientConfig, coin: &mut Coin, wallet_net
 
         let activity_utxo = format!("{}:{}", utxo.tx_hash.to_string(), utxo.tx_pos);
 
-        let activity = Some(create_activity(&activity_utxo, utxo.value as u32, "deposit"));
+        let activity = Some(create_activity(&activity_utxo, utxo.value, "deposit"));
 
         deposit_result = Some(DepositResult {
             activity: activity.unwrap(),
@@ -218,7 +220,7 @@ async fn check_for_duplicated(client_config: &ClientConfig, existing_coins: &Vec
             duplicated_coin.status = CoinStatus::DUPLICATED;
             duplicated_coin.utxo_txid = Some(unspent.tx_hash.to_string());
             duplicated_coin.utxo_vout = Some(unspent.tx_pos as u32);
-            duplicated_coin.amount = Some(unspent.value as u32);
+            duplicated_coin.amount = Some(unspent.value);
             duplicated_coin.duplicate_index = max_duplicated_index;
             duplicated_coin_list.push(duplicated_coin);
         }
@@ -228,15 +230,70 @@ async fn check_for_duplicated(client_config: &ClientConfig, existing_coins: &Vec
 
 }
 
+/// Wallets stored when amounts were u32 may hold the amounts of coins above u32::MAX truncated.
+/// Restores them, and the amounts of the activities of the same UTXO, from the Tx0 outputs.
+async fn restore_truncated_amounts(client_config: &ClientConfig, wallet: &mut Wallet) -> Result<()> {
+
+    for coin in wallet.coins.iter_mut() {
+
+        let (utxo_txid, utxo_vout, amount) = match (coin.utxo_txid.as_ref(), coin.utxo_vout, coin.amount) {
+            (Some(utxo_txid), Some(utxo_vout), Some(amount)) => (utxo_txid.clone(), utxo_vout, amount),
+            _ => continue,
+        };
+
+        let tx0 = match client_config.electrum_client.transaction_get(&Txid::from_str(&utxo_txid)?).ok() {
+            Some(tx0) => tx0,
+            None => continue,
+        };
+
+        let value = match tx0.output.get(utxo_vout as usize) {
+            Some(tx0_output) => tx0_output.value,
+            None => continue,
+        };
+
+        // only an amount that is the Tx0 value truncated to u32 is restored
+        if value == amount || value as u32 as u64 != amount {
+            continue;
+        }
+
+        coin.amount = Some(value);
+
+        let activity_utxo = format!("{}:{}", utxo_txid, utxo_vout);
+
+        for activity in wallet.activities.iter_mut().filter(|activity| activity.utxo == activity_utxo && activity.amount == amount) {
+            activity.amount = value;
+        }
+    }
+
+    Ok(())
+}
+
 pub async fn update_coins(client_config: &ClientConfig, wallet_name: &str) -> Result<()> {
     
-    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    // confirming a deposit signs its first backup transaction, which is skipped while the wallet is locked
+    let is_locked = is_wallet_locked(&client_config.pool, &wallet_name).await?;
+
+    let mut wallet: mercurylib::wallet::Wallet = if is_locked {
+        get_wallet(&client_config.pool, &wallet_name).await?
+    } else {
+        get_unlocked_wallet(&client_config.pool, &wallet_name).await?
+    };
 
     let network = wallet.network.clone();
 
+    let amounts_version = get_wallet_amounts_version(&client_config.pool, wallet_name).await?;
+
+    if amounts_version < WALLET_AMOUNTS_VERSION {
+        restore_truncated_amounts(client_config, &mut wallet).await?;
+    }
+
     for coin in wallet.coins.iter_mut() {
 
         if coin.status == CoinStatus::INITIALISED || coin.status == CoinStatus::IN_MEMPOOL || coin.status == CoinStatus::UNCONFIRMED {
+
+            if is_locked {
+                continue;
+            }
         
             let deposit_result = check_deposit(client_config, coin, &network).await?;
         
@@ -282,5 +339,9 @@ pub async fn update_coins(client_config: &ClientConfig, wallet_name: &str) -> Re
 
     update_wallet(&client_config.pool, &wallet).await?;
 
+    if amounts_version < WALLET_AMOUNTS_VERSION {
+        set_wallet_amounts_version(&client_config.pool, wallet_name, WALLET_AMOUNTS_VERSION).await?;
+    }
+
     Ok(())
 }
\ No newline at end of file
@@ -1,13 +1,13 @@
-use anyhow::{anyhow, Result, Ok};
+use anyhow::{Result, Ok};
 use mercurylib::{deposit::{create_deposit_msg1, create_aggregated_address}, wallet::{Wallet, BackupTx, Coin}, transaction:: get_user_backup_address, utils::get_blockheight};
 
-use crate::{client_config::ClientConfig, sqlite_manager::{get_wallet, update_wallet}, transaction::new_transaction, utils::info_config};
+use crate::{client_config::ClientConfig, sqlite_manager::{get_unlocked_wallet, update_wallet}, transaction::new_transaction, utils::{api_error, info_config}};
 
-pub async fn get_deposit_bitcoin_address(client_config: &ClientConfig, wallet_name: &str, token_id: &str, amount: u32) -> Result<String> {
+pub async fn get_deposit_bitcoin_address(client_config: &ClientConfig, wallet_name: &str, token_id: &str, amount: u64) -> Result<String> {
 
     let token_id = uuid::Uuid::parse_str(&token_id)?;
     // println!("Deposit: {} {} {}", wallet_name, token_id, amount);
-    let wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let wallet = get_unlocked_wallet(&client_config.pool, &wallet_name).await?;
     let mut wallet = init(&client_config, &wallet, token_id).await?;
 
     let coin = wallet.coins.last_mut().unwrap();
@@ -99,9 +99,11 @@ pub async fn init(client_config: &ClientConfig, wallet: &Wallet, token_id: uuid:
 
     let response = request.json(&deposit_msg_1).send().await?;
 
-    if response.status() != 200 {
+    let status = response.status();
+
+    if status != 200 {
         let response_body = response.text().await?;
-        return Err(anyhow!(response_body));
+        return Err(api_error(status, &response_body));
     }
 
     let value = response.text().await?;
@@ -131,9 +133,11 @@ pub async fn get_token(client_config: &ClientConfig) -> Result<mercurylib::depos
 
     let response = request.send().await?;
 
-    if response.status() != 200 {
+    let status = response.status();
+
+    if status != 200 {
         let response_body = response.text().await?;
-        return Err(anyhow!(response_body));
+        return Err(api_error(status, &response_body));
     }
 
     let value = response.text().await?;
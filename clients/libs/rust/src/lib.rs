@@ -3,12 +3,18 @@ pub mod client_config;
 pub mod coin_status;
 pub mod deposit;
 pub mod lightning_latch;
+pub mod ownership;
+pub mod psbt;
+pub mod recovery;
 pub mod sqlite_manager;
 pub mod transaction;
 pub mod transfer_receiver;
 pub mod transfer_sender;
 pub mod utils;
 pub mod wallet;
+#[cfg(unix)]
+pub mod wallet_agent;
+pub mod wallet_encryption;
 pub mod withdraw;
 
 pub use mercurylib::wallet::Wallet;
@@ -17,12 +23,17 @@ pub use mercurylib::wallet::Coin;
 pub use mercurylib::wallet::BackupTx;
 pub use mercurylib::wallet::Activity;
 pub use mercurylib::wallet::get_previous_outpoint;
+pub use mercurylib::wallet::DerivationScheme;
+pub use mercurylib::wallet::recovery::DEFAULT_GAP_LIMIT;
+pub use mercurylib::wallet::ownership::OwnershipProof;
+pub use mercurylib::wallet::descriptor::get_wallet_descriptors;
 
 pub use mercurylib::transfer::sender::{TransferSenderRequestPayload, TransferSenderResponsePayload, create_transfer_signature, create_transfer_update_msg};
-pub use mercurylib::transaction::{SignFirstRequestPayload, SignFirstResponsePayload, create_and_commit_nonces};
+pub use mercurylib::transaction::{SignFirstRequestPayload, SignFirstResponsePayload, WithdrawalOutput, create_and_commit_nonces};
 pub use mercurylib::utils::get_blockheight;
-pub use mercurylib::{validate_address, decode_transfer_address};
+pub use mercurylib::{validate_address, validate_address_at, decode_transfer_address, ScAddressOptions};
 pub use mercurylib::deposit::TokenResponse;
+pub use mercurylib::error::{ApiError, ApiErrorResponsePayload};
 
 pub fn add(left: usize, right: usize) -> usize {
     left + right
@@ -37,4 +48,70 @@ mod tests {
         let result = add(2, 2);
         assert_eq!(result, 4);
     }
+
+    async fn test_pool() -> sqlx::Pool<sqlx::Sqlite> {
+        // a single connection, as each connection to an in-memory database opens a new one
+        let pool = sqlx::sqlite::SqlitePoolOptions::new().max_connections(1).connect("sqlite::memory:").await.unwrap();
+        sqlx::migrate!("./migrations").run(&pool).await.unwrap();
+        pool
+    }
+
+    fn test_wallet(name: &str) -> Wallet {
+        let wallet_json = r#"{"name":"","mnemonic":"ticket sock try two evidence employ fresh beauty settle general ridge lonely","version":"0.1.0","state_entity_endpoint":"","electrum_endpoint":"","network":"testnet","blockheight":0,"initlock":0,"interval":0,"tokens":[],"activities":[],"coins":[],"settings":{"network":"testnet","block_explorerURL":null,"torProxyHost":null,"torProxyPort":null,"torProxyControlPassword":null,"torProxyControlPort":null,"statechainEntityApi":"","torStatechainEntityApi":null,"electrumProtocol":"","electrumHost":"","electrumPort":"","electrumType":"","notifications":false,"tutorials":false}}"#;
+        let mut wallet: Wallet = serde_json::from_str(wallet_json).unwrap();
+        wallet.name = name.to_string();
+        wallet.passphrase = Some("passphrase".to_string());
+        let coin = wallet.get_new_coin().unwrap();
+        wallet.coins.push(coin);
+        wallet
+    }
+
+    #[tokio::test]
+    async fn wallet_encryption() {
+        use sqlite_manager::{get_unlocked_wallet, get_wallet, insert_wallet, is_wallet_locked};
+        use wallet_encryption::{change_wallet_password, encrypt_wallet, has_plaintext_secrets, lock_wallet, unlock_wallet};
+
+        let pool = test_pool().await;
+        let wallet = test_wallet("encrypted");
+        insert_wallet(&pool, &wallet).await.unwrap();
+
+        encrypt_wallet(&pool, &wallet.name, "password").await.unwrap();
+        assert!(encrypt_wallet(&pool, &wallet.name, "password").await.is_err());
+
+        // the secrets are stored encrypted and the key is not stored
+        let stored = get_wallet(&pool, &wallet.name).await.unwrap();
+        assert!(!has_plaintext_secrets(&stored));
+        assert_eq!(stored.coins[0].user_pubkey, wallet.coins[0].user_pubkey);
+        assert!(is_wallet_locked(&pool, &wallet.name).await.unwrap());
+        assert!(get_unlocked_wallet(&pool, &wallet.name).await.is_err());
+
+        let tables: Vec<String> = sqlx::query_scalar("SELECT name FROM sqlite_master WHERE type = 'table'").fetch_all(&pool).await.unwrap();
+        assert!(!tables.contains(&"wallet_session".to_string()));
+
+        // round trip
+        assert!(unlock_wallet(&pool, &wallet.name, "wrong password", 60).await.is_err());
+        assert!(is_wallet_locked(&pool, &wallet.name).await.unwrap());
+
+        unlock_wallet(&pool, &wallet.name, "password", 60).await.unwrap();
+        let unlocked = get_unlocked_wallet(&pool, &wallet.name).await.unwrap();
+        assert_eq!(unlocked.mnemonic, wallet.mnemonic);
+        assert_eq!(unlocked.passphrase, wallet.passphrase);
+        assert_eq!(unlocked.coins[0].user_privkey, wallet.coins[0].user_privkey);
+        assert_eq!(unlocked.coins[0].auth_privkey, wallet.coins[0].auth_privkey);
+
+        lock_wallet(&wallet.name);
+        assert!(get_unlocked_wallet(&pool, &wallet.name).await.is_err());
+
+        // password change
+        assert!(change_wallet_password(&pool, &wallet.name, "wrong password", "new password").await.is_err());
+        change_wallet_password(&pool, &wallet.name, "password", "new password").await.unwrap();
+
+        assert!(unlock_wallet(&pool, &wallet.name, "password", 60).await.is_err());
+        unlock_wallet(&pool, &wallet.name, "new password", 60).await.unwrap();
+        assert_eq!(get_unlocked_wallet(&pool, &wallet.name).await.unwrap().mnemonic, wallet.mnemonic);
+
+        // an expired key is forgotten
+        unlock_wallet(&pool, &wallet.name, "new password", 0).await.unwrap();
+        assert!(is_wallet_locked(&pool, &wallet.name).await.unwrap());
+    }
 }
@@ -0,0 +1,64 @@
+use anyhow::{anyhow, Result};
+use chrono::Utc;
+use mercurylib::{transfer::TxOutpoint, wallet::{ownership::{create_ownership_proof, verify_ownership_proof, OwnershipProof, StatechainOwnerResponsePayload, OWNERSHIP_PROOF_MAX_AGE}, CoinStatus}};
+use reqwest::StatusCode;
+
+use crate::{client_config::ClientConfig, sqlite_manager::get_unlocked_wallet, transfer_receiver::{get_tx0, verify_tx0_output_is_unspent_and_confirmed}};
+
+/// Signs a proof that the wallet controls the confirmed coin of the statechain, answering `challenge`
+pub async fn prove_ownership(client_config: &ClientConfig, wallet_name: &str, statechain_id: &str, challenge: &str) -> Result<OwnershipProof> {
+
+    let wallet = get_unlocked_wallet(&client_config.pool, wallet_name).await?;
+
+    let coin = wallet.coins.iter()
+        .find(|coin| coin.statechain_id == Some(statechain_id.to_string()) && coin.status == CoinStatus::CONFIRMED)
+        .ok_or(anyhow!("There is no confirmed coin with the statechain id {}", statechain_id))?;
+
+    let proof = create_ownership_proof(coin, challenge, Utc::now().timestamp() as u64)?;
+
+    Ok(proof)
+}
+
+async fn get_statechain_owner(client_config: &ClientConfig, statechain_id: &str) -> Result<Option<StatechainOwnerResponsePayload>> {
+
+    let path = format!("info/owner/{}", statechain_id);
+
+    let client = client_config.get_reqwest_client()?;
+    let request = client.get(&format!("{}/{}", client_config.statechain_entity, path));
+
+    let response = request.send().await?;
+
+    if response.status() == StatusCode::NOT_FOUND {
+        return Ok(None);
+    }
+
+    let value = response.text().await?;
+
+    let response: StatechainOwnerResponsePayload = serde_json::from_str(value.as_str())?;
+
+    Ok(Some(response))
+}
+
+/// Verifies a proof received from a counterparty against the server and the chain
+pub async fn verify_ownership(client_config: &ClientConfig, proof: &OwnershipProof, challenge: &str) -> Result<()> {
+
+    let statechain_owner = get_statechain_owner(client_config, &proof.statechain_id).await?
+        .ok_or(anyhow!("Statechain {} not found", proof.statechain_id))?;
+
+    let tx0_hex = get_tx0(&client_config.electrum_client, &proof.utxo_txid).await?;
+
+    verify_ownership_proof(proof, challenge, &statechain_owner, &tx0_hex, Utc::now().timestamp() as u64, OWNERSHIP_PROOF_MAX_AGE)?;
+
+    let tx0_outpoint = TxOutpoint {
+        txid: proof.utxo_txid.clone(),
+        vout: proof.utxo_vout,
+    };
+
+    let (is_tx0_output_unspent, _) = verify_tx0_output_is_unspent_and_confirmed(&client_config.electrum_client, &tx0_outpoint, &tx0_hex, &client_config.network.to_string(), client_config.confirmation_target).await?;
+
+    if !is_tx0_output_unspent {
+        return Err(anyhow!("The Tx0 output of the statechain {} is spent", proof.statechain_id));
+    }
+
+    Ok(())
+}
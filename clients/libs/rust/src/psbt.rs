@@ -0,0 +1,102 @@
+use anyhow::{anyhow, Result};
+use electrum_client::ElectrumApi;
+use mercurylib::{transaction::WithdrawalOutput, wallet::Coin};
+
+use crate::{client_config::ClientConfig, sqlite_manager::{consume_signing_nonce, insert_signing_nonce}, transaction::{sign_first, sign_second}};
+
+/// Creates new nonces for the coin and gets the server public nonce.
+/// The nonce is recorded before it is used, as in `sign_new_transaction`, and can sign a single PSBT.
+async fn start_signing_session(client_config: &ClientConfig, coin: &mut Coin) -> Result<u32> {
+
+    let coin_nonce = mercurylib::transaction::create_and_commit_nonces(coin)?;
+
+    insert_signing_nonce(&client_config.pool, &coin_nonce.sign_first_request_payload.statechain_id, &coin_nonce.public_nonce).await?;
+
+    coin.secret_nonce = Some(coin_nonce.secret_nonce);
+    coin.public_nonce = Some(coin_nonce.public_nonce);
+    coin.blinding_factor = Some(coin_nonce.blinding_factor);
+
+    coin.server_public_nonce = Some(sign_first(client_config, &coin_nonce.sign_first_request_payload).await?);
+
+    let block_header = client_config.electrum_client.block_headers_subscribe_raw()?;
+
+    Ok(block_header.height as u32)
+}
+
+/// Base64 PSBT of a new backup transaction of the coin, to be signed with `sign_psbt`
+pub async fn create_backup_tx_psbt(
+    client_config: &ClientConfig,
+    coin: &mut Coin,
+    to_address: &str,
+    qt_backup_tx: u32,
+    is_withdrawal: bool,
+    network: &str,
+    fee_rate_sats_per_byte: f64,
+    initlock: u32,
+    interval: u32) -> Result<String> {
+
+    let block_height = start_signing_session(client_config, coin).await?;
+
+    let anchor_type = if is_withdrawal { None } else { client_config.anchor_output.clone() };
+
+    let psbt = mercurylib::psbt::create_backup_tx_psbt(
+        coin,
+        block_height,
+        initlock,
+        interval,
+        fee_rate_sats_per_byte,
+        qt_backup_tx,
+        to_address.to_string(),
+        network.to_string(),
+        is_withdrawal,
+        anchor_type)?;
+
+    Ok(psbt)
+}
+
+/// Base64 PSBT of a withdrawal of the coin, to be signed with `sign_psbt`
+pub async fn create_withdrawal_psbt(
+    client_config: &ClientConfig,
+    coin: &mut Coin,
+    outputs: &Vec<WithdrawalOutput>,
+    network: &str,
+    fee_rate_sats_per_byte: f64) -> Result<String> {
+
+    let block_height = start_signing_session(client_config, coin).await?;
+
+    let psbt = mercurylib::psbt::create_withdrawal_psbt(coin, block_height, fee_rate_sats_per_byte, outputs.clone(), network.to_string())?;
+
+    Ok(psbt)
+}
+
+/// Adds the partial signature of the coin owner to the PSBT.
+/// The nonce of the coin is marked as consumed by the PSBT message before the signature is returned,
+/// and removed from the coin, so that it never signs two different PSBTs.
+pub async fn sign_psbt(client_config: &ClientConfig, coin: &mut Coin, psbt: &str) -> Result<String> {
+
+    let public_nonce = coin.public_nonce.clone()
+        .ok_or(anyhow!("The coin has no signing nonce. Create the PSBT with create_backup_tx_psbt or create_withdrawal_psbt."))?;
+
+    let signed_psbt = mercurylib::psbt::sign_psbt(coin, psbt.to_string())?;
+
+    let msg = mercurylib::psbt::get_psbt_msg(psbt.to_string())?;
+
+    consume_signing_nonce(&client_config.pool, &public_nonce, &msg).await?;
+    coin.secret_nonce = None;
+
+    Ok(signed_psbt)
+}
+
+/// Gets the server partial signature of a PSBT signed with `sign_psbt` and returns the signed transaction
+pub async fn finalize_psbt(client_config: &ClientConfig, coin: &Coin, psbt: &str) -> Result<String> {
+
+    let partial_sig_request = mercurylib::psbt::get_partial_sig_request_from_psbt(coin, psbt.to_string())?;
+
+    let server_partial_sig = sign_second(client_config, &partial_sig_request).await?;
+
+    let psbt = mercurylib::psbt::add_server_partial_sig_to_psbt(coin, psbt.to_string(), hex::encode(server_partial_sig.serialize()))?;
+
+    let signed_tx = mercurylib::psbt::finalize_psbt(psbt)?;
+
+    Ok(signed_tx)
+}
@@ -0,0 +1,240 @@
+use std::str::FromStr;
+
+use anyhow::{anyhow, Result};
+use bitcoin::Address;
+use chrono::Utc;
+use electrum_client::ElectrumApi;
+use mercurylib::{deposit::create_aggregated_address, transfer::{receiver::{decrypt_transfer_msg, get_new_key_info, get_tx0_outpoint, sign_message, verify_latest_backup_tx_pays_to_user_pubkey}, TxOutpoint}, utils::get_blockheight, wallet::{recovery::{create_statechain_lookup_request, RecoveredStatechain, StatechainLookupResponsePayload}, BackupTx, Coin, CoinStatus, DerivationScheme, Wallet}};
+
+use crate::{client_config::ClientConfig, sqlite_manager::{insert_or_update_backup_txs, insert_wallet}, transfer_receiver::{get_msg_addr, get_tx0, split_backup_transactions, verify_tx0_output_is_unspent_and_confirmed}, utils::get_statechain_info, wallet::create_wallet_from_mnemonic};
+
+/// A statechain owned by the wallet that could not be recovered
+pub struct StatechainRecoveryError {
+    pub statechain_id: String,
+    pub error: anyhow::Error,
+}
+
+pub struct RecoveryResult {
+    pub wallet: Wallet,
+    /// Coins deposited by this wallet and never transferred. Their backup transactions
+    /// were only stored locally, so they are recovered without them.
+    pub statechains_without_backup_txs: Vec<String>,
+    pub recovery_errors: Vec<StatechainRecoveryError>,
+}
+
+async fn lookup_statechains(client_config: &ClientConfig, coin: &Coin) -> Result<StatechainLookupResponsePayload> {
+
+    let path = "recovery/statechains";
+
+    let statechain_lookup_request_payload = create_statechain_lookup_request(coin, Utc::now().timestamp() as u64)?;
+
+    let client = client_config.get_reqwest_client()?;
+    let request = client.post(&format!("{}/{}", client_config.statechain_entity, path));
+
+    let response = request.json(&statechain_lookup_request_payload).send().await?;
+
+    if !response.status().is_success() {
+        let response_body = response.text().await?;
+        return Err(anyhow!(response_body));
+    }
+
+    let value = response.text().await?;
+
+    let response: StatechainLookupResponsePayload = serde_json::from_str(value.as_str())?;
+
+    Ok(response)
+}
+
+/// Rebuilds a coin received by transfer from its transfer message, which holds the backup transactions
+async fn recover_transferred_coin(client_config: &ClientConfig, coin: &mut Coin, statechain: &RecoveredStatechain, enc_transfer_msg: &str, network: &str) -> Result<Vec<BackupTx>> {
+
+    let transfer_msg = decrypt_transfer_msg(enc_transfer_msg, &coin.auth_privkey)?;
+
+    if transfer_msg.statechain_id != statechain.statechain_id {
+        return Err(anyhow!("The transfer message is for the statechain {}", transfer_msg.statechain_id));
+    }
+
+    if !verify_latest_backup_tx_pays_to_user_pubkey(&transfer_msg, &coin.user_pubkey, network)? {
+        return Err(anyhow!("Latest Backup Tx does not pay to the expected public key"));
+    }
+
+    // the first group has the backup transactions of the coin, the others belong to duplicated deposits
+    let grouped_backup_transactions = split_backup_transactions(&transfer_msg.backup_transactions);
+
+    let backup_transactions = grouped_backup_transactions.first()
+        .ok_or(anyhow!("The transfer message has no backup transactions"))?;
+
+    let tx0_outpoint = get_tx0_outpoint(backup_transactions)?;
+    let tx0_hex = get_tx0(&client_config.electrum_client, &tx0_outpoint.txid).await?;
+
+    let (is_tx0_output_unspent, tx0_status) = verify_tx0_output_is_unspent_and_confirmed(&client_config.electrum_client, &tx0_outpoint, &tx0_hex, network, client_config.confirmation_target).await?;
+
+    if !is_tx0_output_unspent {
+        return Err(anyhow!("tx0 output is spent"));
+    }
+
+    let latest_backup_tx = backup_transactions.last().unwrap();
+
+    let new_key_info = get_new_key_info(&statechain.server_pubkey, coin, &statechain.statechain_id, &tx0_outpoint, &tx0_hex, network)?;
+
+    coin.server_pubkey = Some(statechain.server_pubkey.clone());
+    coin.aggregated_pubkey = Some(new_key_info.aggregate_pubkey);
+    coin.aggregated_address = Some(new_key_info.aggregate_address);
+    coin.statechain_id = Some(statechain.statechain_id.clone());
+    coin.signed_statechain_id = Some(new_key_info.signed_statechain_id);
+    coin.amount = Some(new_key_info.amount);
+    coin.utxo_txid = Some(tx0_outpoint.txid.clone());
+    coin.utxo_vout = Some(tx0_outpoint.vout);
+    coin.locktime = Some(get_blockheight(latest_backup_tx)?);
+    coin.status = tx0_status;
+
+    Ok(transfer_msg.backup_transactions)
+}
+
+/// Rebuilds a coin deposited by this wallet from the chain.
+/// Returns false if its backup transactions were signed, and therefore lost with the local database.
+async fn recover_deposited_coin(client_config: &ClientConfig, coin: &mut Coin, statechain: &RecoveredStatechain, network: &str) -> Result<bool> {
+
+    coin.server_pubkey = Some(statechain.server_pubkey.clone());
+
+    let aggregated_public_key = create_aggregated_address(coin, network.to_string())?;
+
+    coin.aggregated_address = Some(aggregated_public_key.aggregate_address.clone());
+    coin.aggregated_pubkey = Some(aggregated_public_key.aggregate_pubkey);
+    coin.statechain_id = Some(statechain.statechain_id.clone());
+    coin.signed_statechain_id = Some(sign_message(&statechain.statechain_id, coin)?);
+
+    let address = Address::from_str(&aggregated_public_key.aggregate_address)?.require_network(client_config.network)?;
+
+    let utxo_list = client_config.electrum_client.script_list_unspent(&address.script_pubkey())?;
+
+    // Not funded yet. `coin_status::update_coins` keeps watching the address.
+    let utxo = match utxo_list.first() {
+        Some(utxo) => utxo,
+        None => return Ok(true),
+    };
+
+    coin.amount = Some(utxo.value);
+
+    let statechain_info = get_statechain_info(&statechain.statechain_id, client_config).await?
+        .ok_or(anyhow!("Statechain info not found"))?;
+
+    // The first backup transaction was never signed. `coin_status::update_coins` signs it.
+    if statechain_info.num_sigs == 0 {
+        return Ok(true);
+    }
+
+    let tx0_outpoint = TxOutpoint {
+        txid: utxo.tx_hash.to_string(),
+        vout: utxo.tx_pos as u32,
+    };
+
+    let tx0_hex = get_tx0(&client_config.electrum_client, &tx0_outpoint.txid).await?;
+
+    let (_, tx0_status) = verify_tx0_output_is_unspent_and_confirmed(&client_config.electrum_client, &tx0_outpoint, &tx0_hex, network, client_config.confirmation_target).await?;
+
+    coin.utxo_txid = Some(tx0_outpoint.txid);
+    coin.utxo_vout = Some(tx0_outpoint.vout);
+    coin.status = tx0_status;
+
+    Ok(false)
+}
+
+/// Rebuilds a wallet from its mnemonic and stores it.
+/// The coins are derived at successive indices and the server is asked which statechains
+/// their auth keys own or owned, until `gap_limit` consecutive indices have never had a statechain nor a pending transfer.
+/// The coins that were transferred or withdrawn are kept with their final status, so that their keys are not used again.
+pub async fn recover_wallet(
+    client_config: &ClientConfig,
+    name: &str,
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    derivation_scheme: DerivationScheme,
+    account: u32,
+    gap_limit: u32,
+) -> Result<RecoveryResult> {
+
+    let mut wallet = create_wallet_from_mnemonic(name, client_config, mnemonic, passphrase, derivation_scheme, account).await?;
+
+    let network = wallet.network.clone();
+
+    let mut backup_txs_per_statechain = Vec::<(String, Vec<BackupTx>)>::new();
+    let mut statechains_without_backup_txs = Vec::<String>::new();
+    let mut recovery_errors = Vec::<StatechainRecoveryError>::new();
+
+    let mut address_index = 0;
+    let mut unused_indices = 0;
+
+    while unused_indices < gap_limit {
+
+        let coin = wallet.get_coin_at_index(address_index)?;
+        address_index += 1;
+
+        let lookup_response = lookup_statechains(client_config, &coin).await?;
+        let statechains = lookup_response.statechains;
+        let enc_messages = get_msg_addr(&coin.auth_pubkey, client_config).await?;
+
+        if statechains.is_empty() && lookup_response.previous_statechains.is_empty() && enc_messages.is_empty() {
+            unused_indices += 1;
+            continue;
+        }
+
+        unused_indices = 0;
+
+        for previous_statechain in lookup_response.previous_statechains.iter() {
+            let mut spent_coin = coin.clone();
+            spent_coin.statechain_id = Some(previous_statechain.statechain_id.clone());
+            spent_coin.status = if previous_statechain.withdrawn { CoinStatus::WITHDRAWN } else { CoinStatus::TRANSFERRED };
+            wallet.coins.push(spent_coin);
+        }
+
+        // a transfer to this address is waiting to be received
+        if statechains.is_empty() {
+            if !enc_messages.is_empty() {
+                wallet.coins.push(coin);
+            }
+            continue;
+        }
+
+        for statechain in statechains.iter() {
+
+            let mut recovered_coin = coin.clone();
+
+            match statechain.enc_transfer_msg.as_ref() {
+                Some(enc_transfer_msg) => {
+                    match recover_transferred_coin(client_config, &mut recovered_coin, statechain, enc_transfer_msg, &network).await {
+                        Ok(backup_txs) => backup_txs_per_statechain.push((statechain.statechain_id.clone(), backup_txs)),
+                        Err(error) => {
+                            recovery_errors.push(StatechainRecoveryError { statechain_id: statechain.statechain_id.clone(), error });
+                            continue;
+                        }
+                    }
+                },
+                None => {
+                    match recover_deposited_coin(client_config, &mut recovered_coin, statechain, &network).await {
+                        Ok(true) => {},
+                        Ok(false) => statechains_without_backup_txs.push(statechain.statechain_id.clone()),
+                        Err(error) => {
+                            recovery_errors.push(StatechainRecoveryError { statechain_id: statechain.statechain_id.clone(), error });
+                            continue;
+                        }
+                    }
+                }
+            }
+
+            wallet.coins.push(recovered_coin);
+        }
+    }
+
+    insert_wallet(&client_config.pool, &wallet).await?;
+
+    for (statechain_id, backup_txs) in backup_txs_per_statechain.iter() {
+        insert_or_update_backup_txs(&client_config.pool, &wallet.name, statechain_id, backup_txs).await?;
+    }
+
+    Ok(RecoveryResult {
+        wallet,
+        statechains_without_backup_txs,
+        recovery_errors,
+    })
+}
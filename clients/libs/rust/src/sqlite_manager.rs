@@ -3,15 +3,29 @@ use serde_json::json;
 use sqlx::{Pool, Sqlite, Row};
 use anyhow::{anyhow, Result};
 
+use crate::wallet_encryption::{decrypt_wallet_secrets, encrypt_wallet_secrets, get_wallet_key, has_plaintext_secrets, WalletEncryption};
+
+/// Version of the amounts stored in the wallet JSON. Version 1 amounts are u64.
+pub const WALLET_AMOUNTS_VERSION: i64 = 1;
+
+/// The BIP39 passphrase is kept in its own column, never in the wallet JSON with the mnemonic
+fn get_wallet_json(wallet: &Wallet) -> String {
+    let mut wallet = wallet.clone();
+    wallet.passphrase = None;
+    json!(wallet).to_string()
+}
+
 pub async fn insert_wallet(pool: &Pool<Sqlite>, wallet: &Wallet) -> Result<()> {
 
-    let wallet_json = json!(wallet).to_string();
+    let wallet_json = get_wallet_json(wallet);
 
-    let query = "INSERT INTO wallet (wallet_name, wallet_json) VALUES ($1, $2)";
+    let query = "INSERT INTO wallet (wallet_name, wallet_json, passphrase, amounts_version) VALUES ($1, $2, $3, $4)";
 
     let _ = sqlx::query(query)
             .bind(wallet.name.clone())
             .bind(wallet_json)
+            .bind(wallet.passphrase.clone())
+            .bind(WALLET_AMOUNTS_VERSION)
             .execute(pool)
             .await?;
     
@@ -20,7 +34,7 @@ pub async fn insert_wallet(pool: &Pool<Sqlite>, wallet: &Wallet) -> Result<()> {
 
 pub async fn get_wallet(pool: &Pool<Sqlite>, wallet_name: &str) -> Result<Wallet> {
     
-    let query = "SELECT wallet_json FROM wallet WHERE wallet_name = $1";
+    let query = "SELECT wallet_json, passphrase FROM wallet WHERE wallet_name = $1";
 
     let row = sqlx::query(query)
         .bind(wallet_name)
@@ -32,15 +46,54 @@ pub async fn get_wallet(pool: &Pool<Sqlite>, wallet_name: &str) -> Result<Wallet
     }
 
     let wallet_json: String = row.get(0);
+    let passphrase: Option<String> = row.get(1);
 
-    let wallet: Wallet = serde_json::from_str(&wallet_json)?;
+    let mut wallet: Wallet = serde_json::from_str(&wallet_json)?;
+    wallet.passphrase = passphrase;
 
     Ok(wallet)
 }
 
+/// Returns the wallet with its secrets decrypted, which requires an encrypted wallet to be unlocked.
+/// `get_wallet` returns the secrets as stored and is enough when nothing needs to be signed.
+pub async fn get_unlocked_wallet(pool: &Pool<Sqlite>, wallet_name: &str) -> Result<Wallet> {
+
+    let mut wallet = get_wallet(pool, wallet_name).await?;
+
+    if get_wallet_encryption(pool, wallet_name).await?.is_some() {
+        let key = get_wallet_key(wallet_name)
+            .ok_or(anyhow!("Wallet {} is locked", wallet_name))?;
+
+        decrypt_wallet_secrets(&mut wallet, &key)?;
+    }
+
+    Ok(wallet)
+}
+
+/// True if the wallet is encrypted and has not been unlocked in this process
+pub async fn is_wallet_locked(pool: &Pool<Sqlite>, wallet_name: &str) -> Result<bool> {
+
+    if get_wallet_encryption(pool, wallet_name).await?.is_none() {
+        return Ok(false);
+    }
+
+    Ok(get_wallet_key(wallet_name).is_none())
+}
+
+/// Secrets in plaintext (new coins, or a wallet returned by `get_unlocked_wallet`)
+/// are encrypted before being stored if the wallet is encrypted.
 pub async fn update_wallet(pool: &Pool<Sqlite>, wallet: &Wallet) -> Result<()> {
-    
-    let wallet_json = json!(wallet).to_string();
+
+    let mut wallet = wallet.clone();
+
+    if has_plaintext_secrets(&wallet) && get_wallet_encryption(pool, &wallet.name).await?.is_some() {
+        let key = get_wallet_key(&wallet.name)
+            .ok_or(anyhow!("Wallet {} is locked", wallet.name))?;
+
+        encrypt_wallet_secrets(&mut wallet, &key)?;
+    }
+
+    let wallet_json = get_wallet_json(&wallet);
 
     let query = "UPDATE wallet SET wallet_json = $1 WHERE wallet_name = $2";
 
@@ -53,6 +106,71 @@ pub async fn update_wallet(pool: &Pool<Sqlite>, wallet: &Wallet) -> Result<()> {
     Ok(())
 }
 
+pub async fn get_wallet_amounts_version(pool: &Pool<Sqlite>, wallet_name: &str) -> Result<i64> {
+
+    let query = "SELECT amounts_version FROM wallet WHERE wallet_name = $1";
+
+    let row = sqlx::query(query)
+        .bind(wallet_name)
+        .fetch_one(pool)
+        .await?;
+
+    Ok(row.get(0))
+}
+
+pub async fn set_wallet_amounts_version(pool: &Pool<Sqlite>, wallet_name: &str, amounts_version: i64) -> Result<()> {
+
+    let query = "UPDATE wallet SET amounts_version = $1 WHERE wallet_name = $2";
+
+    let _ = sqlx::query(query)
+            .bind(amounts_version)
+            .bind(wallet_name)
+            .execute(pool)
+            .await?;
+
+    Ok(())
+}
+
+pub async fn get_wallet_encryption(pool: &Pool<Sqlite>, wallet_name: &str) -> Result<Option<WalletEncryption>> {
+
+    let query = "SELECT encryption FROM wallet WHERE wallet_name = $1";
+
+    let row = sqlx::query(query)
+        .bind(wallet_name)
+        .fetch_one(pool)
+        .await?;
+
+    let encryption: Option<String> = row.get(0);
+
+    match encryption {
+        Some(encryption) => Ok(Some(serde_json::from_str(&encryption)?)),
+        None => Ok(None),
+    }
+}
+
+/// Stores the wallet, whose secrets must already be encrypted, together with its KDF parameters
+pub async fn update_encrypted_wallet(pool: &Pool<Sqlite>, wallet: &Wallet, encryption: &WalletEncryption) -> Result<()> {
+
+    if has_plaintext_secrets(wallet) {
+        return Err(anyhow!("Wallet {} has secrets in plaintext", wallet.name));
+    }
+
+    let wallet_json = get_wallet_json(wallet);
+    let encryption_json = json!(encryption).to_string();
+
+    let query = "UPDATE wallet SET wallet_json = $1, passphrase = $2, encryption = $3 WHERE wallet_name = $4";
+
+    let _ = sqlx::query(query)
+            .bind(wallet_json)
+            .bind(wallet.passphrase.clone())
+            .bind(encryption_json)
+            .bind(wallet.name.clone())
+            .execute(pool)
+            .await?;
+
+    Ok(())
+}
+
 pub async fn insert_backup_txs(pool: &Pool<Sqlite>, wallet_name: &str, statechain_id: &str, backup_txs: &Vec<BackupTx>) -> Result<()> {
 
     let backup_txs_json = json!(backup_txs).to_string();
@@ -132,4 +250,53 @@ pub async fn insert_or_update_backup_txs(pool: &Pool<Sqlite>, wallet_name: &str,
     transaction.commit().await?;
     
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Records a new signing nonce of the statechain.
+/// Nonces of the statechain that were created but never used to sign are abandoned.
+pub async fn insert_signing_nonce(pool: &Pool<Sqlite>, statechain_id: &str, public_nonce: &str) -> Result<()> {
+
+    let mut transaction = pool.begin().await?;
+
+    let query = "UPDATE signing_nonce SET status = 'abandoned' WHERE statechain_id = $1 AND status = 'created'";
+
+    let _ = sqlx::query(query)
+            .bind(statechain_id)
+            .execute(&mut *transaction)
+            .await?;
+
+    let query = "INSERT INTO signing_nonce (public_nonce, statechain_id, status, created_at) VALUES ($1, $2, 'created', $3)";
+
+    let _ = sqlx::query(query)
+            .bind(public_nonce)
+            .bind(statechain_id)
+            .bind(chrono::Utc::now().timestamp())
+            .execute(&mut *transaction)
+            .await?;
+
+    transaction.commit().await?;
+
+    Ok(())
+}
+
+/// Marks the nonce as consumed by the signature of `msg`.
+/// Fails if the nonce is unknown or was already consumed or abandoned, so that it never signs twice.
+pub async fn consume_signing_nonce(pool: &Pool<Sqlite>, public_nonce: &str, msg: &str) -> Result<()> {
+
+    let query = "\
+        UPDATE signing_nonce SET status = 'consumed', msg = $1, consumed_at = $2 \
+        WHERE public_nonce = $3 AND status = 'created'";
+
+    let result = sqlx::query(query)
+            .bind(msg)
+            .bind(chrono::Utc::now().timestamp())
+            .bind(public_nonce)
+            .execute(pool)
+            .await?;
+
+    if result.rows_affected() != 1 {
+        return Err(anyhow!("Signing nonce {} is not available, refusing to sign with it", public_nonce));
+    }
+
+    Ok(())
+}
@@ -1,10 +1,9 @@
 use electrum_client::ElectrumApi;
-use mercurylib::{transaction::{SignFirstRequestPayload, PartialSignatureRequestPayload, PartialSignatureResponsePayload, get_partial_sig_request, create_signature, new_backup_transaction}, wallet::Coin};
+use mercurylib::{error::MercuryError, transaction::{SignFirstRequestPayload, PartialSignatureMsg1, PartialSignatureRequestPayload, PartialSignatureResponsePayload, WithdrawalOutput, get_partial_sig_request_with_anchor, get_withdrawal_partial_sig_request, create_signature, new_backup_transaction}, wallet::Coin};
 use anyhow::Result;
 use reqwest::StatusCode;
 use secp256k1_zkp::musig::MusigPartialSignature;
-use serde_json::Value;
-use crate::client_config::ClientConfig;
+use crate::{client_config::ClientConfig, sqlite_manager::{consume_signing_nonce, insert_signing_nonce}, utils::api_error};
 
 pub async fn new_transaction(
     client_config: &ClientConfig, 
@@ -20,9 +19,58 @@ pub async fn new_transaction(
 
     // TODO: validate address first
 
+    // The anchor output is only useful in backup transactions, which are broadcast later
+    let anchor_type = if is_withdrawal { None } else { client_config.anchor_output.clone() };
+
+    sign_new_transaction(client_config, coin, block_height, |coin, block_height| {
+        get_partial_sig_request_with_anchor(
+            coin, 
+            block_height, 
+            initlock, 
+            interval, 
+            fee_rate_sats_per_byte,
+            qt_backup_tx,
+            to_address.to_string(),
+            network.to_string(),
+            is_withdrawal,
+            anchor_type)
+    }).await
+}
+
+/// Creates and signs a withdrawal transaction paying to one or more outputs
+pub async fn new_withdrawal_transaction(
+    client_config: &ClientConfig, 
+    coin: &mut Coin, 
+    outputs: &Vec<WithdrawalOutput>, 
+    network: &str, 
+    fee_rate_sats_per_byte: f64) -> Result<String> {
+
+    sign_new_transaction(client_config, coin, None, |coin, block_height| {
+        get_withdrawal_partial_sig_request(
+            coin, 
+            block_height, 
+            fee_rate_sats_per_byte,
+            outputs.clone(),
+            network.to_string())
+    }).await
+}
+
+async fn sign_new_transaction<F>(
+    client_config: &ClientConfig, 
+    coin: &mut Coin, 
+    block_height: Option<u32>,
+    get_partial_sig_request: F) -> Result<String>
+where
+    F: FnOnce(&Coin, u32) -> core::result::Result<PartialSignatureMsg1, MercuryError>
+{
     let coin_nonce = mercurylib::transaction::create_and_commit_nonces(&coin)?;
+
+    // Every signing session uses new nonces. The nonce is stored before it is used,
+    // so that a session interrupted by a crash or an error cannot be resumed with it.
+    insert_signing_nonce(&client_config.pool, &coin_nonce.sign_first_request_payload.statechain_id, &coin_nonce.public_nonce).await?;
+
     coin.secret_nonce = Some(coin_nonce.secret_nonce);
-    coin.public_nonce = Some(coin_nonce.public_nonce);
+    coin.public_nonce = Some(coin_nonce.public_nonce.clone());
     coin.blinding_factor = Some(coin_nonce.blinding_factor);
 
     let server_public_nonce = sign_first(&client_config, &coin_nonce.sign_first_request_payload).await?;
@@ -37,16 +85,12 @@ pub async fn new_transaction(
         },
     };
 
-    let partial_sig_request = get_partial_sig_request(
-        &coin, 
-        block_height, 
-        initlock, 
-        interval, 
-        fee_rate_sats_per_byte,
-        qt_backup_tx,
-        to_address.to_string(),
-        network.to_string(),
-        is_withdrawal)?;
+    let partial_sig_request = get_partial_sig_request(&*coin, block_height)?;
+
+    // The server partial signature completes the signature of the message, so the nonce is marked
+    // as consumed before asking for it. Signing another message with the same nonce would leak the key share.
+    consume_signing_nonce(&client_config.pool, &coin_nonce.public_nonce, &partial_sig_request.msg).await?;
+    coin.secret_nonce = None;
 
     let server_partial_sig_request = partial_sig_request.partial_signature_request_payload;
 
@@ -84,11 +128,9 @@ pub async fn sign_first(client_config: &ClientConfig, sign_first_request_payload
 
     let value = response.text().await?;
 
-    if status != StatusCode::OK{
-        
-        let error_message: Value = serde_json::from_str(value.as_str())?;
-        
-        return Err(anyhow::anyhow!("{}", error_message["message"].as_str().unwrap()));
+    if status != StatusCode::OK {
+
+        return Err(api_error(status, &value));
     }
 
     let sign_first_response_payload: mercurylib::transaction::SignFirstResponsePayload = serde_json::from_str(value.as_str())?;
@@ -109,7 +151,16 @@ pub async fn sign_second(client_config: &ClientConfig, partial_sig_request: &Par
     let client = client_config.get_reqwest_client()?;
     let request = client.post(&format!("{}/{}", endpoint, path));
 
-    let value = request.json(&partial_sig_request).send().await?.text().await?;
+    let response = request.json(&partial_sig_request).send().await?;
+
+    let status = response.status();
+
+    let value = response.text().await?;
+
+    if status != StatusCode::OK {
+
+        return Err(api_error(status, &value));
+    }
 
     let response: PartialSignatureResponsePayload = serde_json::from_str(value.as_str())?;
 
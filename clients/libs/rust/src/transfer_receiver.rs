@@ -1,20 +1,28 @@
 use std::{collections::{HashMap, HashSet}, str::FromStr};
 
-use crate::{sqlite_manager::{get_wallet, update_wallet, insert_or_update_backup_txs}, client_config::ClientConfig, utils};
+use crate::{sqlite_manager::{get_unlocked_wallet, update_wallet, insert_or_update_backup_txs}, client_config::ClientConfig, utils};
 use anyhow::{anyhow, Ok, Result};
 use bitcoin::{Txid, Address};
 use chrono::Utc;
 use electrum_client::ElectrumApi;
-use mercurylib::{utils::{get_network, InfoConfig}, wallet::{get_previous_outpoint, Activity, BackupTx, Coin, CoinStatus}};
+use mercurylib::{ScAddressOptions, utils::{get_network, InfoConfig}, wallet::{get_previous_outpoint, Activity, BackupTx, Coin, CoinStatus, KeyRotationEvidence}};
 use reqwest::StatusCode;
 
 pub async fn new_transfer_address(client_config: &ClientConfig, wallet_name: &str) -> Result<String>{
+    new_transfer_address_with_options(client_config, wallet_name, None).await
+}
+
+/// Creates a new transfer address. With `address_options`, it is a v1 address carrying the server hint and the expiry.
+pub async fn new_transfer_address_with_options(client_config: &ClientConfig, wallet_name: &str, address_options: Option<&ScAddressOptions>) -> Result<String>{
 
-    let wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let wallet = get_unlocked_wallet(&client_config.pool, &wallet_name).await?;
     
     let mut wallet = wallet.clone();
 
-    let coin = wallet.get_new_coin()?;
+    let coin = match address_options {
+        Some(address_options) => wallet.get_new_coin_with_address_options(address_options)?,
+        None => wallet.get_new_coin()?,
+    };
 
     wallet.coins.push(coin.clone());
 
@@ -26,6 +34,8 @@ pub async fn new_transfer_address(client_config: &ClientConfig, wallet_name: &st
 pub struct TransferReceiveResult {
     pub is_there_batch_locked: bool,
     pub received_statechain_ids: Vec<String>,
+    /// Received coins whose server key share rotation could not be verified
+    pub unverified_key_rotations: Vec<KeyRotationEvidence>,
 }
 
 pub struct DuplicatedCoinData {
@@ -39,6 +49,7 @@ pub struct MessageResult {
     pub is_batch_locked: bool,
     pub statechain_id: Option<String>,
     pub duplicated_coins: Vec<DuplicatedCoinData>,
+    pub unverified_key_rotation: Option<KeyRotationEvidence>,
 }
 
 pub fn sort_coins_by_statechain(coins: &mut Vec<Coin>) {
@@ -73,7 +84,7 @@ pub fn sort_coins_by_statechain(coins: &mut Vec<Coin>) {
 
 pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<TransferReceiveResult>{
 
-    let mut wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let mut wallet = get_unlocked_wallet(&client_config.pool, &wallet_name).await?;
 
     let info_config = utils::info_config(&client_config).await.unwrap();
 
@@ -99,6 +110,8 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<
 
     let mut received_statechain_ids =  Vec::<String>::new();
 
+    let mut unverified_key_rotations = Vec::<KeyRotationEvidence>::new();
+
     let mut temp_coins = wallet.coins.clone();
     let mut temp_activities = wallet.activities.clone();
 
@@ -143,6 +156,10 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<
                     received_statechain_ids.push(message_result.statechain_id.unwrap());
                 }
 
+                if message_result.unverified_key_rotation.is_some() {
+                    unverified_key_rotations.push(message_result.unverified_key_rotation.unwrap());
+                }
+
                 if message_result.duplicated_coins.len() > 0 {
 
                     assert!(!message_result.is_batch_locked);
@@ -152,7 +169,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<
                         duplicated_coin.status = CoinStatus::DUPLICATED;
                         duplicated_coin.utxo_txid = Some(duplicated_coin_data.txid);
                         duplicated_coin.utxo_vout = Some(duplicated_coin_data.vout);
-                        duplicated_coin.amount = Some(duplicated_coin_data.amount as u32);
+                        duplicated_coin.amount = Some(duplicated_coin_data.amount);
                         duplicated_coin.duplicate_index = duplicated_coin_data.index;
                         duplicated_coins.push(duplicated_coin);
                     }
@@ -195,6 +212,10 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<
                     received_statechain_ids.push(message_result.statechain_id.unwrap());
                 }
 
+                if message_result.unverified_key_rotation.is_some() {
+                    unverified_key_rotations.push(message_result.unverified_key_rotation.unwrap());
+                }
+
                 if message_result.duplicated_coins.len() > 0 {
 
                     assert!(!message_result.is_batch_locked);
@@ -204,7 +225,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<
                         duplicated_coin.status = CoinStatus::DUPLICATED;
                         duplicated_coin.utxo_txid = Some(duplicated_coin_data.txid);
                         duplicated_coin.utxo_vout = Some(duplicated_coin_data.vout);
-                        duplicated_coin.amount = Some(duplicated_coin_data.amount as u32);
+                        duplicated_coin.amount = Some(duplicated_coin_data.amount);
                         duplicated_coin.duplicate_index = duplicated_coin_data.index;
                         // temp_coins.push(duplicated_coin);
                         duplicated_coins.push(duplicated_coin);
@@ -223,11 +244,12 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str) -> Result<
 
     Ok(TransferReceiveResult{
         is_there_batch_locked,
-        received_statechain_ids
+        received_statechain_ids,
+        unverified_key_rotations,
     })
 }
 
-async fn get_msg_addr(auth_pubkey: &str, client_config: &ClientConfig) -> Result<Vec<String>> {
+pub async fn get_msg_addr(auth_pubkey: &str, client_config: &ClientConfig) -> Result<Vec<String>> {
 
     let path = format!("transfer/get_msg_addr/{}", auth_pubkey.to_string());
 
@@ -366,6 +388,7 @@ async fn process_encrypted_message(client_config: &ClientConfig, coin: &mut Coin
         is_batch_locked: false,
         statechain_id: None,
         duplicated_coins: Vec::new(),
+        unverified_key_rotation: None,
     };
 
     let client_auth_key = coin.auth_privkey.clone();
@@ -410,6 +433,7 @@ async fn process_encrypted_message(client_config: &ClientConfig, coin: &mut Coin
                             is_batch_locked: true,
                             statechain_id: None,
                             duplicated_coins: Vec::new(),
+                            unverified_key_rotation: None,
                         });
                     }
         
@@ -433,6 +457,31 @@ async fn process_encrypted_message(client_config: &ClientConfig, coin: &mut Coin
             coin.locktime = Some(last_tx_lock_time);
             coin.status = tx0_status;
 
+            // the server share used by the previous owner must have been replaced by the one returned above
+            let key_rotation = match utils::get_keylist(client_config).await {
+                std::result::Result::Ok(keylist) => mercurylib::utils::verify_key_rotation(coin, &backup_tx.server_public_key, &keylist).map_err(|err| anyhow!(err.to_string())),
+                Err(err) => Err(err),
+            };
+
+            // a failed check is kept on the coin as well, and returned to the caller
+            let evidence = match key_rotation {
+                std::result::Result::Ok(evidence) => evidence,
+                Err(err) => {
+                    let evidence = KeyRotationEvidence {
+                        statechain_id: transfer_msg.statechain_id.clone(),
+                        previous_server_pubkey: backup_tx.server_public_key.clone(),
+                        current_server_pubkey: coin.server_pubkey.clone().unwrap(),
+                        keylist_tx_n: None,
+                        keylist_created_at: None,
+                        error: Some(err.to_string()),
+                    };
+                    transfer_receive_result.unverified_key_rotation = Some(evidence.clone());
+                    evidence
+                },
+            };
+
+            coin.key_rotations.push(evidence);
+
             let date = Utc::now(); // This will get the current date and time in UTC
             let iso_string = date.to_rfc3339(); // Converts the date to an ISO 8601 string
 
@@ -472,7 +521,7 @@ async fn process_encrypted_message(client_config: &ClientConfig, coin: &mut Coin
     Ok(transfer_receive_result)
 }
 
-async fn get_tx0(electrum_client: &electrum_client::Client, tx0_txid: &str) -> Result<String> {
+pub async fn get_tx0(electrum_client: &electrum_client::Client, tx0_txid: &str) -> Result<String> {
 
     let tx0_txid = Txid::from_str(tx0_txid)?;
     let tx_bytes = electrum_client.batch_transaction_get_raw(&[tx0_txid])?;
@@ -486,7 +535,7 @@ async fn get_tx0(electrum_client: &electrum_client::Client, tx0_txid: &str) -> R
     Ok(tx0_hex)
 }
 
-async fn verify_tx0_output_is_unspent_and_confirmed(electrum_client: &electrum_client::Client, tx0_outpoint: &mercurylib::transfer::TxOutpoint, tx0_hex: &str, network: &str, confirmation_target: u32) -> Result<(bool, CoinStatus)> {
+pub async fn verify_tx0_output_is_unspent_and_confirmed(electrum_client: &electrum_client::Client, tx0_outpoint: &mercurylib::transfer::TxOutpoint, tx0_hex: &str, network: &str, confirmation_target: u32) -> Result<(bool, CoinStatus)> {
     let output_address = mercurylib::transfer::receiver::get_output_address_from_tx0(&tx0_outpoint, &tx0_hex, &network)?;
 
     let network = get_network(&network)?;
@@ -557,31 +606,24 @@ async fn send_transfer_receiver_request_payload(client_config: &ClientConfig, tr
 
         let value = response.text().await?;
 
-        if status == StatusCode::BAD_REQUEST{
+        if status != StatusCode::OK {
 
-            let error: mercurylib::transfer::receiver::TransferReceiverErrorResponsePayload = serde_json::from_str(value.as_str())?;
+            let error = mercurylib::error::decode_api_error(status.as_u16(), value.as_str());
 
-            match error.code {
-                mercurylib::transfer::receiver::TransferReceiverError::ExpiredBatchTimeError => {
-                    return Err(anyhow::anyhow!(error.message));
-                },
-                mercurylib::transfer::receiver::TransferReceiverError::StatecoinBatchLockedError => {
-                    return Ok(TransferReceiveRequestResult {
-                        is_batch_locked: true,
-                        server_pubkey: None,
-                    });
-                },
+            if error.code == mercurylib::error::ApiError::StatecoinBatchLockedError {
+                return Ok(TransferReceiveRequestResult {
+                    is_batch_locked: true,
+                    server_pubkey: None,
+                });
             }
-        }
 
-        if status == StatusCode::OK {
-            let response: mercurylib::transfer::receiver::TransferReceiverPostResponsePayload = serde_json::from_str(value.as_str())?;
-            return Ok(TransferReceiveRequestResult {
-                is_batch_locked: false,
-                server_pubkey: Some(response.server_pubkey)
-            });
-        } else {
-            return Err(anyhow::anyhow!("{}: {}", "Failed to update transfer message".to_string(), value));
+            return Err(anyhow::Error::new(error));
         }
+
+        let response: mercurylib::transfer::receiver::TransferReceiverPostResponsePayload = serde_json::from_str(value.as_str())?;
+        return Ok(TransferReceiveRequestResult {
+            is_batch_locked: false,
+            server_pubkey: Some(response.server_pubkey)
+        });
     
 }
\ No newline at end of file
@@ -1,11 +1,16 @@
 
 use chrono::Utc;
 use electrum_client::ElectrumApi;
-use mercurylib::{transfer::receiver::StatechainInfoResponsePayload, utils::{InfoConfig, ServerConfig}, wallet::Activity, withdraw::WithdrawCompletePayload};
-use anyhow::{anyhow, Result, Ok};
+use mercurylib::{error::decode_api_error, transfer::receiver::StatechainInfoResponsePayload, utils::{InfoConfig, KeyListResponsePayload, ServerConfig}, wallet::Activity, withdraw::WithdrawCompletePayload};
+use anyhow::{Result, Ok};
 use reqwest::StatusCode;
 use crate::client_config::ClientConfig;
 
+/// Decodes a failed server response into an `ApiErrorResponsePayload`, so callers can downcast the error and match on its code.
+pub fn api_error(status: StatusCode, body: &str) -> anyhow::Error {
+    anyhow::Error::new(decode_api_error(status.as_u16(), body))
+}
+
 pub async fn info_config(client_config: &ClientConfig) -> Result<InfoConfig>{
 
     let path = "info/config";
@@ -37,7 +42,7 @@ pub async fn info_config(client_config: &ClientConfig) -> Result<InfoConfig>{
     })
 }
 
-pub fn create_activity(utxo: &str, amount: u32, action: &str) -> Activity {
+pub fn create_activity(utxo: &str, amount: u64, action: &str) -> Activity {
 
     let date = Utc::now(); // This will get the current date and time in UTC
     let iso_string = date.to_rfc3339(); // Converts the date to an ISO 8601 string
@@ -72,6 +77,20 @@ pub async fn get_statechain_info(statechain_id: &str, client_config: &ClientConf
     Ok(Some(response))
 }
 
+pub async fn get_keylist(client_config: &ClientConfig) -> Result<KeyListResponsePayload> {
+
+    let path = "info/keylist";
+
+    let client = client_config.get_reqwest_client()?;
+    let request = client.get(&format!("{}/{}", client_config.statechain_entity, path));
+
+    let value = request.send().await?.text().await?;
+
+    let response: KeyListResponsePayload = serde_json::from_str(value.as_str())?;
+
+    Ok(response)
+}
+
 pub async fn complete_withdraw(statechain_id: &str, signed_statechain_id: &str, client_config: &ClientConfig) -> Result<()> {
 
     let endpoint = client_config.statechain_entity.clone();
@@ -87,9 +106,11 @@ pub async fn complete_withdraw(statechain_id: &str, signed_statechain_id: &str,
 
     let response = request.json(&delete_statechain_payload).send().await?;
 
-    if response.status() != 200 {
+    let status = response.status();
+
+    if status != StatusCode::OK {
         let response_body = response.text().await?;
-        return Err(anyhow!(response_body));
+        return Err(api_error(status, &response_body));
     }
 
     Ok(())
@@ -1,15 +1,38 @@
 use anyhow::Result;
 use electrum_client::ElectrumApi;
-use mercurylib::wallet::{generate_mnemonic, Settings, Wallet};
+use mercurylib::wallet::{generate_mnemonic, DerivationScheme, Settings, Wallet};
 
 use crate::{utils::info_config, client_config::ClientConfig};
 
 pub async fn create_wallet(
     name: &str, 
     client_config: &ClientConfig
+) -> Result<Wallet> {
+    create_wallet_with_options(name, client_config, None, 0).await
+}
+
+/// Creates a wallet whose seed is derived with the BIP39 `passphrase`, using the BIP44 `account`.
+/// The passphrase is stored apart from the wallet JSON (see `sqlite_manager::insert_wallet`).
+pub async fn create_wallet_with_options(
+    name: &str, 
+    client_config: &ClientConfig,
+    passphrase: Option<&str>,
+    account: u32,
 ) -> Result<Wallet> {
     let mnemonic = generate_mnemonic()?;
 
+    create_wallet_from_mnemonic(name, client_config, &mnemonic, passphrase, DerivationScheme::Bip44, account).await
+}
+
+/// Creates an empty wallet with an existing mnemonic. See `recovery::recover_wallet` to also restore its coins.
+pub async fn create_wallet_from_mnemonic(
+    name: &str, 
+    client_config: &ClientConfig,
+    mnemonic: &str,
+    passphrase: Option<&str>,
+    derivation_scheme: DerivationScheme,
+    account: u32,
+) -> Result<Wallet> {
     let server_info = info_config(&client_config).await?;
 
     let block_header = client_config.electrum_client.block_headers_subscribe_raw()?;
@@ -42,7 +65,10 @@ pub async fn create_wallet(
 
     let wallet = Wallet {
         name: name.to_string(),
-        mnemonic,
+        mnemonic: mnemonic.to_string(),
+        passphrase: passphrase.filter(|passphrase| !passphrase.is_empty()).map(|passphrase| passphrase.to_string()),
+        derivation_scheme,
+        account,
         version: String::from("0.1.0"),
         state_entity_endpoint: client_config.statechain_entity.to_string(),
         electrum_endpoint,
@@ -0,0 +1,120 @@
+use std::{os::unix::fs::PermissionsExt, path::{Path, PathBuf}, time::Duration};
+
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::{sha256, Hash};
+use tokio::{io::{AsyncBufReadExt, AsyncWriteExt, BufReader}, net::{UnixListener, UnixStream}};
+
+use crate::{client_config::ClientConfig, wallet_encryption::{set_wallet_key, WalletKey}};
+
+const GET_KEY_REQUEST: &str = "get";
+const LOCK_REQUEST: &str = "lock";
+
+/// Socket of the agent that keeps the key of an unlocked wallet.
+/// It is in the runtime directory of the user if there is one, or next to the database,
+/// and its name depends on the database, so that wallets of different databases do not share an agent.
+pub fn get_agent_socket_path(client_config: &ClientConfig, wallet_name: &str) -> Result<PathBuf> {
+
+    let database_file = client_config.database_file.trim_start_matches("sqlite://").trim_start_matches("sqlite:");
+    let database_path = std::fs::canonicalize(database_file)?;
+
+    let dir = match std::env::var_os("XDG_RUNTIME_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => database_path.parent().ok_or(anyhow!("Invalid database path"))?.to_path_buf(),
+    };
+
+    let id = sha256::Hash::hash(format!("{}\n{}", database_path.display(), wallet_name).as_bytes()).to_string();
+
+    Ok(dir.join(format!("mercury-wallet-{}.sock", &id[..16])))
+}
+
+/// Serves the wallet key on `socket_path` until `expires_at` or a lock request.
+/// The socket is only accessible to its owner, and it is removed when the agent stops.
+pub async fn run_wallet_agent(socket_path: &Path, key: WalletKey, expires_at: i64) -> Result<()> {
+
+    if socket_path.exists() {
+        std::fs::remove_file(socket_path)?;
+    }
+
+    let listener = UnixListener::bind(socket_path)?;
+    std::fs::set_permissions(socket_path, std::fs::Permissions::from_mode(0o600))?;
+
+    let timeout = (expires_at - chrono::Utc::now().timestamp()).max(0) as u64;
+    let expiry = tokio::time::sleep(Duration::from_secs(timeout));
+    tokio::pin!(expiry);
+
+    loop {
+        tokio::select! {
+            _ = &mut expiry => break,
+            connection = listener.accept() => {
+                let (stream, _) = match connection {
+                    Ok(connection) => connection,
+                    Err(_) => continue,
+                };
+
+                let (reader, mut writer) = stream.into_split();
+                let mut request = String::new();
+
+                if BufReader::new(reader).read_line(&mut request).await.is_err() {
+                    continue;
+                }
+
+                match request.trim() {
+                    GET_KEY_REQUEST => {
+                        let _ = writer.write_all(format!("{} {}\n", hex::encode(key), expires_at).as_bytes()).await;
+                    },
+                    LOCK_REQUEST => {
+                        let _ = writer.write_all(b"ok\n").await;
+                        break;
+                    },
+                    _ => {},
+                }
+            },
+        }
+    }
+
+    let _ = std::fs::remove_file(socket_path);
+
+    Ok(())
+}
+
+async fn send_request(socket_path: &Path, request: &str) -> Result<String> {
+
+    let stream = UnixStream::connect(socket_path).await?;
+    let (reader, mut writer) = stream.into_split();
+
+    writer.write_all(format!("{}\n", request).as_bytes()).await?;
+
+    let mut response = String::new();
+    BufReader::new(reader).read_line(&mut response).await?;
+
+    Ok(response.trim().to_string())
+}
+
+/// Gets the key of the wallet from its agent, if it is unlocked, for the duration of the command
+pub async fn unlock_wallet_from_agent(socket_path: &Path, wallet_name: &str) -> bool {
+
+    let response = match send_request(socket_path, GET_KEY_REQUEST).await {
+        Ok(response) => response,
+        Err(_) => return false,
+    };
+
+    let (key, expires_at) = match response.split_once(' ') {
+        Some((key, expires_at)) => (key, expires_at),
+        None => return false,
+    };
+
+    let key: Option<WalletKey> = hex::decode(key).ok().and_then(|key| key.try_into().ok());
+
+    match (key, expires_at.parse::<i64>()) {
+        (Some(key), Ok(expires_at)) => {
+            set_wallet_key(wallet_name, key, expires_at);
+            true
+        },
+        _ => false,
+    }
+}
+
+/// Stops the agent of the wallet. Returns false if the wallet was not unlocked.
+pub async fn lock_wallet_agent(socket_path: &Path) -> bool {
+    matches!(send_request(socket_path, LOCK_REQUEST).await.as_deref(), Ok("ok"))
+}
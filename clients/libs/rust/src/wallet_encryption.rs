@@ -0,0 +1,270 @@
+use std::{collections::HashMap, sync::{LazyLock, Mutex}};
+
+use anyhow::{anyhow, Result};
+use argon2::{Algorithm, Argon2, Params, Version};
+use chacha20poly1305::{aead::{Aead, KeyInit}, XChaCha20Poly1305, XNonce};
+use mercurylib::wallet::Wallet;
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sqlx::{Pool, Sqlite};
+
+use crate::sqlite_manager::{get_wallet, get_wallet_encryption, update_encrypted_wallet};
+
+/// Prefix of the secret fields that are stored encrypted
+const ENCRYPTED_FIELD_PREFIX: &str = "enc1:";
+
+/// Known plaintext encrypted with the wallet key, used to check the password
+const PASSWORD_VERIFIER: &str = "mercury-wallet";
+
+const KEY_SIZE: usize = 32;
+const SALT_SIZE: usize = 16;
+const NONCE_SIZE: usize = 24;
+
+/// Argon2id parameters recommended by OWASP (19 MiB, 2 iterations, 1 lane)
+const DEFAULT_M_COST: u32 = 19 * 1024;
+const DEFAULT_T_COST: u32 = 2;
+const DEFAULT_P_COST: u32 = 1;
+
+pub const DEFAULT_UNLOCK_TIMEOUT: u64 = 300;
+
+pub type WalletKey = [u8; KEY_SIZE];
+
+/// Keys of the unlocked wallets and the time they expire.
+/// They are kept in the process memory only, never in the database.
+static WALLET_KEYS: LazyLock<Mutex<HashMap<String, (WalletKey, i64)>>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// KDF parameters of an encrypted wallet, stored in the `encryption` column of the `wallet` table
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletEncryption {
+    pub version: u32,
+    pub salt: String,
+    pub m_cost: u32,
+    pub t_cost: u32,
+    pub p_cost: u32,
+    /// `PASSWORD_VERIFIER` encrypted with the wallet key
+    pub verifier: String,
+}
+
+impl WalletEncryption {
+
+    /// Derives a new wallet key from `password` with a fresh salt
+    fn new(password: &str) -> Result<(Self, WalletKey)> {
+
+        let mut salt = [0u8; SALT_SIZE];
+        rand::thread_rng().fill_bytes(&mut salt);
+
+        let mut encryption = WalletEncryption {
+            version: 1,
+            salt: hex::encode(salt),
+            m_cost: DEFAULT_M_COST,
+            t_cost: DEFAULT_T_COST,
+            p_cost: DEFAULT_P_COST,
+            verifier: String::new(),
+        };
+
+        let key = encryption.derive_key(password)?;
+        encryption.verifier = encrypt_field(&key, PASSWORD_VERIFIER)?;
+
+        Ok((encryption, key))
+    }
+
+    fn derive_key(&self, password: &str) -> Result<WalletKey> {
+
+        if self.version != 1 {
+            return Err(anyhow!("Unsupported wallet encryption version {}", self.version));
+        }
+
+        let salt = hex::decode(&self.salt)?;
+
+        let params = Params::new(self.m_cost, self.t_cost, self.p_cost, Some(KEY_SIZE))
+            .map_err(|e| anyhow!("Invalid KDF parameters: {}", e))?;
+
+        let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+        let mut key = [0u8; KEY_SIZE];
+        argon2.hash_password_into(password.as_bytes(), &salt, &mut key)
+            .map_err(|e| anyhow!("Key derivation failed: {}", e))?;
+
+        Ok(key)
+    }
+
+    /// Derives the wallet key and checks it against the verifier
+    pub fn unlock(&self, password: &str) -> Result<WalletKey> {
+
+        let key = self.derive_key(password)?;
+
+        match decrypt_field(&key, &self.verifier) {
+            Ok(verifier) if verifier == PASSWORD_VERIFIER => Ok(key),
+            _ => Err(anyhow!("Wrong password")),
+        }
+    }
+}
+
+pub fn is_encrypted_field(value: &str) -> bool {
+    value.starts_with(ENCRYPTED_FIELD_PREFIX)
+}
+
+/// XChaCha20-Poly1305 with a random nonce, encoded as `enc1:<hex(nonce || ciphertext)>`
+fn encrypt_field(key: &WalletKey, plaintext: &str) -> Result<String> {
+
+    let cipher = XChaCha20Poly1305::new_from_slice(key)
+        .map_err(|_| anyhow!("Invalid wallet key"))?;
+
+    let mut nonce = [0u8; NONCE_SIZE];
+    rand::thread_rng().fill_bytes(&mut nonce);
+
+    let ciphertext = cipher.encrypt(XNonce::from_slice(&nonce), plaintext.as_bytes())
+        .map_err(|_| anyhow!("Encryption failed"))?;
+
+    let mut data = nonce.to_vec();
+    data.extend_from_slice(&ciphertext);
+
+    Ok(format!("{}{}", ENCRYPTED_FIELD_PREFIX, hex::encode(data)))
+}
+
+fn decrypt_field(key: &WalletKey, value: &str) -> Result<String> {
+
+    let data = match value.strip_prefix(ENCRYPTED_FIELD_PREFIX) {
+        Some(data) => hex::decode(data)?,
+        None => return Ok(value.to_string()),
+    };
+
+    if data.len() < NONCE_SIZE {
+        return Err(anyhow!("Encrypted field is too short"));
+    }
+
+    let (nonce, ciphertext) = data.split_at(NONCE_SIZE);
+
+    let cipher = XChaCha20Poly1305::new_from_slice(key)
+        .map_err(|_| anyhow!("Invalid wallet key"))?;
+
+    let plaintext = cipher.decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| anyhow!("Decryption failed"))?;
+
+    Ok(String::from_utf8(plaintext)?)
+}
+
+/// Applies `f` to every secret field of the wallet:
+/// the mnemonic, the BIP39 passphrase and, for each coin, the private keys and the secret nonce
+fn map_wallet_secrets(wallet: &mut Wallet, f: impl Fn(&str) -> Result<String>) -> Result<()> {
+
+    wallet.mnemonic = f(&wallet.mnemonic)?;
+
+    if let Some(passphrase) = wallet.passphrase.as_ref() {
+        wallet.passphrase = Some(f(passphrase)?);
+    }
+
+    for coin in wallet.coins.iter_mut() {
+        coin.user_privkey = f(&coin.user_privkey)?;
+        coin.auth_privkey = f(&coin.auth_privkey)?;
+
+        if let Some(secret_nonce) = coin.secret_nonce.as_ref() {
+            coin.secret_nonce = Some(f(secret_nonce)?);
+        }
+    }
+
+    Ok(())
+}
+
+/// Encrypts the secret fields that are still in plaintext
+pub fn encrypt_wallet_secrets(wallet: &mut Wallet, key: &WalletKey) -> Result<()> {
+    map_wallet_secrets(wallet, |value| {
+        if is_encrypted_field(value) {
+            Ok(value.to_string())
+        } else {
+            encrypt_field(key, value)
+        }
+    })
+}
+
+pub fn decrypt_wallet_secrets(wallet: &mut Wallet, key: &WalletKey) -> Result<()> {
+    map_wallet_secrets(wallet, |value| decrypt_field(key, value))
+}
+
+/// True if any secret field of the wallet is in plaintext
+pub fn has_plaintext_secrets(wallet: &Wallet) -> bool {
+    !is_encrypted_field(&wallet.mnemonic) ||
+    wallet.passphrase.as_ref().is_some_and(|passphrase| !is_encrypted_field(passphrase)) ||
+    wallet.coins.iter().any(|coin| {
+        !is_encrypted_field(&coin.user_privkey) ||
+        !is_encrypted_field(&coin.auth_privkey) ||
+        coin.secret_nonce.as_ref().is_some_and(|secret_nonce| !is_encrypted_field(secret_nonce))
+    })
+}
+
+/// Encrypts the secrets of a plaintext wallet with a key derived from `password`
+pub async fn encrypt_wallet(pool: &Pool<Sqlite>, wallet_name: &str, password: &str) -> Result<()> {
+
+    if get_wallet_encryption(pool, wallet_name).await?.is_some() {
+        return Err(anyhow!("Wallet {} is already encrypted", wallet_name));
+    }
+
+    let mut wallet = get_wallet(pool, wallet_name).await?;
+
+    let (encryption, key) = WalletEncryption::new(password)?;
+
+    encrypt_wallet_secrets(&mut wallet, &key)?;
+
+    update_encrypted_wallet(pool, &wallet, &encryption).await?;
+
+    Ok(())
+}
+
+/// Returns the key of an unlocked wallet, forgetting the keys that have expired
+pub fn get_wallet_key(wallet_name: &str) -> Option<WalletKey> {
+
+    let mut wallet_keys = WALLET_KEYS.lock().unwrap();
+
+    let now = chrono::Utc::now().timestamp();
+    wallet_keys.retain(|_, (_, expires_at)| *expires_at > now);
+
+    wallet_keys.get(wallet_name).map(|(key, _)| *key)
+}
+
+/// Keeps the wallet key in memory for `timeout` seconds, so that the secrets can be decrypted for signing.
+/// The key is lost when the process exits.
+pub async fn unlock_wallet(pool: &Pool<Sqlite>, wallet_name: &str, password: &str, timeout: u64) -> Result<()> {
+
+    let encryption = get_wallet_encryption(pool, wallet_name).await?
+        .ok_or(anyhow!("Wallet {} is not encrypted", wallet_name))?;
+
+    let key = encryption.unlock(password)?;
+
+    let expires_at = chrono::Utc::now().timestamp() + timeout as i64;
+
+    set_wallet_key(wallet_name, key, expires_at);
+
+    Ok(())
+}
+
+/// Keeps a key already checked against the wallet, e.g. by the wallet agent, until `expires_at`
+pub fn set_wallet_key(wallet_name: &str, key: WalletKey, expires_at: i64) {
+    WALLET_KEYS.lock().unwrap().insert(wallet_name.to_string(), (key, expires_at));
+}
+
+pub fn lock_wallet(wallet_name: &str) {
+    WALLET_KEYS.lock().unwrap().remove(wallet_name);
+}
+
+/// Re-encrypts the wallet secrets with a key derived from `new_password` and locks the wallet
+pub async fn change_wallet_password(pool: &Pool<Sqlite>, wallet_name: &str, old_password: &str, new_password: &str) -> Result<()> {
+
+    let encryption = get_wallet_encryption(pool, wallet_name).await?
+        .ok_or(anyhow!("Wallet {} is not encrypted", wallet_name))?;
+
+    let old_key = encryption.unlock(old_password)?;
+
+    let mut wallet = get_wallet(pool, wallet_name).await?;
+
+    decrypt_wallet_secrets(&mut wallet, &old_key)?;
+
+    let (encryption, new_key) = WalletEncryption::new(new_password)?;
+
+    encrypt_wallet_secrets(&mut wallet, &new_key)?;
+
+    update_encrypted_wallet(pool, &wallet, &encryption).await?;
+
+    lock_wallet(wallet_name);
+
+    Ok(())
+}
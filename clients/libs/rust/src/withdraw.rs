@@ -1,18 +1,32 @@
-use crate::{client_config::ClientConfig, sqlite_manager::{get_backup_txs, get_wallet, update_wallet}, transaction::new_transaction, utils::info_config};
+use crate::{client_config::ClientConfig, sqlite_manager::{get_backup_txs, get_unlocked_wallet, update_wallet}, transaction::new_withdrawal_transaction, utils::info_config};
 use anyhow::{anyhow, Result};
 use chrono::Utc;
 use electrum_client::ElectrumApi;
-use mercurylib::wallet::{Activity, CoinStatus};
+use mercurylib::{transaction::WithdrawalOutput, wallet::{Activity, CoinStatus}};
 
 
 pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain_id: &str, to_address: &str, fee_rate: Option<f64>, duplicated_index: Option<u32>) -> Result<()>{
 
-    let mut wallet: mercurylib::wallet::Wallet = get_wallet(&client_config.pool, &wallet_name).await?;
+    let outputs = vec![WithdrawalOutput { address: to_address.to_string(), amount: None }];
 
-    let is_address_valid = mercurylib::validate_address(to_address, &wallet.network)?;
+    execute_with_outputs(client_config, wallet_name, statechain_id, &outputs, fee_rate, duplicated_index).await
+}
 
-    if !is_address_valid {
-        return Err(anyhow!("Invalid address"));
+/// Withdraws the coin to several outputs. The output without amount, if any, receives the change.
+pub async fn execute_with_outputs(client_config: &ClientConfig, wallet_name: &str, statechain_id: &str, outputs: &Vec<WithdrawalOutput>, fee_rate: Option<f64>, duplicated_index: Option<u32>) -> Result<()>{
+
+    let mut wallet: mercurylib::wallet::Wallet = get_unlocked_wallet(&client_config.pool, &wallet_name).await?;
+
+    if outputs.is_empty() {
+        return Err(anyhow!("No withdrawal outputs"));
+    }
+
+    for output in outputs.iter() {
+        let is_address_valid = mercurylib::validate_address(&output.address, &wallet.network)?;
+
+        if !is_address_valid {
+            return Err(anyhow!("Invalid address {}", output.address));
+        }
     }
 
     let backup_txs = get_backup_txs(&client_config.pool, &wallet.name, &statechain_id).await?;
@@ -21,9 +35,7 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain
         return Err(anyhow!("No backup transaction associated with this statechain ID were found"));
     }
 
-    let qt_backup_tx = backup_txs.len() as u32;
-
-    // let new_tx_n = qt_backup_tx + 1;
+    // let new_tx_n = backup_txs.len() as u32 + 1;
 
     // If the user sends to himself, he will have two coins with same statechain_id
     // In this case, we need to find the one with the lowest locktime
@@ -71,17 +83,12 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain
         },
     };
 
-    let signed_tx = new_transaction(
+    let signed_tx = new_withdrawal_transaction(
         client_config, 
         coin,
-        &to_address,
-        qt_backup_tx,
-        true,
-        None,
+        outputs,
         &wallet.network,
         fee_rate_sats_per_byte,
-        server_info.initlock,
-        server_info.interval
     ).await?;
 
     if coin.public_nonce.is_none() {
@@ -114,7 +121,9 @@ pub async fn execute(client_config: &ClientConfig, wallet_name: &str, statechain
     let txid = client_config.electrum_client.transaction_broadcast_raw(&tx_bytes)?;
 
     coin.tx_withdraw = Some(txid.to_string());
-    coin.withdrawal_address = Some(to_address.to_string());
+    // The change output is the wallet's own address. Without one, the first payee is recorded.
+    let withdrawal_output = outputs.iter().find(|output| output.amount.is_none()).unwrap_or(&outputs[0]);
+    coin.withdrawal_address = Some(withdrawal_output.address.clone());
     coin.status = CoinStatus::WITHDRAWING;
 
     let date = Utc::now(); // This will get the current date and time in UTC
@@ -50,8 +50,9 @@ const init = async (clientConfig, wallet, token_id) => {
         let err_msg = "";
         if (error.response) {
           // Server responded with error
+          const apiError = await utils.decodeApiError(error);
           console.log('Error body:', error.response.data);
-          err_msg = error.response.data;
+          err_msg = `${apiError.code} - ${apiError.message}`;
         } else if (error.request) {
           // Request made but no response received
           console.log('No response received:', error.request);
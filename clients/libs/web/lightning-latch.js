@@ -2,6 +2,7 @@ import storageManager from './storage_manager.js';
 import { v4 as uuidv4 } from 'uuid';
 import axios from 'axios';
 import CoinStatus from './coin_enum.js';
+import utils from './utils.js';
 
 const createPreImage  = async (clientConfig, walletName, statechainId) => {
 
@@ -123,10 +124,12 @@ const getPaymentHash = async (clientConfig, batchId) => {
         return response?.data?.hash;
 
     } catch (error) {
-        if (error.response.status == 401) {
+        const apiError = await utils.decodeApiError(error);
+
+        if (apiError.code == 'PreimageNotFound') {
             return null;
         } else {
-            throw new Error(`Failed to retrieve payment hash: ${JSON.stringify(error.response.data)}`);
+            throw new Error(`Failed to retrieve payment hash: ${apiError.code} - ${apiError.message}`);
         }
     }
 
@@ -165,18 +165,16 @@ const sendTransferReceiverRequestPayload = async (clientConfig, transferReceiver
     }
     catch (error) {
 
-        if (error.response.status == 400) {
-            if (error.response.data.code == 'ExpiredBatchTimeError') {
-                throw new Error(`Failed to update transfer message ${error.response.data.message}`);
-            } else  if (error.response.data.code == 'StatecoinBatchLockedError') {
-                return {
-                    isBatchLocked: true,
-                    serverPubkey: null,
-                };
-            }
-        } else {
-            throw new Error(`Failed to update transfer message ${JSON.stringify(error.response.data)}`);
+        const apiError = await utils.decodeApiError(error);
+
+        if (apiError.code == 'StatecoinBatchLockedError') {
+            return {
+                isBatchLocked: true,
+                serverPubkey: null,
+            };
         }
+
+        throw new Error(`Failed to update transfer message: ${apiError.code} - ${apiError.message}`);
     }
     
 }
@@ -185,7 +183,12 @@ const validateEncryptedMessage = async (clientConfig, coin, encMessage, network,
     let clientAuthKey = coin.auth_privkey;
     let newUserPubkey = coin.user_pubkey;
 
-    let transferMsg = mercury_wasm.decryptTransferMsg(encMessage, clientAuthKey);
+    let transferMsg;
+    try {
+        transferMsg = mercury_wasm.decryptTransferMsg(encMessage, clientAuthKey);
+    } catch (error) {
+        throw new Error(`Failed to decrypt transfer message: ${error.message}`);
+    }
 
     let groupedBackupTransactions = splitBackupTransactions(transferMsg.backup_transactions);
 
@@ -261,7 +264,12 @@ const processEncryptedMessage = async (clientConfig, coin, encMessage, network,
 
     let clientAuthKey = coin.auth_privkey;
 
-    let transferMsg = mercury_wasm.decryptTransferMsg(encMessage, clientAuthKey);
+    let transferMsg;
+    try {
+        transferMsg = mercury_wasm.decryptTransferMsg(encMessage, clientAuthKey);
+    } catch (error) {
+        throw new Error(`Failed to decrypt transfer message: ${error.message}`);
+    }
 
     let groupedBackupTransactions = splitBackupTransactions(transferMsg.backup_transactions);
 
@@ -346,7 +354,7 @@ const processEncryptedMessage = async (clientConfig, coin, encMessage, network,
             
             let txOutpoint = mercury_wasm.getPreviousOutpoint(firstBackupTx);
 
-            let amount = mercury_wasm.getAmountFromTx0(tx0Hex, txOutpoint);
+            let amount = Number(mercury_wasm.getAmountFromTx0(tx0Hex, txOutpoint));
 
             transferReceiveResult.duplicatedCoins.push({
                 txid: txOutpoint.txid,
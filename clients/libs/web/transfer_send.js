@@ -282,6 +282,12 @@ const execute = async (clientConfig, walletName, statechainId, toAddress, forceS
     const statechain_id = coin.statechain_id;
     const signed_statechain_id = coin.signed_statechain_id;
 
+    const addressError = mercury_wasm.getAddressValidationError(toAddress, wallet.network);
+
+    if (addressError) {
+        throw new Error(addressError.message);
+    }
+
     const decodedTransferAddress = mercury_wasm.decodeTransferAddress(toAddress);
     const new_auth_pubkey = decodedTransferAddress.auth_pubkey;
 
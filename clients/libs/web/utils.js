@@ -62,7 +62,7 @@ const getStatechainInfo = async (clientConfig, statechainId) => {
         let response = await axios.get(statechainEntityUrl + '/' + path);
         return response.data;
     } catch (error) {
-        if (error.response.status == 404) {
+        if ((await decodeApiError(error)).code == 'StatechainNotFound') {
             return null;
         } else {
             throw error;
@@ -80,4 +80,18 @@ async function getBlockheight(backupTx) {
     return mercury_wasm.getBlockheight(backupTx);
 }
 
-export default { infoConfig, createActivity, completeWithdraw, getStatechainInfo, getPreviousOutpoint, getBlockheight };
\ No newline at end of file
+// Typed error of a failed request to the server, as { code, message }, where the code is an ApiError name.
+// Requests that got no response, e.g. when the server is down, are rethrown.
+async function decodeApiError(error) {
+
+    if (!error.response) {
+        throw error;
+    }
+
+    const body = typeof error.response.data === 'string' ? error.response.data : JSON.stringify(error.response.data);
+
+    await initWasm(wasmUrl);
+    return mercury_wasm.decodeApiError(error.response.status, body);
+}
+
+export default { infoConfig, createActivity, completeWithdraw, getStatechainInfo, getPreviousOutpoint, getBlockheight, decodeApiError };
\ No newline at end of file
@@ -43,6 +43,8 @@ const createWallet = async (clientConfig, name) => {
     let wallet = {
         name,
         mnemonic,
+        derivation_scheme: "Bip44",
+        account: 0,
         version: "0.1.0",
         state_entity_endpoint: clientConfig.statechainEntity,
         electrum_endpoint: clientConfig.esploraServer,
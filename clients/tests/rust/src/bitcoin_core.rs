@@ -40,7 +40,7 @@ pub fn execute_bitcoin_command(bitcoin_command: &str) -> Result<String> {
     }
 }
 
-pub fn sendtoaddress(amount_in_sats: u32, address: &str) -> Result<String> {
+pub fn sendtoaddress(amount_in_sats: u64, address: &str) -> Result<String> {
 
     let amount = amount_in_sats as f64 / 100_000_000.0;
 
@@ -4,7 +4,7 @@ use electrum_client::{bitcoin::Address, ElectrumApi, ListUnspentRes};
 use mercuryrustlib::client_config::ClientConfig;
 use anyhow::{Result, Ok};
 
-pub async fn check_address(client_config: &ClientConfig, address: &str, amount: u32) -> Result<bool> {
+pub async fn check_address(client_config: &ClientConfig, address: &str, amount: u64) -> Result<bool> {
 
     let mut utxo: Option<ListUnspentRes> = None;
 
@@ -13,7 +13,7 @@ pub async fn check_address(client_config: &ClientConfig, address: &str, amount:
     let utxo_list =  client_config.electrum_client.script_list_unspent(&address.script_pubkey())?;
 
     for unspent in utxo_list {
-        if unspent.value == amount as u64 {
+        if unspent.value == amount {
             utxo = Some(unspent);
             break;
         }
@@ -5,7 +5,7 @@ use mercuryrustlib::{client_config::ClientConfig, BackupTx, CoinStatus, Wallet};
 
 use crate::{bitcoin_core, electrs};
 
-async fn deposit(amount_in_sats: u32, client_config: &ClientConfig, deposit_address: &str) -> Result<()> {
+async fn deposit(amount_in_sats: u64, client_config: &ClientConfig, deposit_address: &str) -> Result<()> {
 
     let _ = bitcoin_core::sendtoaddress(amount_in_sats, &deposit_address)?;
 
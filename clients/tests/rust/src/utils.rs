@@ -14,7 +14,7 @@ pub async fn handle_token_response(client_config: &ClientConfig,  token_response
         let remaining_blocks = token_response.confirmation_target;
         let deposit_address = token_response.deposit_address.clone().unwrap();
 
-        let amount = token_response.fee as u32;
+        let amount = token_response.fee;
 
         let _ = bitcoin_core::sendtoaddress(amount, &deposit_address)?;
 
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(sc_address) = std::str::from_utf8(data) {
+        let _ = mercurylib::decode_statechain_address_data(sc_address);
+        let _ = mercurylib::validate_address(sc_address, "regtest", 0);
+    }
+});
@@ -0,0 +1,13 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+// Any valid WIF works here: the target exercises the hex decoding, the ECIES decryption and the parsing.
+const PRIVATE_KEY_WIF: &str = "cMcfH8sRgBgDMfpBNG6H3haaxLkaYXgqMRef8Nev6tWyBSNr6c3n";
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(encrypted_message) = std::str::from_utf8(data) {
+        let _ = mercurylib::transfer::receiver::decrypt_transfer_msg(encrypted_message, PRIVATE_KEY_WIF);
+    }
+    let _ = mercurylib::transfer::receiver::decrypt_transfer_msg(&hex::encode(data), PRIVATE_KEY_WIF);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mercurylib::transfer::SerializedBackupTransaction;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(serialized_backup_tx) = serde_json::from_slice::<SerializedBackupTransaction>(data) {
+        let _ = serialized_backup_tx.deserialize();
+    }
+});
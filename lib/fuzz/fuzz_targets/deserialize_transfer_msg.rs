@@ -0,0 +1,15 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mercurylib::transfer::receiver::{deserialize_transfer_msg, get_tx0_outpoint, verify_transfer_signature};
+
+fuzz_target!(|data: &[u8]| {
+    let Ok(transfer_msg) = deserialize_transfer_msg(data) else {
+        return;
+    };
+
+    // Everything the receiver does with the message before talking to the server
+    if let Ok(tx0_outpoint) = get_tx0_outpoint(&transfer_msg.backup_transactions) {
+        let _ = verify_transfer_signature(&transfer_msg.user_public_key, &tx0_outpoint, &transfer_msg);
+    }
+});
@@ -0,0 +1,24 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use mercurylib::transfer::receiver::{
+    reconstruct_transaction, verify_if_locktime_is_reasonable_tx_version_and_output_size,
+    verify_transaction_sequence, verify_transaction_signature,
+};
+
+fuzz_target!(|data: &[u8]| {
+    // The first byte splits the input into the backup transaction and the funding transaction
+    let Some((split, data)) = data.split_first() else {
+        return;
+    };
+    let split = (*split as usize).min(data.len());
+    let (tx_n, tx0) = data.split_at(split);
+
+    let tx_n_hex = hex::encode(tx_n);
+    let tx0_hex = hex::encode(tx0);
+
+    let _ = verify_transaction_signature(&tx_n_hex, &tx0_hex, 0.1, 1.0);
+    let _ = verify_transaction_sequence(&tx_n_hex);
+    let _ = verify_if_locktime_is_reasonable_tx_version_and_output_size(&tx_n_hex, 1000, 10000);
+    let _ = reconstruct_transaction(&tx_n_hex);
+});
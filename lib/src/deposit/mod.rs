@@ -11,6 +11,7 @@ pub struct TokenID {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct TokenResponse {
     pub token_id: String,
     pub payment_method: String,
@@ -21,6 +22,7 @@ pub struct TokenResponse {
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct DepositMsg1 {
     pub auth_key: String,
     pub token_id: String,
@@ -29,6 +31,7 @@ pub struct DepositMsg1 {
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct DepositMsg1Response {
     pub server_pubkey: String,
     pub statechain_id: String,
@@ -1,59 +1,147 @@
-/*
-The simplified struct below cannot be used because the errors from other libs are not compatible with uniffi.
-TODO: Look for a way to make the errors compatible with uniffi.
+use bitcoin::{bip32, sighash::SighashTypeParseError};
+use secp256k1_zkp::{musig::{MusigNonceGenError, MusigSignError, ParseError}, scalar::OutOfRangeError, UpstreamError};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, thiserror::Error)]
-#[cfg_attr(feature = "bindings", derive(uniffi::Error))]
-pub enum MercuryError {
-    #[error("BIP39 Error: {0}")]
-    Bip39Error(#[from] bip39::Error),
+/// Additional information about the cause of an error.
+/// All fields are optional because not every error has all of them.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct ErrorContext {
+    /// The offending value (fee rate, locktime, address, network...)
+    pub value: Option<String>,
+    /// The expected value or range
+    pub expected: Option<String>,
+    /// The backup transaction that caused the error
+    pub tx_n: Option<u32>,
+    pub statechain_id: Option<String>,
+}
 
-    #[error("BIP32 Error: {0}")]
-    Bip32Error(#[from] bip32::Error),
+impl ErrorContext {
+    pub fn new() -> Self {
+        ErrorContext::default()
+    }
 
-    #[error("Invalid bitcoin network: `{0}`")]
-    NetworkConversionError(String),
+    pub fn value<T: ToString>(mut self, value: T) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
 
-    #[error("Secp256k1UpstreamError Error: {0}")]
-    Secp256k1UpstreamError(#[from] UpstreamError),
+    pub fn expected<T: ToString>(mut self, expected: T) -> Self {
+        self.expected = Some(expected.to_string());
+        self
+    }
 
-    #[error("KeyError Error: {0}")]
-    KeyError(#[from] bitcoin::key::Error),
+    pub fn tx_n(mut self, tx_n: u32) -> Self {
+        self.tx_n = Some(tx_n);
+        self
+    }
 
-    #[error("Bech32Error Error: {0}")]
-    Bech32Error(#[from] bech32::Error),
+    pub fn statechain_id(mut self, statechain_id: &str) -> Self {
+        self.statechain_id = Some(statechain_id.to_string());
+        self
+    }
 }
- */
 
-use bitcoin::{bip32, sighash::SighashTypeParseError};
-use secp256k1_zkp::{musig::{MusigNonceGenError, MusigSignError, ParseError}, scalar::OutOfRangeError, UpstreamError};
+impl core::fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut fields = Vec::<String>::new();
+
+        if let Some(value) = &self.value {
+            fields.push(format!("value: {}", value));
+        }
+        if let Some(expected) = &self.expected {
+            fields.push(format!("expected: {}", expected));
+        }
+        if let Some(tx_n) = &self.tx_n {
+            fields.push(format!("tx_n: {}", tx_n));
+        }
+        if let Some(statechain_id) = &self.statechain_id {
+            fields.push(format!("statechain_id: {}", statechain_id));
+        }
 
-// TODO: UniFFI apparently does not support adding fields to the error enum variants.
-//       For example, NetworkConversionError(String) is not supported.
-//       This makes it impossible to detail the error message. Look into this issue.
+        f.write_str(&fields.join(", "))
+    }
+}
+
+/// Flat representation of `MercuryError`, for bindings that cannot
+/// handle enum variants with fields (wasm, logs, JSON APIs)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct MercuryErrorDetails {
+    /// The name of the `MercuryError` variant, e.g. `FeeTooLow`
+    pub code: String,
+    pub message: String,
+    pub value: Option<String>,
+    pub expected: Option<String>,
+    pub tx_n: Option<u32>,
+    pub statechain_id: Option<String>,
+}
 
-#[derive(Debug, thiserror::Error)]
+/// Pattern of the variants that carry an `ErrorContext`, binding it to `$context`
+macro_rules! contextual_variants {
+    ($context:ident) => {
+        MercuryError::NetworkConversionError { context: $context } |
+        MercuryError::LocktimeNotBlockHeightError { context: $context } |
+        MercuryError::LocktimeTooLow { context: $context } |
+        MercuryError::LocktimeTooHigh { context: $context } |
+        MercuryError::TransactionReconstructionError { context: $context } |
+        MercuryError::TransactionVersionError { context: $context } |
+        MercuryError::TransactionSequenceDifferentThanZeroError { context: $context } |
+        MercuryError::InvalidStatechainAddressError { context: $context } |
+        MercuryError::InvalidBitcoinAddressError { context: $context } |
+        MercuryError::StatechainAddressMismatchNetworkError { context: $context } |
+        MercuryError::BitcoinAddressMismatchNetworkError { context: $context } |
+        MercuryError::BackupTransactionDoesNotPayUser { context: $context } |
+        MercuryError::FeeTooHigh { context: $context } |
+        MercuryError::FeeTooLow { context: $context } |
+        MercuryError::NoBackupTransactionFound { context: $context } |
+        MercuryError::Tx1HasMoreThanOneInput { context: $context } |
+        MercuryError::TxHasMoreThanOneOutput { context: $context } |
+        MercuryError::InvalidSignature { context: $context } |
+        MercuryError::IncorrectChallenge { context: $context } |
+        MercuryError::CoinNotFound { context: $context } |
+        MercuryError::SignatureSchemeValidationError { context: $context } |
+        MercuryError::CoinAmountNotSet { context: $context } |
+        MercuryError::InsufficientFundsForFeeBump { context: $context } |
+        MercuryError::UnknownAnchorOutputType { context: $context } |
+        MercuryError::InvalidAnchorOutput { context: $context } |
+        MercuryError::InvalidOutputIndex { context: $context } |
+        MercuryError::InvalidWatchtowerPackage { context: $context } |
+        MercuryError::InvalidWithdrawalOutputs { context: $context } |
+        MercuryError::AmountBelowDust { context: $context } |
+        MercuryError::InvalidDescriptor { context: $context } |
+        MercuryError::UnknownDerivationScheme { context: $context } |
+        MercuryError::InvalidPsbt { context: $context } |
+        MercuryError::TransferMsgTooLarge { context: $context } |
+        MercuryError::UnsupportedTransferMsgVersion { context: $context } |
+        MercuryError::InvalidOwnershipProof { context: $context } |
+        MercuryError::KeyRotationNotVerified { context: $context } |
+        MercuryError::StatechainAddressExpired { context: $context }
+    };
+}
+
+#[derive(Debug, Clone, thiserror::Error)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Error))]
 pub enum MercuryError {
     Bip39Error,
     Bip32Error,
-    NetworkConversionError,
+    NetworkConversionError { context: ErrorContext },
     Secp256k1UpstreamError,
     KeyError,
     Bech32Error,
     HexError,
-    LocktimeNotBlockHeightError,
-    LocktimeTooLow,
-    LocktimeTooHigh,
-    TransactionReconstructionError,
-    TransactionVersionError,
-    TransactionSequenceDifferentThanZeroError,
+    LocktimeNotBlockHeightError { context: ErrorContext },
+    LocktimeTooLow { context: ErrorContext },
+    LocktimeTooHigh { context: ErrorContext },
+    TransactionReconstructionError { context: ErrorContext },
+    TransactionVersionError { context: ErrorContext },
+    TransactionSequenceDifferentThanZeroError { context: ErrorContext },
     BitcoinConsensusEncodeError,
     MusigNonceGenError,
-    InvalidStatechainAddressError,
-    InvalidBitcoinAddressError,
-    StatechainAddressMismatchNetworkError,
-    BitcoinAddressMismatchNetworkError,
+    InvalidStatechainAddressError { context: ErrorContext },
+    InvalidBitcoinAddressError { context: ErrorContext },
+    StatechainAddressMismatchNetworkError { context: ErrorContext },
+    BitcoinAddressMismatchNetworkError { context: ErrorContext },
     BitcoinAddressError,
     BitcoinAbsoluteError,
     BitcoinHashHexError,
@@ -65,33 +153,183 @@ pub enum MercuryError {
     SchnorrSignatureValidationError,
     MoreThanOneInputError,
     UnkownNetwork,
-    BackupTransactionDoesNotPayUser,
-    FeeTooHigh,
-    FeeTooLow,
+    BackupTransactionDoesNotPayUser { context: ErrorContext },
+    FeeTooHigh { context: ErrorContext },
+    FeeTooLow { context: ErrorContext },
     OutOfRangeError,
     SerdeJsonError,
     SecpError,
-    NoBackupTransactionFound,
-    Tx1HasMoreThanOneInput,
-    TxHasMoreThanOneOutput,
+    NoBackupTransactionFound { context: ErrorContext },
+    Tx1HasMoreThanOneInput { context: ErrorContext },
+    TxHasMoreThanOneOutput { context: ErrorContext },
     EmptyInput,
-    InvalidSignature,
+    InvalidSignature { context: ErrorContext },
     EmptyWitness,
     EmptyWitnessData,
-    IncorrectChallenge,
+    IncorrectChallenge { context: ErrorContext },
     InvalidT1,
     IncorrectAggregatedPublicKey,
     T1MustBeExactly32BytesError,
     NoX1Pub,
     NoAggregatedPubkeyError,
-    CoinNotFound,
-    SignatureSchemeValidationError,
+    CoinNotFound { context: ErrorContext },
+    SignatureSchemeValidationError { context: ErrorContext },
     NoPreviousLockTimeError,
+    CoinAmountNotSet { context: ErrorContext },
+    InsufficientFundsForFeeBump { context: ErrorContext },
+    UnknownAnchorOutputType { context: ErrorContext },
+    InvalidAnchorOutput { context: ErrorContext },
+    EciesDecryptionError,
+    InvalidUtf8Error,
+    InvalidTransferMsg,
+    InvalidOutputIndex { context: ErrorContext },
+    InvalidScriptPubkey,
+    EmptyOutput,
+    InvalidWatchtowerPackage { context: ErrorContext },
+    InvalidWithdrawalOutputs { context: ErrorContext },
+    AmountBelowDust { context: ErrorContext },
+    InvalidDescriptor { context: ErrorContext },
+    UnknownDerivationScheme { context: ErrorContext },
+    InvalidPsbt { context: ErrorContext },
+    TransferMsgTooLarge { context: ErrorContext },
+    UnsupportedTransferMsgVersion { context: ErrorContext },
+    InvalidOwnershipProof { context: ErrorContext },
+    KeyRotationNotVerified { context: ErrorContext },
+    StatechainAddressExpired { context: ErrorContext },
+}
+
+impl MercuryError {
+
+    pub fn context(&self) -> Option<&ErrorContext> {
+        match self {
+            contextual_variants!(context) => Some(context),
+            _ => None,
+        }
+    }
+
+    fn context_mut(&mut self) -> Option<&mut ErrorContext> {
+        match self {
+            contextual_variants!(context) => Some(context),
+            _ => None,
+        }
+    }
+
+    /// Adds the backup transaction number to the error context, if the error has one
+    pub fn with_tx_n(mut self, tx_n: u32) -> Self {
+        if let Some(context) = self.context_mut() {
+            context.tx_n = Some(tx_n);
+        }
+        self
+    }
+
+    /// Adds the statechain id to the error context, if the error has one
+    pub fn with_statechain_id(mut self, statechain_id: &str) -> Self {
+        if let Some(context) = self.context_mut() {
+            context.statechain_id = Some(statechain_id.to_string());
+        }
+        self
+    }
+
+    /// The name of the variant, stable across versions
+    pub fn code(&self) -> String {
+        let code = match self {
+            MercuryError::Bip39Error => "Bip39Error",
+            MercuryError::Bip32Error => "Bip32Error",
+            MercuryError::NetworkConversionError { .. } => "NetworkConversionError",
+            MercuryError::Secp256k1UpstreamError => "Secp256k1UpstreamError",
+            MercuryError::KeyError => "KeyError",
+            MercuryError::Bech32Error => "Bech32Error",
+            MercuryError::HexError => "HexError",
+            MercuryError::LocktimeNotBlockHeightError { .. } => "LocktimeNotBlockHeightError",
+            MercuryError::LocktimeTooLow { .. } => "LocktimeTooLow",
+            MercuryError::LocktimeTooHigh { .. } => "LocktimeTooHigh",
+            MercuryError::TransactionReconstructionError { .. } => "TransactionReconstructionError",
+            MercuryError::TransactionVersionError { .. } => "TransactionVersionError",
+            MercuryError::TransactionSequenceDifferentThanZeroError { .. } => "TransactionSequenceDifferentThanZeroError",
+            MercuryError::BitcoinConsensusEncodeError => "BitcoinConsensusEncodeError",
+            MercuryError::MusigNonceGenError => "MusigNonceGenError",
+            MercuryError::InvalidStatechainAddressError { .. } => "InvalidStatechainAddressError",
+            MercuryError::InvalidBitcoinAddressError { .. } => "InvalidBitcoinAddressError",
+            MercuryError::StatechainAddressMismatchNetworkError { .. } => "StatechainAddressMismatchNetworkError",
+            MercuryError::BitcoinAddressMismatchNetworkError { .. } => "BitcoinAddressMismatchNetworkError",
+            MercuryError::BitcoinAddressError => "BitcoinAddressError",
+            MercuryError::BitcoinAbsoluteError => "BitcoinAbsoluteError",
+            MercuryError::BitcoinHashHexError => "BitcoinHashHexError",
+            MercuryError::BitcoinPsbtError => "BitcoinPsbtError",
+            MercuryError::SighashTypeParseError => "SighashTypeParseError",
+            MercuryError::BitcoinSighashError => "BitcoinSighashError",
+            MercuryError::ParseError => "ParseError",
+            MercuryError::MusigSignError => "MusigSignError",
+            MercuryError::SchnorrSignatureValidationError => "SchnorrSignatureValidationError",
+            MercuryError::MoreThanOneInputError => "MoreThanOneInputError",
+            MercuryError::UnkownNetwork => "UnkownNetwork",
+            MercuryError::BackupTransactionDoesNotPayUser { .. } => "BackupTransactionDoesNotPayUser",
+            MercuryError::FeeTooHigh { .. } => "FeeTooHigh",
+            MercuryError::FeeTooLow { .. } => "FeeTooLow",
+            MercuryError::OutOfRangeError => "OutOfRangeError",
+            MercuryError::SerdeJsonError => "SerdeJsonError",
+            MercuryError::SecpError => "SecpError",
+            MercuryError::NoBackupTransactionFound { .. } => "NoBackupTransactionFound",
+            MercuryError::Tx1HasMoreThanOneInput { .. } => "Tx1HasMoreThanOneInput",
+            MercuryError::TxHasMoreThanOneOutput { .. } => "TxHasMoreThanOneOutput",
+            MercuryError::EmptyInput => "EmptyInput",
+            MercuryError::InvalidSignature { .. } => "InvalidSignature",
+            MercuryError::EmptyWitness => "EmptyWitness",
+            MercuryError::EmptyWitnessData => "EmptyWitnessData",
+            MercuryError::IncorrectChallenge { .. } => "IncorrectChallenge",
+            MercuryError::InvalidT1 => "InvalidT1",
+            MercuryError::IncorrectAggregatedPublicKey => "IncorrectAggregatedPublicKey",
+            MercuryError::T1MustBeExactly32BytesError => "T1MustBeExactly32BytesError",
+            MercuryError::NoX1Pub => "NoX1Pub",
+            MercuryError::NoAggregatedPubkeyError => "NoAggregatedPubkeyError",
+            MercuryError::CoinNotFound { .. } => "CoinNotFound",
+            MercuryError::SignatureSchemeValidationError { .. } => "SignatureSchemeValidationError",
+            MercuryError::NoPreviousLockTimeError => "NoPreviousLockTimeError",
+            MercuryError::CoinAmountNotSet { .. } => "CoinAmountNotSet",
+            MercuryError::InsufficientFundsForFeeBump { .. } => "InsufficientFundsForFeeBump",
+            MercuryError::UnknownAnchorOutputType { .. } => "UnknownAnchorOutputType",
+            MercuryError::InvalidAnchorOutput { .. } => "InvalidAnchorOutput",
+            MercuryError::EciesDecryptionError => "EciesDecryptionError",
+            MercuryError::InvalidUtf8Error => "InvalidUtf8Error",
+            MercuryError::InvalidTransferMsg => "InvalidTransferMsg",
+            MercuryError::InvalidOutputIndex { .. } => "InvalidOutputIndex",
+            MercuryError::InvalidScriptPubkey => "InvalidScriptPubkey",
+            MercuryError::EmptyOutput => "EmptyOutput",
+            MercuryError::InvalidWatchtowerPackage { .. } => "InvalidWatchtowerPackage",
+            MercuryError::InvalidWithdrawalOutputs { .. } => "InvalidWithdrawalOutputs",
+            MercuryError::AmountBelowDust { .. } => "AmountBelowDust",
+            MercuryError::InvalidDescriptor { .. } => "InvalidDescriptor",
+            MercuryError::UnknownDerivationScheme { .. } => "UnknownDerivationScheme",
+            MercuryError::InvalidPsbt { .. } => "InvalidPsbt",
+            MercuryError::TransferMsgTooLarge { .. } => "TransferMsgTooLarge",
+            MercuryError::UnsupportedTransferMsgVersion { .. } => "UnsupportedTransferMsgVersion",
+            MercuryError::InvalidOwnershipProof { .. } => "InvalidOwnershipProof",
+            MercuryError::KeyRotationNotVerified { .. } => "KeyRotationNotVerified",
+            MercuryError::StatechainAddressExpired { .. } => "StatechainAddressExpired",
+        };
+        code.to_string()
+    }
+
+    pub fn details(&self) -> MercuryErrorDetails {
+        let context = self.context().cloned().unwrap_or_default();
+
+        MercuryErrorDetails {
+            code: self.code(),
+            message: self.to_string(),
+            value: context.value,
+            expected: context.expected,
+            tx_n: context.tx_n,
+            statechain_id: context.statechain_id,
+        }
+    }
 }
 
 impl core::fmt::Display for MercuryError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("{:?}", self))
+        match self.context() {
+            Some(context) if *context != ErrorContext::default() => f.write_fmt(format_args!("{} ({})", self.code(), context)),
+            _ => f.write_str(&self.code()),
+        }
     }
 }
 
@@ -202,3 +440,127 @@ impl From<serde_json::Error> for MercuryError {
         MercuryError::SerdeJsonError
     }
 }
+
+/// Error returned by the server API.
+/// The variant name is the stable code sent in `ApiErrorResponsePayload`.
+// Variants are documented with plain comments, so that the OpenAPI document describes the codes as a single string enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Enum))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
+pub enum ApiError {
+    // The request payload is malformed
+    BadRequest,
+    InvalidAuthPubkey,
+    // The batch of the statecoin, or the batch sent in the request, has expired
+    ExpiredBatchTimeError,
+    TransferMsgTooLarge,
+    // The signed statechain id or token id does not match the authentication key
+    InvalidSignature,
+    // The signed request timestamp is too old or in the future
+    RequestExpired,
+    TokenGenerationNotSupported,
+    // The route does not exist
+    NotFound,
+    StatechainNotFound,
+    TokenNotFound,
+    TransferMsgNotFound,
+    PreimageNotFound,
+    AuthKeyAlreadyAssigned,
+    // A transfer message was already sent to the same receiver
+    TransferMsgAlreadyExists,
+    // The statecoin is part of a batch that has not completed yet
+    StatecoinBatchLockedError,
+    // The server nonce was already used to sign another message
+    ServerNonceAlreadyUsed,
+    TokenSpent,
+    TokenNotConfirmed,
+    // Too many requests for the client IP, the statechain or the auth key. The response has a Retry-After header.
+    RateLimited,
+    Internal,
+    // The enclave or the token server did not answer
+    UpstreamUnavailable,
+}
+
+impl ApiError {
+
+    /// HTTP status of the responses with this error.
+    /// The batch errors keep the 400 used before the codes were introduced.
+    pub fn status_code(&self) -> u16 {
+        match self {
+            ApiError::BadRequest |
+            ApiError::InvalidAuthPubkey |
+            ApiError::ExpiredBatchTimeError |
+            ApiError::StatecoinBatchLockedError => 400,
+            ApiError::InvalidSignature => 401,
+            ApiError::TokenNotConfirmed => 402,
+            ApiError::RequestExpired |
+            ApiError::TokenGenerationNotSupported => 403,
+            ApiError::NotFound |
+            ApiError::StatechainNotFound |
+            ApiError::TokenNotFound |
+            ApiError::TransferMsgNotFound |
+            ApiError::PreimageNotFound => 404,
+            ApiError::AuthKeyAlreadyAssigned |
+            ApiError::TransferMsgAlreadyExists |
+            ApiError::ServerNonceAlreadyUsed => 409,
+            ApiError::TokenSpent => 410,
+            ApiError::TransferMsgTooLarge => 413,
+            ApiError::RateLimited => 429,
+            ApiError::Internal => 500,
+            ApiError::UpstreamUnavailable => 502,
+        }
+    }
+
+    /// Closest code for a response without one, e.g. from an older server
+    pub fn from_status_code(status_code: u16) -> Self {
+        match status_code {
+            401 => ApiError::InvalidSignature,
+            402 => ApiError::TokenNotConfirmed,
+            403 => ApiError::RequestExpired,
+            404 => ApiError::NotFound,
+            410 => ApiError::TokenSpent,
+            413 => ApiError::TransferMsgTooLarge,
+            429 => ApiError::RateLimited,
+            502 | 503 | 504 => ApiError::UpstreamUnavailable,
+            400..=499 => ApiError::BadRequest,
+            _ => ApiError::Internal,
+        }
+    }
+}
+
+/// Body of the server responses for failed requests
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, thiserror::Error)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[error("{code:?}: {message}")]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
+pub struct ApiErrorResponsePayload {
+    pub code: ApiError,
+    pub message: String,
+}
+
+impl ApiErrorResponsePayload {
+    pub fn new<T: ToString>(code: ApiError, message: T) -> Self {
+        ApiErrorResponsePayload { code, message: message.to_string() }
+    }
+}
+
+/// Decodes the body of a failed response.
+/// Bodies without a known code, as sent by older servers, get the code matching the HTTP status.
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn decode_api_error(status_code: u16, body: &str) -> ApiErrorResponsePayload {
+
+    let value = serde_json::from_str::<serde_json::Value>(body).ok();
+
+    let code = value.as_ref()
+        .and_then(|value| value.get("code"))
+        .and_then(|code| serde_json::from_value::<ApiError>(code.clone()).ok())
+        .unwrap_or_else(|| ApiError::from_status_code(status_code));
+
+    let message = match value {
+        Some(serde_json::Value::String(message)) => message,
+        Some(value) => value.get("message").and_then(|message| message.as_str()).unwrap_or(body).to_string(),
+        None => body.to_string(),
+    };
+
+    ApiErrorResponsePayload { code, message }
+}
@@ -4,6 +4,7 @@ pub mod withdraw;
 pub mod wallet;
 pub mod utils;
 pub mod transaction;
+pub mod psbt;
 pub mod unifii_interface;
 pub mod error;
 
@@ -13,7 +14,9 @@ use bech32::{Variant, ToBase32, FromBase32};
 use bip39::Mnemonic;
 use bitcoin::{bip32::{ChildNumber, DerivationPath, ExtendedPrivKey}, secp256k1::{ffi::types::AlignedType, AllPreallocated, PublicKey, Secp256k1, SecretKey}, Address};
 
-use error::MercuryError;
+use error::{ErrorContext, MercuryError};
+use serde::{Deserialize, Serialize};
+use wallet::DerivationScheme;
 
 #[cfg(feature = "bindings")]
 uniffi::setup_scaffolding!();
@@ -41,24 +44,195 @@ pub fn encode_sc_address(user_pubkey: &PublicKey, auth_pubkey: &PublicKey, netwo
     Ok(encoded)
 }
 
-pub fn decode_transfer_address(sc_address: &str) -> core::result::Result<(u8, PublicKey, PublicKey), MercuryError> {
+/// Statechain address format v1:
+/// version (0x01) || network (1 byte) || user_pubkey (33 bytes) || auth_pubkey (33 bytes) || optional fields
+/// Each optional field is encoded as type (1 byte) || length (1 byte) || value.
+const SC_ADDRESS_V0: u8 = 0x00;
+const SC_ADDRESS_V1: u8 = 0x01;
+
+const SC_ADDRESS_V0_LENGTH: usize = 67;
+const SC_ADDRESS_V1_MIN_LENGTH: usize = 68;
+
+const SC_ADDRESS_FIELD_SERVER_HINT: u8 = 0x01;
+const SC_ADDRESS_FIELD_EXPIRY: u8 = 0x02;
+
+/// Decoded statechain address (any version)
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatechainAddress {
+    pub version: u8,
+    /// Only v1 addresses carry the network. v0 addresses only distinguish mainnet and testnet by the HRP.
+    pub network: Option<bitcoin::Network>,
+    pub user_pubkey: PublicKey,
+    pub auth_pubkey: PublicKey,
+    /// Statechain entity endpoint (URL or onion address) the receiver uses
+    pub server_hint: Option<String>,
+    /// Unix timestamp after which the address should no longer be used
+    pub expiry: Option<u64>,
+}
+
+/// Optional fields of a v1 statechain address.
+/// Addresses generated with them also carry the network, so that they cannot be used on another test network.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct ScAddressOptions {
+    /// Statechain entity endpoint (URL or onion address) the sender should use
+    pub server_hint: Option<String>,
+    /// Unix timestamp after which senders reject the address
+    pub expiry: Option<u64>,
+}
+
+fn network_to_byte(network: bitcoin::Network) -> core::result::Result<u8, MercuryError> {
+    match network {
+        bitcoin::Network::Bitcoin => Ok(0x00),
+        bitcoin::Network::Testnet => Ok(0x01),
+        bitcoin::Network::Signet => Ok(0x02),
+        bitcoin::Network::Regtest => Ok(0x03),
+        _ => Err(MercuryError::NetworkConversionError { context: ErrorContext::new().value(network) }),
+    }
+}
+
+fn byte_to_network(network: u8) -> core::result::Result<bitcoin::Network, MercuryError> {
+    match network {
+        0x00 => Ok(bitcoin::Network::Bitcoin),
+        0x01 => Ok(bitcoin::Network::Testnet),
+        0x02 => Ok(bitcoin::Network::Signet),
+        0x03 => Ok(bitcoin::Network::Regtest),
+        _ => Err(MercuryError::InvalidStatechainAddressError { context: ErrorContext::new().value(format!("network byte {}", network)) }),
+    }
+}
+
+pub fn encode_sc_address_v1(user_pubkey: &PublicKey, auth_pubkey: &PublicKey, network: bitcoin::Network, server_hint: Option<&str>, expiry: Option<u64>) -> core::result::Result<String, MercuryError> {
+
+    let hrp = if network == bitcoin::Network::Bitcoin { MAINNET_HRP } else { TESTNET_HRP };
+
+    let mut data = Vec::<u8>::new();
+    data.push(SC_ADDRESS_V1);
+    data.push(network_to_byte(network)?);
+    data.extend_from_slice(&user_pubkey.serialize());
+    data.extend_from_slice(&auth_pubkey.serialize());
+
+    if let Some(server_hint) = server_hint {
+        let server_hint = server_hint.as_bytes();
+        if server_hint.is_empty() || server_hint.len() > u8::MAX as usize {
+            return Err(MercuryError::InvalidStatechainAddressError { context: ErrorContext::new().value(format!("server hint length {}", server_hint.len())).expected("1 to 255 bytes") });
+        }
+        data.push(SC_ADDRESS_FIELD_SERVER_HINT);
+        data.push(server_hint.len() as u8);
+        data.extend_from_slice(server_hint);
+    }
+
+    if let Some(expiry) = expiry {
+        data.push(SC_ADDRESS_FIELD_EXPIRY);
+        data.push(8);
+        data.extend_from_slice(&expiry.to_be_bytes());
+    }
+
+    let encoded = bech32::encode(hrp, data.to_base32(), Variant::Bech32m)?;
+
+    Ok(encoded)
+}
+
+/// Encodes a v1 address if `address_options` is set, or a v0 address otherwise
+pub fn encode_sc_address_with_options(user_pubkey: &PublicKey, auth_pubkey: &PublicKey, network: bitcoin::Network, address_options: Option<&ScAddressOptions>) -> core::result::Result<String, MercuryError> {
+    match address_options {
+        Some(address_options) => encode_sc_address_v1(user_pubkey, auth_pubkey, network, address_options.server_hint.as_deref(), address_options.expiry),
+        None => encode_sc_address(user_pubkey, auth_pubkey, network),
+    }
+}
+
+pub fn decode_statechain_address_data(sc_address: &str) -> core::result::Result<StatechainAddress, MercuryError> {
     let (hrp, data, variant)  = bech32::decode(sc_address)?;
 
     if hrp != MAINNET_HRP && hrp != TESTNET_HRP {
-        return Err(MercuryError::InvalidStatechainAddressError);
+        return Err(MercuryError::InvalidStatechainAddressError { context: ErrorContext::new().value(hrp).expected(format!("{} or {}", MAINNET_HRP, TESTNET_HRP)) });
     }
 
     if variant != Variant::Bech32m {
-        return Err(MercuryError::InvalidBitcoinAddressError);
+        return Err(MercuryError::InvalidBitcoinAddressError { context: ErrorContext::new().value(sc_address).expected("bech32m encoding") });
     }
 
     let decoded_data = Vec::<u8>::from_base32(&data)?;
 
-    let version = decoded_data[0];
-    let user_pubkey = PublicKey::from_slice(&decoded_data[1..34])?;
-    let auth_pubkey = PublicKey::from_slice(&decoded_data[34..67])?;
+    let version = *decoded_data.first().ok_or(MercuryError::InvalidStatechainAddressError { context: ErrorContext::new().value("empty payload") })?;
+
+    match version {
+        SC_ADDRESS_V0 => {
+            if decoded_data.len() != SC_ADDRESS_V0_LENGTH {
+                return Err(MercuryError::InvalidStatechainAddressError { context: ErrorContext::new().value(format!("payload length {}", decoded_data.len())).expected(SC_ADDRESS_V0_LENGTH) });
+            }
+
+            Ok(StatechainAddress {
+                version,
+                network: None,
+                user_pubkey: PublicKey::from_slice(&decoded_data[1..34])?,
+                auth_pubkey: PublicKey::from_slice(&decoded_data[34..67])?,
+                server_hint: None,
+                expiry: None,
+            })
+        },
+        SC_ADDRESS_V1 => {
+            if decoded_data.len() < SC_ADDRESS_V1_MIN_LENGTH {
+                return Err(MercuryError::InvalidStatechainAddressError { context: ErrorContext::new().value(format!("payload length {}", decoded_data.len())).expected(format!(">= {}", SC_ADDRESS_V1_MIN_LENGTH)) });
+            }
+
+            let network = byte_to_network(decoded_data[1])?;
+
+            if (network == bitcoin::Network::Bitcoin) != (hrp == MAINNET_HRP) {
+                return Err(MercuryError::StatechainAddressMismatchNetworkError { context: ErrorContext::new().value(hrp).expected(network) });
+            }
+
+            let user_pubkey = PublicKey::from_slice(&decoded_data[2..35])?;
+            let auth_pubkey = PublicKey::from_slice(&decoded_data[35..68])?;
+
+            let mut server_hint: Option<String> = None;
+            let mut expiry: Option<u64> = None;
+
+            let mut fields = &decoded_data[SC_ADDRESS_V1_MIN_LENGTH..];
+
+            while !fields.is_empty() {
+                if fields.len() < 2 || fields.len() < 2 + fields[1] as usize {
+                    return Err(MercuryError::InvalidStatechainAddressError { context: ErrorContext::new().value("truncated optional field") });
+                }
+
+                let field_type = fields[0];
+                let value = &fields[2..2 + fields[1] as usize];
+
+                match field_type {
+                    SC_ADDRESS_FIELD_SERVER_HINT if server_hint.is_none() && !value.is_empty() => {
+                        let hint = String::from_utf8(value.to_vec())
+                            .map_err(|_| MercuryError::InvalidStatechainAddressError { context: ErrorContext::new().value("server hint is not valid UTF-8") })?;
+                        server_hint = Some(hint);
+                    },
+                    SC_ADDRESS_FIELD_EXPIRY if expiry.is_none() && value.len() == 8 => {
+                        let mut expiry_bytes = [0u8; 8];
+                        expiry_bytes.copy_from_slice(value);
+                        expiry = Some(u64::from_be_bytes(expiry_bytes));
+                    },
+                    _ => {
+                        return Err(MercuryError::InvalidStatechainAddressError { context: ErrorContext::new().value(format!("invalid optional field {} with length {}", field_type, value.len())) });
+                    }
+                }
+
+                fields = &fields[2 + value.len()..];
+            }
+
+            Ok(StatechainAddress {
+                version,
+                network: Some(network),
+                user_pubkey,
+                auth_pubkey,
+                server_hint,
+                expiry,
+            })
+        },
+        _ => Err(MercuryError::InvalidStatechainAddressError { context: ErrorContext::new().value(format!("version {}", version)).expected(format!("{} or {}", SC_ADDRESS_V0, SC_ADDRESS_V1)) }),
+    }
+}
+
+pub fn decode_transfer_address(sc_address: &str) -> core::result::Result<(u8, PublicKey, PublicKey), MercuryError> {
+    let sc_address = decode_statechain_address_data(sc_address)?;
 
-    Ok((version, user_pubkey, auth_pubkey))
+    Ok((sc_address.version, sc_address.user_pubkey, sc_address.auth_pubkey))
 }
 
 fn get_key(secp: &Secp256k1<AllPreallocated<'_>>, root: ExtendedPrivKey, derivation_path: &str, change_index: u32, address_index:u32) -> core::result::Result<SecretKey, MercuryError> {
@@ -75,7 +249,10 @@ fn get_key(secp: &Secp256k1<AllPreallocated<'_>>, root: ExtendedPrivKey, derivat
     Ok(secret_key)
 }
 
-pub fn get_sc_address(mnemonic: &str, index: u32, network: &str) -> core::result::Result<String, MercuryError> {
+/// `passphrase` is the optional BIP39 passphrase ("25th word") of the wallet.
+/// `derivation_scheme` and `account` must be the ones recorded in the wallet.
+/// A v1 address is returned if `address_options` is set.
+pub fn get_sc_address(mnemonic: &str, passphrase: Option<&str>, derivation_scheme: &DerivationScheme, account: u32, index: u32, network: &str, address_options: Option<&ScAddressOptions>) -> core::result::Result<String, MercuryError> {
 
     let network = utils::get_network(network)?;
 
@@ -83,7 +260,7 @@ pub fn get_sc_address(mnemonic: &str, index: u32, network: &str) -> core::result
     let mnemonic = Mnemonic::parse_normalized(mnemonic)?;
     
     // 2. Get the seed from the mnemonic
-    let seed = mnemonic.to_seed_normalized("");
+    let seed = mnemonic.to_seed(passphrase.unwrap_or(""));
 
     // we need secp256k1 context for key derivation
     let mut buf: Vec<AlignedType> = Vec::new();
@@ -93,34 +270,53 @@ pub fn get_sc_address(mnemonic: &str, index: u32, network: &str) -> core::result
     // calculate root key from seed
     let root = ExtendedPrivKey::new_master(network, &seed)?;
 
-    let user_derivation_path = "m/86h/0h/0h";
+    let user_derivation_path = derivation_scheme.get_user_derivation_path(network, account);
     let user_seckey = get_key(&secp, root, &user_derivation_path, 0, index)?;
     let user_pubkey = user_seckey.public_key(&secp);
 
-    let auth_derivation_path = "m/89h/0h/0h";
+    let auth_derivation_path = derivation_scheme.get_auth_derivation_path(network, account);
     let auth_seckey = get_key(&secp, root, &auth_derivation_path, 0, index)?;
     let auth_pubkey = auth_seckey.public_key(&secp);
 
-    encode_sc_address(&user_pubkey, &auth_pubkey, network)
+    encode_sc_address_with_options(&user_pubkey, &auth_pubkey, network, address_options)
 }
 
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn validate_address(address: &str, network: &str) -> core::result::Result<bool, MercuryError> {
+    let current_time = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).map(|duration| duration.as_secs()).unwrap_or(0);
+    validate_address_at(address, network, current_time)
+}
+
+/// Same as `validate_address`, where `current_time` is the unix timestamp used to reject expired v1 statechain addresses.
+/// For targets without a system clock, such as wasm.
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn validate_address_at(address: &str, network: &str, current_time: u64) -> core::result::Result<bool, MercuryError> {
 
     let network = utils::get_network(network)?;
 
     if address.starts_with(MAINNET_HRP) || address.starts_with(TESTNET_HRP) {
         if address.starts_with(MAINNET_HRP) && network != bitcoin::Network::Bitcoin {
-            return Err(MercuryError::StatechainAddressMismatchNetworkError);
+            return Err(MercuryError::StatechainAddressMismatchNetworkError { context: ErrorContext::new().value(address).expected(network) });
         }
 
         if address.starts_with(TESTNET_HRP) && network == bitcoin::Network::Bitcoin {
-            return Err(MercuryError::StatechainAddressMismatchNetworkError);
+            return Err(MercuryError::StatechainAddressMismatchNetworkError { context: ErrorContext::new().value(address).expected(network) });
         }
 
-        match decode_transfer_address(address) {
-            Ok(_) => Ok(true),
-            Err(_) => Err(MercuryError::InvalidStatechainAddressError),
+        match decode_statechain_address_data(address) {
+            Ok(sc_address) => {
+                // v1 addresses also distinguish between testnet, signet and regtest
+                if sc_address.network.is_some() && sc_address.network != Some(network) {
+                    return Err(MercuryError::StatechainAddressMismatchNetworkError { context: ErrorContext::new().value(address).expected(network) });
+                }
+                if let Some(expiry) = sc_address.expiry {
+                    if current_time >= expiry {
+                        return Err(MercuryError::StatechainAddressExpired { context: ErrorContext::new().value(expiry).expected(format!("> {}", current_time)) });
+                    }
+                }
+                Ok(true)
+            },
+            Err(_) => Err(MercuryError::InvalidStatechainAddressError { context: ErrorContext::new().value(address) }),
         }
     }
     else {
@@ -129,10 +325,10 @@ pub fn validate_address(address: &str, network: &str) -> core::result::Result<bo
 
                 match addr.require_network(network) {
                     Ok(_) => Ok(true),
-                    Err(_) => Err(MercuryError::BitcoinAddressMismatchNetworkError),
+                    Err(_) => Err(MercuryError::BitcoinAddressMismatchNetworkError { context: ErrorContext::new().value(address).expected(network) }),
                 }
             },
-            Err(_) => Err(MercuryError::InvalidBitcoinAddressError),
+            Err(_) => Err(MercuryError::InvalidBitcoinAddressError { context: ErrorContext::new().value(address) }),
         }
     
     }    
@@ -147,13 +343,644 @@ mod tests {
         let mnemonic = String::from("ticket sock try two evidence employ fresh beauty settle general ridge lonely");
 
         let network = "testnet";
-        let sc_address = get_sc_address(&mnemonic, 0, network).unwrap();
+        let sc_address = get_sc_address(&mnemonic, None, &DerivationScheme::Legacy, 0, 0, network, None).unwrap();
         let expected_sc_address = "tml1qqpgha2armzyvwwglqty24ztegut27neyvlkpu3894adsgascq96tjqr78gy6adlzsre3fqyrxdx8n68henrd6fzcgfwcltu3sesuh05nvxslxjnxw";
         assert_eq!(sc_address, expected_sc_address);
        
         let network = "mainnet";
-        let sc_address = get_sc_address(&mnemonic, 0, network).unwrap();
+        let sc_address = get_sc_address(&mnemonic, None, &DerivationScheme::Legacy, 0, 0, network, None).unwrap();
         let expected_sc_address = "ml1qqpgha2armzyvwwglqty24ztegut27neyvlkpu3894adsgascq96tjqr78gy6adlzsre3fqyrxdx8n68henrd6fzcgfwcltu3sesuh05nvxs2dd888";
         assert_eq!(sc_address, expected_sc_address);
     }
+
+    #[test]
+    fn error_details() {
+        let sc_address = "tml1qqpgha2armzyvwwglqty24ztegut27neyvlkpu3894adsgascq96tjqr78gy6adlzsre3fqyrxdx8n68henrd6fzcgfwcltu3sesuh05nvxslxjnxw";
+
+        let error = validate_address_at(sc_address, "bitcoin", 0).unwrap_err();
+        let details = error.details();
+
+        assert_eq!(details.code, "StatechainAddressMismatchNetworkError");
+        assert_eq!(details.value, Some(sc_address.to_string()));
+        assert_eq!(details.expected, Some("bitcoin".to_string()));
+        assert_eq!(details.tx_n, None);
+
+        let error = utils::get_network("mainnet").unwrap_err().with_tx_n(1);
+        assert_eq!(error.code(), "NetworkConversionError");
+        assert_eq!(error.context().unwrap().tx_n, Some(1));
+    }
+
+    #[test]
+    fn sc_address_v1() {
+        let mnemonic = String::from("ticket sock try two evidence employ fresh beauty settle general ridge lonely");
+
+        let v0_address = get_sc_address(&mnemonic, None, &DerivationScheme::Legacy, 0, 0, "signet", None).unwrap();
+        let (_, user_pubkey, auth_pubkey) = decode_transfer_address(&v0_address).unwrap();
+
+        let address_options = ScAddressOptions { server_hint: Some("http://127.0.0.1:8000".to_string()), expiry: Some(1700000000) };
+        let v1_address = get_sc_address(&mnemonic, None, &DerivationScheme::Legacy, 0, 0, "signet", Some(&address_options)).unwrap();
+        assert_eq!(v1_address, encode_sc_address_v1(&user_pubkey, &auth_pubkey, bitcoin::Network::Signet, Some("http://127.0.0.1:8000"), Some(1700000000)).unwrap());
+
+        let decoded = decode_statechain_address_data(&v1_address).unwrap();
+        assert_eq!(decoded.version, 1);
+        assert_eq!(decoded.network, Some(bitcoin::Network::Signet));
+        assert_eq!(decoded.user_pubkey, user_pubkey);
+        assert_eq!(decoded.auth_pubkey, auth_pubkey);
+        assert_eq!(decoded.server_hint, Some("http://127.0.0.1:8000".to_string()));
+        assert_eq!(decoded.expiry, Some(1700000000));
+
+        assert!(validate_address_at(&v1_address, "signet", 1699999999).unwrap());
+        assert!(validate_address_at(&v1_address, "testnet", 1699999999).is_err());
+        assert!(matches!(validate_address_at(&v1_address, "signet", 1700000000), Err(MercuryError::StatechainAddressExpired { .. })));
+
+        // the coins of the wallet get the same address
+        let mut wallet = test_wallet();
+        wallet.network = "signet".to_string();
+        assert_eq!(wallet.get_new_coin_with_address_options(&address_options).unwrap().address, v1_address);
+        assert_eq!(wallet.get_new_coin().unwrap().address, v0_address);
+
+        // truncated payload
+        let (hrp, data, variant) = bech32::decode(&v0_address).unwrap();
+        let mut payload = Vec::<u8>::from_base32(&data).unwrap();
+        payload.truncate(40);
+        let truncated_address = bech32::encode(&hrp, payload.to_base32(), variant).unwrap();
+        assert!(decode_transfer_address(&truncated_address).is_err());
+
+        // unknown version
+        let mut payload = Vec::<u8>::from_base32(&data).unwrap();
+        payload[0] = 0x07;
+        let unknown_version_address = bech32::encode(&hrp, payload.to_base32(), variant).unwrap();
+        assert!(decode_transfer_address(&unknown_version_address).is_err());
+    }
+
+    #[test]
+    fn sc_address_passphrase() {
+        let mnemonic = String::from("ticket sock try two evidence employ fresh beauty settle general ridge lonely");
+
+        let sc_address = get_sc_address(&mnemonic, None, &DerivationScheme::Legacy, 0, 0, "testnet", None).unwrap();
+
+        // an empty passphrase is the same as no passphrase
+        assert_eq!(get_sc_address(&mnemonic, Some(""), &DerivationScheme::Legacy, 0, 0, "testnet", None).unwrap(), sc_address);
+        assert_ne!(get_sc_address(&mnemonic, Some("passphrase"), &DerivationScheme::Legacy, 0, 0, "testnet", None).unwrap(), sc_address);
+    }
+
+    #[test]
+    fn derivation_scheme() {
+        let mnemonic = String::from("ticket sock try two evidence employ fresh beauty settle general ridge lonely");
+
+        assert_eq!(DerivationScheme::Legacy.get_user_derivation_path(bitcoin::Network::Testnet, 0), "m/86h/0h/0h");
+        assert_eq!(DerivationScheme::Bip44.get_user_derivation_path(bitcoin::Network::Testnet, 2), "m/86h/1h/2h");
+        assert_eq!(DerivationScheme::Bip44.get_auth_derivation_path(bitcoin::Network::Bitcoin, 0), "m/89h/0h/0h");
+
+        // legacy wallets keep deriving the same keys
+        let legacy_address = get_sc_address(&mnemonic, None, &DerivationScheme::Legacy, 0, 0, "testnet", None).unwrap();
+        assert_eq!(legacy_address, "tml1qqpgha2armzyvwwglqty24ztegut27neyvlkpu3894adsgascq96tjqr78gy6adlzsre3fqyrxdx8n68henrd6fzcgfwcltu3sesuh05nvxslxjnxw");
+
+        let bip44_address = get_sc_address(&mnemonic, None, &DerivationScheme::Bip44, 0, 0, "testnet", None).unwrap();
+        assert_ne!(bip44_address, legacy_address);
+
+        let account_1_address = get_sc_address(&mnemonic, None, &DerivationScheme::Bip44, 1, 0, "testnet", None).unwrap();
+        assert_ne!(account_1_address, bip44_address);
+
+        // a wallet without a recorded scheme is a legacy wallet
+        let wallet = test_wallet();
+        assert_eq!(wallet.derivation_scheme, DerivationScheme::Legacy);
+        assert_eq!(wallet.account, 0);
+    }
+
+    #[test]
+    fn descriptor_checksum() {
+        // BIP 380 test vector
+        assert_eq!(wallet::descriptor::get_descriptor_checksum("raw(deadbeef)").unwrap(), "89f8spxm");
+
+        let descriptor = "tr([d34db33f/86h/0h/0h/0/0]cc8a4bc64d897bddc5fbc2f670f7a8ba0b386779106cf1223c6fc5d7cd6fc115)";
+        assert_eq!(wallet::descriptor::get_descriptor_checksum(descriptor).unwrap(), "nxf4cq8m");
+
+        // characters outside the descriptor charset
+        assert!(wallet::descriptor::get_descriptor_checksum("tr(\u{e9})").is_err());
+    }
+
+    #[test]
+    fn coin_descriptor() {
+        let mut wallet = test_wallet();
+        wallet.derivation_scheme = DerivationScheme::Bip44;
+
+        let mut coin = wallet.get_coin_at_index(1).unwrap();
+        assert_eq!(wallet::descriptor::get_coin_descriptor(&coin, "testnet").unwrap(),
+            "tr([19a86cc8/86h/1h/0h/0/1]76db0f608e762d17c2672f90d1efa74b31e090eba98ecd7199d8047420bd2b18)#qwt6xcwk");
+
+        // the descriptor must match the address the backup transactions pay to
+        coin.backup_address = wallet.get_coin_at_index(2).unwrap().backup_address;
+        assert!(matches!(wallet::descriptor::get_coin_descriptor(&coin, "testnet"), Err(error::MercuryError::InvalidDescriptor { .. })));
+    }
+
+    #[test]
+    fn signed_tx_vsize() {
+        use bitcoin::{ScriptBuf, TxOut};
+
+        let p2tr_output = TxOut { value: 0, script_pubkey: ScriptBuf::from_bytes([vec![0x51, 0x20], vec![0u8; 32]].concat()) };
+        let p2a_output = TxOut { value: 0, script_pubkey: transaction::get_p2a_script_pubkey() };
+
+        // one P2TR key path input, one P2TR output
+        assert_eq!(transaction::get_signed_tx_weight(&[p2tr_output.clone()]), 445);
+        assert_eq!(transaction::get_signed_tx_vsize(&[p2tr_output.clone()]), 112);
+        assert_eq!(transaction::get_signed_tx_vsize(&[p2tr_output.clone(), p2a_output]), 125);
+
+        // the minimum relay fee applies to low fee rates
+        assert_eq!(transaction::get_absolute_fee(&[p2tr_output.clone()], 0.1), 112);
+        assert_eq!(transaction::get_absolute_fee(&[p2tr_output], 2.5), 280);
+    }
+
+    #[test]
+    fn transfer_msg_encoding() {
+        use transfer::{encoding::serialize_transfer_msg, receiver::deserialize_transfer_msg, TransferMsg};
+
+        let backup_tx = wallet::BackupTx {
+            tx_n: 1,
+            tx: "02000000000101".to_string(),
+            client_public_nonce: "02".repeat(66),
+            server_public_nonce: "03".repeat(66),
+            client_public_key: "02".repeat(33),
+            server_public_key: "03".repeat(33),
+            blinding_factor: "01".repeat(32),
+        };
+
+        let transfer_msg = TransferMsg {
+            statechain_id: "a1b2c3".to_string(),
+            transfer_signature: "ab".repeat(64),
+            backup_transactions: vec![backup_tx.clone(), backup_tx],
+            t1: [7u8; 32],
+            user_public_key: "02".repeat(33),
+        };
+
+        let encoded = serialize_transfer_msg(&transfer_msg).unwrap();
+        let decoded = deserialize_transfer_msg(&encoded).unwrap();
+
+        assert_eq!(decoded.statechain_id, transfer_msg.statechain_id);
+        assert_eq!(decoded.transfer_signature, transfer_msg.transfer_signature);
+        assert_eq!(decoded.t1, transfer_msg.t1);
+        assert_eq!(decoded.user_public_key, transfer_msg.user_public_key);
+        assert_eq!(decoded.backup_transactions.len(), 2);
+        assert_eq!(decoded.backup_transactions[1].blinding_factor, transfer_msg.backup_transactions[1].blinding_factor);
+
+        // the legacy JSON format is still accepted
+        let legacy = serde_json::to_string_pretty(&transfer_msg).unwrap();
+        assert_eq!(deserialize_transfer_msg(legacy.as_bytes()).unwrap().statechain_id, transfer_msg.statechain_id);
+
+        // truncated, extended or unknown version messages are rejected
+        assert!(deserialize_transfer_msg(&encoded[..encoded.len() - 1]).is_err());
+        assert!(deserialize_transfer_msg(&[encoded.clone(), vec![0]].concat()).is_err());
+
+        let mut unknown_version = encoded.clone();
+        unknown_version[3] = 2;
+        assert!(matches!(deserialize_transfer_msg(&unknown_version), Err(error::MercuryError::UnsupportedTransferMsgVersion { .. })));
+    }
+
+    #[test]
+    fn ownership_proof() {
+        use bitcoin::{absolute, ScriptBuf, Transaction, TxIn, TxOut};
+        use wallet::ownership::{create_ownership_proof, verify_ownership_proof, StatechainOwnerResponsePayload};
+
+        let wallet = test_wallet();
+        let mut coin = wallet.get_coin_at_index(0).unwrap();
+
+        // a coin without a statechain cannot be proven
+        assert!(create_ownership_proof(&coin, "challenge", 1000).is_err());
+
+        let secp = Secp256k1::new();
+        let user_pubkey = secp256k1_zkp::PublicKey::from_str(&coin.user_pubkey).unwrap();
+        let server_public_key = secp256k1_zkp::PublicKey::from_str(&wallet.get_coin_at_index(1).unwrap().user_pubkey).unwrap();
+        let aggregate_pubkey = user_pubkey.combine(&server_public_key).unwrap();
+        let tx0_tx = |amount: u64, script_pubkey: ScriptBuf| Transaction { version: 2, lock_time: absolute::LockTime::ZERO, input: vec![TxIn::default()], output: vec![TxOut { value: amount, script_pubkey }] };
+
+        let amount = 5_000_000_000u64;
+        let tx0 = tx0_tx(amount, ScriptBuf::new_v1_p2tr(&secp, aggregate_pubkey.x_only_public_key().0, None));
+        let tx0_hex = hex::encode(bitcoin::consensus::encode::serialize(&tx0));
+
+        coin.statechain_id = Some("statechain".to_string());
+        coin.utxo_txid = Some(tx0.txid().to_string());
+        coin.utxo_vout = Some(0);
+        coin.amount = Some(amount);
+
+        let proof = create_ownership_proof(&coin, "challenge", 1000).unwrap();
+
+        let auth_pubkey = secp256k1_zkp::PublicKey::from_str(&coin.auth_pubkey).unwrap();
+        let owner = StatechainOwnerResponsePayload {
+            statechain_id: "statechain".to_string(),
+            auth_xonly_pubkey: auth_pubkey.x_only_public_key().0.to_string(),
+            server_public_key: server_public_key.to_string(),
+        };
+
+        assert!(verify_ownership_proof(&proof, "challenge", &owner, &tx0_hex, 1100, 600).is_ok());
+
+        // another challenge, a stale proof, another owner or another amount are rejected
+        assert!(verify_ownership_proof(&proof, "other", &owner, &tx0_hex, 1100, 600).is_err());
+        assert!(verify_ownership_proof(&proof, "challenge", &owner, &tx0_hex, 2000, 600).is_err());
+
+        let new_owner = StatechainOwnerResponsePayload { auth_xonly_pubkey: user_pubkey.x_only_public_key().0.to_string(), ..owner.clone() };
+        assert!(verify_ownership_proof(&proof, "challenge", &new_owner, &tx0_hex, 1100, 600).is_err());
+
+        let mut forged_proof = proof.clone();
+        forged_proof.amount = amount + 1;
+        assert!(verify_ownership_proof(&forged_proof, "challenge", &owner, &tx0_hex, 1100, 600).is_err());
+
+        // the owner of the statechain cannot claim an output that the statechain key does not lock
+        let foreign_tx0 = tx0_tx(10 * amount, ScriptBuf::new_v1_p2tr(&secp, server_public_key.x_only_public_key().0, None));
+        let mut foreign_coin = coin.clone();
+        foreign_coin.utxo_txid = Some(foreign_tx0.txid().to_string());
+        foreign_coin.amount = Some(10 * amount);
+
+        let foreign_proof = create_ownership_proof(&foreign_coin, "challenge", 1000).unwrap();
+        let foreign_tx0_hex = hex::encode(bitcoin::consensus::encode::serialize(&foreign_tx0));
+        assert!(matches!(verify_ownership_proof(&foreign_proof, "challenge", &owner, &foreign_tx0_hex, 1100, 600), Err(error::MercuryError::InvalidOwnershipProof { .. })));
+
+        // nor claim it with the user key of another statechain
+        let mut other_user_proof = proof.clone();
+        other_user_proof.user_pubkey = server_public_key.to_string();
+        assert!(verify_ownership_proof(&other_user_proof, "challenge", &owner, &tx0_hex, 1100, 600).is_err());
+    }
+
+    #[test]
+    fn key_rotation() {
+        use utils::{verify_key_rotation, KeyListResponsePayload, PubKeyInfo};
+
+        let wallet = test_wallet();
+        let mut coin = wallet.get_coin_at_index(0).unwrap();
+
+        // any valid points will do as server key shares
+        let previous_server_pubkey = wallet.get_coin_at_index(1).unwrap().user_pubkey;
+        let current_server_pubkey = wallet.get_coin_at_index(2).unwrap().user_pubkey;
+
+        let user_pubkey = secp256k1_zkp::PublicKey::from_str(&coin.user_pubkey).unwrap();
+        let aggregated_pubkey = user_pubkey.combine(&secp256k1_zkp::PublicKey::from_str(&current_server_pubkey).unwrap()).unwrap();
+
+        coin.statechain_id = Some("statechain".to_string());
+        coin.server_pubkey = Some(current_server_pubkey.clone());
+        coin.aggregated_pubkey = Some(aggregated_pubkey.to_string());
+
+        let keyinfo = |server_pubkey: &str| PubKeyInfo { server_pubkey: server_pubkey.to_string(), tx_n: 2, created_at: "2024-01-01 00:00:00".to_string() };
+
+        let keylist = KeyListResponsePayload { list_keyinfo: vec![keyinfo(&current_server_pubkey)] };
+        let evidence = verify_key_rotation(&coin, &previous_server_pubkey, &keylist).unwrap();
+        assert_eq!(evidence.previous_server_pubkey, previous_server_pubkey);
+        assert_eq!(evidence.current_server_pubkey, current_server_pubkey);
+        assert_eq!(evidence.keylist_tx_n, Some(2));
+        assert!(evidence.is_verified());
+
+        // the previous share is still published, or the current one is not
+        let keylist_with_previous = KeyListResponsePayload { list_keyinfo: vec![keyinfo(&current_server_pubkey), keyinfo(&previous_server_pubkey)] };
+        assert!(verify_key_rotation(&coin, &previous_server_pubkey, &keylist_with_previous).is_err());
+        assert!(verify_key_rotation(&coin, &previous_server_pubkey, &KeyListResponsePayload { list_keyinfo: vec![] }).is_err());
+
+        // the share was not rotated
+        assert!(verify_key_rotation(&coin, &current_server_pubkey, &keylist).is_err());
+    }
+
+    #[test]
+    fn api_error() {
+        use crate::error::{decode_api_error, ApiError, ApiErrorResponsePayload};
+
+        let body = serde_json::to_string(&ApiErrorResponsePayload::new(ApiError::StatecoinBatchLockedError, "Statecoin batch locked")).unwrap();
+        let error = decode_api_error(400, &body);
+        assert_eq!(error.code, ApiError::StatecoinBatchLockedError);
+        assert_eq!(error.message, "Statecoin batch locked");
+
+        // older servers answer with a bare message or without a known code
+        let error = decode_api_error(404, r#"{"message": "Statechain not found."}"#);
+        assert_eq!(error.code, ApiError::NotFound);
+        assert_eq!(error.message, "Statechain not found.");
+        assert_eq!(decode_api_error(401, r#""Signature does not match""#).message, "Signature does not match");
+        assert_eq!(decode_api_error(503, "Service Unavailable").code, ApiError::UpstreamUnavailable);
+        assert_eq!(decode_api_error(500, "").code, ApiError::Internal);
+
+        assert_eq!(ApiError::TransferMsgTooLarge.status_code(), 413);
+        assert_eq!(ApiError::ExpiredBatchTimeError.status_code(), 400);
+    }
+
+    fn test_wallet() -> wallet::Wallet {
+        let wallet_json = r#"{"name":"w","mnemonic":"ticket sock try two evidence employ fresh beauty settle general ridge lonely","version":"0.1.0","state_entity_endpoint":"","electrum_endpoint":"","network":"testnet","blockheight":0,"initlock":0,"interval":0,"tokens":[],"activities":[],"coins":[],"settings":{"network":"testnet","block_explorerURL":null,"torProxyHost":null,"torProxyPort":null,"torProxyControlPassword":null,"torProxyControlPort":null,"statechainEntityApi":"","torStatechainEntityApi":null,"electrumProtocol":"","electrumHost":"","electrumPort":"","electrumType":"","notifications":false,"tutorials":false}}"#;
+        serde_json::from_str(wallet_json).unwrap()
+    }
+
+    // Backup transaction of `coin` paying `fee` and, optionally, an anchor output
+    fn test_backup_tx(coin: &wallet::Coin, fee: u64, anchor: Option<bitcoin::TxOut>) -> wallet::BackupTx {
+        use bitcoin::{absolute, Address, Transaction, TxIn, TxOut};
+
+        let backup_address = Address::from_str(&coin.backup_address).unwrap().assume_checked();
+        let anchor_value = anchor.as_ref().map(|anchor| anchor.value).unwrap_or(0);
+
+        let mut output = vec![TxOut { value: coin.amount.unwrap() - fee - anchor_value, script_pubkey: backup_address.script_pubkey() }];
+        output.extend(anchor);
+
+        let tx = Transaction { version: 2, lock_time: absolute::LockTime::ZERO, input: vec![TxIn::default()], output };
+
+        wallet::BackupTx {
+            tx_n: 1,
+            tx: hex::encode(bitcoin::consensus::encode::serialize(&tx)),
+            client_public_nonce: String::new(),
+            server_public_nonce: String::new(),
+            client_public_key: String::new(),
+            server_public_key: String::new(),
+            blinding_factor: String::new(),
+        }
+    }
+
+    #[test]
+    fn cpfp_package() {
+        use bitcoin::Transaction;
+        use wallet::cpfp_tx::{create_cpfp_package_tx, CpfpExtraInput, CpfpParent};
+
+        let wallet = test_wallet();
+        let to_address = wallet.get_coin_at_index(9).unwrap().backup_address;
+
+        let mut coins = Vec::new();
+        for index in 0..3 {
+            let mut coin = wallet.get_coin_at_index(index).unwrap();
+            coin.amount = Some(100_000);
+            coins.push(coin);
+        }
+
+        let decode = |tx_hex: &str| -> Transaction { bitcoin::consensus::deserialize(&hex::decode(tx_hex).unwrap()).unwrap() };
+        let parents_vsize = |parents: &Vec<CpfpParent>| -> u64 { parents.iter().map(|parent| decode(&parent.backup_tx.tx).vsize() as u64).sum() };
+
+        // two parents, the second one with a P2A anchor, spent together
+        let anchor = transaction::create_anchor_tx_out(&transaction::AnchorOutputType::P2A, &bitcoin::ScriptBuf::new());
+        let parents = vec![
+            CpfpParent { backup_tx: test_backup_tx(&coins[0], 200, None), coin: coins[0].clone() },
+            CpfpParent { backup_tx: test_backup_tx(&coins[1], 300, Some(anchor)), coin: coins[1].clone() },
+        ];
+
+        let child = decode(&create_cpfp_package_tx(&parents, &Vec::new(), &to_address, 10.0, "testnet").unwrap());
+        assert_eq!(child.input.len(), 3);
+        assert_eq!(child.output.len(), 1);
+
+        let child_fee = 200_000 - 500 - child.output[0].value;
+        let package_fee = ((parents_vsize(&parents) + child.vsize() as u64) as f64 * 10.0).ceil() as u64;
+        assert_eq!(child_fee, package_fee - 500);
+
+        // an extra input pays for the package when the backup outputs cannot
+        let parents = vec![CpfpParent { backup_tx: test_backup_tx(&coins[0], 200, None), coin: coins[0].clone() }];
+        assert!(matches!(create_cpfp_package_tx(&parents, &Vec::new(), &to_address, 1000.0, "testnet"), Err(error::MercuryError::InsufficientFundsForFeeBump { .. })));
+
+        let extra_input = CpfpExtraInput {
+            txid: "a".repeat(64),
+            vout: 1,
+            amount: 500_000,
+            privkey_wif: coins[2].user_privkey.clone(),
+        };
+
+        let child = decode(&create_cpfp_package_tx(&parents, &vec![extra_input], &to_address, 1000.0, "testnet").unwrap());
+        assert_eq!(child.input.len(), 2);
+        assert_eq!(child.input[1].previous_output.vout, 1);
+
+        let child_fee = 100_000 - 200 + 500_000 - child.output[0].value;
+        let package_fee = ((parents_vsize(&parents) + child.vsize() as u64) as f64 * 1000.0).ceil() as u64;
+        assert_eq!(child_fee, package_fee - 200);
+
+        // the child pays at least the minimum relay fee for its own size, even if the parents already pay more than the target
+        let parents = vec![CpfpParent { backup_tx: test_backup_tx(&coins[0], 50_000, None), coin: coins[0].clone() }];
+
+        let child = decode(&create_cpfp_package_tx(&parents, &Vec::new(), &to_address, 0.5, "testnet").unwrap());
+        let child_fee = 100_000 - 50_000 - child.output[0].value;
+        assert_eq!(child_fee, (child.vsize() as f64 * transaction::MIN_RELAY_FEE_RATE).ceil() as u64);
+    }
+
+
+    #[test]
+    fn backup_tx_anchor_layout() {
+        use bitcoin::{absolute, Transaction, TxIn, TxOut};
+        use transaction::{create_backup_tx_outs, is_anchor_output, AnchorOutputType, P2A_ANCHOR_VALUE, P2TR_ANCHOR_VALUE};
+        use transfer::receiver::verify_backup_tx_output_layout;
+
+        let wallet = test_wallet();
+        let mut coin = wallet.get_coin_at_index(0).unwrap();
+        coin.amount = Some(100_000);
+        let to_address = wallet.get_coin_at_index(1).unwrap().backup_address;
+
+        let network = bitcoin::Network::Testnet;
+        let layout = |output: Vec<TxOut>| verify_backup_tx_output_layout(&Transaction { version: 2, lock_time: absolute::LockTime::ZERO, input: vec![TxIn::default()], output });
+
+        // no anchor
+        let outputs = create_backup_tx_outs(&coin, 1.0, &to_address, None, network).unwrap();
+        assert_eq!(outputs.len(), 1);
+        assert_eq!(outputs[0].value, 100_000 - transaction::get_absolute_fee(&outputs, 1.0));
+        assert!(layout(outputs).is_ok());
+
+        // P2A anchor, paid by the coin
+        let outputs = create_backup_tx_outs(&coin, 1.0, &to_address, Some(AnchorOutputType::P2A), network).unwrap();
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[1].script_pubkey, transaction::get_p2a_script_pubkey());
+        assert_eq!(outputs[1].value, P2A_ANCHOR_VALUE);
+        assert_eq!(outputs[0].value, 100_000 - P2A_ANCHOR_VALUE - transaction::get_absolute_fee(&outputs, 1.0));
+        assert!(is_anchor_output(&outputs[1], &outputs[0]));
+        assert!(layout(outputs).is_ok());
+
+        // P2TR anchor to the recipient key
+        let outputs = create_backup_tx_outs(&coin, 1.0, &to_address, Some(AnchorOutputType::UserKeyP2TR), network).unwrap();
+        assert_eq!(outputs.len(), 2);
+        assert_eq!(outputs[1].script_pubkey, outputs[0].script_pubkey);
+        assert_eq!(outputs[1].value, P2TR_ANCHOR_VALUE);
+        assert!(is_anchor_output(&outputs[1], &outputs[0]));
+        assert!(layout(outputs.clone()).is_ok());
+
+        // an output to another script, an anchor above its value or a third output are rejected
+        let other_address = bitcoin::Address::from_str(&wallet.get_coin_at_index(2).unwrap().backup_address).unwrap().assume_checked();
+        let foreign_output = TxOut { value: P2TR_ANCHOR_VALUE, script_pubkey: other_address.script_pubkey() };
+        assert!(!is_anchor_output(&foreign_output, &outputs[0]));
+        assert!(matches!(layout(vec![outputs[0].clone(), foreign_output]), Err(error::MercuryError::InvalidAnchorOutput { .. })));
+
+        let large_anchor = TxOut { value: P2TR_ANCHOR_VALUE + 1, ..outputs[1].clone() };
+        assert!(!is_anchor_output(&large_anchor, &outputs[0]));
+
+        let extra_output = vec![outputs[0].clone(), outputs[1].clone(), outputs[1].clone()];
+        assert!(matches!(layout(extra_output), Err(error::MercuryError::TxHasMoreThanOneOutput { .. })));
+        assert!(layout(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn signature_scheme_report_interval() {
+        use bitcoin::{absolute, Transaction};
+        use transfer::receiver::{get_signature_scheme_validation_report, StatechainInfoResponsePayload};
+
+        let wallet = test_wallet();
+        let mut coin = wallet.get_coin_at_index(0).unwrap();
+        coin.amount = Some(100_000);
+
+        let backup_tx = |tx_n: u32, lock_time: u32| -> wallet::BackupTx {
+            let mut backup_tx = test_backup_tx(&coin, 200, None);
+            let mut tx: Transaction = bitcoin::consensus::deserialize(&hex::decode(&backup_tx.tx).unwrap()).unwrap();
+            tx.lock_time = absolute::LockTime::from_height(lock_time).unwrap();
+            backup_tx.tx = hex::encode(bitcoin::consensus::encode::serialize(&tx));
+            backup_tx.tx_n = tx_n;
+            backup_tx
+        };
+
+        let statechain_info = StatechainInfoResponsePayload { enclave_public_key: String::new(), num_sigs: 0, statechain_info: Vec::new(), x1_pub: None };
+
+        // the lock_time of the second transaction cannot be decoded, so the interval of the third one cannot be checked
+        let mut undecodable = backup_tx(2, 990);
+        undecodable.tx = "00".to_string();
+        let backup_txs = vec![backup_tx(1, 1000), undecodable, backup_tx(3, 980)];
+
+        let report = get_signature_scheme_validation_report(&backup_txs, &statechain_info, "", 0, 1.0, 1.0, 1000, 10);
+        assert!(!report.is_valid);
+        assert!(report.backup_txs[0].interval_valid);
+        assert!(!report.backup_txs[1].interval_valid);
+        assert!(!report.backup_txs[2].interval_valid);
+        assert_eq!(report.previous_lock_time, Some(980));
+
+        let backup_txs = vec![backup_tx(1, 1000), backup_tx(2, 990), backup_tx(3, 980)];
+        let report = get_signature_scheme_validation_report(&backup_txs, &statechain_info, "", 0, 1.0, 1.0, 1000, 10);
+        assert!(report.backup_txs.iter().all(|validation| validation.interval_valid));
+    }
+
+    #[test]
+    fn watchtower_package() {
+        use bitcoin::{absolute, Transaction};
+        use wallet::watchtower::{create_watchtower_package, export_watchtower_package, import_watchtower_package, verify_watchtower_package};
+
+        let wallet = test_wallet();
+        let mut coin = wallet.get_coin_at_index(0).unwrap();
+        coin.amount = Some(100_000);
+        coin.statechain_id = Some("statechain".to_string());
+
+        let backup_tx = |tx_n: u32, lock_time: u32| -> wallet::BackupTx {
+            let mut backup_tx = test_backup_tx(&coin, 200, None);
+            let mut tx: Transaction = bitcoin::consensus::deserialize(&hex::decode(&backup_tx.tx).unwrap()).unwrap();
+            tx.lock_time = absolute::LockTime::from_height(lock_time).unwrap();
+            tx.input[0].previous_output.vout = 1;
+            backup_tx.tx = hex::encode(bitcoin::consensus::encode::serialize(&tx));
+            backup_tx.tx_n = tx_n;
+            backup_tx
+        };
+
+        let backup_txs = vec![backup_tx(1, 1000), backup_tx(2, 990)];
+
+        let package = create_watchtower_package(&coin, &backup_txs, "testnet").unwrap();
+        assert_eq!(package.tx_n, 2);
+        assert_eq!(package.backup_tx, backup_txs[1].tx);
+        assert_eq!(package.locktime, 990);
+        assert_eq!(package.tx0_vout, 1);
+        assert_eq!(package.previous_txids.len(), 1);
+
+        let package_json = export_watchtower_package(&package).unwrap();
+        assert_eq!(import_watchtower_package(&package_json).unwrap(), package);
+
+        let mut tampered = package.clone();
+        tampered.backup_tx = backup_txs[0].tx.clone();
+        assert!(matches!(verify_watchtower_package(&tampered), Err(error::MercuryError::InvalidSignature { .. })));
+
+        let mut tampered = package.clone();
+        tampered.locktime = 1000;
+        assert!(matches!(verify_watchtower_package(&tampered), Err(error::MercuryError::InvalidSignature { .. })));
+
+        let mut tampered = package.clone();
+        tampered.tx0_vout = 0;
+        assert!(matches!(verify_watchtower_package(&tampered), Err(error::MercuryError::InvalidSignature { .. })));
+
+        // signed by the auth key of another coin
+        let mut other_coin = wallet.get_coin_at_index(1).unwrap();
+        other_coin.backup_address = coin.backup_address.clone();
+        other_coin.statechain_id = coin.statechain_id.clone();
+
+        let mut tampered = package.clone();
+        tampered.signature = create_watchtower_package(&other_coin, &backup_txs, "testnet").unwrap().signature;
+        assert!(matches!(verify_watchtower_package(&tampered), Err(error::MercuryError::InvalidSignature { .. })));
+    }
+
+    #[test]
+    fn withdrawal_tx_outs() {
+        use transaction::{create_withdrawal_tx_outs, get_absolute_fee, WithdrawalOutput};
+
+        let wallet = test_wallet();
+        let mut coin = wallet.get_coin_at_index(0).unwrap();
+        coin.amount = Some(100_000);
+
+        let network = bitcoin::Network::Testnet;
+        let payee = wallet.get_coin_at_index(1).unwrap().backup_address;
+        let change = wallet.get_coin_at_index(2).unwrap().backup_address;
+        let output = |address: &str, amount: Option<u64>| WithdrawalOutput { address: address.to_string(), amount };
+
+        // the output without amount receives the coin value left after the payees and the fee
+        let tx_outs = create_withdrawal_tx_outs(&coin, 2.0, &vec![output(&payee, Some(30_000)), output(&change, None)], network).unwrap();
+        assert_eq!(tx_outs.len(), 2);
+        assert_eq!(tx_outs[0].value, 30_000);
+        let fee = get_absolute_fee(&tx_outs, 2.0);
+        assert_eq!(tx_outs[1].value, 100_000 - 30_000 - fee);
+
+        // payees below dust, or change below dust after the fee
+        assert!(matches!(create_withdrawal_tx_outs(&coin, 2.0, &vec![output(&payee, Some(100)), output(&change, None)], network), Err(error::MercuryError::AmountBelowDust { .. })));
+        assert!(matches!(create_withdrawal_tx_outs(&coin, 2.0, &vec![output(&payee, Some(100_000 - fee - 100)), output(&change, None)], network), Err(error::MercuryError::AmountBelowDust { .. })));
+
+        // without change, the payees must leave the fee, plus at most the dust limit
+        let fee = get_absolute_fee(&create_withdrawal_tx_outs(&coin, 2.0, &vec![output(&payee, None)], network).unwrap(), 2.0);
+        assert!(create_withdrawal_tx_outs(&coin, 2.0, &vec![output(&payee, Some(100_000 - fee))], network).is_ok());
+        assert!(matches!(create_withdrawal_tx_outs(&coin, 2.0, &vec![output(&payee, Some(100_000 - fee + 1))], network), Err(error::MercuryError::InvalidWithdrawalOutputs { .. })));
+        assert!(matches!(create_withdrawal_tx_outs(&coin, 2.0, &vec![output(&payee, Some(50_000))], network), Err(error::MercuryError::FeeTooHigh { .. })));
+
+        assert!(matches!(create_withdrawal_tx_outs(&coin, 2.0, &vec![output(&payee, None), output(&change, None)], network), Err(error::MercuryError::InvalidWithdrawalOutputs { .. })));
+        assert!(create_withdrawal_tx_outs(&coin, 2.0, &Vec::new(), network).is_err());
+    }
+
+    #[test]
+    fn psbt_round_trip() {
+        use bitcoin::{psbt::{Psbt, PsbtSighashType}, sighash::TapSighashType, Transaction};
+        use secp256k1_zkp::{musig::{MusigSession, MusigSessionId}, new_musig_nonce_pair, rand, schnorr, KeyPair, Message, XOnlyPublicKey};
+
+        let secp = secp256k1_zkp::Secp256k1::new();
+        let wallet = test_wallet();
+        let mut coin = wallet.get_coin_at_index(0).unwrap();
+
+        let server_keypair = KeyPair::from_seckey_slice(&secp, &[7u8; 32]).unwrap();
+        let server_pubkey = server_keypair.public_key();
+
+        coin.server_pubkey = Some(server_pubkey.to_string());
+        let aggregated = deposit::create_aggregated_address(&coin, "testnet".to_string()).unwrap();
+        coin.aggregated_pubkey = Some(aggregated.aggregate_pubkey);
+        coin.aggregated_address = Some(aggregated.aggregate_address.clone());
+        coin.statechain_id = Some("c5b4".to_string());
+        coin.signed_statechain_id = Some("3f1a".to_string());
+        coin.utxo_txid = Some("5f".repeat(32));
+        coin.utxo_vout = Some(0);
+        coin.amount = Some(100_000);
+
+        // sign first: the client nonce, and the server nonce it gets back
+        let coin_nonce = transaction::create_and_commit_nonces(&coin).unwrap();
+        coin.secret_nonce = Some(coin_nonce.secret_nonce);
+        coin.public_nonce = Some(coin_nonce.public_nonce);
+        coin.blinding_factor = Some(coin_nonce.blinding_factor);
+
+        let (server_sec_nonce, server_pub_nonce) = new_musig_nonce_pair(&secp, MusigSessionId::new(&mut rand::thread_rng()), None, Some(server_keypair.secret_key()), server_pubkey, None, None).unwrap();
+        coin.server_public_nonce = Some(hex::encode(server_pub_nonce.serialize()));
+
+        let to_address = wallet.get_coin_at_index(1).unwrap().backup_address;
+        let exported_psbt = psbt::create_backup_tx_psbt(&coin, 1000, 10, 5, 2.0, 1, to_address, "testnet".to_string(), false, None).unwrap();
+
+        // a coordinator cannot get a signature that does not commit to the outputs
+        let mut sighash_none_psbt = Psbt::from_str(&exported_psbt).unwrap();
+        sighash_none_psbt.inputs[0].sighash_type = Some(PsbtSighashType::from(TapSighashType::NonePlusAnyoneCanPay));
+        assert!(matches!(psbt::sign_psbt(&coin, sighash_none_psbt.to_string()), Err(error::MercuryError::InvalidPsbt { .. })));
+        assert!(matches!(psbt::finalize_psbt(sighash_none_psbt.to_string()), Err(error::MercuryError::InvalidPsbt { .. })));
+
+        let signed_psbt = psbt::sign_psbt(&coin, exported_psbt).unwrap();
+        assert!(matches!(psbt::sign_psbt(&coin, signed_psbt.clone()), Err(error::MercuryError::InvalidPsbt { .. })));
+
+        // sign second: the server signs the blinded session
+        let request = psbt::get_partial_sig_request_from_psbt(&coin, signed_psbt.clone()).unwrap();
+        let session = MusigSession::from_slice(hex::decode(&request.session).unwrap().try_into().unwrap());
+        let server_partial_sig = session.blinded_partial_sign_without_keyaggcoeff(&secp, server_sec_nonce, &server_keypair, request.negate_seckey == 1).unwrap();
+
+        let complete_psbt = psbt::add_server_partial_sig_to_psbt(&coin, signed_psbt, hex::encode(server_partial_sig.serialize())).unwrap();
+        let signed_tx: Transaction = bitcoin::consensus::deserialize(&hex::decode(psbt::finalize_psbt(complete_psbt.clone()).unwrap()).unwrap()).unwrap();
+
+        // the key path signature is valid for the taproot output key of the coin
+        let script_pubkey = Address::from_str(&aggregated.aggregate_address).unwrap().assume_checked().script_pubkey();
+        let output_key = XOnlyPublicKey::from_slice(&script_pubkey.as_bytes()[2..]).unwrap();
+        let witness_signature = signed_tx.input[0].witness.nth(0).unwrap();
+        assert_eq!(witness_signature.len(), 65);
+        let signature = schnorr::Signature::from_slice(&witness_signature[..64]).unwrap();
+        let msg = Message::from_slice(&hex::decode(psbt::get_psbt_msg(complete_psbt).unwrap()).unwrap()).unwrap();
+        assert!(secp.verify_schnorr(&signature, &msg, &output_key).is_ok());
+    }
+
 }
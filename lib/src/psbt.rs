@@ -0,0 +1,380 @@
+//! PSBT export and import of the backup and withdrawal transactions.
+//!
+//! The MuSig2 data is stored in the input with the BIP 373 fields (participant public keys,
+//! public nonces and partial signatures), keyed by the aggregated key, which is the taproot internal key.
+//! The data specific to the blinded scheme (the session and the key negation) is stored in
+//! proprietary fields with the `mercury` prefix.
+//!
+//! Flow: `create_backup_tx_psbt` or `create_withdrawal_psbt` (online, after sign first),
+//! `sign_psbt` (where the coin secrets are), `get_partial_sig_request_from_psbt` and
+//! `add_server_partial_sig_to_psbt` (online), and `finalize_psbt`.
+
+use std::str::FromStr;
+
+use bitcoin::{hashes::Hash, psbt::{raw, Input, Psbt}, sighash::{TapSighash, TapSighashType}, taproot::TapTweakHash};
+use secp256k1_zkp::{musig::{blinded_musig_pubkey_xonly_tweak_add, MusigPartialSignature, MusigPubNonce}, PublicKey, Secp256k1, SecretKey};
+
+use crate::{error::{ErrorContext, MercuryError}, transaction::{calculate_block_height, calculate_musig_session, create_backup_tx_outs, create_signature, create_unsigned_psbt, create_withdrawal_tx_outs, get_psbt_sighash, new_backup_transaction, AnchorOutputType, PartialSignatureRequestPayload, WithdrawalOutput}, utils::get_network, wallet::Coin};
+
+const PSBT_IN_MUSIG2_PARTICIPANT_PUBKEYS: u8 = 0x1a;
+const PSBT_IN_MUSIG2_PUB_NONCE: u8 = 0x1b;
+const PSBT_IN_MUSIG2_PARTIAL_SIG: u8 = 0x1c;
+
+const PSBT_MERCURY_PREFIX: &[u8] = b"mercury";
+/// Session with the final nonce, used to aggregate the partial signatures
+const PSBT_MERCURY_SESSION: u8 = 0x00;
+/// Session without the final nonce, sent to the server
+const PSBT_MERCURY_BLINDED_SESSION: u8 = 0x01;
+const PSBT_MERCURY_NEGATE_SECKEY: u8 = 0x02;
+
+fn invalid_psbt<T: ToString>(value: T) -> MercuryError {
+    MercuryError::InvalidPsbt { context: ErrorContext::new().value(value) }
+}
+
+fn get_coin_field<'a>(field: &'a Option<String>, name: &str) -> Result<&'a String, MercuryError> {
+    field.as_ref().ok_or(invalid_psbt(format!("coin has no {}", name)))
+}
+
+fn parse_psbt(psbt: &str) -> Result<Psbt, MercuryError> {
+
+    let psbt = Psbt::from_str(psbt).map_err(invalid_psbt)?;
+
+    if psbt.inputs.len() != 1 || psbt.unsigned_tx.input.len() != 1 {
+        return Err(MercuryError::MoreThanOneInputError);
+    }
+
+    Ok(psbt)
+}
+
+/// Sighash of the PSBT input, always computed as SIGHASH_ALL.
+/// `finalize_psbt` builds the transaction with `new_backup_transaction`, which signs with SIGHASH_ALL,
+/// and a partial signature over a hash that does not commit to the outputs must never be produced,
+/// so an input asking for any other type is rejected.
+fn get_sighash(psbt: &Psbt) -> Result<TapSighash, MercuryError> {
+
+    let sighash_type = psbt.inputs[0].sighash_type;
+
+    match sighash_type.map(|sighash_type| sighash_type.taproot_hash_ty()) {
+        None | Some(Ok(TapSighashType::Default)) | Some(Ok(TapSighashType::All)) => {},
+        _ => return Err(MercuryError::InvalidPsbt { context: ErrorContext::new().value(format!("sighash type {}", sighash_type.unwrap())).expected("SIGHASH_ALL") }),
+    }
+
+    let mut psbt = psbt.clone();
+    psbt.inputs[0].sighash_type = Some(TapSighashType::All.into());
+
+    get_psbt_sighash(&psbt)
+}
+
+/// BIP 373 key of the nonce and the partial signature of a participant
+fn get_participant_key(type_value: u8, participant_pubkey: &PublicKey, aggregate_pubkey: &PublicKey) -> raw::Key {
+
+    let mut key = participant_pubkey.serialize().to_vec();
+    key.extend_from_slice(&aggregate_pubkey.serialize());
+
+    raw::Key { type_value, key }
+}
+
+fn get_proprietary_key(subtype: u8) -> raw::ProprietaryKey {
+    raw::ProprietaryKey { prefix: PSBT_MERCURY_PREFIX.to_vec(), subtype, key: Vec::new() }
+}
+
+fn get_proprietary_field<'a>(input: &'a Input, subtype: u8, name: &str) -> Result<&'a Vec<u8>, MercuryError> {
+    input.proprietary.get(&get_proprietary_key(subtype))
+        .ok_or(invalid_psbt(format!("missing {}", name)))
+}
+
+/// The aggregated key and its participants. The aggregated key must be the taproot internal key.
+fn get_participants(input: &Input) -> Result<(PublicKey, Vec<PublicKey>), MercuryError> {
+
+    let (key, value) = input.unknown.iter()
+        .find(|(key, _)| key.type_value == PSBT_IN_MUSIG2_PARTICIPANT_PUBKEYS)
+        .ok_or(invalid_psbt("missing MuSig2 participant pubkeys"))?;
+
+    let aggregate_pubkey = PublicKey::from_slice(&key.key)?;
+
+    if input.tap_internal_key != Some(aggregate_pubkey.x_only_public_key().0) {
+        return Err(invalid_psbt("the aggregated key is not the taproot internal key"));
+    }
+
+    if value.len() % 33 != 0 {
+        return Err(invalid_psbt("invalid MuSig2 participant pubkeys"));
+    }
+
+    let participants = value.chunks(33)
+        .map(PublicKey::from_slice)
+        .collect::<Result<Vec<PublicKey>, _>>()?;
+
+    // in the statechain scheme, the aggregated key is the sum of the user and server keys
+    if participants.len() != 2 || participants[0].combine(&participants[1])? != aggregate_pubkey {
+        return Err(invalid_psbt("the participant pubkeys do not add up to the aggregated key"));
+    }
+
+    Ok((aggregate_pubkey, participants))
+}
+
+fn get_pub_nonce(input: &Input, participant_pubkey: &PublicKey, aggregate_pubkey: &PublicKey) -> Result<MusigPubNonce, MercuryError> {
+
+    let pub_nonce = input.unknown.get(&get_participant_key(PSBT_IN_MUSIG2_PUB_NONCE, participant_pubkey, aggregate_pubkey))
+        .ok_or(invalid_psbt(format!("missing pubnonce of {}", participant_pubkey)))?;
+
+    Ok(MusigPubNonce::from_slice(pub_nonce)?)
+}
+
+fn get_partial_sig(input: &Input, participant_pubkey: &PublicKey, aggregate_pubkey: &PublicKey) -> Result<MusigPartialSignature, MercuryError> {
+
+    let partial_sig = input.unknown.get(&get_participant_key(PSBT_IN_MUSIG2_PARTIAL_SIG, participant_pubkey, aggregate_pubkey))
+        .ok_or(invalid_psbt(format!("missing partial signature of {}", participant_pubkey)))?;
+
+    Ok(MusigPartialSignature::from_slice(partial_sig)?)
+}
+
+/// The server participant, given the coin
+fn get_server_pubkey(coin: &Coin, participants: &Vec<PublicKey>) -> Result<PublicKey, MercuryError> {
+
+    let server_pubkey = PublicKey::from_str(get_coin_field(&coin.server_pubkey, "server_pubkey")?)?;
+
+    if !participants.contains(&server_pubkey) {
+        return Err(invalid_psbt(format!("{} is not a participant", server_pubkey)));
+    }
+
+    Ok(server_pubkey)
+}
+
+/// Adds the BIP 373 participants and public nonces to the unsigned PSBT
+fn create_musig_psbt(coin: &Coin, block_height: u32, outputs: &Vec<bitcoin::TxOut>, network: bitcoin::Network) -> Result<String, MercuryError> {
+
+    let aggregate_pubkey = PublicKey::from_str(get_coin_field(&coin.aggregated_pubkey, "aggregated_pubkey")?)?;
+    let server_pubkey = PublicKey::from_str(get_coin_field(&coin.server_pubkey, "server_pubkey")?)?;
+    let user_pubkey = PublicKey::from_str(&coin.user_pubkey)?;
+
+    let client_pub_nonce = MusigPubNonce::from_slice(&hex::decode(get_coin_field(&coin.public_nonce, "public_nonce")?)?)?;
+    let server_pub_nonce = MusigPubNonce::from_slice(&hex::decode(get_coin_field(&coin.server_public_nonce, "server_public_nonce")?)?)?;
+
+    get_coin_field(&coin.utxo_txid, "utxo_txid")?;
+    get_coin_field(&coin.aggregated_address, "aggregated_address")?;
+
+    if coin.utxo_vout.is_none() || coin.amount.is_none() {
+        return Err(invalid_psbt("coin has no utxo_vout or amount"));
+    }
+
+    let mut psbt = create_unsigned_psbt(coin, block_height, outputs, network)?;
+
+    let input = &mut psbt.inputs[0];
+
+    let mut participants = user_pubkey.serialize().to_vec();
+    participants.extend_from_slice(&server_pubkey.serialize());
+
+    input.unknown.insert(raw::Key { type_value: PSBT_IN_MUSIG2_PARTICIPANT_PUBKEYS, key: aggregate_pubkey.serialize().to_vec() }, participants);
+    input.unknown.insert(get_participant_key(PSBT_IN_MUSIG2_PUB_NONCE, &user_pubkey, &aggregate_pubkey), client_pub_nonce.serialize().to_vec());
+    input.unknown.insert(get_participant_key(PSBT_IN_MUSIG2_PUB_NONCE, &server_pubkey, &aggregate_pubkey), server_pub_nonce.serialize().to_vec());
+
+    Ok(psbt.to_string())
+}
+
+/// Base64 PSBT of a backup transaction, with the same parameters as `get_partial_sig_request_with_anchor`
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_backup_tx_psbt(
+    coin: &Coin,
+    block_height: u32,
+    initlock: u32,
+    interval: u32,
+    fee_rate_sats_per_byte: f64,
+    qt_backup_tx: u32,
+    to_address: String,
+    network: String,
+    is_withdrawal: bool,
+    anchor_type: Option<AnchorOutputType>) -> Result<String, MercuryError>
+{
+    let network = get_network(&network)?;
+
+    let outputs = create_backup_tx_outs(coin, fee_rate_sats_per_byte, &to_address, anchor_type, network)?;
+
+    let block_height = calculate_block_height(block_height, initlock, interval, qt_backup_tx, is_withdrawal)?;
+
+    create_musig_psbt(coin, block_height, &outputs, network)
+}
+
+/// Base64 PSBT of a withdrawal, with the same parameters as `get_withdrawal_partial_sig_request`
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_withdrawal_psbt(
+    coin: &Coin,
+    block_height: u32,
+    fee_rate_sats_per_byte: f64,
+    outputs: Vec<WithdrawalOutput>,
+    network: String) -> Result<String, MercuryError>
+{
+    let network = get_network(&network)?;
+
+    let outputs = create_withdrawal_tx_outs(coin, fee_rate_sats_per_byte, &outputs, network)?;
+
+    let block_height = calculate_block_height(block_height, 0, 0, 0, true)?;
+
+    create_musig_psbt(coin, block_height, &outputs, network)
+}
+
+/// Adds the partial signature of the coin owner to the PSBT.
+/// It needs the coin private key, secret nonce and blinding factor.
+/// The secret nonce must not be used again once the PSBT is signed: the caller consumes it first,
+/// as `mercuryrustlib::psbt::sign_psbt` does with the signing nonce table.
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn sign_psbt(coin: &Coin, psbt: String) -> Result<String, MercuryError> {
+
+    let mut psbt = parse_psbt(&psbt)?;
+
+    let previous_output = psbt.unsigned_tx.input[0].previous_output;
+
+    if coin.utxo_txid != Some(previous_output.txid.to_string()) || coin.utxo_vout != Some(previous_output.vout) {
+        return Err(MercuryError::InvalidPsbt { context: ErrorContext::new().value(previous_output).expected(format!("{}:{}", coin.utxo_txid.clone().unwrap_or_default(), coin.utxo_vout.unwrap_or_default())) });
+    }
+
+    get_coin_field(&coin.secret_nonce, "secret_nonce")?;
+    get_coin_field(&coin.blinding_factor, "blinding_factor")?;
+    get_coin_field(&coin.statechain_id, "statechain_id")?;
+    get_coin_field(&coin.signed_statechain_id, "signed_statechain_id")?;
+
+    let input = &psbt.inputs[0];
+
+    let (aggregate_pubkey, participants) = get_participants(input)?;
+
+    if coin.aggregated_pubkey != Some(aggregate_pubkey.to_string()) {
+        return Err(MercuryError::InvalidPsbt { context: ErrorContext::new().value(aggregate_pubkey).expected(coin.aggregated_pubkey.clone().unwrap_or_default()) });
+    }
+
+    let user_pubkey = PublicKey::from_str(&coin.user_pubkey)?;
+
+    if !participants.contains(&user_pubkey) {
+        return Err(invalid_psbt(format!("{} is not a participant", user_pubkey)));
+    }
+
+    let server_pubkey = participants.iter().find(|participant| **participant != user_pubkey)
+        .ok_or(invalid_psbt("the coin owner is the only participant"))?;
+
+    if input.unknown.contains_key(&get_participant_key(PSBT_IN_MUSIG2_PARTIAL_SIG, &user_pubkey, &aggregate_pubkey)) {
+        return Err(invalid_psbt("the PSBT is already signed by the coin owner"));
+    }
+
+    let client_pub_nonce = hex::encode(get_pub_nonce(input, &user_pubkey, &aggregate_pubkey)?.serialize());
+    let server_pub_nonce = hex::encode(get_pub_nonce(input, server_pubkey, &aggregate_pubkey)?.serialize());
+
+    // the pubnonce must be the one committed with the secret nonce of the coin
+    if coin.public_nonce.is_some() && coin.public_nonce != Some(client_pub_nonce.clone()) {
+        return Err(MercuryError::InvalidPsbt { context: ErrorContext::new().value(client_pub_nonce).expected(coin.public_nonce.clone().unwrap_or_default()) });
+    }
+
+    let mut coin = coin.clone();
+    coin.public_nonce = Some(client_pub_nonce);
+    coin.server_public_nonce = Some(server_pub_nonce);
+
+    let hash = get_sighash(&psbt)?;
+
+    let encoded_unsigned_tx = hex::encode(bitcoin::consensus::encode::serialize(&psbt.unsigned_tx));
+
+    let partial_signature_msg1 = calculate_musig_session(&coin, hash, encoded_unsigned_tx)?;
+
+    let input = &mut psbt.inputs[0];
+
+    input.unknown.insert(
+        get_participant_key(PSBT_IN_MUSIG2_PARTIAL_SIG, &user_pubkey, &aggregate_pubkey),
+        hex::decode(&partial_signature_msg1.client_partial_sig)?);
+
+    input.proprietary.insert(get_proprietary_key(PSBT_MERCURY_SESSION), hex::decode(&partial_signature_msg1.encoded_session)?);
+    input.proprietary.insert(get_proprietary_key(PSBT_MERCURY_BLINDED_SESSION), hex::decode(&partial_signature_msg1.partial_signature_request_payload.session)?);
+    input.proprietary.insert(get_proprietary_key(PSBT_MERCURY_NEGATE_SECKEY), vec![partial_signature_msg1.partial_signature_request_payload.negate_seckey]);
+
+    Ok(psbt.to_string())
+}
+
+/// Hex of the message that `sign_psbt` signs, to record the nonce consumed by the signature
+pub fn get_psbt_msg(psbt: String) -> Result<String, MercuryError> {
+
+    let psbt = parse_psbt(&psbt)?;
+
+    Ok(hex::encode(get_sighash(&psbt)?.as_byte_array()))
+}
+
+/// The request for the server partial signature of a PSBT signed with `sign_psbt`
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn get_partial_sig_request_from_psbt(coin: &Coin, psbt: String) -> Result<PartialSignatureRequestPayload, MercuryError> {
+
+    let psbt = parse_psbt(&psbt)?;
+
+    let input = &psbt.inputs[0];
+
+    let (aggregate_pubkey, participants) = get_participants(input)?;
+
+    let server_pubkey = get_server_pubkey(coin, &participants)?;
+
+    let server_pub_nonce = get_pub_nonce(input, &server_pubkey, &aggregate_pubkey)?;
+
+    let negate_seckey = get_proprietary_field(input, PSBT_MERCURY_NEGATE_SECKEY, "key negation")?
+        .first().copied()
+        .ok_or(invalid_psbt("invalid key negation"))?;
+
+    let blinded_session = get_proprietary_field(input, PSBT_MERCURY_BLINDED_SESSION, "blinded session")?;
+
+    Ok(PartialSignatureRequestPayload {
+        statechain_id: get_coin_field(&coin.statechain_id, "statechain_id")?.to_string(),
+        negate_seckey,
+        session: hex::encode(blinded_session),
+        signed_statechain_id: get_coin_field(&coin.signed_statechain_id, "signed_statechain_id")?.to_string(),
+        server_pub_nonce: hex::encode(server_pub_nonce.serialize()),
+    })
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn add_server_partial_sig_to_psbt(coin: &Coin, psbt: String, server_partial_sig_hex: String) -> Result<String, MercuryError> {
+
+    let mut psbt = parse_psbt(&psbt)?;
+
+    let (aggregate_pubkey, participants) = get_participants(&psbt.inputs[0])?;
+
+    let server_pubkey = get_server_pubkey(coin, &participants)?;
+
+    let server_partial_sig = MusigPartialSignature::from_slice(&hex::decode(server_partial_sig_hex)?)?;
+
+    psbt.inputs[0].unknown.insert(
+        get_participant_key(PSBT_IN_MUSIG2_PARTIAL_SIG, &server_pubkey, &aggregate_pubkey),
+        server_partial_sig.serialize().to_vec());
+
+    Ok(psbt.to_string())
+}
+
+/// Aggregates the partial signatures of the PSBT with `create_signature`
+/// and returns the signed transaction, built by `new_backup_transaction`
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn finalize_psbt(psbt: String) -> Result<String, MercuryError> {
+
+    let psbt = parse_psbt(&psbt)?;
+
+    let input = &psbt.inputs[0];
+
+    let (aggregate_pubkey, participants) = get_participants(input)?;
+
+    let partial_sigs = participants.iter()
+        .map(|participant| get_partial_sig(input, participant, &aggregate_pubkey))
+        .collect::<Result<Vec<MusigPartialSignature>, MercuryError>>()?;
+
+    let session = get_proprietary_field(input, PSBT_MERCURY_SESSION, "session")?;
+
+    if session.len() != 133 {
+        return Err(invalid_psbt("invalid session"));
+    }
+
+    let tap_tweak = TapTweakHash::from_key_and_tweak(aggregate_pubkey.x_only_public_key().0, None);
+    let tweak = SecretKey::from_slice(tap_tweak.as_byte_array())?;
+
+    let (_, output_pubkey, _) = blinded_musig_pubkey_xonly_tweak_add(&Secp256k1::new(), &aggregate_pubkey, tweak);
+
+    let hash = get_sighash(&psbt)?;
+
+    let signature = create_signature(
+        hex::encode(hash.as_byte_array()),
+        hex::encode(partial_sigs[0].serialize()),
+        hex::encode(partial_sigs[1].serialize()),
+        hex::encode(session),
+        output_pubkey.to_string())?;
+
+    let encoded_unsigned_tx = hex::encode(bitcoin::consensus::encode::serialize(&psbt.unsigned_tx));
+
+    new_backup_transaction(encoded_unsigned_tx, signature)
+}
@@ -4,10 +4,11 @@ use bitcoin::{Txid, ScriptBuf, Transaction, absolute, TxIn, OutPoint, Witness, T
 use secp256k1_zkp::{SecretKey, PublicKey,  Secp256k1, schnorr::Signature, Message, musig::{MusigSessionId, MusigPubNonce, BlindingFactor, MusigSession, MusigPartialSignature, blinded_musig_pubkey_xonly_tweak_add, blinded_musig_negate_seckey, MusigAggNonce, MusigSecNonce}, new_musig_nonce_pair, KeyPair, rand::{self, Rng}};
 use serde::{Serialize, Deserialize};
 
-use crate::{decode_transfer_address, error::MercuryError, utils::{self, get_network}, wallet::Coin};
+use crate::{decode_transfer_address, error::{ErrorContext, MercuryError}, utils::{self, get_network}, wallet::Coin};
 
 #[derive(Serialize, Deserialize, Debug)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct SignFirstRequestPayload {
     pub statechain_id: String,
     pub signed_statechain_id: String,
@@ -24,6 +25,7 @@ pub struct CoinNonce {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct SignFirstResponsePayload {
     pub server_pubnonce: String,
 }
@@ -41,6 +43,7 @@ pub struct PartialSignatureMsg1 {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct PartialSignatureRequestPayload {
     pub statechain_id: String,
     pub negate_seckey: u8,
@@ -57,10 +60,40 @@ pub struct ServerPublicNonceResponsePayload {
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct PartialSignatureResponsePayload {
     pub partial_sig: String,
 }
 
+/// Optional anchor output added to backup transactions so that the owner can
+/// bump the fee (CPFP) when the backup transaction is broadcast.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Enum))]
+pub enum AnchorOutputType {
+    /// Pay-to-Anchor (OP_1 <0x4e73>), spendable by anyone with an empty witness
+    P2A,
+    /// P2TR output paying to the same key as the backup output
+    UserKeyP2TR,
+}
+
+/// An output of a withdrawal transaction.
+/// The output without `amount`, if any, receives the coin value left after the other outputs and the fee.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct WithdrawalOutput {
+    pub address: String,
+    pub amount: Option<u64>,
+}
+
+pub const P2A_ANCHOR_VALUE: u64 = 240;
+pub const P2TR_ANCHOR_VALUE: u64 = 330;
+
+/// Minimum fee rate, in sats/vbyte, relayed by the default node policy
+pub const MIN_RELAY_FEE_RATE: f64 = 1.0;
+
+// 64-byte schnorr signature followed by the SIGHASH_ALL byte
+const P2TR_KEY_PATH_SIGNATURE_SIZE: usize = 65;
+
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn create_and_commit_nonces(coin: &Coin) -> core::result::Result<CoinNonce, MercuryError>{
     
@@ -106,6 +139,61 @@ fn get_locktime_for_withdrawal_transaction (block_height: u32) -> u32 {
     std::cmp::max(0, locktime) as u32
 }
 
+/// Weight of the final signed transaction spending one P2TR key path input to `outputs`.
+/// The output values do not change the weight, so they can be placeholders.
+pub fn get_signed_tx_weight(outputs: &[TxOut]) -> u64 {
+
+    let mut witness = Witness::new();
+    witness.push([0u8; P2TR_KEY_PATH_SIGNATURE_SIZE]);
+
+    let tx = Transaction {
+        version: 2,
+        lock_time: absolute::LockTime::ZERO,
+        input: vec![TxIn {
+            previous_output: OutPoint::null(),
+            script_sig: ScriptBuf::new(),
+            sequence: bitcoin::Sequence(0x0),
+            witness,
+        }],
+        output: outputs.to_vec(),
+    };
+
+    tx.weight().to_wu()
+}
+
+pub fn get_signed_tx_vsize(outputs: &[TxOut]) -> u64 {
+    (get_signed_tx_weight(outputs) + 3) / 4
+}
+
+/// Fee of the signed transaction paying to `outputs`, never below the minimum relay fee
+pub fn get_absolute_fee(outputs: &[TxOut], fee_rate_sats_per_byte: f64) -> u64 {
+    let fee_rate = fee_rate_sats_per_byte.max(MIN_RELAY_FEE_RATE);
+    (get_signed_tx_vsize(outputs) as f64 * fee_rate).ceil() as u64
+}
+
+/// Sets the value of the first output to the coin amount minus `extra_value` and the fee of the transaction.
+/// `extra_value` is the total value of the other outputs.
+fn set_output_value_after_fee(coin: &Coin, outputs: &mut Vec<TxOut>, extra_value: u64, fee_rate_sats_per_byte: f64) -> core::result::Result<(), MercuryError> {
+
+    let input_amount = coin.amount.ok_or(MercuryError::CoinAmountNotSet { context: ErrorContext::new().value(&coin.user_pubkey) })?;
+
+    let absolute_fee = get_absolute_fee(outputs, fee_rate_sats_per_byte);
+
+    let amount_out = input_amount.checked_sub(extra_value)
+        .and_then(|amount| amount.checked_sub(absolute_fee))
+        .ok_or(MercuryError::FeeTooHigh { context: ErrorContext::new().value(absolute_fee.saturating_add(extra_value)).expected(format!("<= {}", input_amount)) })?;
+
+    let dust_value = outputs[0].script_pubkey.dust_value().to_sat();
+
+    if amount_out < dust_value {
+        return Err(MercuryError::AmountBelowDust { context: ErrorContext::new().value(amount_out).expected(format!(">= {}", dust_value)) });
+    }
+
+    outputs[0].value = amount_out;
+
+    Ok(())
+}
+
 pub fn create_tx_out(
     coin: &Coin, 
     fee_rate_sats_per_byte: f64,
@@ -113,25 +201,93 @@ pub fn create_tx_out(
     network: Network,
 ) -> core::result::Result<TxOut, MercuryError>
 {
-    const BACKUP_TX_SIZE: u64 = 112; // virtual size one input P2TR and one output P2TR
-    // 163 is the real size one input P2TR and one output P2TR
+    let mut outputs = vec![TxOut { value: 0, script_pubkey: get_recipient_script_pubkey(to_address, network)? }];
 
-    let input_amount = coin.amount.unwrap() as u64;
-    let absolute_fee = (BACKUP_TX_SIZE as f64 * fee_rate_sats_per_byte).ceil() as u64;
-    let amount_out = input_amount - absolute_fee;
+    set_output_value_after_fee(coin, &mut outputs, 0, fee_rate_sats_per_byte)?;
+
+    Ok(outputs.remove(0))
+}
+
+/// Statechain addresses are paid to the P2TR address of the recipient user key
+fn get_recipient_script_pubkey(to_address: &str, network: Network) -> core::result::Result<ScriptBuf, MercuryError> {
 
     let recipient_address = if to_address.starts_with(crate::MAINNET_HRP) || to_address.starts_with(crate::TESTNET_HRP) {
         let (_, recipient_user_pubkey, _) = decode_transfer_address(to_address)?;
         let new_address = Address::p2tr(&Secp256k1::new(), recipient_user_pubkey.x_only_public_key().0, None, network);
         new_address
     } else {
-        let new_address = Address::from_str(&to_address).unwrap().require_network(network)?;
+        let new_address = Address::from_str(&to_address)?.require_network(network)?;
         new_address
     };
 
-    let tx_out = TxOut { value: amount_out, script_pubkey: recipient_address.script_pubkey() };
+    Ok(recipient_address.script_pubkey())
+}
+
+/// Creates the outputs of a withdrawal transaction spending the whole coin.
+/// At most one output can have no amount: it receives the change.
+/// If every output has an amount, the coin value left after the outputs must cover the fee
+/// and cannot exceed it by more than the dust limit of a change output.
+pub fn create_withdrawal_tx_outs(
+    coin: &Coin,
+    fee_rate_sats_per_byte: f64,
+    outputs: &Vec<WithdrawalOutput>,
+    network: Network,
+) -> core::result::Result<Vec<TxOut>, MercuryError>
+{
+    if outputs.is_empty() {
+        return Err(MercuryError::InvalidWithdrawalOutputs { context: ErrorContext::new().value("no outputs") });
+    }
+
+    let change_outputs = outputs.iter().filter(|output| output.amount.is_none()).count();
+
+    if change_outputs > 1 {
+        return Err(MercuryError::InvalidWithdrawalOutputs { context: ErrorContext::new().value(format!("{} outputs without amount", change_outputs)).expected("at most 1") });
+    }
+
+    let input_amount = coin.amount.ok_or(MercuryError::CoinAmountNotSet { context: ErrorContext::new().value(&coin.user_pubkey) })?;
+
+    let mut tx_outs = Vec::<TxOut>::new();
+
+    let mut total_amount: u64 = 0;
+
+    for output in outputs.iter() {
+        let script_pubkey = get_recipient_script_pubkey(&output.address, network)?;
+
+        let value = output.amount.unwrap_or(0);
+
+        if output.amount.is_some() && value < script_pubkey.dust_value().to_sat() {
+            return Err(MercuryError::AmountBelowDust { context: ErrorContext::new().value(value).expected(format!(">= {}", script_pubkey.dust_value().to_sat())) });
+        }
 
-    Ok(tx_out)
+        total_amount = total_amount.checked_add(value)
+            .ok_or(MercuryError::InvalidWithdrawalOutputs { context: ErrorContext::new().value("amount overflow") })?;
+
+        tx_outs.push(TxOut { value, script_pubkey });
+    }
+
+    let absolute_fee = get_absolute_fee(&tx_outs, fee_rate_sats_per_byte);
+
+    let remaining_amount = input_amount.checked_sub(total_amount)
+        .and_then(|amount| amount.checked_sub(absolute_fee))
+        .ok_or(MercuryError::InvalidWithdrawalOutputs { context: ErrorContext::new().value(format!("{} (outputs and fee)", total_amount.saturating_add(absolute_fee))).expected(format!("<= {}", input_amount)) })?;
+
+    match tx_outs.iter_mut().find(|tx_out| tx_out.value == 0) {
+        Some(change_tx_out) => {
+            if remaining_amount < change_tx_out.script_pubkey.dust_value().to_sat() {
+                return Err(MercuryError::AmountBelowDust { context: ErrorContext::new().value(remaining_amount).expected(format!(">= {}", change_tx_out.script_pubkey.dust_value().to_sat())) });
+            }
+            change_tx_out.value = remaining_amount;
+        },
+        None => {
+            // without a change output, the remaining amount is paid as fee
+            let max_extra_fee = tx_outs[0].script_pubkey.dust_value().to_sat();
+            if remaining_amount > max_extra_fee {
+                return Err(MercuryError::FeeTooHigh { context: ErrorContext::new().value(absolute_fee + remaining_amount).expected(format!("<= {} (add an output without amount to receive the change)", absolute_fee + max_extra_fee)) });
+            }
+        },
+    }
+
+    Ok(tx_outs)
 }
 
 pub fn calculate_block_height(
@@ -161,6 +317,27 @@ pub fn get_user_backup_address(coin: &Coin, network: String) -> core::result::Re
     Ok(to_address.to_string())
 }
 
+pub fn get_p2a_script_pubkey() -> ScriptBuf {
+    ScriptBuf::from_bytes(vec![0x51, 0x02, 0x4e, 0x73])
+}
+
+/// Creates the anchor output. The `UserKeyP2TR` anchor pays to the same script as the backup output.
+pub fn create_anchor_tx_out(anchor_type: &AnchorOutputType, recipient_script_pubkey: &ScriptBuf) -> TxOut {
+    match anchor_type {
+        AnchorOutputType::P2A => TxOut { value: P2A_ANCHOR_VALUE, script_pubkey: get_p2a_script_pubkey() },
+        AnchorOutputType::UserKeyP2TR => TxOut { value: P2TR_ANCHOR_VALUE, script_pubkey: recipient_script_pubkey.clone() },
+    }
+}
+
+/// Returns true if `anchor` is a valid anchor output for a backup transaction whose first output is `output`.
+pub fn is_anchor_output(anchor: &TxOut, output: &TxOut) -> bool {
+    if anchor.script_pubkey == get_p2a_script_pubkey() {
+        return anchor.value <= P2A_ANCHOR_VALUE;
+    }
+
+    anchor.script_pubkey == output.script_pubkey && anchor.value <= P2TR_ANCHOR_VALUE
+}
+
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn get_partial_sig_request(
     coin: &Coin, 
@@ -172,10 +349,36 @@ pub fn get_partial_sig_request(
     to_address: String,
     network: String,
     is_withdrawal: bool) -> core::result::Result<PartialSignatureMsg1, MercuryError>
+{
+    get_partial_sig_request_with_anchor(
+        coin, 
+        block_height, 
+        initlock, 
+        interval, 
+        fee_rate_sats_per_byte,
+        qt_backup_tx,
+        to_address,
+        network,
+        is_withdrawal,
+        None)
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn get_partial_sig_request_with_anchor(
+    coin: &Coin, 
+    block_height: u32, 
+    initlock: u32, 
+    interval: u32, 
+    fee_rate_sats_per_byte: f64,
+    qt_backup_tx: u32,
+    to_address: String,
+    network: String,
+    is_withdrawal: bool,
+    anchor_type: Option<AnchorOutputType>) -> core::result::Result<PartialSignatureMsg1, MercuryError>
 {
     let network = utils::get_network(&network)?;
-    
-    let tx_out = create_tx_out(coin, fee_rate_sats_per_byte, &to_address, network)?;
+
+    let outputs = create_backup_tx_outs(coin, fee_rate_sats_per_byte, &to_address, anchor_type, network)?;
 
     let block_height = calculate_block_height(
         block_height, 
@@ -187,22 +390,90 @@ pub fn get_partial_sig_request(
     let session = get_musig_session(
         coin,
         block_height, 
-        &tx_out,
+        &outputs,
         network)?;
 
     Ok(session)
 }
 
+/// The backup output, optionally followed by an anchor output
+pub fn create_backup_tx_outs(
+    coin: &Coin,
+    fee_rate_sats_per_byte: f64,
+    to_address: &str,
+    anchor_type: Option<AnchorOutputType>,
+    network: Network) -> core::result::Result<Vec<TxOut>, MercuryError>
+{
+    let script_pubkey = get_recipient_script_pubkey(to_address, network)?;
+
+    let mut outputs = vec![TxOut { value: 0, script_pubkey }];
+
+    if let Some(anchor_type) = anchor_type {
+        outputs.push(create_anchor_tx_out(&anchor_type, &outputs[0].script_pubkey));
+    }
+
+    // the anchor output is paid by the coin, and its size by the fee
+    let anchor_value: u64 = outputs.iter().skip(1).map(|tx_out| tx_out.value).sum();
+
+    set_output_value_after_fee(coin, &mut outputs, anchor_value, fee_rate_sats_per_byte)?;
+
+    Ok(outputs)
+}
+
+/// Creates the partial signature request of a withdrawal paying to one or more outputs
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn get_withdrawal_partial_sig_request(
+    coin: &Coin, 
+    block_height: u32, 
+    fee_rate_sats_per_byte: f64,
+    outputs: Vec<WithdrawalOutput>,
+    network: String) -> core::result::Result<PartialSignatureMsg1, MercuryError>
+{
+    let network = utils::get_network(&network)?;
+
+    let outputs = create_withdrawal_tx_outs(coin, fee_rate_sats_per_byte, &outputs, network)?;
+
+    let block_height = calculate_block_height(block_height, 0, 0, 0, true)?;
+
+    get_musig_session(
+        coin,
+        block_height, 
+        &outputs,
+        network)
+}
+
 pub fn get_musig_session(
     coin: &Coin,
     block_height: u32, 
-    output: &TxOut,
+    outputs: &Vec<TxOut>,
     network: Network) -> core::result::Result<PartialSignatureMsg1, MercuryError>
+{
+    let psbt = create_unsigned_psbt(coin, block_height, outputs, network)?;
+
+    let hash = get_psbt_sighash(&psbt)?;
+
+    let tx_bytes = bitcoin::consensus::encode::serialize(&psbt.unsigned_tx);
+    let encoded_unsigned_tx = hex::encode(tx_bytes);
+
+    let session = calculate_musig_session(
+        coin,
+        hash,
+        encoded_unsigned_tx)?;
+
+    Ok(session)
+}
+
+/// PSBT spending the coin UTXO through the key path of the aggregated key
+pub fn create_unsigned_psbt(
+    coin: &Coin,
+    block_height: u32, 
+    outputs: &Vec<TxOut>,
+    network: Network) -> core::result::Result<Psbt, MercuryError>
 {
     let input_pubkey = PublicKey::from_str(&coin.aggregated_pubkey.as_ref().unwrap())?;
     let input_xonly_pubkey = input_pubkey.x_only_public_key().0;
 
-    let outputs = [output.to_owned()].to_vec();
+    let outputs = outputs.to_owned();
 
     let lock_time = absolute::LockTime::from_height(block_height)?;
 
@@ -223,7 +494,7 @@ pub fn get_musig_session(
 
     let mut psbt = Psbt::from_unsigned_tx(tx1)?;
 
-    let input_amount = coin.amount.unwrap() as u64;
+    let input_amount = coin.amount.unwrap();
     
     let input_address = Address::from_str(&coin.aggregated_address.as_ref().unwrap())?.require_network(network)?;
     let input_scriptpubkey = input_address.script_pubkey();
@@ -237,38 +508,39 @@ pub fn get_musig_session(
     input.tap_internal_key = Some(input_xonly_pubkey.to_owned());
     psbt.inputs = vec![input];
 
-    let unsigned_tx = psbt.unsigned_tx.clone();
+    Ok(psbt)
+}
+
+/// Taproot key path sighash of the only input of the PSBT
+pub fn get_psbt_sighash(psbt: &Psbt) -> core::result::Result<TapSighash, MercuryError> {
 
     // There must not be more than one input.
-    // The input is the funding transaction and the output the backup address.
-    assert!(psbt.inputs.len() == 1);
+    // The input is the funding transaction and the output the backup address (optionally followed by an anchor output).
+    if psbt.inputs.len() != 1 || psbt.unsigned_tx.input.len() != 1 {
+        return Err(MercuryError::MoreThanOneInputError);
+    }
 
     let vout = 0; // the vout is always 0 (only one input)
-    let input = psbt.inputs.iter_mut().nth(vout).unwrap();
+    let input = &psbt.inputs[vout];
+
+    let witness_utxo = input.witness_utxo.as_ref()
+        .ok_or(MercuryError::InvalidPsbt { context: ErrorContext::new().value("input has no witness_utxo") })?;
 
     let hash_ty = input
         .sighash_type
         .and_then(|psbt_sighash_type| psbt_sighash_type.taproot_hash_ty().ok())
         .unwrap_or(TapSighashType::All);
 
-    let hash = SighashCache::new(&unsigned_tx).taproot_key_spend_signature_hash(
+    let hash = SighashCache::new(&psbt.unsigned_tx).taproot_key_spend_signature_hash(
         vout,
         &sighash::Prevouts::All(&[TxOut {
-            value: input.witness_utxo.as_ref().unwrap().value,
-            script_pubkey: input.witness_utxo.as_ref().unwrap().script_pubkey.clone(),
+            value: witness_utxo.value,
+            script_pubkey: witness_utxo.script_pubkey.clone(),
         }]),
         hash_ty,
     )?;
 
-    let tx_bytes = bitcoin::consensus::encode::serialize(&unsigned_tx);
-    let encoded_unsigned_tx = hex::encode(tx_bytes);
-
-    let session = calculate_musig_session(
-        coin,
-        hash,
-        encoded_unsigned_tx)?;
-
-    Ok(session)
+    Ok(hash)
 }
 
 pub fn calculate_musig_session(
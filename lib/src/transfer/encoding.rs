@@ -0,0 +1,170 @@
+use bitcoin::consensus::encode::{deserialize_partial, serialize, VarInt};
+
+use crate::{error::{ErrorContext, MercuryError}, wallet::BackupTx};
+
+use super::TransferMsg;
+
+/// Prefix of the binary transfer messages. Legacy JSON messages start with `{`.
+const TRANSFER_MSG_MAGIC: &[u8; 3] = b"MTM";
+
+pub const TRANSFER_MSG_VERSION: u8 = 1;
+
+/// Maximum size of a decrypted transfer message, in either format.
+/// The hex encoded encrypted message stays under the 1 MiB JSON limit of the server.
+pub const MAX_TRANSFER_MSG_SIZE: usize = 384 * 1024;
+
+/// ECIES adds the ephemeral public key (65 bytes), the nonce (16 bytes) and the tag (16 bytes)
+pub const MAX_ENCRYPTED_TRANSFER_MSG_SIZE: usize = MAX_TRANSFER_MSG_SIZE + 65 + 16 + 16;
+
+fn write_bytes(buf: &mut Vec<u8>, data: &[u8]) {
+    buf.extend_from_slice(&serialize(&VarInt(data.len() as u64)));
+    buf.extend_from_slice(data);
+}
+
+fn write_hex(buf: &mut Vec<u8>, data: &str) -> Result<(), MercuryError> {
+    write_bytes(buf, &hex::decode(data)?);
+    Ok(())
+}
+
+fn write_backup_tx(buf: &mut Vec<u8>, backup_tx: &BackupTx) -> Result<(), MercuryError> {
+    buf.extend_from_slice(&backup_tx.tx_n.to_le_bytes());
+    write_hex(buf, &backup_tx.tx)?;
+    write_hex(buf, &backup_tx.client_public_nonce)?;
+    write_hex(buf, &backup_tx.server_public_nonce)?;
+    write_hex(buf, &backup_tx.client_public_key)?;
+    write_hex(buf, &backup_tx.server_public_key)?;
+    write_hex(buf, &backup_tx.blinding_factor)?;
+    Ok(())
+}
+
+/// Encodes the transfer message as `MTM || version || fields`.
+/// Variable length fields are prefixed with their length as a Bitcoin compact size,
+/// and hex strings are stored as raw bytes.
+pub fn serialize_transfer_msg(transfer_msg: &TransferMsg) -> Result<Vec<u8>, MercuryError> {
+
+    let mut buf = Vec::<u8>::new();
+
+    buf.extend_from_slice(TRANSFER_MSG_MAGIC);
+    buf.push(TRANSFER_MSG_VERSION);
+
+    write_bytes(&mut buf, transfer_msg.statechain_id.as_bytes());
+    write_hex(&mut buf, &transfer_msg.transfer_signature)?;
+    buf.extend_from_slice(&transfer_msg.t1);
+    write_hex(&mut buf, &transfer_msg.user_public_key)?;
+
+    buf.extend_from_slice(&serialize(&VarInt(transfer_msg.backup_transactions.len() as u64)));
+
+    for backup_tx in transfer_msg.backup_transactions.iter() {
+        write_backup_tx(&mut buf, backup_tx)?;
+    }
+
+    if buf.len() > MAX_TRANSFER_MSG_SIZE {
+        return Err(MercuryError::TransferMsgTooLarge { context: ErrorContext::new().value(buf.len()).expected(format!("<= {}", MAX_TRANSFER_MSG_SIZE)) });
+    }
+
+    Ok(buf)
+}
+
+/// Reads the fields of a binary transfer message. Every read is bounds checked.
+struct TransferMsgReader<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> TransferMsgReader<'a> {
+
+    fn read_exact(&mut self, len: usize) -> Result<&'a [u8], MercuryError> {
+        if len > self.data.len() {
+            return Err(MercuryError::InvalidTransferMsg);
+        }
+        let (value, rest) = self.data.split_at(len);
+        self.data = rest;
+        Ok(value)
+    }
+
+    fn read_u32(&mut self) -> Result<u32, MercuryError> {
+        let value: [u8; 4] = self.read_exact(4)?.try_into().map_err(|_| MercuryError::InvalidTransferMsg)?;
+        Ok(u32::from_le_bytes(value))
+    }
+
+    fn read_compact_size(&mut self) -> Result<usize, MercuryError> {
+        let (VarInt(value), consumed) = deserialize_partial::<VarInt>(self.data).map_err(|_| MercuryError::InvalidTransferMsg)?;
+        self.data = &self.data[consumed..];
+        usize::try_from(value).map_err(|_| MercuryError::InvalidTransferMsg)
+    }
+
+    fn read_bytes(&mut self) -> Result<&'a [u8], MercuryError> {
+        let len = self.read_compact_size()?;
+        self.read_exact(len)
+    }
+
+    fn read_hex(&mut self) -> Result<String, MercuryError> {
+        Ok(hex::encode(self.read_bytes()?))
+    }
+
+    fn read_string(&mut self) -> Result<String, MercuryError> {
+        let value = std::str::from_utf8(self.read_bytes()?).map_err(|_| MercuryError::InvalidUtf8Error)?;
+        Ok(value.to_string())
+    }
+
+    fn read_backup_tx(&mut self) -> Result<BackupTx, MercuryError> {
+        Ok(BackupTx {
+            tx_n: self.read_u32()?,
+            tx: self.read_hex()?,
+            client_public_nonce: self.read_hex()?,
+            server_public_nonce: self.read_hex()?,
+            client_public_key: self.read_hex()?,
+            server_public_key: self.read_hex()?,
+            blinding_factor: self.read_hex()?,
+        })
+    }
+}
+
+pub fn is_binary_transfer_msg(data: &[u8]) -> bool {
+    data.starts_with(TRANSFER_MSG_MAGIC)
+}
+
+/// Decodes a message created by `serialize_transfer_msg`. Trailing bytes are rejected.
+pub fn deserialize_binary_transfer_msg(data: &[u8]) -> Result<TransferMsg, MercuryError> {
+
+    if data.len() > MAX_TRANSFER_MSG_SIZE {
+        return Err(MercuryError::TransferMsgTooLarge { context: ErrorContext::new().value(data.len()).expected(format!("<= {}", MAX_TRANSFER_MSG_SIZE)) });
+    }
+
+    let mut reader = TransferMsgReader { data };
+
+    if reader.read_exact(TRANSFER_MSG_MAGIC.len())? != TRANSFER_MSG_MAGIC {
+        return Err(MercuryError::InvalidTransferMsg);
+    }
+
+    let version = reader.read_exact(1)?[0];
+
+    if version != TRANSFER_MSG_VERSION {
+        return Err(MercuryError::UnsupportedTransferMsgVersion { context: ErrorContext::new().value(version).expected(TRANSFER_MSG_VERSION) });
+    }
+
+    let statechain_id = reader.read_string()?;
+    let transfer_signature = reader.read_hex()?;
+    let t1: [u8; 32] = reader.read_exact(32)?.try_into().map_err(|_| MercuryError::InvalidTransferMsg)?;
+    let user_public_key = reader.read_hex()?;
+
+    let backup_tx_count = reader.read_compact_size()?;
+
+    // the count comes from the sender, so the vector grows with the data actually read
+    let mut backup_transactions = Vec::<BackupTx>::new();
+
+    for _ in 0..backup_tx_count {
+        backup_transactions.push(reader.read_backup_tx()?);
+    }
+
+    if !reader.data.is_empty() {
+        return Err(MercuryError::InvalidTransferMsg);
+    }
+
+    Ok(TransferMsg {
+        statechain_id,
+        transfer_signature,
+        backup_transactions,
+        t1,
+        user_public_key,
+    })
+}
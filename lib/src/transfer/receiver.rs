@@ -4,12 +4,13 @@ use bitcoin::{hashes::{sha256, Hash}, sighash::{self, SighashCache, TapSighashTy
 use secp256k1_zkp::{PublicKey, schnorr::Signature, Secp256k1, Message, XOnlyPublicKey, musig::{MusigPubNonce, BlindingFactor, blinded_musig_pubkey_xonly_tweak_add, MusigAggNonce, MusigSession}, SecretKey, Scalar, KeyPair};
 use serde::{Serialize, Deserialize};
 
-use crate::{error::MercuryError, utils::get_network, wallet::{get_previous_outpoint, BackupTx, Coin, CoinStatus, Wallet}};
+use crate::{error::{ErrorContext, MercuryError, MercuryErrorDetails}, transaction::{get_signed_tx_vsize, is_anchor_output, MIN_RELAY_FEE_RATE}, utils::get_network, wallet::{get_previous_outpoint, BackupTx, Coin, CoinStatus, Wallet}};
 
-use super::{TransferMsg, TxOutpoint};
+use super::{encoding::{deserialize_binary_transfer_msg, is_binary_transfer_msg, MAX_ENCRYPTED_TRANSFER_MSG_SIZE, MAX_TRANSFER_MSG_SIZE}, TransferMsg, TxOutpoint};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct TransferUnlockRequestPayload { 
     pub statechain_id: String,
     pub auth_sig: String, // signed_statechain_id
@@ -18,6 +19,7 @@ pub struct TransferUnlockRequestPayload {
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct TransferReceiverRequestPayload { 
     pub statechain_id: String,
     pub batch_data: Option<String>,
@@ -25,22 +27,9 @@ pub struct TransferReceiverRequestPayload {
     pub auth_sig: String,
 }
 
-#[derive(Serialize, Deserialize)]
-#[cfg_attr(feature = "bindings", derive(uniffi::Enum))]
-pub enum TransferReceiverError {
-    StatecoinBatchLockedError,
-    ExpiredBatchTimeError,
-}
-
-#[derive(Serialize, Deserialize)]
-#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
-pub struct TransferReceiverErrorResponsePayload {
-    pub code: TransferReceiverError,
-    pub message: String,
-}
-
 #[derive(Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct TransferReceiverPostResponsePayload {
     pub server_pubkey: String,
 }
@@ -55,12 +44,14 @@ pub struct KeyUpdateResponsePayload {
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct GetMsgAddrResponsePayload {
     pub list_enc_transfer_msg: Vec<String>,
 }
  
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct StatechainInfo {
     pub statechain_id: String,
     pub server_pubnonce: String,
@@ -70,6 +61,7 @@ pub struct StatechainInfo {
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct StatechainInfoResponsePayload {
     pub enclave_public_key: String,
     pub num_sigs: u32,
@@ -83,7 +75,7 @@ pub struct NewKeyInfo {
     pub aggregate_pubkey: String,
     pub aggregate_address: String,
     pub signed_statechain_id: String,
-    pub amount: u32,
+    pub amount: u64,
 }
 
 #[cfg_attr(feature = "bindings", uniffi::export)]
@@ -92,7 +84,7 @@ pub fn duplicate_coin_to_initialized_state(wallet: &Wallet, auth_pubkey: &str) -
     let coin = wallet.coins.iter().find(|coin| coin.auth_pubkey == auth_pubkey.to_string());
 
     if coin.is_none() {
-        return Err(MercuryError::CoinNotFound);
+        return Err(MercuryError::CoinNotFound { context: ErrorContext::new().value(auth_pubkey) });
     }
 
     let coin = coin.unwrap();
@@ -125,6 +117,7 @@ pub fn duplicate_coin_to_initialized_state(wallet: &Wallet, auth_pubkey: &str) -
         withdrawal_address: None,
         status: CoinStatus::INITIALISED,
         duplicate_index: coin.duplicate_index,
+        key_rotations: Vec::new(),
     })
 }   
 
@@ -132,17 +125,51 @@ pub fn decrypt_transfer_msg(encrypted_message: &str, private_key_wif: &str) -> R
 
     let client_auth_key = PrivateKey::from_wif(private_key_wif)?.inner;
 
+    if encrypted_message.len() > 2 * MAX_ENCRYPTED_TRANSFER_MSG_SIZE {
+        return Err(MercuryError::TransferMsgTooLarge { context: ErrorContext::new().value(encrypted_message.len() / 2).expected(format!("<= {}", MAX_ENCRYPTED_TRANSFER_MSG_SIZE)) });
+    }
+
     let decoded_enc_message = hex::decode(encrypted_message)?;
 
-    let decrypted_msg = ecies::decrypt(client_auth_key.secret_bytes().as_slice(), decoded_enc_message.as_slice()).unwrap();
+    let decrypted_msg = ecies::decrypt(client_auth_key.secret_bytes().as_slice(), decoded_enc_message.as_slice())
+        .map_err(|_| MercuryError::EciesDecryptionError)?;
+
+    deserialize_transfer_msg(&decrypted_msg)
+}
+
+/// Parses a decrypted transfer message, in the binary format or in the legacy JSON format.
+/// The content comes from the sender, so it must not be trusted.
+pub fn deserialize_transfer_msg(decrypted_msg: &[u8]) -> Result<TransferMsg, MercuryError> {
+
+    if is_binary_transfer_msg(decrypted_msg) {
+        return deserialize_binary_transfer_msg(decrypted_msg);
+    }
+
+    if decrypted_msg.len() > MAX_TRANSFER_MSG_SIZE {
+        return Err(MercuryError::TransferMsgTooLarge { context: ErrorContext::new().value(decrypted_msg.len()).expected(format!("<= {}", MAX_TRANSFER_MSG_SIZE)) });
+    }
 
-    let decrypted_msg_str = String::from_utf8(decrypted_msg).unwrap();
+    let decrypted_msg_str = std::str::from_utf8(decrypted_msg).map_err(|_| MercuryError::InvalidUtf8Error)?;
 
-    let transfer_msg: TransferMsg = serde_json::from_str(decrypted_msg_str.as_str()).unwrap();
+    let transfer_msg: TransferMsg = serde_json::from_str(decrypted_msg_str).map_err(|_| MercuryError::InvalidTransferMsg)?;
 
     Ok(transfer_msg)
 }
 
+/// Returns the output of `tx` at `vout` without panicking on an out-of-range index.
+fn get_tx_output(tx: &Transaction, vout: usize) -> Result<TxOut, MercuryError> {
+    tx.output.get(vout).cloned().ok_or(MercuryError::InvalidOutputIndex { context: ErrorContext::new().value(vout).expected(format!("< {}", tx.output.len())) })
+}
+
+/// Returns the x-only public key of a P2TR script pubkey.
+fn get_p2tr_xonly_pubkey(script_pubkey: &bitcoin::ScriptBuf) -> Result<XOnlyPublicKey, MercuryError> {
+    if !script_pubkey.is_v1_p2tr() {
+        return Err(MercuryError::InvalidScriptPubkey);
+    }
+
+    Ok(XOnlyPublicKey::from_slice(&script_pubkey.as_bytes()[2..])?)
+}
+
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn get_tx0_outpoint(backup_transactions: &Vec<BackupTx>) -> Result<TxOutpoint, MercuryError> {
 
@@ -150,7 +177,7 @@ pub fn get_tx0_outpoint(backup_transactions: &Vec<BackupTx>) -> Result<TxOutpoin
 
     backup_transactions.sort_by(|a, b| a.tx_n.cmp(&b.tx_n));
 
-    let bkp_tx1 = backup_transactions.first().ok_or(MercuryError::NoBackupTransactionFound)?;
+    let bkp_tx1 = backup_transactions.first().ok_or(MercuryError::NoBackupTransactionFound { context: ErrorContext::new() })?;
 
     get_previous_outpoint(bkp_tx1)
 }
@@ -181,23 +208,23 @@ pub fn validate_tx0_output_pubkey(enclave_public_key: &str, transfer_msg: &Trans
 
     let network = get_network(&network)?;
 
-    let enclave_public_key = PublicKey::from_str(enclave_public_key).unwrap();
+    let enclave_public_key = PublicKey::from_str(enclave_public_key)?;
     let sender_public_key = PublicKey::from_str(&transfer_msg.user_public_key)?;
 
-    let transfer_aggregate_pubkey = sender_public_key.combine(&enclave_public_key).unwrap();
+    let transfer_aggregate_pubkey = sender_public_key.combine(&enclave_public_key)?;
     let transfer_aggregate_xonly_pubkey = transfer_aggregate_pubkey.x_only_public_key().0;
 
     let secp = Secp256k1::new();
 
     let transfer_aggregate_address = Address::p2tr(&secp, transfer_aggregate_xonly_pubkey, None, network);
 
-    let transfer_aggregate_xonly_pubkey = XOnlyPublicKey::from_slice(transfer_aggregate_address.script_pubkey()[2..].as_bytes()).unwrap();
+    let transfer_aggregate_xonly_pubkey = get_p2tr_xonly_pubkey(&transfer_aggregate_address.script_pubkey())?;
 
     let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx0_hex)?)?;
 
-    let tx0_output = tx0.output[tx0_outpoint.vout as usize].clone();
+    let tx0_output = get_tx_output(&tx0, tx0_outpoint.vout as usize)?;
 
-    let tx0_output_xonly_pubkey = XOnlyPublicKey::from_slice(tx0_output.script_pubkey[2..].as_bytes()).unwrap();
+    let tx0_output_xonly_pubkey = get_p2tr_xonly_pubkey(&tx0_output.script_pubkey)?;
 
     Ok(transfer_aggregate_xonly_pubkey == tx0_output_xonly_pubkey)
 }
@@ -211,14 +238,14 @@ pub fn verify_latest_backup_tx_pays_to_user_pubkey(transfer_msg: &TransferMsg, c
     let last_bkp_tx = transfer_msg.backup_transactions.last();
 
     if last_bkp_tx.is_none() {
-        return Err(MercuryError::NoBackupTransactionFound);
+        return Err(MercuryError::NoBackupTransactionFound { context: ErrorContext::new().statechain_id(&transfer_msg.statechain_id) });
     }
 
     let last_bkp_tx = last_bkp_tx.unwrap();
 
     let last_tx: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&last_bkp_tx.tx)?)?;
 
-    let output = &last_tx.output[0];
+    let output = last_tx.output.first().ok_or(MercuryError::EmptyOutput)?;
 
     let aggregate_address = Address::p2tr(&Secp256k1::new(), client_pubkey_share.x_only_public_key().0, None, network);
 
@@ -232,7 +259,7 @@ pub fn get_output_address_from_tx0(tx0_outpoint: &TxOutpoint, tx0_hex: &str, net
 
     let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx0_hex)?)?;
 
-    let tx0_output = tx0.output[tx0_outpoint.vout as usize].clone();
+    let tx0_output = get_tx_output(&tx0, tx0_outpoint.vout as usize)?;
 
     let output_script_pubkey = tx0_output.script_pubkey;
 
@@ -246,117 +273,316 @@ pub fn get_amount_from_tx0(tx0_hex: &str, tx0_outpoint: &TxOutpoint,) -> Result<
 
     let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx0_hex)?)?;
 
-    assert!(tx0_outpoint.txid == tx0.txid().to_string());
+    if tx0_outpoint.txid != tx0.txid().to_string() {
+        return Err(MercuryError::TransactionReconstructionError { context: ErrorContext::new().value(tx0.txid()).expected(&tx0_outpoint.txid) });
+    }
 
-    Ok(tx0.output[tx0_outpoint.vout as usize].value)
+    Ok(get_tx_output(&tx0, tx0_outpoint.vout as usize)?.value)
 }
 
-#[cfg_attr(feature = "bindings", uniffi::export)]
-pub fn validate_signature_scheme(
-    backup_transactions: &Vec<BackupTx>, 
-    statechain_info: &StatechainInfoResponsePayload, 
-    tx0_hex: &str, 
+/// Result of the checks performed on a single backup transaction
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct BackupTxValidation {
+    pub tx_n: u32,
+    pub signature_valid: bool,
+    /// The blinded challenge computed from the transaction matches the one stored by the server
+    pub challenge_valid: bool,
+    pub sequence_valid: bool,
+    /// Locktime, transaction version and output layout are acceptable
+    pub locktime_valid: bool,
+    pub reconstruction_valid: bool,
+    /// The locktime is `interval` blocks lower than the one of the previous backup transaction
+    pub interval_valid: bool,
+    pub lock_time: Option<u32>,
+    /// Measured fee rate in sats/vbyte, if the transaction could be decoded
+    pub fee_rate: Option<f64>,
+    pub fee_rate_valid: bool,
+    /// Absolute fee in sats, if the transaction could be decoded
+    pub fee: Option<u64>,
+    /// The absolute fee matches the weight of the signed transaction and the backup output is above dust
+    pub fee_valid: bool,
+    /// One entry per failed check
+    pub errors: Vec<MercuryErrorDetails>,
+}
+
+impl BackupTxValidation {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct SignatureSchemeValidationReport {
+    pub is_valid: bool,
+    pub backup_txs: Vec<BackupTxValidation>,
+    /// Locktime of the last backup transaction
+    pub previous_lock_time: Option<u32>,
+    pub current_fee_rate_sats_per_byte: f64,
+    pub fee_rate_tolerance: f64,
+}
+
+/// Runs every check on every backup transaction, instead of stopping at the first failure.
+/// Returns the report and the first error found, if any.
+fn build_signature_scheme_report(
+    backup_transactions: &Vec<BackupTx>,
+    statechain_info: &StatechainInfoResponsePayload,
+    tx0_hex: &str,
     current_blockheight: u32,
-    fee_rate_tolerance: f64, 
+    fee_rate_tolerance: f64,
     current_fee_rate_sats_per_byte: f64,
     lockheight_init: u32,
-    interval: u32) -> Result<u32, MercuryError> {
+    interval: u32) -> (SignatureSchemeValidationReport, Option<MercuryError>) {
+
+    let mut first_error: Option<MercuryError> = None;
 
+    // Last lock_time decoded, and whether the transaction just before failed to decode
     let mut previous_lock_time: Option<u32> = None;
+    let mut is_previous_lock_time_missing = false;
 
-    let mut sig_scheme_validation = true;
+    let mut backup_tx_validations = Vec::<BackupTxValidation>::new();
 
     for backup_tx in backup_transactions.iter() {
 
+        let mut errors = Vec::<MercuryError>::new();
+
         let statechain_info = statechain_info.statechain_info
             .iter()
             .find(|info| info.tx_n == backup_tx.tx_n);
 
-        if statechain_info.is_none() {
-            println!("statechain_info not found");
-            sig_scheme_validation = false;
-            break;
+        let mut failure_context = ErrorContext::new().tx_n(backup_tx.tx_n);
+        if let Some(statechain_info) = statechain_info {
+            failure_context = failure_context.statechain_id(&statechain_info.statechain_id);
         }
 
-        let statechain_info = statechain_info.unwrap();
+        let decoded = get_backup_tx_and_tx0_output(&backup_tx.tx, tx0_hex);
 
-        let is_signature_valid = verify_transaction_signature(&backup_tx.tx, &tx0_hex, fee_rate_tolerance, current_fee_rate_sats_per_byte);
-        if is_signature_valid.is_err() {
-            println!("{}", is_signature_valid.err().unwrap().to_string());
-            sig_scheme_validation = false;
-            break;
-        }
+        let fee_rate = decoded.as_ref().ok().and_then(|(tx_n, tx0_output)| get_backup_tx_fee_rate(tx_n, tx0_output).ok());
 
-        let is_blinded_musig_scheme_valid = verify_blinded_musig_scheme(&backup_tx, &tx0_hex, statechain_info);
-        if is_blinded_musig_scheme_valid.is_err() {
-            println!("{}", is_blinded_musig_scheme_valid.err().unwrap().to_string());
-            sig_scheme_validation = false;
-            break;
-        }
+        let fee = decoded.as_ref().ok().and_then(|(tx_n, tx0_output)| get_backup_tx_fee(tx_n, tx0_output).ok());
 
-        if verify_transaction_sequence(&backup_tx.tx).is_err() {
-            println!("transaction sequence is not correct");
-            sig_scheme_validation = false;
-            break;
-        }
+        let signature_result = decoded.as_ref().map_err(|e| e.clone()).and_then(|(tx_n, tx0_output)| verify_backup_tx_signature(tx_n, tx0_output));
 
-        if verify_if_locktime_is_reasonable_tx_version_and_output_size(&backup_tx.tx, current_blockheight, lockheight_init).is_err() {
-            println!("locktime is not reasonable");
-            sig_scheme_validation = false;
-            break;
-        }
+        let fee_rate_result = decoded.as_ref().map_err(|e| e.clone())
+            .and_then(|(tx_n, tx0_output)| get_backup_tx_fee_rate(tx_n, tx0_output))
+            .and_then(|fee_rate| verify_fee_rate(fee_rate, fee_rate_tolerance, current_fee_rate_sats_per_byte));
 
-        if reconstruct_transaction(&backup_tx.tx).is_err() {
-            println!("reconstruction failed");
-            sig_scheme_validation = false;
-            break;
-        }
+        let fee_result = decoded.as_ref().map_err(|e| e.clone())
+            .and_then(|(tx_n, tx0_output)| verify_absolute_fee(tx_n, tx0_output, fee_rate_tolerance, current_fee_rate_sats_per_byte));
+
+        let challenge_result = match statechain_info {
+            Some(statechain_info) => verify_blinded_musig_scheme(&backup_tx, tx0_hex, statechain_info),
+            None => Err(MercuryError::SignatureSchemeValidationError { context: ErrorContext::new().value("statechain_info not found").tx_n(backup_tx.tx_n) }),
+        };
+
+        let sequence_result = verify_transaction_sequence(&backup_tx.tx);
 
-        if previous_lock_time.is_some() {
-            let prev_lock_time = previous_lock_time.unwrap();
-            let current_lock_time = crate::utils::get_blockheight(&backup_tx)?;
-            if (prev_lock_time - current_lock_time) as i32 != interval as i32 {
-                println!("interval is not correct");
-                sig_scheme_validation = false;
-                break;
+        let locktime_result = verify_if_locktime_is_reasonable_tx_version_and_output_size(&backup_tx.tx, current_blockheight, lockheight_init);
+
+        let reconstruction_result = reconstruct_transaction(&backup_tx.tx);
+
+        let lock_time = crate::utils::get_blockheight(&backup_tx);
+
+        let interval_result = match (&lock_time, previous_lock_time) {
+            (Err(error), _) => Err(error.clone()),
+            (Ok(_), _) if is_previous_lock_time_missing => {
+                Err(MercuryError::SignatureSchemeValidationError {
+                    context: failure_context.clone().value("lock_time of the previous transaction not decoded")
+                })
+            },
+            (Ok(current_lock_time), Some(prev_lock_time)) if prev_lock_time as i64 - *current_lock_time as i64 != interval as i64 => {
+                Err(MercuryError::SignatureSchemeValidationError {
+                    context: failure_context.clone().value(format!("interval {}", prev_lock_time as i64 - *current_lock_time as i64)).expected(format!("interval {}", interval))
+                })
+            },
+            _ => Ok(()),
+        };
+
+        let mut check = |result: &Result<(), MercuryError>| -> bool {
+            match result {
+                Ok(()) => true,
+                Err(error) => {
+                    errors.push(error.clone());
+                    false
+                }
+            }
+        };
+
+        let mut validation = BackupTxValidation {
+            tx_n: backup_tx.tx_n,
+            signature_valid: check(&signature_result),
+            fee_rate_valid: check(&fee_rate_result),
+            fee_valid: check(&fee_result),
+            challenge_valid: check(&challenge_result),
+            sequence_valid: check(&sequence_result),
+            locktime_valid: check(&locktime_result),
+            reconstruction_valid: check(&reconstruction_result),
+            interval_valid: check(&interval_result),
+            lock_time: lock_time.as_ref().ok().copied(),
+            fee_rate,
+            fee,
+            errors: Vec::new(),
+        };
+
+        if first_error.is_none() {
+            if let Some(error) = errors.first() {
+                first_error = Some(MercuryError::SignatureSchemeValidationError { context: failure_context.value(error) });
             }
         }
 
-        previous_lock_time = Some(crate::utils::get_blockheight(&backup_tx)?);
-    }
+        validation.errors = errors.iter().map(|error| error.clone().with_tx_n(backup_tx.tx_n).details()).collect();
+
+        is_previous_lock_time_missing = validation.lock_time.is_none();
+        if validation.lock_time.is_some() {
+            previous_lock_time = validation.lock_time;
+        }
 
-    if !sig_scheme_validation {
-        return Err(MercuryError::SignatureSchemeValidationError);
+        backup_tx_validations.push(validation);
     }
 
-    if previous_lock_time.is_none() {
-        return Err(MercuryError::NoPreviousLockTimeError);
+    if backup_tx_validations.is_empty() && first_error.is_none() {
+        first_error = Some(MercuryError::NoPreviousLockTimeError);
     }
 
-    Ok(previous_lock_time.unwrap())
+    let report = SignatureSchemeValidationReport {
+        is_valid: first_error.is_none(),
+        backup_txs: backup_tx_validations,
+        previous_lock_time,
+        current_fee_rate_sats_per_byte,
+        fee_rate_tolerance,
+    };
+
+    (report, first_error)
 }
 
+/// Same checks as `validate_signature_scheme`, but reports the result of each check for each backup transaction.
 #[cfg_attr(feature = "bindings", uniffi::export)]
-pub fn verify_transaction_signature(tx_n_hex: &str, tx0_hex: &str, fee_rate_tolerance: f64, current_fee_rate_sats_per_byte: f64) -> Result<(), MercuryError> {
+pub fn get_signature_scheme_validation_report(
+    backup_transactions: &Vec<BackupTx>,
+    statechain_info: &StatechainInfoResponsePayload,
+    tx0_hex: &str,
+    current_blockheight: u32,
+    fee_rate_tolerance: f64,
+    current_fee_rate_sats_per_byte: f64,
+    lockheight_init: u32,
+    interval: u32) -> SignatureSchemeValidationReport {
 
-    let tx_n: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx_n_hex)?)?;
+    build_signature_scheme_report(backup_transactions, statechain_info, tx0_hex, current_blockheight, fee_rate_tolerance, current_fee_rate_sats_per_byte, lockheight_init, interval).0
+}
 
-    let witness = tx_n.input[0].witness.clone();
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn validate_signature_scheme(
+    backup_transactions: &Vec<BackupTx>, 
+    statechain_info: &StatechainInfoResponsePayload, 
+    tx0_hex: &str, 
+    current_blockheight: u32,
+    fee_rate_tolerance: f64, 
+    current_fee_rate_sats_per_byte: f64,
+    lockheight_init: u32,
+    interval: u32) -> Result<u32, MercuryError> {
 
-    let witness_data = witness.nth(0).unwrap();
+    let (report, error) = build_signature_scheme_report(backup_transactions, statechain_info, tx0_hex, current_blockheight, fee_rate_tolerance, current_fee_rate_sats_per_byte, lockheight_init, interval);
 
-    // the last element is the hash type
-    let signature_data = witness_data.split_last().unwrap().1;
+    if let Some(error) = error {
+        return Err(error);
+    }
+
+    report.previous_lock_time.ok_or(MercuryError::NoPreviousLockTimeError)
+}
 
-    let signature = Signature::from_slice(signature_data).unwrap();
+/// Decodes the backup transaction and the output of tx0 it spends
+fn get_backup_tx_and_tx0_output(tx_n_hex: &str, tx0_hex: &str) -> Result<(Transaction, TxOut), MercuryError> {
+
+    let tx_n: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx_n_hex)?)?;
 
     let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx0_hex)?)?;
 
-    let vout = tx_n.input[0].previous_output.vout as usize;
+    let vout = tx_n.input.first().ok_or(MercuryError::EmptyInput)?.previous_output.vout as usize;
+
+    let tx0_output = get_tx_output(&tx0, vout)?;
+
+    Ok((tx_n, tx0_output))
+}
+
+/// Absolute fee of the backup transaction in sats
+fn get_backup_tx_fee(tx_n: &Transaction, tx0_output: &TxOut) -> Result<u64, MercuryError> {
+
+    // the anchor output, if any, is also paid by the backup transaction input
+    let total_output_value = tx_n.output.iter().try_fold(0u64, |total, output| total.checked_add(output.value))
+        .ok_or(MercuryError::FeeTooLow { context: ErrorContext::new().value("output value overflow") })?;
 
-    let tx0_output = tx0.output[vout].clone();
+    tx0_output.value.checked_sub(total_output_value)
+        .ok_or(MercuryError::FeeTooLow { context: ErrorContext::new().value(total_output_value).expected(format!("<= {}", tx0_output.value)) })
+}
+
+/// Fee rate of the backup transaction in sats/vbyte
+fn get_backup_tx_fee_rate(tx_n: &Transaction, tx0_output: &TxOut) -> Result<f64, MercuryError> {
+
+    let fee = get_backup_tx_fee(tx_n, tx0_output)?;
+
+    Ok(fee as f64 / tx_n.vsize() as f64)
+}
 
-    let xonly_pubkey = XOnlyPublicKey::from_slice(tx0_output.script_pubkey[2..].as_bytes()).unwrap();
+/// Checks the absolute fee against the one expected for the weight of the signed transaction,
+/// which cannot be lowered by padding the witness, and never accepts less than the minimum relay fee.
+fn verify_absolute_fee(tx_n: &Transaction, tx0_output: &TxOut, fee_rate_tolerance: f64, current_fee_rate_sats_per_byte: f64) -> Result<(), MercuryError> {
+
+    let fee = get_backup_tx_fee(tx_n, tx0_output)?;
+
+    let vsize = get_signed_tx_vsize(&tx_n.output) as f64;
+
+    let min_relay_fee = (vsize * MIN_RELAY_FEE_RATE).ceil() as u64;
+    let min_fee = std::cmp::max(((current_fee_rate_sats_per_byte - fee_rate_tolerance) * vsize).floor() as u64, min_relay_fee);
+    let max_fee = std::cmp::max(((current_fee_rate_sats_per_byte + fee_rate_tolerance) * vsize).ceil() as u64, min_fee);
+
+    let expected_fee = format!("{} to {} sats", min_fee, max_fee);
+
+    if fee < min_fee {
+        return Err(MercuryError::FeeTooLow { context: ErrorContext::new().value(format!("{} sats", fee)).expected(expected_fee) });
+    }
+
+    if fee > max_fee {
+        return Err(MercuryError::FeeTooHigh { context: ErrorContext::new().value(format!("{} sats", fee)).expected(expected_fee) });
+    }
+
+    let backup_output = tx_n.output.first().ok_or(MercuryError::EmptyOutput)?;
+    let dust_value = backup_output.script_pubkey.dust_value().to_sat();
+
+    if backup_output.value < dust_value {
+        return Err(MercuryError::AmountBelowDust { context: ErrorContext::new().value(backup_output.value).expected(format!(">= {}", dust_value)) });
+    }
+
+    Ok(())
+}
+
+fn verify_fee_rate(fee_rate: f64, fee_rate_tolerance: f64, current_fee_rate_sats_per_byte: f64) -> Result<(), MercuryError> {
+
+    let expected_fee_rate = format!("{} to {} sats/vbyte", current_fee_rate_sats_per_byte - fee_rate_tolerance, current_fee_rate_sats_per_byte + fee_rate_tolerance);
+
+    if (fee_rate + fee_rate_tolerance) < current_fee_rate_sats_per_byte {
+        return Err(MercuryError::FeeTooLow { context: ErrorContext::new().value(format!("{} sats/vbyte", fee_rate)).expected(expected_fee_rate) });
+    }
+
+    if (fee_rate - fee_rate_tolerance) > current_fee_rate_sats_per_byte {
+        return Err(MercuryError::FeeTooHigh { context: ErrorContext::new().value(format!("{} sats/vbyte", fee_rate)).expected(expected_fee_rate) });
+    }
+
+    Ok(())
+}
+
+fn verify_backup_tx_signature(tx_n: &Transaction, tx0_output: &TxOut) -> Result<(), MercuryError> {
+
+    let input = tx_n.input.first().ok_or(MercuryError::EmptyInput)?;
+
+    let witness_data = input.witness.nth(0).ok_or(MercuryError::EmptyWitness)?;
+
+    // the last element is the hash type
+    let signature_data = witness_data.split_last().ok_or(MercuryError::EmptyWitnessData)?.1;
+
+    let signature = Signature::from_slice(signature_data)?;
+
+    let xonly_pubkey = get_p2tr_xonly_pubkey(&tx0_output.script_pubkey)?;
 
     let sighash_type = TapSighashType::All;
 
@@ -367,27 +593,29 @@ pub fn verify_transaction_signature(tx_n_hex: &str, tx0_hex: &str, fee_rate_tole
             script_pubkey: tx0_output.script_pubkey.clone(),
         }]),
         sighash_type,
-    ).unwrap();
+    )?;
 
     let msg: Message = hash.into();
 
-    let fee = tx0_output.value - tx_n.output[0].value;
-    let fee_rate = fee as f64 / tx_n.vsize() as f64;
-
-    if (fee_rate + fee_rate_tolerance) < current_fee_rate_sats_per_byte {
-        return Err(MercuryError::FeeTooLow);
-    }
-
-    if (fee_rate - fee_rate_tolerance) > current_fee_rate_sats_per_byte {
-        return Err(MercuryError::FeeTooHigh);
-    }
-
     if !Secp256k1::new().verify_schnorr(&signature, &msg, &xonly_pubkey).is_ok() {
-        return Err(MercuryError::InvalidSignature);
+        return Err(MercuryError::InvalidSignature { context: ErrorContext::new().value(signature) });
     }
 
     Ok(())
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn verify_transaction_signature(tx_n_hex: &str, tx0_hex: &str, fee_rate_tolerance: f64, current_fee_rate_sats_per_byte: f64) -> Result<(), MercuryError> {
+
+    let (tx_n, tx0_output) = get_backup_tx_and_tx0_output(tx_n_hex, tx0_hex)?;
+
+    let fee_rate = get_backup_tx_fee_rate(&tx_n, &tx0_output)?;
+
+    verify_fee_rate(fee_rate, fee_rate_tolerance, current_fee_rate_sats_per_byte)?;
+
+    verify_absolute_fee(&tx_n, &tx0_output, fee_rate_tolerance, current_fee_rate_sats_per_byte)?;
 
+    verify_backup_tx_signature(&tx_n, &tx0_output)
 }
 
 #[cfg_attr(feature = "bindings", uniffi::export)]
@@ -400,13 +628,13 @@ pub fn verify_transaction_sequence(tx_n_hex: &str) -> Result<(), MercuryError> {
     }
 
     if tx_n.input.len() > 1 {
-        return Err(MercuryError::Tx1HasMoreThanOneInput);
+        return Err(MercuryError::Tx1HasMoreThanOneInput { context: ErrorContext::new().value(tx_n.input.len()).expected(1) });
     }
 
     let input = tx_n.input.first().unwrap();
 
     if input.sequence != Sequence(0) {
-        return Err(MercuryError::TransactionSequenceDifferentThanZeroError);
+        return Err(MercuryError::TransactionSequenceDifferentThanZeroError { context: ErrorContext::new().value(input.sequence).expected(Sequence(0)) });
     }
 
     Ok(())
@@ -417,25 +645,44 @@ pub fn verify_if_locktime_is_reasonable_tx_version_and_output_size(tx_n_hex: &st
     let tx_n: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx_n_hex)?)?;
 
     if tx_n.version != 2 {
-        return Err(MercuryError::TransactionVersionError);
+        return Err(MercuryError::TransactionVersionError { context: ErrorContext::new().value(tx_n.version).expected(2) });
     }
 
-    if tx_n.output.len() > 1 {
-        return Err(MercuryError::TxHasMoreThanOneOutput);
-    }
+    verify_backup_tx_output_layout(&tx_n)?;
 
     let lock_time = tx_n.lock_time;
 
     if !(lock_time.is_block_height()) {
-        return Err(MercuryError::LocktimeNotBlockHeightError);
+        return Err(MercuryError::LocktimeNotBlockHeightError { context: ErrorContext::new().value(lock_time) });
     }
 
+    let expected_lock_time = format!("{} to {}", current_blockheight + 1, current_blockheight + lockheight_init);
+
     if lock_time.to_consensus_u32() <= current_blockheight {
-        return Err(MercuryError::LocktimeTooLow);
+        return Err(MercuryError::LocktimeTooLow { context: ErrorContext::new().value(lock_time).expected(expected_lock_time) });
     }
 
     if current_blockheight + lockheight_init < lock_time.to_consensus_u32() {
-        return Err(MercuryError::LocktimeTooHigh);
+        return Err(MercuryError::LocktimeTooHigh { context: ErrorContext::new().value(lock_time).expected(expected_lock_time) });
+    }
+
+    Ok(())
+}
+
+/// A backup transaction has either a single output (the backup address)
+/// or the backup output followed by an anchor output.
+pub fn verify_backup_tx_output_layout(tx_n: &Transaction) -> Result<(), MercuryError> {
+
+    if tx_n.output.is_empty() {
+        return Err(MercuryError::TransactionReconstructionError { context: ErrorContext::new().value("no outputs") });
+    }
+
+    if tx_n.output.len() > 2 {
+        return Err(MercuryError::TxHasMoreThanOneOutput { context: ErrorContext::new().value(tx_n.output.len()).expected("1 or 2 (with anchor)") });
+    }
+
+    if tx_n.output.len() == 2 && !is_anchor_output(&tx_n.output[1], &tx_n.output[0]) {
+        return Err(MercuryError::InvalidAnchorOutput { context: ErrorContext::new().value(hex::encode(tx_n.output[1].script_pubkey.as_bytes())) });
     }
 
     Ok(())
@@ -445,22 +692,25 @@ pub fn reconstruct_transaction(tx_n_hex: &str) -> Result<(), MercuryError> {
 
     let tx_n: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx_n_hex)?)?;
 
-    // this assumes that the transaction has only one input and one output (suposedly checked before)
-    let output = tx_n.output[0].clone();
-    let input = tx_n.input[0].clone();
+    verify_backup_tx_output_layout(&tx_n)?;
+
+    // this assumes that the transaction has only one input (suposedly checked before)
+    // and the backup output, optionally followed by an anchor output
+    let outputs = tx_n.output.clone();
+    let input = tx_n.input.first().ok_or(MercuryError::EmptyInput)?.clone();
     let locktime = tx_n.lock_time;
 
     let new_tx = Transaction {
         version: 2,
         lock_time: locktime,
         input: [input].to_vec(),
-        output: [output].to_vec(),
+        output: outputs,
     };
 
     let serialized_new_tx = hex::encode(bitcoin::consensus::encode::serialize(&new_tx));
 
     if tx_n_hex != serialized_new_tx {
-        return Err(MercuryError::TransactionReconstructionError);
+        return Err(MercuryError::TransactionReconstructionError { context: ErrorContext::new().value(tx_n.txid()) });
     }
 
     Ok(())
@@ -468,7 +718,9 @@ pub fn reconstruct_transaction(tx_n_hex: &str) -> Result<(), MercuryError> {
 
 fn get_tx_hash(tx_0: &Transaction, tx_n: &Transaction) -> Result<Message, MercuryError> {
 
-    let witness = tx_n.input[0].witness.clone();
+    let input = tx_n.input.first().ok_or(MercuryError::EmptyInput)?;
+
+    let witness = input.witness.clone();
 
     if witness.nth(0).is_none() {
         return Err(MercuryError::EmptyWitness);
@@ -476,9 +728,9 @@ fn get_tx_hash(tx_0: &Transaction, tx_n: &Transaction) -> Result<Message, Mercur
 
     let witness_data = witness.nth(0).unwrap();
 
-    let vout = tx_n.input[0].previous_output.vout as usize;
+    let vout = input.previous_output.vout as usize;
 
-    let tx_0_output = tx_0.output[vout].clone();
+    let tx_0_output = get_tx_output(tx_0, vout)?;
 
     if witness_data.last().is_none() {
         return Err(MercuryError::EmptyWitnessData);
@@ -548,7 +800,7 @@ pub fn verify_blinded_musig_scheme(backup_tx: &BackupTx, tx0_hex: &str, statecha
     let challenge = hex::encode(challenge);
 
     if statechain_info.challenge != challenge {
-        return Err(MercuryError::IncorrectChallenge);
+        return Err(MercuryError::IncorrectChallenge { context: ErrorContext::new().value(challenge).expected(&statechain_info.challenge).tx_n(backup_tx.tx_n).statechain_id(&statechain_info.statechain_id) });
     }
 
     Ok(())
@@ -649,13 +901,13 @@ pub fn get_new_key_info(server_public_key_hex: &str, coin: &Coin, statechain_id:
 
     let aggregate_address = Address::p2tr(&secp, aggregated_xonly_pubkey, None, network);
 
-    let xonly_pubkey = XOnlyPublicKey::from_slice(aggregate_address.script_pubkey()[2..].as_bytes()).unwrap();
+    let xonly_pubkey = get_p2tr_xonly_pubkey(&aggregate_address.script_pubkey())?;
 
     let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&tx0_hex)?)?;
 
-    let tx0_output = tx0.output[tx0_outpoint.vout as usize].clone();
+    let tx0_output = get_tx_output(&tx0, tx0_outpoint.vout as usize)?;
 
-    let tx0_output_xonly_pubkey = XOnlyPublicKey::from_slice(tx0_output.script_pubkey[2..].as_bytes()).unwrap();
+    let tx0_output_xonly_pubkey = get_p2tr_xonly_pubkey(&tx0_output.script_pubkey)?;
 
     if tx0_output_xonly_pubkey != xonly_pubkey {
         return Err(MercuryError::IncorrectAggregatedPublicKey);
@@ -671,6 +923,6 @@ pub fn get_new_key_info(server_public_key_hex: &str, coin: &Coin, statechain_id:
         aggregate_pubkey: aggregate_pubkey.to_string(),
         aggregate_address: p2tr_agg_address.to_string(),
         signed_statechain_id: signed_statechain_id.to_string(),
-        amount: tx0_output.value as u32,
+        amount: tx0_output.value,
     })
 }
\ No newline at end of file
@@ -3,14 +3,14 @@ use std::str::FromStr;
 use bitcoin::{secp256k1, hashes::sha256, Txid, PrivateKey};
 use secp256k1_zkp::{Secp256k1, Message, Scalar};
 use serde::{Serialize, Deserialize};
-use serde_json::json;
 
 use crate::{decode_transfer_address, error::MercuryError, wallet::{BackupTx, Coin}};
 
-use super::TransferMsg;
+use super::{encoding::serialize_transfer_msg, TransferMsg};
 
 #[derive(Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct PaymentHashRequestPayload {
     pub statechain_id: String,
     pub auth_sig: String, // signed_statechain_id
@@ -19,12 +19,14 @@ pub struct PaymentHashRequestPayload {
 
 #[derive(Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct PaymentHashResponsePayload {
     pub hash: String,
 }
 
 #[derive(Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct TransferSenderRequestPayload {
     pub statechain_id: String,
     pub auth_sig: String, // signed_statechain_id
@@ -34,12 +36,14 @@ pub struct TransferSenderRequestPayload {
 
 #[derive(Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct TransferSenderResponsePayload {
     pub x1: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct TransferUpdateMsgRequestPayload {
     pub statechain_id: String,
     pub auth_sig: String, // signed_statechain_id
@@ -49,6 +53,7 @@ pub struct TransferUpdateMsgRequestPayload {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct TransferPreimageRequestPayload {
     pub statechain_id: String,
     pub auth_sig: String, // signed_statechain_id
@@ -58,6 +63,7 @@ pub struct TransferPreimageRequestPayload {
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct TransferPreimageResponsePayload {
     pub preimage: String,// signed_statechain_id
 }
@@ -112,14 +118,10 @@ pub fn create_transfer_update_msg(x1: &str, recipient_address: &str, coin: &Coin
         user_public_key: client_public_key,
     };
 
-    let transfer_msg_json = json!(&transfer_msg);
-
-    let transfer_msg_json_str = serde_json::to_string_pretty(&transfer_msg_json)?;
-
-    let msg = transfer_msg_json_str.as_bytes();
+    let msg = serialize_transfer_msg(&transfer_msg)?;
 
     let serialized_new_auth_pubkey = &recipient_auth_pubkey.serialize();
-    let encrypted_msg = ecies::encrypt(serialized_new_auth_pubkey, msg);
+    let encrypted_msg = ecies::encrypt(serialized_new_auth_pubkey, &msg);
 
     if encrypted_msg.is_err() {
         return Err(MercuryError::SecpError);
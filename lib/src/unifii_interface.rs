@@ -5,7 +5,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{decode_transfer_address, transfer::{receiver::{StatechainInfoResponsePayload, TransferReceiverRequestPayload}, TransferMsg, TxOutpoint}, wallet::{BackupTx, Coin, Wallet}, MercuryError};
+use crate::{decode_statechain_address_data, transfer::{receiver::{StatechainInfoResponsePayload, TransferReceiverRequestPayload}, TransferMsg, TxOutpoint}, wallet::{BackupTx, Coin, Wallet}, MercuryError, ScAddressOptions};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
@@ -13,6 +13,9 @@ pub struct DecodedSCAddress {
     version: u8,
     user_pubkey: String,
     auth_pubkey: String,
+    network: Option<String>,
+    server_hint: Option<String>,
+    expiry: Option<u64>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -30,15 +33,28 @@ pub fn get_new_coin(wallet: &Wallet) -> Result<Coin, MercuryError> {
     wallet.get_new_coin()
 }
 
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn get_new_coin_with_address_options(wallet: &Wallet, address_options: &ScAddressOptions) -> Result<Coin, MercuryError> {
+    wallet.get_new_coin_with_address_options(address_options)
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn get_coin_at_index(wallet: &Wallet, address_index: u32) -> Result<Coin, MercuryError> {
+    wallet.get_coin_at_index(address_index)
+}
+
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn decode_statechain_address(sc_address: String) -> Result<DecodedSCAddress, MercuryError> {
     
-    let (version, user_pubkey, auth_pubkey) = decode_transfer_address(&sc_address)?;
+    let sc_address = decode_statechain_address_data(&sc_address)?;
 
     Ok(DecodedSCAddress {
-        version,
-        user_pubkey: user_pubkey.to_string(),
-        auth_pubkey: auth_pubkey.to_string(),
+        version: sc_address.version,
+        user_pubkey: sc_address.user_pubkey.to_string(),
+        auth_pubkey: sc_address.auth_pubkey.to_string(),
+        network: sc_address.network.map(|network| network.to_string()),
+        server_hint: sc_address.server_hint,
+        expiry: sc_address.expiry,
     })
 }
 
@@ -3,10 +3,11 @@ use std::str::FromStr;
 use bitcoin::Transaction;
 use serde::{Serialize, Deserialize};
 
-use crate::{wallet::{BackupTx, Coin}, MercuryError};
+use crate::{error::ErrorContext, transaction::AnchorOutputType, wallet::{BackupTx, Coin, DerivationScheme, KeyRotationEvidence}, MercuryError};
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct ServerConfig {
     pub initlock: u32,
     pub interval: u32,
@@ -24,6 +25,7 @@ pub struct InfoConfig {
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct PubKeyInfo {
     pub server_pubkey: String,
     pub tx_n: u32,
@@ -32,6 +34,7 @@ pub struct PubKeyInfo {
 
 #[derive(Debug, Serialize, Deserialize)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct KeyListResponsePayload {
     pub list_keyinfo: Vec<PubKeyInfo>,
 }
@@ -42,7 +45,23 @@ pub fn get_network(network: &str) -> Result<bitcoin::Network, MercuryError> {
         "testnet" => Ok(bitcoin::Network::Testnet),
         "regtest" => Ok(bitcoin::Network::Regtest),
         "bitcoin" => Ok(bitcoin::Network::Bitcoin),
-        _ => Err(MercuryError::NetworkConversionError)
+        _ => Err(MercuryError::NetworkConversionError { context: ErrorContext::new().value(network).expected("signet, testnet, regtest or bitcoin") })
+    }
+}
+
+pub fn get_derivation_scheme(derivation_scheme: &str) -> Result<DerivationScheme, MercuryError> {
+    match derivation_scheme {
+        "legacy" => Ok(DerivationScheme::Legacy),
+        "bip44" => Ok(DerivationScheme::Bip44),
+        _ => Err(MercuryError::UnknownDerivationScheme { context: ErrorContext::new().value(derivation_scheme).expected("legacy or bip44") })
+    }
+}
+
+pub fn get_anchor_output_type(anchor_type: &str) -> Result<AnchorOutputType, MercuryError> {
+    match anchor_type {
+        "p2a" => Ok(AnchorOutputType::P2A),
+        "p2tr" => Ok(AnchorOutputType::UserKeyP2TR),
+        _ => Err(MercuryError::UnknownAnchorOutputType { context: ErrorContext::new().value(anchor_type).expected("p2a or p2tr") })
     }
 }
 
@@ -53,7 +72,7 @@ pub fn get_blockheight(bkp_tx: &BackupTx) -> Result<u32, MercuryError> {
 
     let lock_time = tx1.lock_time;
     if !(lock_time.is_block_height()) {
-        return Err(MercuryError::LocktimeNotBlockHeightError);
+        return Err(MercuryError::LocktimeNotBlockHeightError { context: ErrorContext::new().value(lock_time).tx_n(bkp_tx.tx_n) });
     }
     let block_height = lock_time.to_consensus_u32();
 
@@ -78,4 +97,56 @@ pub fn is_enclave_pubkey_part_of_coin(coin: &Coin, enclave_pubkey: &str) -> Resu
     let coin_aggregated_pubkey = secp256k1_zkp::PublicKey::from_str(coin_aggregated_pubkey)?;
 
     return Ok(aggregate_enclave_pubkey == coin_aggregated_pubkey);
-}
\ No newline at end of file
+}
+
+/// Checks, after a transfer, that the server key share of the previous owner was replaced.
+/// The current share of the coin must be in the keylist published by the server,
+/// and the previous share must neither be in the keylist nor be part of the coin.
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn verify_key_rotation(coin: &Coin, previous_server_pubkey: &str, keylist: &KeyListResponsePayload) -> Result<KeyRotationEvidence, MercuryError> {
+
+    let (statechain_id, current_server_pubkey) = match (coin.statechain_id.as_ref(), coin.server_pubkey.as_ref()) {
+        (Some(statechain_id), Some(server_pubkey)) => (statechain_id.clone(), server_pubkey.clone()),
+        _ => return Err(MercuryError::KeyRotationNotVerified { context: ErrorContext::new().value("coin has no statechain_id or server_pubkey") }),
+    };
+
+    let previous_key = secp256k1_zkp::PublicKey::from_str(previous_server_pubkey)?;
+    let current_key = secp256k1_zkp::PublicKey::from_str(&current_server_pubkey)?;
+
+    if previous_key == current_key {
+        return Err(MercuryError::KeyRotationNotVerified { context: ErrorContext::new().value(format!("server key {} was not rotated", previous_key)).statechain_id(&statechain_id) });
+    }
+
+    if !is_enclave_pubkey_part_of_coin(coin, &current_server_pubkey)? {
+        return Err(MercuryError::KeyRotationNotVerified { context: ErrorContext::new().value(format!("server key {} is not part of the coin", current_key)).statechain_id(&statechain_id) });
+    }
+
+    if is_enclave_pubkey_part_of_coin(coin, previous_server_pubkey)? {
+        return Err(MercuryError::KeyRotationNotVerified { context: ErrorContext::new().value(format!("previous server key {} is still part of the coin", previous_key)).statechain_id(&statechain_id) });
+    }
+
+    let mut current_keyinfo = None;
+
+    for keyinfo in keylist.list_keyinfo.iter() {
+        let server_pubkey = secp256k1_zkp::PublicKey::from_str(&keyinfo.server_pubkey)?;
+
+        if server_pubkey == previous_key {
+            return Err(MercuryError::KeyRotationNotVerified { context: ErrorContext::new().value(format!("previous server key {} is in the keylist", previous_key)).statechain_id(&statechain_id) });
+        }
+
+        if server_pubkey == current_key {
+            current_keyinfo = Some(keyinfo);
+        }
+    }
+
+    let current_keyinfo = current_keyinfo.ok_or(MercuryError::KeyRotationNotVerified { context: ErrorContext::new().value(format!("server key {} is not in the keylist", current_key)).statechain_id(&statechain_id) })?;
+
+    Ok(KeyRotationEvidence {
+        statechain_id,
+        previous_server_pubkey: previous_key.to_string(),
+        current_server_pubkey: current_key.to_string(),
+        keylist_tx_n: Some(current_keyinfo.tx_n),
+        keylist_created_at: Some(current_keyinfo.created_at.clone()),
+        error: None,
+    })
+}
@@ -1,10 +1,11 @@
 use std::{str::FromStr, collections::BTreeMap};
 
-use crate::{error::MercuryError, utils::get_network};
+use crate::{error::{ErrorContext, MercuryError}, transaction::{get_p2a_script_pubkey, MIN_RELAY_FEE_RATE}, transfer::receiver::verify_backup_tx_output_layout, utils::get_network};
 
 use super::{BackupTx, Coin};
-use bitcoin::{Transaction, Address, TxOut, Txid, OutPoint, TxIn, ScriptBuf, Witness, absolute, psbt::{Psbt, Input, PsbtSighashType, self}, bip32::{Fingerprint, DerivationPath}, Amount, Network, sighash::{TapSighashType, SighashCache, self, TapSighash}, taproot::{TapLeafHash, self}, secp256k1, key::TapTweak, PrivateKey};
-use secp256k1_zkp::{Secp256k1, SecretKey, PublicKey, XOnlyPublicKey};
+use bitcoin::{Transaction, Address, TxOut, Txid, OutPoint, TxIn, ScriptBuf, Witness, Sequence, absolute, psbt::{Psbt, Input, PsbtSighashType, self}, bip32::{Fingerprint, DerivationPath}, sighash::{TapSighashType, SighashCache, self, TapSighash}, taproot::{TapLeafHash, self}, secp256k1, key::TapTweak, PrivateKey};
+use secp256k1_zkp::{Secp256k1, SecretKey, XOnlyPublicKey};
+use serde::{Serialize, Deserialize};
 
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn latest_backup_tx_pays_to_user_pubkey(backup_txs: &Vec<BackupTx>, coin: &Coin, network: &str) -> Result<BackupTx, MercuryError> {
@@ -18,9 +19,7 @@ pub fn latest_backup_tx_pays_to_user_pubkey(backup_txs: &Vec<BackupTx>, coin: &C
             let tx_bytes = hex::decode(&bkp_tx.tx).ok()?;
             let tx: Transaction = bitcoin::consensus::deserialize(&tx_bytes).ok()?;
             
-            if tx.output.len() != 1 {
-                return None;
-            }
+            verify_backup_tx_output_layout(&tx).ok()?;
 
             let output: &TxOut = tx.output.get(0)?;
             if backup_address.script_pubkey() == output.script_pubkey {
@@ -36,58 +35,164 @@ pub fn latest_backup_tx_pays_to_user_pubkey(backup_txs: &Vec<BackupTx>, coin: &C
             return Ok(tx.clone());
         },
         None => {
-            return Err(MercuryError::NoBackupTransactionFound);
+            return Err(MercuryError::NoBackupTransactionFound { context: ErrorContext::new().expected(&coin.backup_address) });
         }
     }
 }
 
 
+/// A stuck backup transaction to be fee-bumped, together with the coin it belongs to.
+/// The coin is required to know the value of the Tx0 output (and therefore the fee paid by
+/// the backup transaction) and the key that can spend the backup output.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct CpfpParent {
+    pub backup_tx: BackupTx,
+    pub coin: Coin,
+}
+
+/// An additional P2TR (key path) UTXO used to fund the child when the backup outputs
+/// alone cannot pay for the target package fee rate.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct CpfpExtraInput {
+    pub txid: String,
+    pub vout: u32,
+    pub amount: u64,
+    pub privkey_wif: String,
+}
+
+struct CpfpInput {
+    outpoint: OutPoint,
+    txout: TxOut,
+    /// P2A anchor outputs are spent with an empty witness and have no key
+    secret_key: Option<SecretKey>,
+    key_origin: Option<(Fingerprint, DerivationPath)>,
+}
+
 #[cfg_attr(feature = "bindings", uniffi::export)]
 pub fn create_cpfp_tx(backup_tx: &BackupTx, coin: &Coin, to_address: &str, fee_rate_sats_per_byte: f64, network: &str) -> Result<String, MercuryError> {
 
-    let network = get_network(network)?;
+    let parent = CpfpParent {
+        backup_tx: backup_tx.clone(),
+        coin: coin.clone(),
+    };
 
-    let tx_bytes = hex::decode(&backup_tx.tx)?;
-    let tx: Transaction = bitcoin::consensus::deserialize(&tx_bytes)?;
+    create_cpfp_package_tx(&vec![parent], &Vec::new(), to_address, fee_rate_sats_per_byte, network)
+}
 
-    if tx.output.len() != 1 {
-        return Err(MercuryError::UnkownNetwork);
+/// Creates a child transaction that spends the outputs of one or more backup transactions (the parents)
+/// and, optionally, extra wallet inputs. The child fee is calculated so that the whole package
+/// (parents + child) reaches `fee_rate_sats_per_byte`, taking into account the fee already paid by the parents.
+/// If the parents already pay more than the target, the child still pays the target fee rate for its own size.
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_cpfp_package_tx(parents: &Vec<CpfpParent>, extra_inputs: &Vec<CpfpExtraInput>, to_address: &str, fee_rate_sats_per_byte: f64, network: &str) -> Result<String, MercuryError> {
+
+    if parents.is_empty() {
+        return Err(MercuryError::NoBackupTransactionFound { context: ErrorContext::new() });
     }
 
-    let output: &TxOut = tx.output.get(0).unwrap();
+    let network = get_network(network)?;
 
-    let backup_address = Address::from_str(coin.backup_address.as_str())?.require_network(network)?;
+    let secp = Secp256k1::new();
+
+    let mut inputs = Vec::<CpfpInput>::new();
+    let mut parents_fee: u64 = 0;
+    let mut parents_vsize: u64 = 0;
+
+    for parent in parents.iter() {
+
+        let coin = &parent.coin;
+
+        let tx_bytes = hex::decode(&parent.backup_tx.tx)?;
+        let tx: Transaction = bitcoin::consensus::deserialize(&tx_bytes)?;
+
+        verify_backup_tx_output_layout(&tx).map_err(|e| e.with_tx_n(parent.backup_tx.tx_n))?;
+
+        let output: &TxOut = tx.output.first().ok_or(MercuryError::EmptyOutput)?;
+
+        let backup_address = Address::from_str(coin.backup_address.as_str())?.require_network(network)?;
+
+        if backup_address.script_pubkey() != output.script_pubkey {
+            return Err(MercuryError::BackupTransactionDoesNotPayUser { context: ErrorContext::new().expected(backup_address).tx_n(parent.backup_tx.tx_n) });
+        }
+
+        // The backup transaction spends the Tx0 output, whose value is the coin amount
+        let parent_input_amount = coin.amount.ok_or(MercuryError::CoinAmountNotSet { context: ErrorContext::new().tx_n(parent.backup_tx.tx_n) })?;
+
+        let parent_output_value: u64 = tx.output.iter().map(|output| output.value).sum();
+        let parent_fee = parent_input_amount.checked_sub(parent_output_value)
+            .ok_or(MercuryError::FeeTooLow { context: ErrorContext::new().value(parent_output_value).expected(format!("<= {}", parent_input_amount)).tx_n(parent.backup_tx.tx_n) })?;
+
+        parents_fee += parent_fee;
+        parents_vsize += tx.vsize() as u64;
+
+        let user_secret_key = PrivateKey::from_wif(&coin.user_privkey)?.inner;
+        let key_origin = (
+            Fingerprint::from_str(&coin.fingerprint)?,
+            DerivationPath::from_str(&coin.derivation_path)?,
+        );
 
-    if backup_address.script_pubkey() != output.script_pubkey {
-        return Err(MercuryError::BackupTransactionDoesNotPayUser);
+        // Spend the backup output and, if present, the anchor output
+        for (vout, output) in tx.output.iter().enumerate() {
+
+            let is_p2a = output.script_pubkey == get_p2a_script_pubkey();
+
+            inputs.push(CpfpInput {
+                outpoint: OutPoint { txid: tx.txid(), vout: vout as u32 },
+                txout: output.clone(),
+                secret_key: if is_p2a { None } else { Some(user_secret_key) },
+                key_origin: if is_p2a { None } else { Some(key_origin.clone()) },
+            });
+        }
     }
 
-    let input_tx_hash = tx.txid();
-    let input_vout = 0;
+    for extra_input in extra_inputs.iter() {
+
+        let secret_key = PrivateKey::from_wif(&extra_input.privkey_wif)?.inner;
+        let x_only_public_key = secret_key.x_only_public_key(&secp).0;
+        let address = Address::p2tr(&secp, x_only_public_key, None, network);
+
+        inputs.push(CpfpInput {
+            outpoint: OutPoint { txid: Txid::from_str(&extra_input.txid)?, vout: extra_input.vout },
+            txout: TxOut { value: extra_input.amount, script_pubkey: address.script_pubkey() },
+            secret_key: Some(secret_key),
+            key_origin: None,
+        });
+    }
 
     let to_address = Address::from_str(to_address)?.require_network(network)?;
 
-    let input_amount: u64 = output.value;
+    let input_amount: u64 = inputs.iter().map(|input| input.txout.value).sum();
 
+    // The first transaction is only used to measure the size of the child.
+    // Schnorr signatures have fixed size, so the final transaction has the same vsize.
     let outputs = vec![
         TxOut { value: input_amount, script_pubkey: to_address.script_pubkey() },
     ];
 
-    let tx = create_transaction(&input_tx_hash, input_vout, &coin, input_amount, &outputs, network)?;
+    let tx = create_transaction(&inputs, &outputs)?;
+
+    let child_vsize = tx.vsize() as u64;
 
-    let absolute_fee: u64 = (tx.vsize() as f64 * fee_rate_sats_per_byte).ceil() as u64;
+    let package_fee = ((parents_vsize + child_vsize) as f64 * fee_rate_sats_per_byte).ceil() as u64;
+    let child_min_fee = (child_vsize as f64 * fee_rate_sats_per_byte.max(MIN_RELAY_FEE_RATE)).ceil() as u64;
 
-    let amount_out = input_amount as i64 - absolute_fee as i64;
+    let child_fee = std::cmp::max(package_fee.saturating_sub(parents_fee), child_min_fee);
 
-    if amount_out < 0 {
-        return Err(MercuryError::FeeTooHigh);
+    let dust_value = to_address.script_pubkey().dust_value().to_sat();
+
+    let amount_out = input_amount.checked_sub(child_fee).unwrap_or(0);
+
+    if amount_out < dust_value {
+        return Err(MercuryError::InsufficientFundsForFeeBump { context: ErrorContext::new().value(input_amount).expected(format!(">= {}", child_fee + dust_value)) });
     }
 
     let outputs = vec![
-        TxOut { value: amount_out as u64, script_pubkey: to_address.script_pubkey() },
+        TxOut { value: amount_out, script_pubkey: to_address.script_pubkey() },
     ];
 
-    let tx = create_transaction(&input_tx_hash, input_vout, &coin, input_amount, &outputs, network)?;
+    let tx = create_transaction(&inputs, &outputs)?;
 
     let tx_bytes = bitcoin::consensus::encode::serialize(&tx);
     let encoded_signed_tx = hex::encode(tx_bytes);
@@ -95,98 +200,94 @@ pub fn create_cpfp_tx(backup_tx: &BackupTx, coin: &Coin, to_address: &str, fee_r
     Ok(encoded_signed_tx)
 }
 
-fn create_transaction(input_tx_hash: &Txid, input_vout: u32, coin: &Coin, input_amount: u64, outputs: &Vec<TxOut>, network: Network) -> Result<Transaction, MercuryError> {
+fn create_transaction(inputs: &Vec<CpfpInput>, outputs: &Vec<TxOut>) -> Result<Transaction, MercuryError> {
 
     let secp = Secp256k1::new();
 
-    let input_utxo = OutPoint { txid: input_tx_hash.clone(), vout: input_vout };
-    let input = TxIn {
-        previous_output: input_utxo,
+    let tx_inputs = inputs.iter().map(|input| TxIn {
+        previous_output: input.outpoint,
         script_sig: ScriptBuf::new(),
-        sequence: bitcoin::Sequence(0xFFFFFFFF), // Ignore nSequence.
+        // Signal replaceability, so the child can be bumped again if necessary
+        sequence: Sequence::ENABLE_RBF_NO_LOCKTIME,
         witness: Witness::default(),
-    };
+    }).collect::<Vec<TxIn>>();
 
     let tx1 = Transaction {
         version: 2,
         lock_time: absolute::LockTime::ZERO,
-        input: [input].to_vec(),
+        input: tx_inputs,
         output: outputs.clone(),
     };
     let mut psbt = Psbt::from_unsigned_tx(tx1)?;
 
-    let input_public_key = PublicKey::from_str(&coin.user_pubkey)?;
-    let input_x_only_public_key = input_public_key.x_only_public_key().0;
-
-    let mut origins = BTreeMap::new();
-    origins.insert(
-        input_x_only_public_key,
-        (
-            vec![],
-            (
-                Fingerprint::from_str(&coin.fingerprint).unwrap(),
-                DerivationPath::from_str(&coin.derivation_path).unwrap(),
-            ),
-        ),
-    );
-
     let mut psbt_inputs = Vec::<Input>::new();
 
-    let backup_address = Address::from_str(coin.backup_address.as_str())?.require_network(network)?;
-    let input_script_pubkey = backup_address.script_pubkey();
+    for cpfp_input in inputs.iter() {
 
-    let mut input = Input {
-        witness_utxo: {
-            let amount = Amount::from_sat(input_amount);
-            Some(TxOut { value: amount.to_sat(), script_pubkey: input_script_pubkey.clone() })
-        },
-        tap_key_origins: origins.clone(),
-        ..Default::default()
-    };
-    let ty = PsbtSighashType::from_str("SIGHASH_ALL").unwrap();
-    input.sighash_type = Some(ty);
-    input.tap_internal_key = Some(input_x_only_public_key);
-    psbt_inputs.push(input);
+        let mut input = Input {
+            witness_utxo: Some(cpfp_input.txout.clone()),
+            ..Default::default()
+        };
 
+        if let Some(secret_key) = &cpfp_input.secret_key {
+            let input_x_only_public_key = secret_key.x_only_public_key(&secp).0;
 
-    psbt.inputs = psbt_inputs;
-
-    // SIGNER
-    let unsigned_tx = psbt.unsigned_tx.clone();
-
-    let mut input_txouts = Vec::<TxOut>::new();
-    input_txouts.push(TxOut { value: input_amount, script_pubkey: input_script_pubkey });
+            if let Some(key_origin) = &cpfp_input.key_origin {
+                input.tap_key_origins.insert(input_x_only_public_key, (vec![], key_origin.clone()));
+            }
 
-    let vout = 0;
-    let input = psbt.inputs.iter_mut().nth(vout).unwrap();
+            let ty = PsbtSighashType::from_str("SIGHASH_ALL")?;
+            input.sighash_type = Some(ty);
+            input.tap_internal_key = Some(input_x_only_public_key);
+        }
 
-    let hash_ty = input
-        .sighash_type
-        .and_then(|psbt_sighash_type| psbt_sighash_type.taproot_hash_ty().ok())
-        .unwrap_or(TapSighashType::All);
+        psbt_inputs.push(input);
+    }
 
-    let hash = SighashCache::new(&unsigned_tx).taproot_key_spend_signature_hash(
-        vout,
-        &sighash::Prevouts::All(&input_txouts.as_slice()),
-        hash_ty,
-    )?;
+    psbt.inputs = psbt_inputs;
 
-    let secret_key = PrivateKey::from_wif(&coin.user_privkey)?.inner;
+    // SIGNER
+    let unsigned_tx = psbt.unsigned_tx.clone();
 
-    sign_psbt_taproot(
-        &secret_key,
-        input.tap_internal_key.unwrap(),
-        None,
-        input,
-        hash,
-        hash_ty,
-        &secp,
-    );
+    let input_txouts = inputs.iter().map(|input| input.txout.clone()).collect::<Vec<TxOut>>();
+
+    for (vout, cpfp_input) in inputs.iter().enumerate() {
+
+        let secret_key = match &cpfp_input.secret_key {
+            Some(secret_key) => secret_key,
+            None => continue,
+        };
+
+        let input = psbt.inputs.iter_mut().nth(vout).unwrap();
+
+        let hash_ty = input
+            .sighash_type
+            .and_then(|psbt_sighash_type| psbt_sighash_type.taproot_hash_ty().ok())
+            .unwrap_or(TapSighashType::All);
+
+        let hash = SighashCache::new(&unsigned_tx).taproot_key_spend_signature_hash(
+            vout,
+            &sighash::Prevouts::All(&input_txouts.as_slice()),
+            hash_ty,
+        )?;
+
+        sign_psbt_taproot(
+            secret_key,
+            input.tap_internal_key.unwrap(),
+            None,
+            input,
+            hash,
+            hash_ty,
+            &secp,
+        );
+    }
 
     // FINALIZER
     psbt.inputs.iter_mut().for_each(|input| {
         let mut script_witness: Witness = Witness::new();
-        script_witness.push(input.tap_key_sig.unwrap().to_vec());
+        if let Some(tap_key_sig) = input.tap_key_sig {
+            script_witness.push(tap_key_sig.to_vec());
+        }
         input.final_script_witness = Some(script_witness);
 
         // Clear all the data fields as per the spec.
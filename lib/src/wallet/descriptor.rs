@@ -0,0 +1,125 @@
+use std::str::FromStr;
+
+use bitcoin::{bip32::{DerivationPath, Fingerprint}, Address};
+use secp256k1_zkp::{PublicKey, Secp256k1};
+use serde::{Deserialize, Serialize};
+
+use crate::{error::{ErrorContext, MercuryError}, utils::get_network};
+
+use super::{Coin, CoinStatus, Wallet};
+
+const INPUT_CHARSET: &str = "0123456789()[],'/*abcdefgh@:$%{}IJKLMNOPQRSTUVWXYZ&+-.;<=>?!^_|~ijklmnopqrstuvwxyzABCDEFGH`#\"\\ ";
+const CHECKSUM_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// Output descriptor of the backup address of a coin
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct CoinDescriptor {
+    pub index: u32,
+    pub statechain_id: Option<String>,
+    pub status: CoinStatus,
+    pub backup_address: String,
+    /// `tr([fingerprint/86h/coin_type/account/0/index]xonly_pubkey)#checksum`
+    pub descriptor: String,
+}
+
+fn poly_mod(mut c: u64, val: u64) -> u64 {
+    let c0 = c >> 35;
+    c = ((c & 0x7ffffffff) << 5) ^ val;
+    if c0 & 1 != 0 { c ^= 0xf5dee51989; }
+    if c0 & 2 != 0 { c ^= 0xa9fdca3312; }
+    if c0 & 4 != 0 { c ^= 0x1bab10e32d; }
+    if c0 & 8 != 0 { c ^= 0x3706b1677a; }
+    if c0 & 16 != 0 { c ^= 0x644d626ffd; }
+    c
+}
+
+/// Descriptor checksum, as defined in BIP 380
+pub fn get_descriptor_checksum(descriptor: &str) -> Result<String, MercuryError> {
+
+    let mut c: u64 = 1;
+    let mut cls: u64 = 0;
+    let mut cls_count = 0;
+
+    for ch in descriptor.chars() {
+        let pos = INPUT_CHARSET.find(ch)
+            .ok_or(MercuryError::InvalidDescriptor { context: ErrorContext::new().value(ch) })? as u64;
+
+        c = poly_mod(c, pos & 31);
+        cls = cls * 3 + (pos >> 5);
+        cls_count += 1;
+
+        if cls_count == 3 {
+            c = poly_mod(c, cls);
+            cls = 0;
+            cls_count = 0;
+        }
+    }
+
+    if cls_count > 0 {
+        c = poly_mod(c, cls);
+    }
+
+    for _ in 0..8 {
+        c = poly_mod(c, 0);
+    }
+
+    c ^= 1;
+
+    let checksum = (0..8)
+        .map(|j| CHECKSUM_CHARSET[((c >> (5 * (7 - j))) & 31) as usize] as char)
+        .collect::<String>();
+
+    Ok(checksum)
+}
+
+/// Returns the `tr()` descriptor, with key origin, of the backup address of the coin
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn get_coin_descriptor(coin: &Coin, network: &str) -> Result<String, MercuryError> {
+
+    let network = get_network(network)?;
+
+    let fingerprint = Fingerprint::from_str(&coin.fingerprint)
+        .map_err(|_| MercuryError::InvalidDescriptor { context: ErrorContext::new().value(&coin.fingerprint).expected("key fingerprint") })?;
+
+    let derivation_path = DerivationPath::from_str(&coin.derivation_path)?;
+
+    let xonly_pubkey = PublicKey::from_str(&coin.user_pubkey)?.x_only_public_key().0;
+
+    // the descriptor must describe the address the backup transactions pay to
+    let backup_address = Address::p2tr(&Secp256k1::new(), xonly_pubkey, None, network);
+
+    if backup_address.to_string() != coin.backup_address {
+        return Err(MercuryError::InvalidDescriptor { context: ErrorContext::new().value(backup_address).expected(&coin.backup_address) });
+    }
+
+    // `DerivationPath` displays as `m/86'/0'/0'/0/0`; descriptors use the `h` notation without the `m`
+    let key_origin = derivation_path.as_ref().iter()
+        .map(|child_number| child_number.to_string().replace('\'', "h"))
+        .fold(fingerprint.to_string(), |key_origin, child_number| format!("{}/{}", key_origin, child_number));
+
+    let descriptor = format!("tr([{}]{})", key_origin, xonly_pubkey);
+
+    let checksum = get_descriptor_checksum(&descriptor)?;
+
+    Ok(format!("{}#{}", descriptor, checksum))
+}
+
+/// Returns the descriptors of the backup addresses of all the coins in the wallet
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn get_wallet_descriptors(wallet: &Wallet) -> Result<Vec<CoinDescriptor>, MercuryError> {
+
+    let mut descriptors = Vec::<CoinDescriptor>::new();
+
+    for coin in wallet.coins.iter() {
+        descriptors.push(CoinDescriptor {
+            index: coin.index,
+            statechain_id: coin.statechain_id.clone(),
+            status: coin.status.clone(),
+            backup_address: coin.backup_address.clone(),
+            descriptor: get_coin_descriptor(coin, &wallet.network)?,
+        });
+    }
+
+    Ok(descriptors)
+}
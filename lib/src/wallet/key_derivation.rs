@@ -4,7 +4,7 @@ use bip39::Mnemonic;
 use bitcoin::{bip32::{ExtendedPrivKey, DerivationPath, ExtendedPubKey, ChildNumber}, Address, PrivateKey};
 use secp256k1_zkp::{SecretKey, PublicKey, ffi::types::AlignedType, Secp256k1};
 
-use crate::{encode_sc_address, error::MercuryError, utils::get_network, wallet::{Coin, CoinStatus, Wallet}};
+use crate::{encode_sc_address_with_options, error::MercuryError, utils::get_network, wallet::{Coin, CoinStatus, Wallet}, ScAddressOptions};
 
 pub struct KeyData {
     pub secret_key: SecretKey,
@@ -18,7 +18,8 @@ pub struct KeyData {
 impl Wallet {
 
     fn get_seed(&self) -> Result<[u8; 64], MercuryError> {
-        let seed: [u8; 64] = Mnemonic::from_str(&self.mnemonic)?.to_seed("");
+        let passphrase = self.passphrase.as_deref().unwrap_or("");
+        let seed: [u8; 64] = Mnemonic::from_str(&self.mnemonic)?.to_seed(passphrase);
         Ok(seed)
     }
 
@@ -71,21 +72,34 @@ impl Wallet {
     }
 
     pub fn get_new_coin(&self) -> Result<Coin, MercuryError> {
+        self.get_coin_at_index(self.get_next_address_index())
+    }
+
+    /// New coin whose statechain address is a v1 address with `address_options`
+    pub fn get_new_coin_with_address_options(&self, address_options: &ScAddressOptions) -> Result<Coin, MercuryError> {
+        self.derive_coin(self.get_next_address_index(), Some(address_options))
+    }
+
+    /// Derives the keys of the coin at `address_index`, used to rebuild a wallet from its mnemonic
+    pub fn get_coin_at_index(&self, address_index: u32) -> Result<Coin, MercuryError> {
+        self.derive_coin(address_index, None)
+    }
+
+    fn derive_coin(&self, address_index: u32, address_options: Option<&ScAddressOptions>) -> Result<Coin, MercuryError> {
 
         let network = get_network(&self.network)?;
 
-        let derivation_path = "m/86h/0h/0h";
+        let derivation_path = self.derivation_scheme.get_user_derivation_path(network, self.account);
         let change_index = 0;
-        let address_index = self.get_next_address_index();
 
-        let agg_key_data = self.generate_new_key(derivation_path, change_index, address_index)?;
+        let agg_key_data = self.generate_new_key(&derivation_path, change_index, address_index)?;
 
         let client_secret_key = agg_key_data.secret_key;
         let client_pubkey_share = agg_key_data.public_key;
         let backup_address = Address::p2tr(&Secp256k1::new(), client_pubkey_share.x_only_public_key().0, None, network);
 
-        let derivation_path = "m/89h/0h/0h";
-        let auth_key_data = self.generate_new_key(derivation_path, change_index, address_index)?;
+        let derivation_path = self.derivation_scheme.get_auth_derivation_path(network, self.account);
+        let auth_key_data = self.generate_new_key(&derivation_path, change_index, address_index)?;
 
         assert!(auth_key_data.fingerprint == agg_key_data.fingerprint);
         assert!(auth_key_data.address_index == agg_key_data.address_index);
@@ -102,7 +116,7 @@ impl Wallet {
         let user_pubkey = client_secret_key.public_key(&secp).to_string();
         let auth_pubkey = auth_secret.public_key(&secp).to_string();
 
-        let coin_address = encode_sc_address(&client_pubkey_share, &auth_key_data.public_key, network)?;
+        let coin_address = encode_sc_address_with_options(&client_pubkey_share, &auth_key_data.public_key, network, address_options)?;
 
         let coin = Coin {
             index: address_index,
@@ -132,6 +146,7 @@ impl Wallet {
             withdrawal_address: None,
             status: CoinStatus::INITIALISED,
             duplicate_index: 0,
+            key_rotations: Vec::new(),
         };
 
         Ok(coin)
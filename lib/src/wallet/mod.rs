@@ -1,20 +1,33 @@
 pub mod key_derivation;
 pub mod cpfp_tx;
+pub mod descriptor;
+pub mod ownership;
+pub mod recovery;
+pub mod watchtower;
 
 use std::{fmt, str::FromStr};
 
 use bip39::{Mnemonic, Language};
-use bitcoin::Transaction;
+use bitcoin::{Network, Transaction};
 use secp256k1_zkp::rand::{self, Rng};
 use serde::{Serialize, Deserialize};
 
-use crate::{transfer::TxOutpoint, utils::ServerConfig, MercuryError};
+use crate::{error::ErrorContext, transfer::TxOutpoint, utils::ServerConfig, MercuryError};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
 pub struct Wallet {
     pub name: String,
     pub mnemonic: String,
+    /// Optional BIP39 passphrase. Clients should store it separately from the mnemonic.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub passphrase: Option<String>,
+    /// Wallets created before the scheme was recorded use `Legacy`
+    #[serde(default)]
+    pub derivation_scheme: DerivationScheme,
+    /// BIP44 account, to keep separate wallets with the same mnemonic
+    #[serde(default)]
+    pub account: u32,
     pub version: String,
     pub state_entity_endpoint: String,
     pub electrum_endpoint: String,
@@ -28,6 +41,38 @@ pub struct Wallet {
     pub settings: Settings,
 }
 
+/// How the user and auth keys of the coins are derived from the seed
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Enum))]
+pub enum DerivationScheme {
+    /// `m/86h/0h/{account}h` and `m/89h/0h/{account}h` on every network
+    #[default]
+    Legacy,
+    /// `m/86h/{coin_type}h/{account}h` and `m/89h/{coin_type}h/{account}h`,
+    /// where the coin type is 0 on mainnet and 1 on the test networks
+    Bip44,
+}
+
+impl DerivationScheme {
+
+    fn get_coin_type(&self, network: Network) -> u32 {
+        match self {
+            DerivationScheme::Legacy => 0,
+            DerivationScheme::Bip44 => if network == Network::Bitcoin { 0 } else { 1 },
+        }
+    }
+
+    /// Account level path of the user keys (backup and aggregated addresses)
+    pub fn get_user_derivation_path(&self, network: Network, account: u32) -> String {
+        format!("m/86h/{}h/{}h", self.get_coin_type(network), account)
+    }
+
+    /// Account level path of the auth keys
+    pub fn get_auth_derivation_path(&self, network: Network, account: u32) -> String {
+        format!("m/89h/{}h/{}h", self.get_coin_type(network), account)
+    }
+}
+
 #[allow(non_snake_case)]
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
@@ -64,7 +109,7 @@ pub struct Token {
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
 pub struct Activity {
     pub utxo: String,
-    pub amount: u32,
+    pub amount: u64,
     pub action: String,
     pub date: String
 }
@@ -93,7 +138,7 @@ pub struct Coin {
     pub aggregated_address: Option<String>,
     pub utxo_txid: Option<String>,
     pub utxo_vout: Option<u32>,
-    pub amount: Option<u32>,
+    pub amount: Option<u64>,
     pub statechain_id: Option<String>,
     pub signed_statechain_id: Option<String>,
     pub locktime: Option<u32>,
@@ -106,6 +151,33 @@ pub struct Coin {
     pub withdrawal_address: Option<String>,
     pub status: CoinStatus,
     pub duplicate_index: u32,
+    /// Checks, made when the coin was received, that the server share of the previous owner was rotated
+    #[serde(default)]
+    pub key_rotations: Vec<KeyRotationEvidence>,
+}
+
+/// Record that the published keylist lists the current server key share of a statechain,
+/// and that the share used for the previous owner is no longer part of the coin.
+/// A check that failed is recorded as well, with its `error` and without a keylist entry.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct KeyRotationEvidence {
+    pub statechain_id: String,
+    pub previous_server_pubkey: String,
+    pub current_server_pubkey: String,
+    /// `tx_n` and `created_at` of the keylist entry of the current server key share
+    pub keylist_tx_n: Option<u32>,
+    pub keylist_created_at: Option<String>,
+    /// Why the key rotation could not be verified, `None` if it was
+    #[serde(default)]
+    #[cfg_attr(feature = "bindings", uniffi(default = None))]
+    pub error: Option<String>,
+}
+
+impl KeyRotationEvidence {
+    pub fn is_verified(&self) -> bool {
+        self.error.is_none()
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -198,15 +270,15 @@ pub fn get_previous_outpoint(backup_tx: &BackupTx) -> Result<TxOutpoint, Mercury
     let tx1: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(backup_tx.tx.clone())?)?;
 
     if tx1.input.len() > 1 {
-        return Err(MercuryError::Tx1HasMoreThanOneInput);
+        return Err(MercuryError::Tx1HasMoreThanOneInput { context: ErrorContext::new().value(tx1.input.len()).expected(1).tx_n(backup_tx.tx_n) });
     }
 
-    if tx1.output.len() > 1 {
-        return Err(MercuryError::Tx1HasMoreThanOneInput);
-    }
+    crate::transfer::receiver::verify_backup_tx_output_layout(&tx1).map_err(|e| e.with_tx_n(backup_tx.tx_n))?;
+
+    let input = tx1.input.first().ok_or(MercuryError::EmptyInput)?;
 
-    let tx0_txid = tx1.input[0].previous_output.txid;
-    let tx0_vout = tx1.input[0].previous_output.vout as u32;
+    let tx0_txid = input.previous_output.txid;
+    let tx0_vout = input.previous_output.vout as u32;
 
     Ok(TxOutpoint{ txid: tx0_txid.to_string(), vout: tx0_vout })
 }
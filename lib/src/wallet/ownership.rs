@@ -0,0 +1,148 @@
+use std::str::FromStr;
+
+use bitcoin::{hashes::{sha256, Hash}, PrivateKey, ScriptBuf, Transaction};
+use secp256k1_zkp::{schnorr::Signature, KeyPair, Message, PublicKey, Secp256k1, XOnlyPublicKey};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ErrorContext, MercuryError};
+
+use super::Coin;
+
+/// Maximum difference, in seconds, between the proof timestamp and the clock of the verifier
+pub const OWNERSHIP_PROOF_MAX_AGE: u64 = 600;
+
+/// Statement that the owner of `auth_pubkey` controls the statecoin of `amount` sats
+/// locked in the Tx0 output `utxo_txid:utxo_vout`, answering `challenge` at `timestamp`.
+/// `user_pubkey` is the owner share of the aggregated key, which the verifier recomputes
+/// with the server share to tie the output to the statechain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct OwnershipProof {
+    pub statechain_id: String,
+    pub utxo_txid: String,
+    pub utxo_vout: u32,
+    pub amount: u64,
+    pub user_pubkey: String,
+    /// Chosen by the verifier, so that the proof cannot be replayed to someone else
+    pub challenge: String,
+    /// Unix time, in seconds, at which the proof was signed
+    pub timestamp: u64,
+    pub auth_pubkey: String,
+    pub signature: String,
+}
+
+/// Current auth key and server key share of a statechain, as known by the server
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
+pub struct StatechainOwnerResponsePayload {
+    pub statechain_id: String,
+    pub auth_xonly_pubkey: String,
+    pub server_public_key: String,
+}
+
+/// The challenge is hashed, so that it cannot be confused with the fields around it
+fn get_ownership_proof_message(proof: &OwnershipProof) -> Message {
+    let challenge_hash = sha256::Hash::hash(proof.challenge.as_bytes());
+    let data = format!("ownership_proof:{}:{}:{}:{}:{}:{}:{}:{}",
+        proof.statechain_id, proof.utxo_txid, proof.utxo_vout, proof.amount, proof.user_pubkey, challenge_hash, proof.timestamp, proof.auth_pubkey);
+    Message::from_hashed_data::<sha256::Hash>(data.as_bytes())
+}
+
+fn invalid_ownership_proof(value: impl ToString) -> MercuryError {
+    MercuryError::InvalidOwnershipProof { context: ErrorContext::new().value(value) }
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_ownership_proof(coin: &Coin, challenge: &str, timestamp: u64) -> Result<OwnershipProof, MercuryError> {
+
+    let (statechain_id, utxo_txid, utxo_vout, amount) = match (coin.statechain_id.as_ref(), coin.utxo_txid.as_ref(), coin.utxo_vout, coin.amount) {
+        (Some(statechain_id), Some(utxo_txid), Some(utxo_vout), Some(amount)) => (statechain_id.clone(), utxo_txid.clone(), utxo_vout, amount),
+        _ => return Err(invalid_ownership_proof("coin has no statechain_id, utxo or amount")),
+    };
+
+    let client_auth_key = PrivateKey::from_wif(&coin.auth_privkey)?.inner;
+
+    let secp = Secp256k1::new();
+
+    let client_auth_keypair = KeyPair::from_seckey_slice(&secp, client_auth_key.as_ref())?;
+
+    let mut proof = OwnershipProof {
+        statechain_id,
+        utxo_txid,
+        utxo_vout,
+        amount,
+        user_pubkey: coin.user_pubkey.clone(),
+        challenge: challenge.to_string(),
+        timestamp,
+        auth_pubkey: coin.auth_pubkey.clone(),
+        signature: String::new(),
+    };
+
+    let msg = get_ownership_proof_message(&proof);
+    proof.signature = secp.sign_schnorr(&msg, &client_auth_keypair).to_string();
+
+    Ok(proof)
+}
+
+/// Checks the proof against the challenge sent to the prover, the current auth key and server key share
+/// of the statechain returned by the server and the Tx0 of the coin, whose output must pay to the key
+/// aggregated from the user and server shares.
+/// Whether the Tx0 output is still unspent must be checked by the caller against the chain.
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn verify_ownership_proof(
+    proof: &OwnershipProof,
+    challenge: &str,
+    statechain_owner: &StatechainOwnerResponsePayload,
+    tx0_hex: &str,
+    current_time: u64,
+    max_age: u64) -> Result<(), MercuryError> {
+
+    if proof.challenge != challenge {
+        return Err(MercuryError::InvalidOwnershipProof { context: ErrorContext::new().value(format!("challenge {}", proof.challenge)).expected(format!("challenge {}", challenge)) });
+    }
+
+    if current_time.abs_diff(proof.timestamp) > max_age {
+        return Err(MercuryError::InvalidOwnershipProof { context: ErrorContext::new().value(format!("timestamp {}", proof.timestamp)).expected(format!("within {} seconds of {}", max_age, current_time)) });
+    }
+
+    let auth_pubkey = PublicKey::from_str(&proof.auth_pubkey)?.x_only_public_key().0;
+
+    let signature = Signature::from_str(&proof.signature)?;
+
+    if Secp256k1::new().verify_schnorr(&signature, &get_ownership_proof_message(proof), &auth_pubkey).is_err() {
+        return Err(MercuryError::InvalidSignature { context: ErrorContext::new().value(signature).expected(&proof.auth_pubkey) });
+    }
+
+    let owner_auth_pubkey = XOnlyPublicKey::from_str(&statechain_owner.auth_xonly_pubkey)?;
+
+    if statechain_owner.statechain_id != proof.statechain_id || owner_auth_pubkey != auth_pubkey {
+        return Err(MercuryError::InvalidOwnershipProof { context: ErrorContext::new().value(format!("auth key {}", auth_pubkey)).expected(format!("auth key {}", owner_auth_pubkey)).statechain_id(&statechain_owner.statechain_id) });
+    }
+
+    let tx0: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(tx0_hex)?)?;
+
+    if tx0.txid().to_string() != proof.utxo_txid {
+        return Err(MercuryError::InvalidOwnershipProof { context: ErrorContext::new().value(format!("tx0 {}", tx0.txid())).expected(format!("tx0 {}", proof.utxo_txid)) });
+    }
+
+    let tx0_output = tx0.output.get(proof.utxo_vout as usize)
+        .ok_or(MercuryError::InvalidOutputIndex { context: ErrorContext::new().value(proof.utxo_vout).expected(format!("< {}", tx0.output.len())) })?;
+
+    if tx0_output.value != proof.amount {
+        return Err(MercuryError::InvalidOwnershipProof { context: ErrorContext::new().value(format!("amount {}", proof.amount)).expected(format!("amount {}", tx0_output.value)) });
+    }
+
+    let user_pubkey = PublicKey::from_str(&proof.user_pubkey)?;
+    let server_public_key = PublicKey::from_str(&statechain_owner.server_public_key)?;
+
+    let aggregate_pubkey = user_pubkey.combine(&server_public_key)?;
+
+    let script_pubkey = ScriptBuf::new_v1_p2tr(&Secp256k1::new(), aggregate_pubkey.x_only_public_key().0, None);
+
+    if tx0_output.script_pubkey != script_pubkey {
+        return Err(MercuryError::InvalidOwnershipProof { context: ErrorContext::new().value(format!("tx0 output {}", tx0_output.script_pubkey)).expected(format!("tx0 output {}", script_pubkey)).statechain_id(&proof.statechain_id) });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use std::str::FromStr;
+
+use bitcoin::{hashes::sha256, PrivateKey};
+use secp256k1_zkp::{schnorr::Signature, KeyPair, Message, PublicKey, Secp256k1};
+use serde::{Deserialize, Serialize};
+
+use crate::error::{ErrorContext, MercuryError};
+
+use super::Coin;
+
+/// Number of consecutive unused address indices after which the recovery scan stops
+pub const DEFAULT_GAP_LIMIT: u32 = 20;
+
+/// Maximum difference, in seconds, between the request timestamp and the server clock
+pub const STATECHAIN_LOOKUP_MAX_AGE: u64 = 300;
+
+/// Asks the server which statechains are owned by `auth_pubkey`.
+/// The request is signed with the auth key, so only its owner can list them.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
+pub struct StatechainLookupRequestPayload {
+    pub auth_pubkey: String,
+    /// Unix time, in seconds, at which the request was signed
+    pub timestamp: u64,
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
+pub struct RecoveredStatechain {
+    pub statechain_id: String,
+    pub server_pubkey: String,
+    /// The transfer message that gave the statechain to `auth_pubkey`, if it was received by transfer.
+    /// It holds the backup transactions of the coin.
+    pub enc_transfer_msg: Option<String>,
+}
+
+/// A statechain that was transferred or withdrawn by `auth_pubkey`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
+pub struct PreviousStatechain {
+    pub statechain_id: String,
+    pub withdrawn: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
+pub struct StatechainLookupResponsePayload {
+    pub statechains: Vec<RecoveredStatechain>,
+    /// Statechains owned before by `auth_pubkey`. The key is used even if it owns nothing now.
+    #[serde(default)]
+    pub previous_statechains: Vec<PreviousStatechain>,
+}
+
+fn get_statechain_lookup_message(auth_pubkey: &str, timestamp: u64) -> Message {
+    let data = format!("statechain_lookup:{}:{}", auth_pubkey, timestamp);
+    Message::from_hashed_data::<sha256::Hash>(data.as_bytes())
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_statechain_lookup_request(coin: &Coin, timestamp: u64) -> Result<StatechainLookupRequestPayload, MercuryError> {
+
+    let client_auth_key = PrivateKey::from_wif(&coin.auth_privkey)?.inner;
+
+    let secp = Secp256k1::new();
+
+    let client_auth_keypair = KeyPair::from_seckey_slice(&secp, client_auth_key.as_ref())?;
+
+    let msg = get_statechain_lookup_message(&coin.auth_pubkey, timestamp);
+    let signature = secp.sign_schnorr(&msg, &client_auth_keypair);
+
+    Ok(StatechainLookupRequestPayload {
+        auth_pubkey: coin.auth_pubkey.clone(),
+        timestamp,
+        signature: signature.to_string(),
+    })
+}
+
+/// Checks the signature of the request. The freshness of `timestamp` is checked by the caller.
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn verify_statechain_lookup_request(payload: &StatechainLookupRequestPayload) -> Result<(), MercuryError> {
+
+    let auth_pubkey = PublicKey::from_str(&payload.auth_pubkey)?.x_only_public_key().0;
+
+    let signature = Signature::from_str(&payload.signature)?;
+
+    let msg = get_statechain_lookup_message(&payload.auth_pubkey, payload.timestamp);
+
+    if Secp256k1::new().verify_schnorr(&signature, &msg, &auth_pubkey).is_err() {
+        return Err(MercuryError::InvalidSignature { context: ErrorContext::new().value(signature).expected(&payload.auth_pubkey) });
+    }
+
+    Ok(())
+}
@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use bitcoin::{hashes::sha256, PrivateKey, Transaction, Txid};
+use secp256k1_zkp::{schnorr::Signature, KeyPair, Message, PublicKey, Secp256k1};
+use serde::{Deserialize, Serialize};
+
+use crate::{error::{ErrorContext, MercuryError}, utils::get_blockheight};
+
+use super::{cpfp_tx::latest_backup_tx_pays_to_user_pubkey, get_previous_outpoint, BackupTx, Coin};
+
+pub const WATCHTOWER_PACKAGE_VERSION: u32 = 1;
+
+/// Everything a third party needs to watch a coin and broadcast
+/// its latest backup transaction on behalf of the owner.
+/// The package is signed with the coin's auth key.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "bindings", derive(uniffi::Record))]
+pub struct WatchtowerPackage {
+    pub version: u32,
+    pub statechain_id: String,
+    pub tx0_txid: String,
+    pub tx0_vout: u32,
+    pub tx_n: u32,
+    /// The latest backup transaction, ready to be broadcast
+    pub backup_tx: String,
+    /// The block height from which `backup_tx` can be broadcast
+    pub locktime: u32,
+    /// Txids of the earlier backup transactions of the coin (previous owners)
+    pub previous_txids: Vec<String>,
+    pub auth_pubkey: String,
+    /// Schnorr signature of the package by `auth_pubkey`
+    pub signature: String,
+}
+
+fn push_field(data: &mut Vec<u8>, field: &[u8]) {
+    data.extend_from_slice(&(field.len() as u32).to_le_bytes());
+    data.extend_from_slice(field);
+}
+
+/// Every field except the signature, each one length prefixed
+fn get_package_message(package: &WatchtowerPackage) -> Message {
+
+    let mut data = Vec::<u8>::new();
+
+    data.extend_from_slice(&package.version.to_le_bytes());
+    push_field(&mut data, package.statechain_id.as_bytes());
+    push_field(&mut data, package.tx0_txid.as_bytes());
+    data.extend_from_slice(&package.tx0_vout.to_le_bytes());
+    data.extend_from_slice(&package.tx_n.to_le_bytes());
+    push_field(&mut data, package.backup_tx.as_bytes());
+    data.extend_from_slice(&package.locktime.to_le_bytes());
+    data.extend_from_slice(&(package.previous_txids.len() as u32).to_le_bytes());
+    for txid in package.previous_txids.iter() {
+        push_field(&mut data, txid.as_bytes());
+    }
+    push_field(&mut data, package.auth_pubkey.as_bytes());
+
+    Message::from_hashed_data::<sha256::Hash>(&data)
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn create_watchtower_package(coin: &Coin, backup_transactions: &Vec<BackupTx>, network: &str) -> Result<WatchtowerPackage, MercuryError> {
+
+    let statechain_id = coin.statechain_id.as_ref()
+        .ok_or(MercuryError::InvalidWatchtowerPackage { context: ErrorContext::new().value("coin has no statechain_id") })?;
+
+    let latest_backup_tx = latest_backup_tx_pays_to_user_pubkey(backup_transactions, coin, network)?;
+
+    let tx0_outpoint = get_previous_outpoint(&latest_backup_tx)?;
+
+    let locktime = get_blockheight(&latest_backup_tx)?;
+
+    let mut previous_backup_txs = backup_transactions.iter()
+        .filter(|bkp_tx| bkp_tx.tx_n < latest_backup_tx.tx_n)
+        .collect::<Vec<&BackupTx>>();
+
+    previous_backup_txs.sort_by(|a, b| a.tx_n.cmp(&b.tx_n));
+
+    let mut previous_txids = Vec::<String>::new();
+
+    for bkp_tx in previous_backup_txs {
+        let tx: Transaction = bitcoin::consensus::encode::deserialize(&hex::decode(&bkp_tx.tx)?)?;
+        previous_txids.push(tx.txid().to_string());
+    }
+
+    let mut package = WatchtowerPackage {
+        version: WATCHTOWER_PACKAGE_VERSION,
+        statechain_id: statechain_id.to_string(),
+        tx0_txid: tx0_outpoint.txid,
+        tx0_vout: tx0_outpoint.vout,
+        tx_n: latest_backup_tx.tx_n,
+        backup_tx: latest_backup_tx.tx,
+        locktime,
+        previous_txids,
+        auth_pubkey: coin.auth_pubkey.clone(),
+        signature: String::new(),
+    };
+
+    let client_auth_key = PrivateKey::from_wif(&coin.auth_privkey)?.inner;
+
+    let secp = Secp256k1::new();
+
+    let client_auth_keypair = KeyPair::from_seckey_slice(&secp, client_auth_key.as_ref())?;
+
+    if client_auth_keypair.public_key().to_string() != coin.auth_pubkey {
+        return Err(MercuryError::InvalidWatchtowerPackage { context: ErrorContext::new().value("auth_privkey does not match auth_pubkey") });
+    }
+
+    let msg = get_package_message(&package);
+    package.signature = secp.sign_schnorr(&msg, &client_auth_keypair).to_string();
+
+    Ok(package)
+}
+
+/// Checks the signature and the internal consistency of the package.
+/// The caller is responsible for checking that `auth_pubkey` is the expected one.
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn verify_watchtower_package(package: &WatchtowerPackage) -> Result<(), MercuryError> {
+
+    if package.version != WATCHTOWER_PACKAGE_VERSION {
+        return Err(MercuryError::InvalidWatchtowerPackage { context: ErrorContext::new().value(format!("version {}", package.version)).expected(format!("version {}", WATCHTOWER_PACKAGE_VERSION)) });
+    }
+
+    let auth_pubkey = PublicKey::from_str(&package.auth_pubkey)?.x_only_public_key().0;
+
+    let signature = Signature::from_str(&package.signature)?;
+
+    let msg = get_package_message(package);
+
+    if Secp256k1::new().verify_schnorr(&signature, &msg, &auth_pubkey).is_err() {
+        return Err(MercuryError::InvalidSignature { context: ErrorContext::new().value(signature).statechain_id(&package.statechain_id) });
+    }
+
+    let backup_tx = BackupTx {
+        tx_n: package.tx_n,
+        tx: package.backup_tx.clone(),
+        client_public_nonce: String::new(),
+        server_public_nonce: String::new(),
+        client_public_key: String::new(),
+        server_public_key: String::new(),
+        blinding_factor: String::new(),
+    };
+
+    let tx0_outpoint = get_previous_outpoint(&backup_tx)?;
+
+    if tx0_outpoint.txid != package.tx0_txid || tx0_outpoint.vout != package.tx0_vout {
+        return Err(MercuryError::InvalidWatchtowerPackage {
+            context: ErrorContext::new().value(format!("{}:{}", tx0_outpoint.txid, tx0_outpoint.vout)).expected(format!("{}:{}", package.tx0_txid, package.tx0_vout)).tx_n(package.tx_n)
+        });
+    }
+
+    let locktime = get_blockheight(&backup_tx)?;
+
+    if locktime != package.locktime {
+        return Err(MercuryError::InvalidWatchtowerPackage { context: ErrorContext::new().value(format!("locktime {}", locktime)).expected(format!("locktime {}", package.locktime)).tx_n(package.tx_n) });
+    }
+
+    for txid in package.previous_txids.iter() {
+        Txid::from_str(txid)?;
+    }
+
+    Ok(())
+}
+
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn export_watchtower_package(package: &WatchtowerPackage) -> Result<String, MercuryError> {
+    Ok(serde_json::to_string(package)?)
+}
+
+/// Parses and verifies a package received from a coin owner
+#[cfg_attr(feature = "bindings", uniffi::export)]
+pub fn import_watchtower_package(package_json: &str) -> Result<WatchtowerPackage, MercuryError> {
+
+    let package: WatchtowerPackage = serde_json::from_str(package_json)?;
+
+    verify_watchtower_package(&package)?;
+
+    Ok(package)
+}
+
@@ -2,6 +2,7 @@ use serde::{Serialize, Deserialize};
 
 #[cfg_attr(feature = "bindings", derive(uniffi::Record))]
 #[derive(Debug, Serialize, Deserialize, Clone)]
+#[cfg_attr(feature = "openapi", derive(schemars::JsonSchema))]
 pub struct WithdrawCompletePayload {
     pub statechain_id: String,
     pub signed_statechain_id: String,
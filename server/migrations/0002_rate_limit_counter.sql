@@ -0,0 +1,6 @@
+CREATE TABLE public.rate_limit_counter (
+	key varchar NOT NULL,
+	window_start int8 NOT NULL,
+	count int4 NOT NULL,
+	CONSTRAINT rate_limit_counter_pkey PRIMARY KEY (key)
+);
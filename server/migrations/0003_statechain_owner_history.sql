@@ -0,0 +1,12 @@
+-- Auth keys that have owned each statechain. statechain_data only keeps the current owner and is deleted on withdrawal,
+-- so this is how a wallet recovery tells the keys of spent coins from unused keys.
+CREATE TABLE public.statechain_owner_history (
+	statechain_id varchar NOT NULL,
+	auth_xonly_public_key bytea NOT NULL,
+	created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+	CONSTRAINT statechain_owner_history_pkey PRIMARY KEY (statechain_id, auth_xonly_public_key)
+);
+
+INSERT INTO public.statechain_owner_history (statechain_id, auth_xonly_public_key)
+SELECT statechain_id, auth_xonly_public_key FROM public.statechain_data
+WHERE statechain_id IS NOT NULL AND auth_xonly_public_key IS NOT NULL;
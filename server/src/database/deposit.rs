@@ -97,6 +97,8 @@ pub async fn check_existing_key(pool: &sqlx::PgPool, auth_key: &XOnlyPublicKey)
 
 pub async fn insert_new_deposit(pool: &sqlx::PgPool, token_id: &str, auth_key: &XOnlyPublicKey, server_public_key: &PublicKey, statechain_id: &String, enclave_index: i32)  {
 
+    let mut transaction = pool.begin().await.unwrap();
+
     let query = "INSERT INTO statechain_data (token_id, auth_xonly_public_key, server_public_key, statechain_id, enclave_index) VALUES ($1, $2, $3, $4, $5)";
 
     let _ = sqlx::query(query)
@@ -105,9 +107,13 @@ pub async fn insert_new_deposit(pool: &sqlx::PgPool, token_id: &str, auth_key: &
         .bind(&server_public_key.serialize())
         .bind(statechain_id)
         .bind(enclave_index)
-        .execute(pool)
+        .execute(&mut *transaction)
         .await
         .unwrap();
+
+    crate::database::recovery::insert_statechain_owner(&mut transaction, auth_key, statechain_id).await;
+
+    transaction.commit().await.unwrap();
 }
 
 pub async fn insert_new_token(pool: &sqlx::PgPool, token_id: &str)  {
@@ -0,0 +1,34 @@
+use sqlx::Row;
+
+pub struct StatechainCounts {
+    pub active_statechains: i64,
+    pub pending_transfers: i64,
+    pub pending_batches: i64,
+    pub outstanding_lightning_latches: i64,
+}
+
+/// Counts the statechains, the transfers not completed yet, the batches that have not expired and the locked lightning latches
+pub async fn get_statechain_counts(pool: &sqlx::PgPool, batch_timeout: u32) -> Result<StatechainCounts, sqlx::Error> {
+
+    let query = "\
+        SELECT \
+            (SELECT COUNT(*) FROM statechain_data WHERE statechain_id IS NOT NULL), \
+            (SELECT COUNT(*) FROM statechain_transfer WHERE key_updated IS NOT TRUE), \
+            (SELECT COUNT(DISTINCT batch_id) FROM statechain_transfer \
+                WHERE batch_id IS NOT NULL \
+                AND key_updated IS NOT TRUE \
+                AND batch_time > NOW() - make_interval(secs => $1)), \
+            (SELECT COUNT(*) FROM lightning_latch WHERE locked = true AND expires_at > NOW())";
+
+    let row = sqlx::query(query)
+        .bind(batch_timeout as f64)
+        .fetch_one(pool)
+        .await?;
+
+    Ok(StatechainCounts {
+        active_statechains: row.get(0),
+        pending_transfers: row.get(1),
+        pending_batches: row.get(2),
+        outstanding_lightning_latches: row.get(3),
+    })
+}
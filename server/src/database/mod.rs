@@ -5,3 +5,6 @@ pub mod deposit;
 pub mod utils;
 pub mod lightning_latch;
 pub mod sign;
+pub mod recovery;
+pub mod rate_limit;
+pub mod metrics;
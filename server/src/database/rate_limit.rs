@@ -0,0 +1,34 @@
+use sqlx::Row;
+
+/// Counts a request for `key` in the window starting at `window_start` and returns the requests counted in that window.
+/// The counter restarts when the key is seen in a new window.
+pub async fn increment_rate_limit_counter(pool: &sqlx::PgPool, key: &str, window_start: i64) -> Result<i32, sqlx::Error> {
+
+    let query = "\
+        INSERT INTO rate_limit_counter (key, window_start, count) \
+        VALUES ($1, $2, 1) \
+        ON CONFLICT (key) DO UPDATE SET \
+            count = CASE WHEN rate_limit_counter.window_start = EXCLUDED.window_start THEN rate_limit_counter.count + 1 ELSE 1 END, \
+            window_start = EXCLUDED.window_start \
+        RETURNING count";
+
+    let row = sqlx::query(query)
+        .bind(key)
+        .bind(window_start)
+        .fetch_one(pool)
+        .await?;
+
+    Ok(row.get(0))
+}
+
+pub async fn delete_expired_rate_limit_counters(pool: &sqlx::PgPool, window_start: i64) -> Result<(), sqlx::Error> {
+
+    let query = "DELETE FROM rate_limit_counter WHERE window_start < $1";
+
+    sqlx::query(query)
+        .bind(window_start)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
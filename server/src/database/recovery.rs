@@ -0,0 +1,86 @@
+use mercurylib::wallet::recovery::{PreviousStatechain, RecoveredStatechain};
+use secp256k1_zkp::{PublicKey, XOnlyPublicKey};
+use sqlx::{Postgres, Row, Transaction};
+
+/// Records that `auth_key` owns the statechain, for the recovery of the wallets that spent it later
+pub async fn insert_statechain_owner(transaction: &mut Transaction<'_, Postgres>, auth_key: &XOnlyPublicKey, statechain_id: &str) {
+
+    let query = "\
+        INSERT INTO statechain_owner_history (statechain_id, auth_xonly_public_key) \
+        VALUES ($1, $2) \
+        ON CONFLICT DO NOTHING";
+
+    let _ = sqlx::query(query)
+        .bind(statechain_id)
+        .bind(&auth_key.serialize())
+        .execute(&mut **transaction)
+        .await
+        .unwrap();
+}
+
+/// Statechains currently owned by `auth_key`, with the transfer message that gave them to it, if any
+pub async fn get_statechains_by_auth_key(pool: &sqlx::PgPool, auth_key: &PublicKey) -> Vec::<RecoveredStatechain> {
+
+    let query = "\
+        SELECT sd.statechain_id, sd.server_public_key, st.encrypted_transfer_msg \
+        FROM statechain_data sd \
+        LEFT JOIN statechain_transfer st \
+            ON st.statechain_id = sd.statechain_id \
+            AND st.new_user_auth_public_key = $2 \
+            AND st.key_updated = true \
+        WHERE sd.auth_xonly_public_key = $1 \
+        AND sd.statechain_id IS NOT NULL \
+        AND sd.server_public_key IS NOT NULL \
+        ORDER BY sd.id ASC";
+
+    let rows = sqlx::query(query)
+        .bind(&auth_key.x_only_public_key().0.serialize())
+        .bind(&auth_key.serialize())
+        .fetch_all(pool)
+        .await
+        .unwrap();
+
+    let mut result = Vec::<RecoveredStatechain>::new();
+
+    for row in rows {
+        let statechain_id: String = row.get(0);
+        let server_public_key_bytes: Vec<u8> = row.get(1);
+        let encrypted_transfer_msg: Option<Vec<u8>> = row.get(2);
+
+        let server_pubkey = PublicKey::from_slice(&server_public_key_bytes).unwrap();
+
+        result.push(RecoveredStatechain {
+            statechain_id,
+            server_pubkey: server_pubkey.to_string(),
+            enc_transfer_msg: encrypted_transfer_msg.map(hex::encode),
+        });
+    }
+
+    result
+}
+
+/// Statechains that `auth_key` owned and no longer owns, because it transferred or withdrew them
+pub async fn get_previous_statechains_by_auth_key(pool: &sqlx::PgPool, auth_key: &PublicKey) -> Vec::<PreviousStatechain> {
+
+    let query = "\
+        SELECT h.statechain_id, sd.statechain_id IS NULL \
+        FROM statechain_owner_history h \
+        LEFT JOIN statechain_data sd \
+            ON sd.statechain_id = h.statechain_id \
+        WHERE h.auth_xonly_public_key = $1 \
+        AND (sd.statechain_id IS NULL OR sd.auth_xonly_public_key IS DISTINCT FROM $1) \
+        ORDER BY h.created_at ASC";
+
+    let rows = sqlx::query(query)
+        .bind(&auth_key.x_only_public_key().0.serialize())
+        .fetch_all(pool)
+        .await
+        .unwrap();
+
+    rows.iter()
+        .map(|row| PreviousStatechain {
+            statechain_id: row.get(0),
+            withdrawn: row.get(1),
+        })
+        .collect()
+}
@@ -64,18 +64,24 @@ pub async fn insert_new_signature_data(pool: &sqlx::PgPool, server_pubnonce: &st
     transaction.commit().await.unwrap();
 }
 
-pub async fn update_signature_data_challenge(pool: &sqlx::PgPool, server_pub_nonce: &str, challenge: &str, statechain_id: &str)  {
+/// Sets the challenge signed with the server nonce. A nonce is used for a single challenge:
+/// the update is refused, and false returned, if the nonce already has a different one.
+/// Repeating the same challenge is a retry of the same signature and is allowed.
+pub async fn update_signature_data_challenge(pool: &sqlx::PgPool, server_pub_nonce: &str, challenge: &str, statechain_id: &str) -> bool {
 
     let query = "\
         UPDATE statechain_signature_data \
         SET challenge = $1 \
-        WHERE statechain_id = $2 AND server_pubnonce= $3";
+        WHERE statechain_id = $2 AND server_pubnonce= $3 \
+        AND (challenge IS NULL OR challenge = $1)";
 
-    let _ = sqlx::query(query)
+    let result = sqlx::query(query)
         .bind(challenge)
         .bind(statechain_id)
         .bind(server_pub_nonce)
         .execute(pool)
         .await
         .unwrap();
+
+    result.rows_affected() > 0
 }
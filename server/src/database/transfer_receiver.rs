@@ -206,6 +206,8 @@ pub async fn update_statechain(pool: &sqlx::PgPool, auth_key: &XOnlyPublicKey, s
         .await
         .unwrap();
 
+    crate::database::recovery::insert_statechain_owner(&mut transaction, auth_key, statechain_id).await;
+
     transaction.commit().await.unwrap();
 }
 
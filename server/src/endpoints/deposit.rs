@@ -1,21 +1,19 @@
 use std::str::FromStr;
 
 use bitcoin::hashes::{sha256, Hash};
+use mercurylib::error::ApiError;
 use rocket::{serde::json::Json, response::status, State, http::Status};
-use secp256k1_zkp::{XOnlyPublicKey, schnorr::Signature, Message, Secp256k1, PublicKey};
+use secp256k1_zkp::{XOnlyPublicKey, Message, Secp256k1, PublicKey};
 use serde::{Serialize, Deserialize};
 use serde_json::{Value, json};
 use crate::{server::StateChainEntity, server_config::Enclave};
 
+use super::api_error;
+
 pub async fn get_token_no_server(statechain_entity: &State<StateChainEntity>, config: &crate::server_config::ServerConfig) -> status::Custom<Json<Value>>  {
 
     if config.network == "mainnet" {
-        let response_body = json!({
-            "error": "Internal Server Error",
-            "message": "Token generation not supported on mainnet."
-        });
-    
-        return status::Custom(Status::InternalServerError, Json(response_body));
+        return api_error(ApiError::TokenGenerationNotSupported, "Token generation not supported on mainnet.");
     }
 
     let token_id = uuid::Uuid::new_v4().to_string();   
@@ -46,19 +44,7 @@ pub async fn get_token_from_server(config: &crate::server_config::ServerConfig)
             text
         },
         Err(err) => {
-            let response_body = json!({
-                "message": err.to_string()
-            });
-
-            let err = err.status();
-            let status = if err.is_some() {
-                Status::from_code(err.unwrap().as_u16()).unwrap_or(Status::InternalServerError)
-            } else {
-                Status::InternalServerError
-            };
-
-        
-            return status::Custom(status, Json(response_body));
+            return api_error(ApiError::UpstreamUnavailable, err);
         },
     };
 
@@ -85,7 +71,7 @@ pub async fn get_token_from_server(config: &crate::server_config::ServerConfig)
 #[get("/deposit/get_token")]
 pub async fn get_token(statechain_entity: &State<StateChainEntity>) -> status::Custom<Json<Value>>  {
 
-    let config = crate::server_config::ServerConfig::load();
+    let config = statechain_entity.config.get();
 
     if config.token_server_url.is_none() {
         return get_token_no_server(statechain_entity, &config).await;
@@ -125,13 +111,10 @@ struct TokenStatusResponse {
     confirmed: bool,
     spent: bool,
     err: bool,
-    status: Option<Status>,
     err_message: Option<String>
 }
 
-pub async fn check_token_status(token_id: &str) -> TokenStatusResponse{
-
-    let config = crate::server_config::ServerConfig::load();
+pub async fn check_token_status(config: &crate::server_config::ServerConfig, token_id: &str) -> TokenStatusResponse{
 
     let client: reqwest::Client = reqwest::Client::new();
     let request = client.get(&format!("{}/token/token_verify/{}", config.token_server_url.as_ref().unwrap(), token_id));
@@ -142,21 +125,11 @@ pub async fn check_token_status(token_id: &str) -> TokenStatusResponse{
             text
         },
         Err(err) => {
-            let message = err.to_string();
-
-            let err = err.status();
-            let status = if err.is_some() {
-                Status::from_code(err.unwrap().as_u16()).unwrap_or(Status::InternalServerError)
-            } else {
-                Status::InternalServerError
-            };
-
             return TokenStatusResponse {
                 confirmed: false,
                 spent: false,
                 err: true,
-                status: Some(status),
-                err_message: Some(message),
+                err_message: Some(err.to_string()),
             };
         },
     };
@@ -170,7 +143,6 @@ pub async fn check_token_status(token_id: &str) -> TokenStatusResponse{
         confirmed,
         spent,
         err: false,
-        status: None,
         err_message: None,
     };
 }
@@ -180,80 +152,59 @@ pub async fn post_deposit(statechain_entity: &State<StateChainEntity>, deposit_m
 
     let statechain_entity = statechain_entity.inner();
 
-    let auth_key = XOnlyPublicKey::from_str(&deposit_msg1.auth_key).unwrap();
+    let auth_key = match XOnlyPublicKey::from_str(&deposit_msg1.auth_key) {
+        Ok(auth_key) => auth_key,
+        Err(_) => return api_error(ApiError::InvalidAuthPubkey, "Invalid authentication public key"),
+    };
+
     let token_id = deposit_msg1.token_id.clone();
-    let signed_token_id = Signature::from_str(&deposit_msg1.signed_token_id.to_string()).unwrap();
+
+    let signed_token_id = match crate::endpoints::utils::parse_signature(&deposit_msg1.signed_token_id) {
+        Ok(signed_token_id) => signed_token_id,
+        Err(error) => return error,
+    };
 
     let msg = Message::from_hashed_data::<sha256::Hash>(token_id.to_string().as_bytes());
 
     let secp = Secp256k1::new();
     if !secp.verify_schnorr(&signed_token_id, &msg, &auth_key).is_ok() {
 
-        let response_body = json!({
-            "message": "Signature does not match authentication key."
-        });
-    
-        return status::Custom(Status::InternalServerError, Json(response_body));
+        return api_error(ApiError::InvalidSignature, "Signature does not match authentication key.");
 
     }
 
     let is_existing_key = crate::database::deposit::check_existing_key(&statechain_entity.pool, &auth_key).await;
 
     if is_existing_key {
-        let response_body = json!({
-            "message": "The authentication key is already assigned to a statecoin."
-        });
-    
-        return status::Custom(Status::BadRequest, Json(response_body));
+        return api_error(ApiError::AuthKeyAlreadyAssigned, "The authentication key is already assigned to a statecoin.");
     }
 
    let token_info = crate::database::deposit::get_token_info(&statechain_entity.pool, &token_id).await;
 
    if token_info.is_none() {
-        let response_body = json!({
-            "error": "Deposit Error",
-            "message": "Token ID not found."
-        });
-
-        return status::Custom(Status::NotFound, Json(response_body));
+        return api_error(ApiError::TokenNotFound, "Token ID not found.");
     }
 
     let token_info = token_info.unwrap();
 
     if token_info.spent {
-        let response_body = json!({
-            "message": "Token already spent."
-        });
-
-        return status::Custom(Status::Gone, Json(response_body));
+        return api_error(ApiError::TokenSpent, "Token already spent.");
     }
 
     if !token_info.confirmed {
 
-        let token_status_response = check_token_status(&token_id).await;
+        let token_status_response = check_token_status(&statechain_entity.config.get(), &token_id).await;
 
         if token_status_response.err {
-            let response_body = json!({
-                "message": token_status_response.err_message.unwrap()
-            });
-        
-            return status::Custom(token_status_response.status.unwrap(), Json(response_body));
+            return api_error(ApiError::UpstreamUnavailable, token_status_response.err_message.unwrap());
         }
 
         if token_status_response.spent {
-            let response_body = json!({
-                "message": "Token already spent."
-            });
-    
-            return status::Custom(Status::Gone, Json(response_body));
+            return api_error(ApiError::TokenSpent, "Token already spent.");
         }
 
         if !token_status_response.confirmed {
-            let response_body = json!({
-                "message": "Token not confirmed."
-            });
-        
-            return status::Custom(Status::Gone, Json(response_body));
+            return api_error(ApiError::TokenNotConfirmed, "Token not confirmed.");
         }
     }
 
@@ -264,7 +215,7 @@ pub async fn post_deposit(statechain_entity: &State<StateChainEntity>, deposit_m
 
     let statechain_id = uuid::Uuid::new_v4().as_simple().to_string();
 
-    let config = crate::server_config::ServerConfig::load();
+    let config = statechain_entity.config.get();
 
     let enclave_index = get_random_enclave_index(&statechain_id, &config.enclaves).unwrap();
 
@@ -278,18 +229,13 @@ pub async fn post_deposit(statechain_entity: &State<StateChainEntity>, deposit_m
         statechain_id: statechain_id.clone(),
     };
 
-    let value = match request.json(&payload).send().await {
+    let value = match statechain_entity.metrics.send_lockbox_request(enclave_index, path, request.json(&payload)).await {
         Ok(response) => {
             let text = response.text().await.unwrap();
             text
         },
         Err(err) => {
-            let response_body = json!({
-                "error": "Internal Server Error",
-                "message": err.to_string()
-            });
-        
-            return status::Custom(Status::InternalServerError, Json(response_body));
+            return api_error(ApiError::UpstreamUnavailable, err.to_string());
         },
     };
 
@@ -1,31 +1,31 @@
-use std::str::FromStr;
-
 use chrono::Duration;
 use mercurylib::transfer::sender::{PaymentHashRequestPayload, PaymentHashResponsePayload, TransferPreimageRequestPayload, TransferPreimageResponsePayload};
+use mercurylib::error::ApiError;
 use rand::Rng;
 use rocket::{State, serde::json::Json, response::status, http::Status};
-use secp256k1_zkp::PublicKey;
 use serde_json::{json, Value};
 
 use sha2::{Sha256, Digest};
 
 use crate::server::StateChainEntity;
 
+use super::api_error;
+
 #[get("/transfer/paymenthash/<batch_id>")]
 pub async fn get_paymenthash(statechain_entity: &State<StateChainEntity>, batch_id: &str) -> status::Custom<Json<Value>> {
 
     let pre_image = crate::database::lightning_latch::get_preimage_by_batch_id(&statechain_entity.pool, batch_id).await;
 
     if pre_image.is_none() {
-        let response_body = json!({
-            "message": "Pre-image not found"
-        });
-
-        return status::Custom(Status::NotFound, Json(response_body));
+        return api_error(ApiError::PreimageNotFound, "Pre-image not found");
     }
 
     let pre_image = pre_image.unwrap();
-    let buffer = hex::decode(pre_image).unwrap();
+
+    let buffer = match hex::decode(pre_image) {
+        Ok(buffer) => buffer,
+        Err(_) => return api_error(ApiError::Internal, "Invalid pre-image stored for the batch."),
+    };
 
     let mut hasher = Sha256::new();
     hasher.update(buffer);
@@ -49,16 +49,16 @@ pub async fn post_paymenthash(statechain_entity: &State<StateChainEntity>, payme
     let signed_statechain_id = payment_hash_payload.0.auth_sig.clone();
     let batch_id = payment_hash_payload.0.batch_id.clone();
 
-    if !crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
-
-        let response_body = json!({
-            "message": "Signature does not match authentication key."
-        });
+    let auth_key = match crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+        Ok(auth_key) => auth_key,
+        Err(error) => return error,
+    };
 
-        return status::Custom(Status::InternalServerError, Json(response_body));
+    if let Err(rate_limited) = statechain_entity.rate_limiter.limit_authenticated(&statechain_id, &auth_key).await {
+        return rate_limited;
     }
 
-    let sender_auth_key = super::utils::get_auth_key_by_statechain_id(&statechain_entity.pool, &statechain_id).await.unwrap();
+    let sender_auth_key = auth_key;
 
     let buffer = rand::thread_rng().gen::<[u8; 32]>();
     let pre_image = hex::encode(buffer.clone());
@@ -94,27 +94,30 @@ pub async fn transfer_preimage(statechain_entity: &State<StateChainEntity>, tran
     let previous_user_auth_key = transfer_preimage_request_payload.0.previous_user_auth_key.clone();
     let batch_id = transfer_preimage_request_payload.0.batch_id.clone();
 
-    if !crate::endpoints::utils::validate_signature_given_public_key(&signed_statechain_id, &statechain_id, &previous_user_auth_key).await {
+    let previous_user_auth_key = match crate::endpoints::utils::parse_public_key(&previous_user_auth_key) {
+        Ok(previous_user_auth_key) => previous_user_auth_key.x_only_public_key().0,
+        Err(error) => return error,
+    };
 
-        let response_body = json!({
-            "message": "Signature does not match authentication key."
-        });
-    
-        return status::Custom(Status::Forbidden, Json(response_body));
+    let signed_statechain_id = match crate::endpoints::utils::parse_signature(&signed_statechain_id) {
+        Ok(signed_statechain_id) => signed_statechain_id,
+        Err(error) => return error,
+    };
+
+    if !crate::endpoints::utils::validate_signature_given_public_key(&signed_statechain_id, &statechain_id, &previous_user_auth_key) {
+
+        return api_error(ApiError::InvalidSignature, "Signature does not match authentication key.");
     }
 
-    let previous_user_auth_key = PublicKey::from_str(&previous_user_auth_key).unwrap();
-    let previous_user_auth_key = previous_user_auth_key.x_only_public_key().0;
+    if let Err(rate_limited) = statechain_entity.rate_limiter.limit_authenticated(&statechain_id, &previous_user_auth_key).await {
+        return rate_limited;
+    }
 
     let pre_image = crate::database::lightning_latch::get_preimage(&statechain_entity.pool, &statechain_id, &previous_user_auth_key, &batch_id).await;
 
     if pre_image.is_none() {
         let message = format!("Pre-image for statechain {} not available. The transaction may still be locked", statechain_id);
-        let response_body = json!({
-            "message": message
-        });
-
-        return status::Custom(Status::NotFound, Json(response_body));
+        return api_error(ApiError::PreimageNotFound, message);
     }
 
     let pre_image = pre_image.unwrap();
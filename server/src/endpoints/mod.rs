@@ -1,4 +1,9 @@
 use chrono::{DateTime, Duration, Utc};
+use mercurylib::error::{ApiError, ApiErrorResponsePayload};
+use rocket::{http::Status, response::status, serde::json::Json};
+use schemars::JsonSchema;
+use serde::Serialize;
+use serde_json::{json, Value};
 
 pub mod deposit;
 pub mod sign;
@@ -7,12 +12,36 @@ pub mod transfer_sender;
 pub mod transfer_receiver;
 pub mod withdraw;
 pub mod lightning_latch;
+pub mod recovery;
+pub mod ownership;
+pub mod openapi;
 
-fn is_batch_expired(batch_time: DateTime<Utc>) -> bool {
-
-    let config = crate::server_config::ServerConfig::load();
+/// Routes of the API, which `openapi::openapi_spec` describes
+pub fn api_routes() -> Vec<rocket::Route> {
+    routes![
+        deposit::post_deposit,
+        deposit::get_token,
+        // deposit::token_init,
+        sign::sign_first,
+        sign::sign_second,
+        lightning_latch::get_paymenthash,
+        lightning_latch::post_paymenthash,
+        lightning_latch::transfer_preimage,
+        transfer_sender::transfer_sender,
+        transfer_sender::transfer_update_msg,
+        transfer_receiver::get_msg_addr,
+        transfer_receiver::statechain_info,
+        transfer_receiver::transfer_unlock,
+        transfer_receiver::transfer_receiver,
+        withdraw::withdraw_complete,
+        recovery::statechain_lookup,
+        ownership::statechain_owner,
+        utils::info_config,
+        utils::info_keylist,
+    ]
+}
 
-    let batch_timeout = config.batch_timeout;
+fn is_batch_expired(batch_time: DateTime<Utc>, batch_timeout: u32) -> bool {
 
     let expiration_time = batch_time + Duration::seconds(batch_timeout as i64);
 
@@ -20,3 +49,20 @@ fn is_batch_expired(batch_time: DateTime<Utc>) -> bool {
 
     return now > expiration_time
 }
+
+/// Response of a failed request, with the HTTP status of its error code
+pub fn api_error<T: ToString>(code: ApiError, message: T) -> status::Custom<Json<Value>> {
+    let status = Status::from_code(code.status_code()).unwrap_or(Status::InternalServerError);
+    status::Custom(status, Json(json!(ApiErrorResponsePayload::new(code, message))))
+}
+
+/// Body of the responses that only acknowledge the request
+#[derive(Serialize, JsonSchema)]
+pub struct MessageResponsePayload {
+    pub message: String,
+}
+
+#[derive(Serialize, JsonSchema)]
+pub struct TransferUpdateMsgResponsePayload {
+    pub updated: bool,
+}
@@ -0,0 +1,239 @@
+use mercurylib::{
+    deposit::{DepositMsg1, DepositMsg1Response, TokenResponse},
+    error::ApiErrorResponsePayload,
+    transaction::{PartialSignatureRequestPayload, PartialSignatureResponsePayload, SignFirstRequestPayload, SignFirstResponsePayload},
+    transfer::{receiver::{GetMsgAddrResponsePayload, StatechainInfoResponsePayload, TransferReceiverPostResponsePayload, TransferReceiverRequestPayload, TransferUnlockRequestPayload}, sender::{PaymentHashRequestPayload, PaymentHashResponsePayload, TransferPreimageRequestPayload, TransferPreimageResponsePayload, TransferSenderRequestPayload, TransferSenderResponsePayload, TransferUpdateMsgRequestPayload}},
+    utils::{KeyListResponsePayload, ServerConfig},
+    wallet::{ownership::StatechainOwnerResponsePayload, recovery::{StatechainLookupRequestPayload, StatechainLookupResponsePayload}},
+    withdraw::WithdrawCompletePayload,
+};
+use rocket::{response::content::RawHtml, serde::json::Json, State};
+use schemars::{gen::{SchemaGenerator, SchemaSettings}, JsonSchema};
+use serde_json::{json, Map, Value};
+
+use crate::server::StateChainEntity;
+
+use super::{MessageResponsePayload, TransferUpdateMsgResponsePayload};
+
+/// swagger-ui-dist assets of the `/swagger` page, unless `swagger_ui_url` is set
+const DEFAULT_SWAGGER_UI_URL: &str = "https://unpkg.com/swagger-ui-dist@5";
+
+struct ApiDoc {
+    gen: SchemaGenerator,
+    paths: Map<String, Value>,
+}
+
+impl ApiDoc {
+
+    fn new() -> Self {
+        ApiDoc {
+            gen: SchemaSettings::openapi3().into_generator(),
+            paths: Map::new(),
+        }
+    }
+
+    fn schema<T: JsonSchema>(&mut self) -> Value {
+        serde_json::to_value(self.gen.subschema_for::<T>()).unwrap()
+    }
+
+    fn get<Res: JsonSchema>(&mut self, path: &str, operation_id: &str, summary: &str) {
+        let response = self.schema::<Res>();
+        self.add_operation("get", path, operation_id, summary, None, response);
+    }
+
+    fn post<Req: JsonSchema, Res: JsonSchema>(&mut self, path: &str, operation_id: &str, summary: &str) {
+        let request = self.schema::<Req>();
+        let response = self.schema::<Res>();
+        self.add_operation("post", path, operation_id, summary, Some(request), response);
+    }
+
+    fn add_operation(&mut self, method: &str, path: &str, operation_id: &str, summary: &str, request: Option<Value>, response: Value) {
+
+        let error = self.schema::<ApiErrorResponsePayload>();
+
+        let mut operation = json!({
+            "operationId": operation_id,
+            "summary": summary,
+            "responses": {
+                "200": {
+                    "description": "Success",
+                    "content": { "application/json": { "schema": response } },
+                },
+                "default": {
+                    "description": "Failed request. The code identifies the error and sets the HTTP status.",
+                    "content": { "application/json": { "schema": error } },
+                },
+            },
+        });
+
+        // Path parameters are written as {name}, as in OpenAPI, rather than as <name> in the Rocket routes
+        let parameters: Vec<Value> = path.split('/')
+            .filter_map(|segment| segment.strip_prefix('{').and_then(|segment| segment.strip_suffix('}')))
+            .map(|name| json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string" },
+            }))
+            .collect();
+
+        if !parameters.is_empty() {
+            operation["parameters"] = Value::Array(parameters);
+        }
+
+        if let Some(request) = request {
+            operation["requestBody"] = json!({
+                "required": true,
+                "content": { "application/json": { "schema": request } },
+            });
+        }
+
+        self.paths.entry(path).or_insert_with(|| json!({}))[method] = operation;
+    }
+
+    fn into_spec(mut self) -> Value {
+        json!({
+            "openapi": "3.0.3",
+            "info": {
+                "title": "Mercury server",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "paths": self.paths,
+            "components": {
+                "schemas": self.gen.take_definitions(),
+            },
+        })
+    }
+}
+
+/// OpenAPI document of the routes in `api_routes`.
+/// A route added there must be added here as well, and the committed document regenerated.
+/// The handlers build their responses from the types declared here, not from ad-hoc JSON.
+pub fn openapi_spec() -> Value {
+
+    let mut doc = ApiDoc::new();
+
+    doc.post::<DepositMsg1, DepositMsg1Response>("/deposit/init/pod", "post_deposit",
+        "Initiate a deposit with a token and create the statechain id and server public key share");
+    doc.get::<TokenResponse>("/deposit/get_token", "get_token",
+        "Create a deposit token");
+
+    doc.post::<SignFirstRequestPayload, SignFirstResponsePayload>("/sign/first", "sign_first",
+        "Get a server public nonce to sign a new backup transaction");
+    doc.post::<PartialSignatureRequestPayload, PartialSignatureResponsePayload>("/sign/second", "sign_second",
+        "Get the server partial signature of a backup transaction");
+
+    doc.get::<PaymentHashResponsePayload>("/transfer/paymenthash/{batch_id}", "get_paymenthash",
+        "Get the lightning latch payment hash of a batch");
+    doc.post::<PaymentHashRequestPayload, PaymentHashResponsePayload>("/transfer/paymenthash", "post_paymenthash",
+        "Create the lightning latch payment hash of a statecoin in a batch");
+    doc.post::<TransferPreimageRequestPayload, TransferPreimageResponsePayload>("/transfer/transfer_preimage", "transfer_preimage",
+        "Get the lightning latch preimage once the batch transfer is complete");
+
+    doc.post::<TransferSenderRequestPayload, TransferSenderResponsePayload>("/transfer/sender", "transfer_sender",
+        "Start a transfer to a new auth key and get the x1 secret");
+    doc.post::<TransferUpdateMsgRequestPayload, TransferUpdateMsgResponsePayload>("/transfer/update_msg", "transfer_update_msg",
+        "Store the encrypted transfer message for the receiver");
+    doc.get::<GetMsgAddrResponsePayload>("/transfer/get_msg_addr/{new_auth_key}", "get_msg_addr",
+        "Get the encrypted transfer messages sent to an auth key");
+    doc.get::<StatechainInfoResponsePayload>("/info/statechain/{statechain_id}", "statechain_info",
+        "Get the signatures made by the server for a statechain");
+    doc.post::<TransferUnlockRequestPayload, MessageResponsePayload>("/transfer/unlock", "transfer_unlock",
+        "Unlock a statecoin in a batch transfer");
+    doc.post::<TransferReceiverRequestPayload, TransferReceiverPostResponsePayload>("/transfer/receiver", "transfer_receiver",
+        "Complete a transfer and update the server key share");
+
+    doc.post::<WithdrawCompletePayload, MessageResponsePayload>("/withdraw/complete", "withdraw_complete",
+        "Delete the server key share of a withdrawn statecoin");
+
+    doc.post::<StatechainLookupRequestPayload, StatechainLookupResponsePayload>("/recovery/statechains", "statechain_lookup",
+        "List the statechains owned by an auth key");
+    doc.get::<StatechainOwnerResponsePayload>("/info/owner/{statechain_id}", "statechain_owner",
+        "Get the current auth key of a statechain");
+
+    doc.get::<ServerConfig>("/info/config", "info_config",
+        "Get the server configuration used by the clients");
+    doc.get::<KeyListResponsePayload>("/info/keylist", "info_keylist",
+        "Get the current server public key of every statechain");
+
+    doc.into_spec()
+}
+
+#[get("/openapi.json")]
+pub fn openapi_json() -> Json<Value> {
+    Json(openapi_spec())
+}
+
+#[get("/swagger")]
+pub fn swagger_ui(statechain_entity: &State<StateChainEntity>) -> RawHtml<String> {
+
+    let config = statechain_entity.config.get();
+    let assets_url = config.swagger_ui_url.as_deref().unwrap_or(DEFAULT_SWAGGER_UI_URL).trim_end_matches('/');
+
+    RawHtml(format!(r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="utf-8" />
+    <title>Mercury server API</title>
+    <link rel="stylesheet" href="{assets_url}/swagger-ui.css" />
+</head>
+<body>
+    <div id="swagger-ui"></div>
+    <script src="{assets_url}/swagger-ui-bundle.js" crossorigin></script>
+    <script>
+        window.onload = () => {{
+            window.ui = SwaggerUIBundle({{ url: "/openapi.json", dom_id: "#swagger-ui" }});
+        }};
+    </script>
+</body>
+</html>
+"##))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Committed copy of the document, which partner teams generate their clients from
+    const OPENAPI_SPEC_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../docs/openapi.json");
+
+    // Run with MERCURY_UPDATE_OPENAPI=1 to rewrite the committed document after changing the API
+    #[test]
+    fn committed_openapi_spec_is_up_to_date() {
+
+        let generated = openapi_spec();
+
+        if std::env::var("MERCURY_UPDATE_OPENAPI").is_ok() {
+            let content = serde_json::to_string_pretty(&generated).unwrap() + "\n";
+            std::fs::write(OPENAPI_SPEC_PATH, content).unwrap();
+            return;
+        }
+
+        let committed = std::fs::read_to_string(OPENAPI_SPEC_PATH).unwrap();
+        let committed: Value = serde_json::from_str(&committed).unwrap();
+
+        assert!(committed == generated,
+            "{} differs from the API served by the server. Regenerate it with MERCURY_UPDATE_OPENAPI=1 cargo test -p mercury-server openapi",
+            OPENAPI_SPEC_PATH);
+    }
+
+    #[test]
+    fn every_api_route_is_documented() {
+
+        let spec = openapi_spec();
+
+        let routes = crate::endpoints::api_routes();
+
+        for route in &routes {
+            // <name> in the Rocket routes, {name} in the document
+            let path = route.uri.path().replace('<', "{").replace('>', "}");
+            let method = route.method.as_str().to_lowercase();
+
+            assert!(spec["paths"][&path][&method].is_object(), "{} {} is mounted but not in openapi_spec()", route.method, path);
+        }
+
+        let operations: usize = spec["paths"].as_object().unwrap().values().map(|path| path.as_object().unwrap().len()).sum();
+
+        assert_eq!(operations, routes.len(), "openapi_spec() documents operations that are not in api_routes()");
+    }
+}
@@ -0,0 +1,35 @@
+use mercurylib::wallet::ownership::StatechainOwnerResponsePayload;
+use mercurylib::error::ApiError;
+use rocket::{State, response::status, serde::json::Json, http::Status};
+use serde_json::{Value, json};
+
+use crate::server::StateChainEntity;
+
+use super::api_error;
+
+/// The current auth key and server key share of a statechain, against which ownership proofs are verified
+#[get("/info/owner/<statechain_id>")]
+pub async fn statechain_owner(statechain_entity: &State<StateChainEntity>, statechain_id: &str) -> status::Custom<Json<Value>> {
+
+    let auth_key = match crate::endpoints::utils::get_auth_key_by_statechain_id(&statechain_entity.pool, statechain_id).await {
+        Ok(auth_key) => auth_key,
+        Err(_) => {
+            return api_error(ApiError::StatechainNotFound, "Statechain Id not found.");
+        }
+    };
+
+    let server_public_key = match crate::database::transfer_receiver::get_server_public_key(&statechain_entity.pool, statechain_id).await {
+        Some(server_public_key) => server_public_key,
+        None => {
+            return api_error(ApiError::StatechainNotFound, "Statechain Id not found.");
+        }
+    };
+
+    let response_body = json!(StatechainOwnerResponsePayload {
+        statechain_id: statechain_id.to_string(),
+        auth_xonly_pubkey: auth_key.to_string(),
+        server_public_key: server_public_key.to_string(),
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
@@ -0,0 +1,46 @@
+use std::str::FromStr;
+
+use mercurylib::wallet::recovery::{StatechainLookupRequestPayload, StatechainLookupResponsePayload, STATECHAIN_LOOKUP_MAX_AGE};
+use mercurylib::error::ApiError;
+use rocket::{State, response::status, serde::json::Json, http::Status};
+use secp256k1_zkp::PublicKey;
+use serde_json::{Value, json};
+
+use crate::server::StateChainEntity;
+
+use super::api_error;
+
+/// Lists the statechains owned by an auth key, and those it owned before, so that a wallet can be recovered from its mnemonic
+#[post("/recovery/statechains", format = "json", data = "<statechain_lookup_request_payload>")]
+pub async fn statechain_lookup(statechain_entity: &State<StateChainEntity>, statechain_lookup_request_payload: Json<StatechainLookupRequestPayload>) -> status::Custom<Json<Value>> {
+
+    let statechain_lookup_request_payload = statechain_lookup_request_payload.0;
+
+    let auth_pubkey = match PublicKey::from_str(&statechain_lookup_request_payload.auth_pubkey) {
+        Ok(auth_pubkey) => auth_pubkey,
+        Err(_) => {
+            return api_error(ApiError::InvalidAuthPubkey, "Invalid authentication public key");
+        }
+    };
+
+    let now = chrono::Utc::now().timestamp() as u64;
+
+    if now.abs_diff(statechain_lookup_request_payload.timestamp) > STATECHAIN_LOOKUP_MAX_AGE {
+        return api_error(ApiError::RequestExpired, "Request timestamp is too old or in the future.");
+    }
+
+    if mercurylib::wallet::recovery::verify_statechain_lookup_request(&statechain_lookup_request_payload).is_err() {
+        return api_error(ApiError::InvalidSignature, "Signature does not match authentication key.");
+    }
+
+    let statechains = crate::database::recovery::get_statechains_by_auth_key(&statechain_entity.pool, &auth_pubkey).await;
+
+    let previous_statechains = crate::database::recovery::get_previous_statechains_by_auth_key(&statechain_entity.pool, &auth_pubkey).await;
+
+    let response_body = json!(StatechainLookupResponsePayload {
+        statechains,
+        previous_statechains,
+    });
+
+    status::Custom(Status::Ok, Json(response_body))
+}
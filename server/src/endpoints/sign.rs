@@ -1,16 +1,18 @@
 use mercurylib::transaction::SignFirstRequestPayload;
+use mercurylib::error::ApiError;
 use rocket::{http::Status, response::status, serde::json::Json, State};
 use secp256k1_zkp::musig::MusigSession;
-use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 
 
 use crate::server::StateChainEntity;
 
+use super::api_error;
+
 #[post("/sign/first", format = "json", data = "<sign_first_request_payload>")]
 pub async fn sign_first(statechain_entity: &State<StateChainEntity>, sign_first_request_payload: Json<SignFirstRequestPayload>) -> status::Custom<Json<Value>>  {
 
-    let config = crate::server_config::ServerConfig::load();
+    let config = statechain_entity.config.get();
     
     let statechain_id = sign_first_request_payload.0.statechain_id.clone();
 
@@ -21,11 +23,7 @@ pub async fn sign_first(statechain_entity: &State<StateChainEntity>, sign_first_
     let enclave_index = match enclave_index {
         Some(index) => index,
         None => {
-            let response_body = json!({
-                "message": format!("Enclave index for statechain {} ID not found.", statechain_id)
-            });
-        
-            return status::Custom(Status::InternalServerError, Json(response_body));
+            return api_error(ApiError::Internal, format!("Enclave index for statechain {} ID not found.", statechain_id));
         }
     };
 
@@ -39,17 +37,19 @@ pub async fn sign_first(statechain_entity: &State<StateChainEntity>, sign_first_
 
     let signed_statechain_id = sign_first_request_payload.0.signed_statechain_id.clone();
 
-    if !crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+    let auth_key = match crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+        Ok(auth_key) => auth_key,
+        Err(error) => return error,
+    };
 
-        let response_body = json!({
-            "message": "Signature does not match authentication key."
-        });
-    
-        return status::Custom(Status::Unauthorized, Json(response_body));
+    if let Err(rate_limited) = statechain_entity.rate_limiter.limit_authenticated(&statechain_id, &auth_key).await {
+        return rate_limited;
     }
 
-    // This situation should not happen, as this state is only possible if the client has called signFirst, but not signSecond
-    // In this case, the server should have already stored server_pubnonce in the database and the challenge is still null because the client did not call signSecond
+    // A nonce with a null challenge was returned by a previous sign_first that was not followed by sign_second,
+    // for instance because the client crashed or retried. The server has not signed anything with it, so it is
+    // returned again instead of creating another one. The client uses new nonces of its own in each attempt.
+    // sign_second accepts a single challenge per server nonce.
     let server_pubnonce_hex = crate::database::sign::get_server_pubnonce_from_null_challenge(&statechain_entity.pool, &statechain_id).await;
 
     if server_pubnonce_hex.is_some() {
@@ -63,18 +63,13 @@ pub async fn sign_first(statechain_entity: &State<StateChainEntity>, sign_first_
         return status::Custom(Status::Ok, Json(response_body));
     }
 
-    let value = match request.json(&sign_first_request_payload.0).send().await {
+    let value = match statechain_entity.metrics.send_lockbox_request(enclave_index, path, request.json(&sign_first_request_payload.0)).await {
         Ok(response) => {
             let text = response.text().await.unwrap();
             text
         },
         Err(err) => {
-            let response_body = json!({
-                "error": "Internal Server Error",
-                "message": err.to_string()
-            });
-        
-            return status::Custom(Status::InternalServerError, Json(response_body));
+            return api_error(ApiError::UpstreamUnavailable, err.to_string());
         },
     };
 
@@ -100,18 +95,14 @@ pub async fn sign_second (statechain_entity: &State<StateChainEntity>, partial_s
 
     let statechain_entity = statechain_entity.inner();
 
-    let config = crate::server_config::ServerConfig::load();
+    let config = statechain_entity.config.get();
 
     let enclave_index = crate::database::utils::get_enclave_index_from_database(&statechain_entity.pool, &statechain_id).await;
 
     let enclave_index = match enclave_index {
         Some(index) => index,
         None => {
-            let response_body = json!({
-                "message": format!("Enclave index for statechain {} ID not found.", statechain_id)
-            });
-        
-            return status::Custom(Status::InternalServerError, Json(response_body));
+            return api_error(ApiError::Internal, format!("Enclave index for statechain {} ID not found.", statechain_id));
         }
     };
 
@@ -125,47 +116,43 @@ pub async fn sign_second (statechain_entity: &State<StateChainEntity>, partial_s
 
     let signed_statechain_id = partial_signature_request_payload.0.signed_statechain_id.clone();
 
-    if !crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+    let auth_key = match crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+        Ok(auth_key) => auth_key,
+        Err(error) => return error,
+    };
 
-        let response_body = json!({
-            "message": "Signature does not match authentication key."
-        });
-    
-        return status::Custom(Status::Unauthorized, Json(response_body));
+    if let Err(rate_limited) = statechain_entity.rate_limiter.limit_authenticated(&statechain_id, &auth_key).await {
+        return rate_limited;
     }
 
     let partial_signature_request_payload = partial_signature_request_payload.0.clone(); 
     let session = partial_signature_request_payload.session.clone();
     let server_pub_nonce = partial_signature_request_payload.server_pub_nonce.clone();
 
-    let session_bytes: [u8; 133] = hex::decode(&session).unwrap().try_into().unwrap();
+    let session_bytes: [u8; 133] = match hex::decode(&session).ok().and_then(|session_bytes| session_bytes.try_into().ok()) {
+        Some(session_bytes) => session_bytes,
+        None => return api_error(ApiError::BadRequest, "Invalid session encoding."),
+    };
     let session = MusigSession::from_slice(session_bytes);
     let challenge = session.get_challenge_from_session();
     let challenge_str = hex::encode(challenge);
 
-    crate::database::sign::update_signature_data_challenge(&statechain_entity.pool, &server_pub_nonce, &challenge_str, &statechain_id).await;
+    if !crate::database::sign::update_signature_data_challenge(&statechain_entity.pool, &server_pub_nonce, &challenge_str, &statechain_id).await {
+
+        return api_error(ApiError::ServerNonceAlreadyUsed, "Server nonce not found or already used for another message.");
+    }
 
-    let value = match request.json(&partial_signature_request_payload).send().await {
+    let value = match statechain_entity.metrics.send_lockbox_request(enclave_index, path, request.json(&partial_signature_request_payload)).await {
         Ok(response) => {
             let text = response.text().await.unwrap();
             text
         },
         Err(err) => {
-            let response_body = json!({
-                "error": "Internal Server Error",
-                "message": err.to_string()
-            });
-        
-            return status::Custom(Status::InternalServerError, Json(response_body));
+            return api_error(ApiError::UpstreamUnavailable, err.to_string());
         },
     };
 
-    #[derive(Serialize, Deserialize, Debug, Clone)]
-    pub struct PartialSignatureResponsePayload<'r> {
-        partial_sig: &'r str,
-    }
-
-    let response: PartialSignatureResponsePayload = serde_json::from_str(value.as_str()).expect(&format!("failed to parse: {}", value.as_str()));
+    let response: mercurylib::transaction::PartialSignatureResponsePayload = serde_json::from_str(value.as_str()).expect(&format!("failed to parse: {}", value.as_str()));
 
     let response_body = json!(response);
 
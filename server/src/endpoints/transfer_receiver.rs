@@ -1,14 +1,15 @@
 use std::str::FromStr;
 
 use bitcoin::hashes::sha256;
-use mercurylib::transfer::receiver::{GetMsgAddrResponsePayload, StatechainInfoResponsePayload, TransferReceiverError, TransferReceiverErrorResponsePayload, TransferReceiverPostResponsePayload, TransferReceiverRequestPayload, TransferUnlockRequestPayload};
+use mercurylib::transfer::receiver::{GetMsgAddrResponsePayload, StatechainInfoResponsePayload, TransferReceiverPostResponsePayload, TransferReceiverRequestPayload, TransferUnlockRequestPayload};
+use mercurylib::error::ApiError;
 use rocket::{State, response::status, serde::json::Json, http::Status};
-use secp256k1_zkp::{PublicKey, schnorr::Signature, Message, Secp256k1};
+use secp256k1_zkp::{PublicKey, Message, Secp256k1};
 use serde_json::{Value, json};
 
 use crate::server::StateChainEntity;
 
-use super::is_batch_expired;
+use super::{api_error, is_batch_expired, MessageResponsePayload};
 
 #[get("/info/statechain/<statechain_id>")]
 pub async fn statechain_info(statechain_entity: &State<StateChainEntity>, statechain_id: &str) -> status::Custom<Json<Value>> {
@@ -16,27 +17,19 @@ pub async fn statechain_info(statechain_entity: &State<StateChainEntity>, statec
     let enclave_public_key = crate::database::transfer_receiver::get_enclave_pubkey(&statechain_entity.pool, &statechain_id).await;
 
     if enclave_public_key.is_none() {
-        let response_body = json!({
-            "message": "Statechain Id key not found."
-        });
-    
-        return status::Custom(Status::NotFound, Json(response_body));
+        return api_error(ApiError::StatechainNotFound, "Statechain Id key not found.");
     }
 
     let enclave_public_key = enclave_public_key.unwrap();
 
-    let config = crate::server_config::ServerConfig::load();
+    let config = statechain_entity.config.get();
 
     let enclave_index = crate::database::utils::get_enclave_index_from_database(&statechain_entity.pool, &statechain_id).await;
 
     let enclave_index = match enclave_index {
         Some(index) => index,
         None => {
-            let response_body = json!({
-                "message": format!("Enclave index for statechain {} ID not found.", statechain_id)
-            });
-        
-            return status::Custom(Status::InternalServerError, Json(response_body));
+            return api_error(ApiError::Internal, format!("Enclave index for statechain {} ID not found.", statechain_id));
         }
     };
 
@@ -48,18 +41,13 @@ pub async fn statechain_info(statechain_entity: &State<StateChainEntity>, statec
     let client: reqwest::Client = reqwest::Client::new();
     let request = client.get(&format!("{}/{}/{}", lockbox_endpoint, path, statechain_id));
 
-    let value = match request.send().await {
+    let value = match statechain_entity.metrics.send_lockbox_request(enclave_index, path, request).await {
         Ok(response) => {
             let text = response.text().await.unwrap();
             text
         },
         Err(err) => {
-            let response_body = json!({
-                "error": "Internal Server Error",
-                "message": err.to_string()
-            });
-
-            return status::Custom(Status::InternalServerError, Json(response_body));
+            return api_error(ApiError::UpstreamUnavailable, err.to_string());
         },
     };
 
@@ -95,12 +83,7 @@ pub async fn get_msg_addr(statechain_entity: &State<StateChainEntity>, new_auth_
     let new_user_auth_public_key = PublicKey::from_str(new_auth_key);
 
     if new_user_auth_public_key.is_err() {
-        let response_body = json!({
-            "error": "Internal Server Error",
-            "message": "Invalid authentication public key"
-        });
-    
-        return status::Custom(Status::InternalServerError, Json(response_body));
+        return api_error(ApiError::InvalidAuthPubkey, "Invalid authentication public key");
     }
 
     let new_user_auth_public_key = new_user_auth_public_key.unwrap();
@@ -123,21 +106,43 @@ pub async fn transfer_unlock(statechain_entity: &State<StateChainEntity>, transf
     let signed_statechain_id = transfer_unlock_request_payload.0.auth_sig.clone();
     let auth_pub_key = transfer_unlock_request_payload.0.auth_pub_key.clone();
 
-    let is_current_owner_signature = crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await;
+    let signed_statechain_id = match crate::endpoints::utils::parse_signature(&signed_statechain_id) {
+        Ok(signed_statechain_id) => signed_statechain_id,
+        Err(error) => return error,
+    };
+
+    let current_owner_auth_key = match crate::endpoints::utils::get_auth_key_by_statechain_id(&statechain_entity.pool, &statechain_id).await {
+        Ok(auth_key) => auth_key,
+        Err(_) => return api_error(ApiError::StatechainNotFound, format!("Statechain {} not found.", statechain_id)),
+    };
 
-    if !is_current_owner_signature && auth_pub_key.is_some() && !crate::endpoints::utils::validate_signature_given_public_key(&signed_statechain_id, &statechain_id, &auth_pub_key.unwrap()).await {
+    let is_current_owner_signature = crate::endpoints::utils::validate_signature_given_public_key(&signed_statechain_id, &statechain_id, &current_owner_auth_key);
 
-        let response_body = json!({
-            "message": "Signature does not match authentication key."
-        });
-    
-        return status::Custom(Status::Forbidden, Json(response_body));
+    // otherwise, the statecoin is unlocked by its new owner
+    let auth_key = if is_current_owner_signature {
+        current_owner_auth_key
+    } else {
+        let auth_pub_key = match auth_pub_key.as_deref().map(crate::endpoints::utils::parse_public_key) {
+            Some(Ok(auth_pub_key)) => auth_pub_key.x_only_public_key().0,
+            Some(Err(error)) => return error,
+            None => return api_error(ApiError::InvalidSignature, "Signature does not match authentication key."),
+        };
+
+        if !crate::endpoints::utils::validate_signature_given_public_key(&signed_statechain_id, &statechain_id, &auth_pub_key) {
+            return api_error(ApiError::InvalidSignature, "Signature does not match authentication key.");
+        }
+
+        auth_pub_key
+    };
+
+    if let Err(rate_limited) = statechain_entity.rate_limiter.limit_authenticated(&statechain_id, &auth_key).await {
+        return rate_limited;
     }
 
     crate::database::transfer_receiver::update_unlock_transfer(&statechain_entity.pool, is_current_owner_signature, &statechain_id).await;
 
-    let response_body = json!({
-        "message": "Success"
+    let response_body = json!(MessageResponsePayload {
+        message: "Success".to_string(),
     });
 
     status::Custom(Status::Ok, Json(response_body))
@@ -162,7 +167,7 @@ pub async fn validate_batch(statechain_entity: &State<StateChainEntity>, statech
 
         let (batch_id, batch_time) = batch_info.unwrap();
 
-        if is_batch_expired(batch_time) {
+        if is_batch_expired(batch_time, statechain_entity.config.get().batch_timeout) {
             // the batch time has not expired. It is possible to add a new coin to the batch.
             return BatchTransferReceiveValidationResult::ExpiredBatchTimeError("Batch time has expired".to_string());
         } else {
@@ -191,22 +196,10 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
 
     match batch_validation_result {
         BatchTransferReceiveValidationResult::StatecoinBatchLockedError(msg) => {
-
-            let response_body = json!(TransferReceiverErrorResponsePayload {
-                code: TransferReceiverError::StatecoinBatchLockedError,
-                message: msg
-            });
-        
-            return status::Custom(Status::BadRequest, Json(response_body));
+            return api_error(ApiError::StatecoinBatchLockedError, msg);
         },
         BatchTransferReceiveValidationResult::ExpiredBatchTimeError(msg) => {
-            
-            let response_body = json!(TransferReceiverErrorResponsePayload {
-                code: TransferReceiverError::ExpiredBatchTimeError,
-                message: msg
-            });
-        
-            return status::Custom(Status::BadRequest, Json(response_body));
+            return api_error(ApiError::ExpiredBatchTimeError, msg);
         },
         BatchTransferReceiveValidationResult::Success => {},
     }
@@ -214,11 +207,7 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
     let auth_pubkey_x1 = crate::database::transfer_receiver::get_auth_pubkey_and_x1(&statechain_entity.pool, &transfer_receiver_request_payload.statechain_id).await;
 
     if auth_pubkey_x1.is_none() {
-        let response_body = json!({
-            "message": "No transfer messages found for this statechain_id"
-        });
-    
-        return status::Custom(Status::NotFound, Json(response_body));
+        return api_error(ApiError::TransferMsgNotFound, "No transfer messages found for this statechain_id");
     }
 
     let auth_pubkey_x1 = auth_pubkey_x1.unwrap();
@@ -231,19 +220,22 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
     let t2 = transfer_receiver_request_payload.t2.clone();
     let auth_sign = transfer_receiver_request_payload.auth_sig.clone();
 
-    let signed_message = Signature::from_str(&auth_sign).unwrap();
+    let signed_message = match crate::endpoints::utils::parse_signature(&auth_sign) {
+        Ok(signed_message) => signed_message,
+        Err(error) => return error,
+    };
     let msg = Message::from_hashed_data::<sha256::Hash>(t2.as_bytes());
 
     let secp = Secp256k1::new();
     
     if !secp.verify_schnorr(&signed_message, &msg, &auth_pubkey).is_ok() {
 
-        let response_body = json!({
-            "message": "Signature does not match authentication key."
-        });
-    
-        return status::Custom(Status::InternalServerError, Json(response_body));
+        return api_error(ApiError::InvalidSignature, "Signature does not match authentication key.");
+
+    }
 
+    if let Err(rate_limited) = statechain_entity.rate_limiter.limit_authenticated(&statechain_id, &auth_pubkey).await {
+        return rate_limited;
     }
 
     if crate::database::transfer_receiver::is_key_already_updated(&statechain_entity.pool, &statechain_id).await {
@@ -251,17 +243,13 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
         let server_public_key = crate::database::transfer_receiver::get_server_public_key(&statechain_entity.pool, &statechain_id).await;
 
         if server_public_key.is_none() {
-            let response_body = json!({
-                "message": "Server public key not found."
-            });
-        
-            return status::Custom(Status::InternalServerError, Json(response_body));
+            return api_error(ApiError::Internal, "Server public key not found.");
         }
 
         let server_public_key = server_public_key.unwrap();
 
-        let response_body = json!({
-            "server_pubkey": server_public_key.to_string(),
+        let response_body = json!(TransferReceiverPostResponsePayload {
+            server_pubkey: server_public_key.to_string(),
         });
 
         return status::Custom(Status::Ok, Json(response_body));
@@ -275,18 +263,14 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
         x1: x1_hex,
     };
 
-    let config = crate::server_config::ServerConfig::load();
+    let config = statechain_entity.config.get();
 
     let enclave_index = crate::database::utils::get_enclave_index_from_database(&statechain_entity.pool, &statechain_id).await;
 
     let enclave_index = match enclave_index {
         Some(index) => index,
         None => {
-            let response_body = json!({
-                "message": format!("Enclave index for statechain {} ID not found.", statechain_id)
-            });
-        
-            return status::Custom(Status::InternalServerError, Json(response_body));
+            return api_error(ApiError::Internal, format!("Enclave index for statechain {} ID not found.", statechain_id));
         }
     };
 
@@ -298,18 +282,13 @@ pub async fn transfer_receiver(statechain_entity: &State<StateChainEntity>, tran
     let client: reqwest::Client = reqwest::Client::new();
     let request = client.post(&format!("{}/{}", lockbox_endpoint, path));
 
-    let value = match request.json(&key_update_response_payload).send().await {
+    let value = match statechain_entity.metrics.send_lockbox_request(enclave_index, path, request.json(&key_update_response_payload)).await {
         Ok(response) => {
             let text = response.text().await.unwrap();
             text
         },
         Err(err) => {
-            let response_body = json!({
-                "error": "Internal Server Error",
-                "message": err.to_string()
-            });
-        
-            return status::Custom(Status::InternalServerError, Json(response_body));
+            return api_error(ApiError::UpstreamUnavailable, err.to_string());
         },
     };
 
@@ -1,13 +1,12 @@
-use std::str::FromStr;
-
-use mercurylib::transfer::sender::{TransferSenderRequestPayload, TransferSenderResponsePayload, TransferUpdateMsgRequestPayload};
+use mercurylib::transfer::{encoding::MAX_ENCRYPTED_TRANSFER_MSG_SIZE, sender::{TransferSenderRequestPayload, TransferSenderResponsePayload, TransferUpdateMsgRequestPayload}};
+use mercurylib::error::ApiError;
 use rocket::{State, serde::json::Json, response::status, http::Status};
-use secp256k1_zkp::{PublicKey, Scalar, SecretKey};
+use secp256k1_zkp::{Scalar, SecretKey};
 use serde_json::{Value, json};
 
 use crate::server::StateChainEntity;
 
-use super::is_batch_expired;
+use super::{api_error, is_batch_expired, TransferUpdateMsgResponsePayload};
 
 /// Enun to represent the possible results of the batch transfer validation
 pub enum BatchTransferValidationResult {
@@ -30,7 +29,7 @@ pub async fn validate_batch_transfer(statechain_entity: &State<StateChainEntity>
 
         let (batch_id, batch_time) = batch_info.unwrap();
 
-        if !is_batch_expired(batch_time) {
+        if !is_batch_expired(batch_time, statechain_entity.config.get().batch_timeout) {
 
             let all_coins_unlocked = crate::database::transfer::is_all_coins_unlocked(&statechain_entity.pool, &batch_id).await;
 
@@ -63,7 +62,7 @@ pub async fn validate_batch_transfer(statechain_entity: &State<StateChainEntity>
         if batch_time.is_some() {
             let batch_time = batch_time.unwrap();
 
-            if !is_batch_expired(batch_time) {
+            if !is_batch_expired(batch_time, statechain_entity.config.get().batch_timeout) {
                 // the batch time has not expired. It is possible to add a new coin to the batch.
                 return BatchTransferValidationResult::Success
             } else {
@@ -85,31 +84,33 @@ pub async fn transfer_sender(statechain_entity: &State<StateChainEntity>, transf
     let signed_statechain_id = transfer_sender_request_payload.0.auth_sig.clone();
     let batch_id = transfer_sender_request_payload.0.batch_id.clone();
 
-    if !crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+    let auth_key = match crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+        Ok(auth_key) => auth_key,
+        Err(error) => return error,
+    };
 
-        let response_body = json!({
-            "message": "Signature does not match authentication key."
-        });
-    
-        return status::Custom(Status::InternalServerError, Json(response_body));
+    if let Err(rate_limited) = statechain_entity.rate_limiter.limit_authenticated(&statechain_id, &auth_key).await {
+        return rate_limited;
     }
 
     let batch_transfer_validation_result = validate_batch_transfer(&statechain_entity, &statechain_id, &batch_id).await;
 
     match batch_transfer_validation_result {
-        BatchTransferValidationResult::StatecoinBatchLockedError(message) | BatchTransferValidationResult::ExpiredBatchTimeError(message) => {
-            let response_body = json!({
-                "message": message
-            });
-        
-            return status::Custom(Status::BadRequest, Json(response_body));
+        BatchTransferValidationResult::StatecoinBatchLockedError(message) => {
+            return api_error(ApiError::StatecoinBatchLockedError, message);
+        },
+        BatchTransferValidationResult::ExpiredBatchTimeError(message) => {
+            return api_error(ApiError::ExpiredBatchTimeError, message);
         },
         BatchTransferValidationResult::Success => {
             // nothing to do. continue.
         }
     }
 
-    let new_user_auth_key = PublicKey::from_str(&transfer_sender_request_payload.0.new_user_auth_key).unwrap();
+    let new_user_auth_key = match crate::endpoints::utils::parse_public_key(&transfer_sender_request_payload.0.new_user_auth_key) {
+        Ok(new_user_auth_key) => new_user_auth_key,
+        Err(error) => return error,
+    };
 
     if crate::database::transfer_sender::exists_msg_for_same_statechain_id_and_new_user_auth_key(&statechain_entity.pool, &new_user_auth_key, &statechain_id, &batch_id).await {
 
@@ -119,11 +120,7 @@ pub async fn transfer_sender(statechain_entity: &State<StateChainEntity>, transf
             "Transfer message already exists for this statechain_id and new_user_auth_key."
         };
 
-        let response_body = json!({
-            "message": message
-        });
-    
-        return status::Custom(Status::BadRequest, Json(response_body));
+        return api_error(ApiError::TransferMsgAlreadyExists, message);
     }
 
     let secret_x1 = SecretKey::new(&mut rand::thread_rng());
@@ -148,24 +145,34 @@ pub async fn transfer_update_msg(statechain_entity: &State<StateChainEntity>, tr
     let statechain_id = transfer_update_msg_request_payload.0.statechain_id.clone();
     let signed_statechain_id = transfer_update_msg_request_payload.0.auth_sig.clone();
 
-    if !crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+    let auth_key = match crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+        Ok(auth_key) => auth_key,
+        Err(error) => return error,
+    };
 
-        let response_body = json!({
-            "error": "Internal Server Error",
-            "message": "Signature does not match authentication key."
-        });
-    
-        return status::Custom(Status::InternalServerError, Json(response_body));
+    if let Err(rate_limited) = statechain_entity.rate_limiter.limit_authenticated(&statechain_id, &auth_key).await {
+        return rate_limited;
     }
 
-    let new_user_auth_key = PublicKey::from_str(&transfer_update_msg_request_payload.0.new_user_auth_key).unwrap();
+    let new_user_auth_key = match crate::endpoints::utils::parse_public_key(&transfer_update_msg_request_payload.0.new_user_auth_key) {
+        Ok(new_user_auth_key) => new_user_auth_key,
+        Err(error) => return error,
+    };
     let enc_transfer_msg_hex =  transfer_update_msg_request_payload.0.enc_transfer_msg;
-    let enc_transfer_msg = hex::decode(enc_transfer_msg_hex).unwrap();
+
+    if enc_transfer_msg_hex.len() > 2 * MAX_ENCRYPTED_TRANSFER_MSG_SIZE {
+
+        return api_error(ApiError::TransferMsgTooLarge, format!("Transfer message is larger than {} bytes.", MAX_ENCRYPTED_TRANSFER_MSG_SIZE));
+    }
+    let enc_transfer_msg = match hex::decode(enc_transfer_msg_hex) {
+        Ok(enc_transfer_msg) => enc_transfer_msg,
+        Err(_) => return api_error(ApiError::BadRequest, "Invalid transfer message encoding."),
+    };
 
     crate::database::transfer_sender::update_transfer_msg(&statechain_entity.pool, &new_user_auth_key, &enc_transfer_msg, &statechain_id).await;
 
-    let response_body = json!({
-        "updated": true,
+    let response_body = json!(TransferUpdateMsgResponsePayload {
+        updated: true,
     });
 
     return status::Custom(Status::Ok, Json(response_body));
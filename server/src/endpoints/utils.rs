@@ -1,6 +1,7 @@
 use std::str::FromStr;
 
 use bitcoin::hashes::sha256;
+use mercurylib::error::ApiError;
 use rocket::{State, response::status, http::Status, serde::json::Json};
 use secp256k1_zkp::{schnorr::Signature, Message, Secp256k1, XOnlyPublicKey};
 use serde_json::{json, Value};
@@ -9,6 +10,8 @@ use secp256k1_zkp::PublicKey;
 
 use crate::server::StateChainEntity;
 
+use super::api_error;
+
 pub async fn get_auth_key_by_statechain_id(pool: &sqlx::PgPool, statechain_id: &str) -> Result<XOnlyPublicKey, sqlx::Error> {
 
     let row = sqlx::query(
@@ -32,32 +35,45 @@ pub async fn get_auth_key_by_statechain_id(pool: &sqlx::PgPool, statechain_id: &
 
 }
 
-pub async fn validate_signature_given_public_key(signed_message_hex: &str, statechain_id: &str, auth_key: &str) -> bool {
+/// Parses a public key sent in a request
+pub fn parse_public_key(public_key_hex: &str) -> Result<PublicKey, status::Custom<Json<Value>>> {
+    PublicKey::from_str(public_key_hex).map_err(|_| api_error(ApiError::InvalidAuthPubkey, "Invalid authentication public key"))
+}
 
-    let auth_key = PublicKey::from_str(auth_key).unwrap().x_only_public_key().0;
+/// Parses a schnorr signature sent in a request
+pub fn parse_signature(signature_hex: &str) -> Result<Signature, status::Custom<Json<Value>>> {
+    Signature::from_str(signature_hex).map_err(|_| api_error(ApiError::BadRequest, "Invalid signature encoding"))
+}
+
+pub fn validate_signature_given_public_key(signed_message: &Signature, statechain_id: &str, auth_key: &XOnlyPublicKey) -> bool {
 
-    let signed_message = Signature::from_str(signed_message_hex).unwrap();
     let msg = Message::from_hashed_data::<sha256::Hash>(statechain_id.to_string().as_bytes());
 
     let secp = Secp256k1::new();
-    secp.verify_schnorr(&signed_message, &msg, &auth_key).is_ok()
+    secp.verify_schnorr(signed_message, &msg, auth_key).is_ok()
 }
 
-pub async fn validate_signature(pool: &sqlx::PgPool, signed_message_hex: &str, statechain_id: &str) -> bool {
+/// Auth key of the statechain, if it signed the statechain id, or the error response
+pub async fn validate_signature(pool: &sqlx::PgPool, signed_message_hex: &str, statechain_id: &str) -> Result<XOnlyPublicKey, status::Custom<Json<Value>>> {
 
-    let auth_key = get_auth_key_by_statechain_id(pool, statechain_id).await.unwrap();
+    let signed_message = parse_signature(signed_message_hex)?;
 
-    let signed_message = Signature::from_str(signed_message_hex).unwrap();
-    let msg = Message::from_hashed_data::<sha256::Hash>(statechain_id.to_string().as_bytes());
+    let auth_key = match get_auth_key_by_statechain_id(pool, statechain_id).await {
+        Ok(auth_key) => auth_key,
+        Err(_) => return Err(api_error(ApiError::StatechainNotFound, format!("Statechain {} not found.", statechain_id))),
+    };
 
-    let secp = Secp256k1::new();
-    secp.verify_schnorr(&signed_message, &msg, &auth_key).is_ok()
+    if !validate_signature_given_public_key(&signed_message, statechain_id, &auth_key) {
+        return Err(api_error(ApiError::InvalidSignature, "Signature does not match authentication key."));
+    }
+
+    Ok(auth_key)
 }
 
 #[get("/info/config")]
-pub async fn info_config() -> status::Custom<Json<Value>> {
+pub async fn info_config(statechain_entity: &State<StateChainEntity>) -> status::Custom<Json<Value>> {
 
-    let config = crate::server_config::ServerConfig::load();
+    let config = statechain_entity.config.get();
 
     let version: &str = env!("CARGO_PKG_VERSION");
 
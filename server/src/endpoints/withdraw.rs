@@ -1,8 +1,11 @@
+use mercurylib::error::ApiError;
 use rocket::{State, serde::json::Json, response::status, http::Status};
 use serde_json::{json, Value};
 
 use crate::server::StateChainEntity;
 
+use super::{api_error, MessageResponsePayload};
+
 async fn delete_statechain_db(pool: &sqlx::PgPool,  statechain_id: &String)  {
 
     let mut transaction = pool.begin().await.unwrap();
@@ -34,27 +37,23 @@ pub async fn withdraw_complete(statechain_entity: &State<StateChainEntity>, dele
     let statechain_id = delete_statechain_payload.0.statechain_id.clone();
     let signed_statechain_id = delete_statechain_payload.0.signed_statechain_id.clone();
 
-    if !crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+    let auth_key = match crate::endpoints::utils::validate_signature(&statechain_entity.pool, &signed_statechain_id, &statechain_id).await {
+        Ok(auth_key) => auth_key,
+        Err(error) => return error,
+    };
 
-        let response_body = json!({
-            "message": "Signature does not match authentication key."
-        });
-    
-        return status::Custom(Status::InternalServerError, Json(response_body));
+    if let Err(rate_limited) = statechain_entity.rate_limiter.limit_authenticated(&statechain_id, &auth_key).await {
+        return rate_limited;
     }
 
-    let config = crate::server_config::ServerConfig::load();
+    let config = statechain_entity.config.get();
 
     let enclave_index = crate::database::utils::get_enclave_index_from_database(&statechain_entity.pool, &statechain_id).await;
 
     let enclave_index = match enclave_index {
         Some(index) => index,
         None => {
-            let response_body = json!({
-                "message": format!("Enclave index for statechain {} ID not found.", statechain_id)
-            });
-        
-            return status::Custom(Status::InternalServerError, Json(response_body));
+            return api_error(ApiError::Internal, format!("Enclave index for statechain {} ID not found.", statechain_id));
         }
     };
 
@@ -66,22 +65,17 @@ pub async fn withdraw_complete(statechain_entity: &State<StateChainEntity>, dele
     let client: reqwest::Client = reqwest::Client::new();
     let request = client.delete(&format!("{}/{}/{}", lockbox_endpoint, path, statechain_id));
 
-    let response = request.send().await;
+    let response = statechain_entity.metrics.send_lockbox_request(enclave_index, path, request).await;
 
     if response.is_err() {
 
-        let response_body = json!({
-            "error": "Internal Server Error",
-            "message": response.err().unwrap().to_string()
-        });
-
-        return status::Custom(Status::InternalServerError, Json(response_body));
+        return api_error(ApiError::UpstreamUnavailable, response.err().unwrap().to_string());
     };
 
     delete_statechain_db(&statechain_entity.pool, &statechain_id).await;
 
-    let response_body = json!({
-        "message": "Statechain deleted.",
+    let response_body = json!(MessageResponsePayload {
+        message: "Statechain deleted.".to_string(),
     });
 
     return status::Custom(Status::Ok, Json(response_body));
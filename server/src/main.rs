@@ -2,38 +2,46 @@ mod endpoints;
 mod server_config;
 mod server;
 mod database;
+mod rate_limit;
+mod metrics;
 
 #[macro_use] extern crate rocket;
 
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-use endpoints::utils;
-use rocket::{serde::json::{json, Value}, tokio::{self, time::interval}, Request, Response};
+use mercurylib::error::{ApiError, ApiErrorResponsePayload};
+use rocket::{serde::json::{json, Value}, tokio, Request, Response};
 use rocket::fairing::{Fairing, Info};
 use rocket::http::Header;
 use server::StateChainEntity;
 
-use log::error;
+use log::{error, info};
+
+#[cfg(unix)]
+use rocket::tokio::signal::unix::{signal, SignalKind};
+
+/// Seconds between checks for nostr info added by a configuration reload
+const NOSTR_INFO_CHECK_INTERVAL: u64 = 60;
 
 #[catch(500)]
 fn internal_error(req: &Request) -> Value {
     let message = format!("500 - Internal Server Error: {}", req.uri());
     error!("{}", message);
-    json!(message)
+    json!(ApiErrorResponsePayload::new(ApiError::Internal, message))
 }
 
 #[catch(400)]
 fn bad_request(req: &Request) -> Value {
     let message = format!("400 - Bad request: {}", req.uri());
     error!("{}", message);
-    json!(message)
+    json!(ApiErrorResponsePayload::new(ApiError::BadRequest, message))
 }
 
 #[catch(404)]
 fn not_found(req: &Request) -> Value {
     let message = format!("404 - Not Found: {}", req.uri());
     error!("{}", message);
-    json!(message)
+    json!(ApiErrorResponsePayload::new(ApiError::NotFound, message))
 }
 
 async fn broadcast_nip_100(nostr_info: &server_config::NostrInfo, published_at: u64, timelock: u32) -> Result<(), Box<dyn std::error::Error>> {
@@ -99,66 +107,92 @@ async fn main() {
 
     env_logger::init();
 
-    let config = server_config::ServerConfig::load();
+    let config = match server_config::ServerConfig::load() {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("Invalid server configuration:\n{}", err);
+            std::process::exit(1);
+        }
+    };
 
-    let statechain_entity = StateChainEntity::new().await;
+    let statechain_entity = StateChainEntity::new(config).await;
 
     sqlx::migrate!("./migrations")
         .run(&statechain_entity.pool)
         .await
         .unwrap();
 
-    if config.nostr_info.is_some() {
-        let nostr_info = config.nostr_info.unwrap();
-
-        /* println!("nostr_info: {:?}", nostr_info); */
+    if statechain_entity.config.get().nostr_info.is_some() {
         println!("Nostr info found. Starting NIP-100 broadcast");
+    } else {
+        println!("No Nostr info found in config file");
+    }
+
+    // The nostr info is read on each broadcast, so that a reload can change it
+    let nostr_config = statechain_entity.config.clone();
+    let nostr_metrics = statechain_entity.metrics.clone();
+
+    tokio::spawn(async move {
+        let start = SystemTime::now();
+        let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
+        let published_at = since_the_epoch.as_secs();
+
+        loop {
+            let config = nostr_config.get();
+
+            let interval_seconds = match config.nostr_info.as_ref() {
+                Some(nostr_info) => {
+                    let result = broadcast_nip_100(nostr_info, published_at, config.lockheight_init).await;
+                    if let Err(e) = result {
+                        nostr_metrics.record_nostr_broadcast_failure();
+                        println!("Error: {:?}", e);
+                    }
+                    nostr_info.relay_interval as u64
+                },
+                None => NOSTR_INFO_CHECK_INTERVAL,
+            };
+
+            tokio::time::sleep(Duration::from_secs(interval_seconds)).await;
+        }
+    });
+
+    let gauges_metrics = statechain_entity.metrics.clone();
+    let gauges_pool = statechain_entity.pool.clone();
+    let gauges_config = statechain_entity.config.clone();
+
+    tokio::spawn(async move {
+        loop {
+            gauges_metrics.update_gauges(&gauges_pool, &gauges_config).await;
+            tokio::time::sleep(Duration::from_secs(metrics::GAUGES_UPDATE_INTERVAL)).await;
+        }
+    });
 
-        let interval_seconds = nostr_info.relay_interval as u64;
+    #[cfg(unix)]
+    {
+        let reload_config = statechain_entity.config.clone();
 
         tokio::spawn(async move {
-            let mut ticker = interval(Duration::from_secs(interval_seconds));
-
-            let start = SystemTime::now();
-            let since_the_epoch = start.duration_since(UNIX_EPOCH).unwrap();
-            let published_at = since_the_epoch.as_secs();
-
-            let timelock = config.lockheight_init;
-
-            loop {
-                ticker.tick().await;
-                let result = broadcast_nip_100(&nostr_info, published_at, timelock).await;
-                if let Err(e) = result {
-                    println!("Error: {:?}", e);
-                } /* else {
-                    println!("NIP-100 broadcasted");
-                } */
+            let mut hangup = signal(SignalKind::hangup()).expect("failed to listen to SIGHUP");
+
+            while hangup.recv().await.is_some() {
+                match reload_config.reload() {
+                    Ok(()) => info!("Server configuration reloaded"),
+                    Err(err) => error!("Server configuration not reloaded:\n{}", err),
+                }
             }
         });
-    } else {
-        println!("No Nostr info found in config file");
     }
-    
+
+    let request_metrics = metrics::RequestMetrics(statechain_entity.metrics.clone());
+    let rate_limiter = statechain_entity.rate_limiter.clone();
 
     let _ = rocket::build()
+        .mount("/", endpoints::api_routes())
         .mount("/", routes![
-            endpoints::deposit::post_deposit,
-            endpoints::deposit::get_token,
-            // endpoints::deposit::token_init,
-            endpoints::sign::sign_first,
-            endpoints::sign::sign_second,
-            endpoints::lightning_latch::get_paymenthash,
-            endpoints::lightning_latch::post_paymenthash,
-            endpoints::lightning_latch::transfer_preimage,
-            endpoints::transfer_sender::transfer_sender,
-            endpoints::transfer_sender::transfer_update_msg,
-            endpoints::transfer_receiver::get_msg_addr,
-            endpoints::transfer_receiver::statechain_info,
-            endpoints::transfer_receiver::transfer_unlock,
-            endpoints::transfer_receiver::transfer_receiver,
-            endpoints::withdraw::withdraw_complete,
-            utils::info_config,
-            utils::info_keylist,
+            endpoints::openapi::openapi_json,
+            endpoints::openapi::swagger_ui,
+            rate_limit::rate_limited,
+            metrics::metrics,
             all_options,
         ])
         .register("/", catchers![
@@ -167,6 +201,8 @@ async fn main() {
             bad_request,
         ])
         .manage(statechain_entity)
+        .attach(request_metrics)
+        .attach(rate_limiter)
         .attach(Cors)
         // .attach(MercuryPgDatabase::fairing())
         .launch()
@@ -0,0 +1,214 @@
+use std::{sync::Arc, time::Instant};
+
+use log::error;
+use prometheus::{HistogramOpts, HistogramVec, IntCounter, IntCounterVec, IntGauge, IntGaugeVec, Opts, Registry, TextEncoder};
+use rocket::{fairing::{Fairing, Info, Kind}, http::{ContentType, Status}, request::{FromRequest, Outcome}, Data, Request, Response, State};
+
+use crate::{server::StateChainEntity, server_config::SharedServerConfig};
+
+/// Seconds between updates of the statechain and database pool gauges
+pub const GAUGES_UPDATE_INTERVAL: u64 = 15;
+
+/// Prometheus metrics of the server, exported at `/metrics`.
+/// The statechain and database pool gauges are updated every `GAUGES_UPDATE_INTERVAL` seconds, not on each scrape.
+pub struct Metrics {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_request_duration: HistogramVec,
+    lockbox_request_duration: HistogramVec,
+    lockbox_request_failures: IntCounterVec,
+    active_statechains: IntGauge,
+    pending_transfers: IntGauge,
+    pending_batches: IntGauge,
+    outstanding_lightning_latches: IntGauge,
+    db_pool_connections: IntGaugeVec,
+    db_pool_max_connections: IntGauge,
+    nostr_broadcast_failures: IntCounter,
+    rate_limit_fallbacks: IntCounter,
+}
+
+impl Metrics {
+
+    pub fn new() -> Self {
+
+        let registry = Registry::new();
+
+        let http_requests = IntCounterVec::new(
+            Opts::new("mercury_http_requests_total", "HTTP requests by route, method and status"),
+            &["route", "method", "status"]).unwrap();
+        let http_request_duration = HistogramVec::new(
+            HistogramOpts::new("mercury_http_request_duration_seconds", "HTTP request latency by route and method"),
+            &["route", "method"]).unwrap();
+        let lockbox_request_duration = HistogramVec::new(
+            HistogramOpts::new("mercury_lockbox_request_duration_seconds", "Lockbox request latency by enclave index and operation"),
+            &["enclave_index", "operation"]).unwrap();
+        let lockbox_request_failures = IntCounterVec::new(
+            Opts::new("mercury_lockbox_request_failures_total", "Lockbox requests without a success response, by enclave index and operation"),
+            &["enclave_index", "operation"]).unwrap();
+        let active_statechains = IntGauge::new("mercury_active_statechains", "Statechains not withdrawn").unwrap();
+        let pending_transfers = IntGauge::new("mercury_pending_transfers", "Transfers started and not completed").unwrap();
+        let pending_batches = IntGauge::new("mercury_pending_batches", "Batches with transfers not completed and not expired").unwrap();
+        let outstanding_lightning_latches = IntGauge::new("mercury_outstanding_lightning_latches", "Lightning latches locked and not expired").unwrap();
+        let db_pool_connections = IntGaugeVec::new(
+            Opts::new("mercury_db_pool_connections", "Database pool connections by state"),
+            &["state"]).unwrap();
+        let db_pool_max_connections = IntGauge::new("mercury_db_pool_max_connections", "Database pool size limit").unwrap();
+        let nostr_broadcast_failures = IntCounter::new("mercury_nostr_broadcast_failures_total", "Failed NIP-100 broadcasts").unwrap();
+        let rate_limit_fallbacks = IntCounter::new("mercury_rate_limit_fallbacks_total", "Rate limit counts kept in memory because the database failed").unwrap();
+
+        registry.register(Box::new(http_requests.clone())).unwrap();
+        registry.register(Box::new(http_request_duration.clone())).unwrap();
+        registry.register(Box::new(lockbox_request_duration.clone())).unwrap();
+        registry.register(Box::new(lockbox_request_failures.clone())).unwrap();
+        registry.register(Box::new(active_statechains.clone())).unwrap();
+        registry.register(Box::new(pending_transfers.clone())).unwrap();
+        registry.register(Box::new(pending_batches.clone())).unwrap();
+        registry.register(Box::new(outstanding_lightning_latches.clone())).unwrap();
+        registry.register(Box::new(db_pool_connections.clone())).unwrap();
+        registry.register(Box::new(db_pool_max_connections.clone())).unwrap();
+        registry.register(Box::new(nostr_broadcast_failures.clone())).unwrap();
+        registry.register(Box::new(rate_limit_fallbacks.clone())).unwrap();
+
+        Metrics {
+            registry,
+            http_requests,
+            http_request_duration,
+            lockbox_request_duration,
+            lockbox_request_failures,
+            active_statechains,
+            pending_transfers,
+            pending_batches,
+            outstanding_lightning_latches,
+            db_pool_connections,
+            db_pool_max_connections,
+            nostr_broadcast_failures,
+            rate_limit_fallbacks,
+        }
+    }
+
+    /// Sends a request to the lockbox of `enclave_index`, recording its latency.
+    /// A request that fails or is not answered with a success status is counted as a failure.
+    pub async fn send_lockbox_request(&self, enclave_index: usize, operation: &str, request: reqwest::RequestBuilder) -> Result<reqwest::Response, reqwest::Error> {
+
+        let enclave_index = enclave_index.to_string();
+        let labels = [enclave_index.as_str(), operation];
+
+        let timer = self.lockbox_request_duration.with_label_values(&labels).start_timer();
+        let result = request.send().await;
+        timer.observe_duration();
+
+        if !matches!(&result, Ok(response) if response.status().is_success()) {
+            self.lockbox_request_failures.with_label_values(&labels).inc();
+        }
+
+        result
+    }
+
+    pub fn record_nostr_broadcast_failure(&self) {
+        self.nostr_broadcast_failures.inc();
+    }
+
+    pub fn record_rate_limit_fallback(&self) {
+        self.rate_limit_fallbacks.inc();
+    }
+
+    pub async fn update_gauges(&self, pool: &sqlx::PgPool, config: &SharedServerConfig) {
+
+        let batch_timeout = config.get().batch_timeout;
+
+        match crate::database::metrics::get_statechain_counts(pool, batch_timeout).await {
+            Ok(counts) => {
+                self.active_statechains.set(counts.active_statechains);
+                self.pending_transfers.set(counts.pending_transfers);
+                self.pending_batches.set(counts.pending_batches);
+                self.outstanding_lightning_latches.set(counts.outstanding_lightning_latches);
+            },
+            Err(err) => error!("Failed to count statechains for the metrics: {}", err),
+        }
+
+        let idle = pool.num_idle() as i64;
+        self.db_pool_connections.with_label_values(&["idle"]).set(idle);
+        self.db_pool_connections.with_label_values(&["in_use"]).set(pool.size() as i64 - idle);
+        self.db_pool_max_connections.set(pool.options().get_max_connections() as i64);
+    }
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Metrics::new()
+    }
+}
+
+/// Start of the request, for its latency
+struct RequestStart(Instant);
+
+/// Records the count and the latency of the requests
+pub struct RequestMetrics(pub Arc<Metrics>);
+
+#[rocket::async_trait]
+impl Fairing for RequestMetrics {
+    fn info(&self) -> Info {
+        Info {
+            name: "Request Metrics",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _data: &mut Data<'_>) {
+        request.local_cache(|| RequestStart(Instant::now()));
+    }
+
+    async fn on_response<'r>(&self, request: &'r Request<'_>, response: &mut Response<'r>) {
+
+        let start = request.local_cache(|| RequestStart(Instant::now())).0;
+
+        // Requests that match no route are counted together, so that the labels only take the route values
+        let route = request.route().map(|route| route.uri.to_string()).unwrap_or_else(|| "unmatched".to_string());
+        let method = request.method().as_str();
+        let status = response.status().code.to_string();
+
+        self.0.http_requests.with_label_values(&[route.as_str(), method, status.as_str()]).inc();
+        self.0.http_request_duration.with_label_values(&[route.as_str(), method]).observe(start.elapsed().as_secs_f64());
+    }
+}
+
+/// Requests with the configured `metrics_token` as bearer token.
+/// Without a token in the configuration, the metrics are not served.
+pub struct MetricsToken;
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for MetricsToken {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+
+        let statechain_entity = request.rocket().state::<StateChainEntity>().unwrap();
+
+        let metrics_token = match statechain_entity.config.get().metrics_token.clone() {
+            Some(metrics_token) => metrics_token,
+            None => return Outcome::Forward(Status::NotFound),
+        };
+
+        let token = request.headers().get_one("Authorization").and_then(|header| header.strip_prefix("Bearer "));
+
+        match token {
+            Some(token) if is_same_token(token, &metrics_token) => Outcome::Success(MetricsToken),
+            _ => Outcome::Error((Status::Unauthorized, ())),
+        }
+    }
+}
+
+/// Compares the tokens in a time that does not depend on where they differ
+fn is_same_token(token: &str, expected: &str) -> bool {
+    token.len() == expected.len() && token.bytes().zip(expected.bytes()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+#[get("/metrics")]
+pub async fn metrics(_token: MetricsToken, statechain_entity: &State<StateChainEntity>) -> (ContentType, String) {
+
+    let metrics = &statechain_entity.metrics;
+
+    let body = TextEncoder::new().encode_to_string(&metrics.registry.gather()).unwrap();
+
+    (ContentType::Plain, body)
+}
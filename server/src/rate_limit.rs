@@ -0,0 +1,308 @@
+use std::{collections::HashMap, net::IpAddr, sync::{atomic::{AtomicU64, Ordering}, Arc, Mutex}, time::{SystemTime, UNIX_EPOCH}};
+
+use log::error;
+use mercurylib::error::ApiError;
+use rocket::{fairing::{Fairing, Info, Kind}, http::{uri::Origin, Method, Status}, request::{FromRequest, Outcome}, response::{self, status, Responder}, serde::json::Json, Data, Request, Response};
+use secp256k1_zkp::XOnlyPublicKey;
+use serde_json::Value;
+use sqlx::{Pool, Postgres};
+
+use crate::{endpoints::api_error, metrics::Metrics, server_config::{RateLimitStorage, RateLimits, SharedServerConfig}};
+
+/// A fairing cannot answer a request, so the limited requests are rerouted to the `rate_limited` route
+const RATE_LIMITED_PATH: &str = "/rate_limited";
+
+/// Limits the requests per client IP, per statechain and per auth key, counted in fixed windows.
+/// The limits are read from the configuration on each request, so a reload changes them.
+/// The per IP limit is applied by the fairing. The per statechain and per auth key limits are applied
+/// by the handlers with `limit_authenticated`, once the request signature is checked.
+pub struct RateLimiter {
+    config: SharedServerConfig,
+    pool: Pool<Postgres>,
+    metrics: Arc<Metrics>,
+    /// Window start and count of each key, when the counters are kept in memory,
+    /// or while the database fails
+    counters: Mutex<HashMap<String, (u64, u32)>>,
+    /// Window in which the counters of the previous windows were last deleted
+    cleaned_window: AtomicU64,
+}
+
+impl RateLimiter {
+
+    pub fn new(config: SharedServerConfig, pool: Pool<Postgres>, metrics: Arc<Metrics>) -> Self {
+        RateLimiter {
+            config,
+            pool,
+            metrics,
+            counters: Mutex::new(HashMap::new()),
+            cleaned_window: AtomicU64::new(0),
+        }
+    }
+
+    fn count_in_memory(&self, key: &str, window_start: u64) -> u32 {
+
+        let mut counters = self.counters.lock().unwrap();
+
+        let counter = counters.entry(key.to_string()).or_insert((window_start, 0));
+
+        if counter.0 != window_start {
+            *counter = (window_start, 0);
+        }
+
+        counter.1 += 1;
+        counter.1
+    }
+
+    /// Counts the request for `key` and returns the requests counted in the window
+    async fn count(&self, limits: &RateLimits, key: &str, window_start: u64) -> u32 {
+
+        match limits.storage {
+            RateLimitStorage::Memory => self.count_in_memory(key, window_start),
+            RateLimitStorage::Postgres => {
+                match crate::database::rate_limit::increment_rate_limit_counter(&self.pool, key, window_start as i64).await {
+                    Ok(count) => count as u32,
+                    Err(err) => {
+                        // The limits still apply while the database fails, but for this server only
+                        error!("Failed to count request {} for rate limiting, counting it in memory: {}", key, err);
+                        self.metrics.record_rate_limit_fallback();
+                        self.count_in_memory(key, window_start)
+                    }
+                }
+            },
+        }
+    }
+
+    /// Counts the request for each key and returns the seconds until the end of the window if a limit is exceeded
+    async fn count_request(&self, limits: &RateLimits, keys: Vec<(String, u32)>) -> Option<u64> {
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        let window_start = now - now % limits.window;
+
+        self.delete_expired_counters(limits, window_start).await;
+
+        let mut is_limited = false;
+
+        for (key, limit) in keys {
+            if self.count(limits, &key, window_start).await > limit {
+                is_limited = true;
+            }
+        }
+
+        if is_limited {
+            Some(window_start + limits.window - now)
+        } else {
+            None
+        }
+    }
+
+    /// Counts a request for `statechain_id` signed by `auth_key`.
+    /// It is called after the signature check, so that requests with the id or the key of a statechain
+    /// that the client does not own cannot use up its requests.
+    pub async fn limit_authenticated(&self, statechain_id: &str, auth_key: &XOnlyPublicKey) -> Result<(), status::Custom<Json<Value>>> {
+
+        let config = self.config.get();
+
+        let limits = match config.rate_limits.as_ref() {
+            Some(limits) => limits,
+            None => return Ok(()),
+        };
+
+        let mut keys = Vec::<(String, u32)>::new();
+
+        if let Some(limit) = limits.per_statechain {
+            keys.push((format!("statechain:{}", statechain_id), limit));
+        }
+
+        if let Some(limit) = limits.per_auth_key {
+            keys.push((format!("auth_key:{}", auth_key), limit));
+        }
+
+        match self.count_request(limits, keys).await {
+            Some(retry_after) => Err(api_error(ApiError::RateLimited, rate_limited_message(retry_after))),
+            None => Ok(()),
+        }
+    }
+
+    /// Deletes the counters of the previous windows, once per window
+    async fn delete_expired_counters(&self, limits: &RateLimits, window_start: u64) {
+
+        if self.cleaned_window.swap(window_start, Ordering::Relaxed) == window_start {
+            return;
+        }
+
+        self.counters.lock().unwrap().retain(|_, (start, _)| *start == window_start);
+
+        if limits.storage == RateLimitStorage::Postgres {
+            if let Err(err) = crate::database::rate_limit::delete_expired_rate_limit_counters(&self.pool, window_start as i64).await {
+                error!("Failed to delete expired rate limit counters: {}", err);
+            }
+        }
+    }
+}
+
+#[rocket::async_trait]
+impl Fairing for RateLimiter {
+    fn info(&self) -> Info {
+        Info {
+            name: "Rate Limiter",
+            kind: Kind::Request | Kind::Response,
+        }
+    }
+
+    async fn on_request(&self, request: &mut Request<'_>, _data: &mut Data<'_>) {
+
+        let config = self.config.get();
+
+        let limits = match config.rate_limits.as_ref() {
+            Some(limits) => limits,
+            None => return,
+        };
+
+        if request.method() == Method::Options {
+            return;
+        }
+
+        let (limit, ip) = match (limits.per_ip, get_client_ip(request, limits)) {
+            (Some(limit), Some(ip)) => (limit, ip),
+            _ => return,
+        };
+
+        if let Some(retry_after) = self.count_request(limits, vec![(format!("ip:{}", ip), limit)]).await {
+            request.local_cache(|| RetryAfter(Some(retry_after)));
+            request.set_method(Method::Get);
+            request.set_uri(Origin::parse(RATE_LIMITED_PATH).unwrap());
+        }
+    }
+
+    /// Adds the Retry-After header to the requests limited by the handlers
+    async fn on_response<'r>(&self, _request: &'r Request<'_>, response: &mut Response<'r>) {
+
+        if response.status() != Status::TooManyRequests || response.headers().contains("Retry-After") {
+            return;
+        }
+
+        if let Some(limits) = self.config.get().rate_limits.as_ref() {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+            response.set_raw_header("Retry-After", (limits.window - now % limits.window).to_string());
+        }
+    }
+}
+
+/// Seconds until the window of a limited request ends, set by the fairing
+struct RetryAfter(Option<u64>);
+
+pub struct RateLimited {
+    retry_after: u64,
+}
+
+#[rocket::async_trait]
+impl<'r> FromRequest<'r> for RateLimited {
+    type Error = ();
+
+    async fn from_request(request: &'r Request<'_>) -> Outcome<Self, Self::Error> {
+        match request.local_cache(|| RetryAfter(None)).0 {
+            Some(retry_after) => Outcome::Success(RateLimited { retry_after }),
+            None => Outcome::Forward(Status::NotFound),
+        }
+    }
+}
+
+impl<'r> Responder<'r, 'static> for RateLimited {
+    fn respond_to(self, request: &'r Request<'_>) -> response::Result<'static> {
+
+        Response::build_from(api_error(ApiError::RateLimited, rate_limited_message(self.retry_after)).respond_to(request)?)
+            .raw_header("Retry-After", self.retry_after.to_string())
+            .ok()
+    }
+}
+
+#[get("/rate_limited")]
+pub fn rate_limited(rate_limited: RateLimited) -> RateLimited {
+    rate_limited
+}
+
+/// Address of the connection, or the one in the header set by the trusted proxy.
+/// Rocket's `client_ip` is not used, as it reads X-Real-IP from any client by default.
+fn get_client_ip(request: &Request<'_>, limits: &RateLimits) -> Option<IpAddr> {
+
+    let forwarded_ip = limits.trusted_ip_header.as_ref()
+        .and_then(|header| request.headers().get_one(header))
+        .and_then(|ip| ip.trim().parse::<IpAddr>().ok());
+
+    forwarded_ip.or_else(|| request.remote().map(|remote| remote.ip()))
+}
+
+fn rate_limited_message(retry_after: u64) -> String {
+    format!("Too many requests. Retry in {} seconds.", retry_after)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::SocketAddr, str::FromStr, time::Duration};
+
+    use rocket::http::Header;
+
+    use super::*;
+    use crate::server_config::ServerConfig;
+
+    fn rate_limits(storage: RateLimitStorage) -> RateLimits {
+        RateLimits { window: 3600, per_ip: None, per_statechain: Some(2), per_auth_key: Some(3), storage, trusted_ip_header: None }
+    }
+
+    fn rate_limiter(rate_limits: RateLimits) -> RateLimiter {
+
+        let config = SharedServerConfig::new(ServerConfig { rate_limits: Some(rate_limits), ..ServerConfig::default() });
+
+        // nothing listens on this port, so the database requests fail
+        let pool = sqlx::postgres::PgPoolOptions::new()
+            .acquire_timeout(Duration::from_millis(100))
+            .connect_lazy("postgres://127.0.0.1:1/mercury")
+            .unwrap();
+
+        RateLimiter::new(config, pool, Arc::new(Metrics::new()))
+    }
+
+    #[rocket::async_test]
+    async fn authenticated_limits() {
+
+        let rate_limiter = rate_limiter(rate_limits(RateLimitStorage::Memory));
+
+        let auth_key = XOnlyPublicKey::from_str("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798").unwrap();
+
+        assert!(rate_limiter.limit_authenticated("c5b4", &auth_key).await.is_ok());
+        assert!(rate_limiter.limit_authenticated("c5b4", &auth_key).await.is_ok());
+
+        let limited = rate_limiter.limit_authenticated("c5b4", &auth_key).await.unwrap_err();
+        assert_eq!(limited.0, Status::TooManyRequests);
+
+        // the auth key has used up its requests as well, even for another statechain
+        assert!(rate_limiter.limit_authenticated("3f1a", &auth_key).await.is_err());
+    }
+
+    #[rocket::async_test]
+    async fn database_failure_counts_in_memory() {
+
+        let rate_limiter = rate_limiter(rate_limits(RateLimitStorage::Postgres));
+
+        let auth_key = XOnlyPublicKey::from_str("79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798").unwrap();
+
+        assert!(rate_limiter.limit_authenticated("c5b4", &auth_key).await.is_ok());
+        assert!(rate_limiter.limit_authenticated("c5b4", &auth_key).await.is_ok());
+        assert!(rate_limiter.limit_authenticated("c5b4", &auth_key).await.is_err());
+    }
+
+    #[test]
+    fn client_ip() {
+
+        let client = rocket::local::blocking::Client::untracked(rocket::build()).unwrap();
+
+        let remote: SocketAddr = "10.0.0.1:8000".parse().unwrap();
+        let request = client.get("/").remote(remote).header(Header::new("X-Real-IP", "203.0.113.7"));
+
+        let mut limits = rate_limits(RateLimitStorage::Memory);
+        assert_eq!(get_client_ip(request.inner(), &limits), Some(remote.ip()));
+
+        limits.trusted_ip_header = Some("X-Real-IP".to_string());
+        assert_eq!(get_client_ip(request.inner(), &limits), Some("203.0.113.7".parse().unwrap()));
+    }
+}
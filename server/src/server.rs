@@ -1,17 +1,19 @@
-use std::time::Duration;
+use std::{sync::Arc, time::Duration};
 
 use sqlx::{Pool, Postgres, postgres::PgPoolOptions};
 
-use crate::server_config::ServerConfig;
+use crate::{metrics::Metrics, rate_limit::RateLimiter, server_config::{ServerConfig, SharedServerConfig}};
 
 pub struct StateChainEntity {
     pub pool: Pool<Postgres>,
+    pub config: SharedServerConfig,
+    pub metrics: Arc<Metrics>,
+    pub rate_limiter: Arc<RateLimiter>,
 }
 
 impl StateChainEntity {
-    pub async fn new() -> Self {
+    pub async fn new(config: ServerConfig) -> Self {
 
-        let config = ServerConfig::load();
         let connection_string = config.build_postgres_connection_string();
 
         let pool = 
@@ -22,8 +24,14 @@ impl StateChainEntity {
             .await
             .unwrap();
 
+        let config = SharedServerConfig::new(config);
+        let metrics = Arc::new(Metrics::new());
+
         StateChainEntity {
+            rate_limiter: Arc::new(RateLimiter::new(config.clone(), pool.clone(), metrics.clone())),
             pool,
+            config,
+            metrics,
         }
     }
 }
\ No newline at end of file
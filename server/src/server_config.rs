@@ -1,15 +1,16 @@
-use config::{Config as ConfigRs, File};
-use serde::{Serialize, Deserialize};
+use config::{Config as ConfigRs, ConfigError, File};
+use log::warn;
+use serde::{de::DeserializeOwned, Serialize, Deserialize};
 use sqlx::postgres::PgConnectOptions;
-use std::env;
+use std::{env, fmt::Display, str::FromStr, sync::{Arc, RwLock}};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Enclave {
     pub url: String,
     pub allow_deposit: bool,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NostrInfo {
     /// Nostr Relay server
     pub relay_server: String,
@@ -33,8 +34,38 @@ pub struct NostrInfo {
     pub unit: String,
 }
 
+/// Where the rate limit counters are kept
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RateLimitStorage {
+    /// Counters of this server only
+    #[default]
+    Memory,
+    /// Counters shared by the servers using the same database
+    Postgres,
+}
+
+/// Requests allowed in each window. A missing limit is not enforced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RateLimits {
+    /// Window length, in seconds
+    pub window: u64,
+    /// Requests per client IP
+    pub per_ip: Option<u32>,
+    /// Requests per statechain id
+    pub per_statechain: Option<u32>,
+    /// Requests per auth key
+    pub per_auth_key: Option<u32>,
+    /// Counter storage
+    #[serde(default)]
+    pub storage: RateLimitStorage,
+    /// Header with the client IP, set by a trusted proxy (e.g. X-Real-IP).
+    /// The address of the connection is used if absent, so that clients cannot choose their IP.
+    pub trusted_ip_header: Option<String>,
+}
+
 /// Config struct storing all StataChain Entity config
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerConfig {
     /// Bitcoin network name (testnet, regtest, mainnet)
     pub network: String,
@@ -60,6 +91,12 @@ pub struct ServerConfig {
     pub nostr_info: Option<NostrInfo>,
     /// URL of the token server
     pub token_server_url: Option<String>,
+    /// Request rate limits. Requests are not limited if absent.
+    pub rate_limits: Option<RateLimits>,
+    /// Base URL of the swagger-ui-dist assets loaded by the `/swagger` page, unpkg if absent
+    pub swagger_ui_url: Option<String>,
+    /// Bearer token of the `/metrics` requests. The metrics are not served if absent.
+    pub metrics_token: Option<String>,
 }
 
 impl Default for ServerConfig {
@@ -86,122 +123,170 @@ impl Default for ServerConfig {
             db_name: String::from("mercury"),
             nostr_info: None,
             token_server_url: None,
+            rate_limits: None,
+            swagger_ui_url: None,
+            metrics_token: None,
         }
     }
 }
 
-/* impl From<ConfigRs> for ServerConfig {
-    fn from(config: ConfigRs) -> Self {
-        ServerConfig {
-            network: config.get::<String>("network").unwrap_or_else(|_| String::new()),
-            lockheight_init: config.get::<u32>("lockheight_init").unwrap_or(0),
-            lh_decrement: config.get::<u32>("lh_decrement").unwrap_or(0),
-            batch_timeout: config.get::<u32>("batch_timeout").unwrap_or(0),
-            enclaves: config.get::<Vec<Enclave>>("enclaves").unwrap_or_else(|_| Vec::new()),
-            db_user: config.get::<String>("db_user").unwrap_or_else(|_| String::new()),
-            db_password: config.get::<String>("db_password").unwrap_or_else(|_| String::new()),
-            db_host: config.get::<String>("db_host").unwrap_or_else(|_| String::new()),
-            db_port: config.get::<u16>("db_port").unwrap_or(0),
-            db_name: config.get::<String>("db_name").unwrap_or_else(|_| String::new()),
-        }
-    }
-} */
-
 impl ServerConfig {
-    pub fn load() -> Self {
-        let mut conf_rs = ConfigRs::default();
-        let _ = conf_rs
-            // First merge struct default config
-            .merge(ConfigRs::try_from(&ServerConfig::default()).unwrap());
-        // Override with settings in file Settings.toml if exists
-        conf_rs.merge(File::with_name("Settings").required(false));
-        // Override with settings in file Rocket.toml if exists
-        conf_rs.merge(File::with_name("Rocket").required(false));
-
-        let settings: Option<ConfigRs> = ConfigRs::builder()
-            .add_source(File::with_name("Settings"))
-            .build()
-            .ok();
 
-        // Function to fetch a setting from the environment or fallback to the config file
-        let get_env_or_config = |key: &str, env_var: &str| -> String {
-            env::var(env_var).unwrap_or_else(|_| settings.as_ref().unwrap().get_string(key).unwrap())
-        };
+    /// Reads Settings.toml, overridden by the environment variables, and validates the result.
+    /// The error lists every missing or invalid setting.
+    pub fn load() -> Result<Self, String> {
 
-        let get_env_or_config_enclave = |key: &str, env_var: &str| -> Vec<Enclave> {
+        let settings = ConfigRs::builder()
+            .add_source(File::with_name("Settings").required(false))
+            .build()
+            .map_err(|err| format!("Failed to read Settings.toml: {}", err))?;
 
-            let env_enclaves = env::var(env_var);
+        let mut errors = Vec::<String>::new();
 
-            
-            if env_enclaves.is_ok() {
+        let network = get_setting::<String>(&settings, "network", "BITCOIN_NETWORK", &mut errors);
+        let lockheight_init = get_setting::<u32>(&settings, "lockheight_init", "LOCKHEIGHT_INIT", &mut errors);
+        let lh_decrement = get_setting::<u32>(&settings, "lh_decrement", "LH_DECREMENT", &mut errors);
+        let batch_timeout = get_setting::<u32>(&settings, "batch_timeout", "BATCH_TIMEOUT", &mut errors);
+        let enclaves = get_json_setting::<Vec<Enclave>>(&settings, "enclaves", "ENCLAVES", &mut errors);
+        let db_user = get_setting::<String>(&settings, "db_user", "DB_USER", &mut errors);
+        let db_password = get_setting::<String>(&settings, "db_password", "DB_PASSWORD", &mut errors);
+        let db_host = get_setting::<String>(&settings, "db_host", "DB_HOST", &mut errors);
+        let db_port = get_setting::<u16>(&settings, "db_port", "DB_PORT", &mut errors);
+        let db_name = get_setting::<String>(&settings, "db_name", "DB_NAME", &mut errors);
+        let nostr_info = get_optional_json_setting::<NostrInfo>(&settings, "nostr_info", "NOSTR_INFO", &mut errors);
+        let token_server_url = get_optional_setting::<String>(&settings, "token_server_url", "TOKEN_SERVER_URL", &mut errors);
+        let rate_limits = get_optional_json_setting::<RateLimits>(&settings, "rate_limits", "RATE_LIMITS", &mut errors);
+        let swagger_ui_url = get_optional_setting::<String>(&settings, "swagger_ui_url", "SWAGGER_UI_URL", &mut errors);
+        let metrics_token = get_optional_setting::<String>(&settings, "metrics_token", "METRICS_TOKEN", &mut errors);
 
-                return serde_json::from_str::<Vec<Enclave>>(&env_enclaves.unwrap()).unwrap();
-            }
+        if !errors.is_empty() {
+            return Err(errors.join("\n"));
+        }
 
-            settings.as_ref().unwrap().get::<Vec<Enclave>>(key).unwrap()
+        let config = ServerConfig {
+            network: network.unwrap(),
+            lockheight_init: lockheight_init.unwrap(),
+            lh_decrement: lh_decrement.unwrap(),
+            batch_timeout: batch_timeout.unwrap(),
+            enclaves: enclaves.unwrap(),
+            db_user: db_user.unwrap(),
+            db_password: db_password.unwrap(),
+            db_host: db_host.unwrap(),
+            db_port: db_port.unwrap(),
+            db_name: db_name.unwrap(),
+            nostr_info: nostr_info.flatten(),
+            token_server_url: token_server_url.flatten(),
+            rate_limits: rate_limits.flatten(),
+            swagger_ui_url: swagger_ui_url.flatten(),
+            metrics_token: metrics_token.flatten(),
         };
 
-        let get_env_or_config_nostr_info = |key: &str, env_var: &str| -> Option<NostrInfo> {
+        config.validate()?;
 
-            let env_nostr_info = env::var(env_var);
+        Ok(config)
+    }
 
-            
-            if env_nostr_info.is_ok() {
+    /// Checks the values that would otherwise only fail when a request uses them
+    pub fn validate(&self) -> Result<(), String> {
 
-                let res = serde_json::from_str::<NostrInfo>(&env_nostr_info.unwrap()).unwrap();
-                return Some(res)
-            }
+        let mut errors = Vec::<String>::new();
 
-            if settings.as_ref().is_none() {
-                return None
-            }
+        if !["mainnet", "bitcoin", "testnet", "signet", "regtest"].contains(&self.network.as_str()) {
+            errors.push(format!("Invalid setting `network` = {:?}: expected mainnet, bitcoin, testnet, signet or regtest", self.network));
+        }
 
-            let res = settings.as_ref().unwrap().get::<NostrInfo>(key);
+        if self.lh_decrement == 0 || self.lh_decrement >= self.lockheight_init {
+            errors.push(format!("Invalid settings `lockheight_init` = {} and `lh_decrement` = {}: lh_decrement must be greater than 0 and lower than lockheight_init", self.lockheight_init, self.lh_decrement));
+        }
 
-            if res.is_ok() {
-                return Some(res.unwrap())
-            } else {
-                return None
-            }
-        };
+        if self.batch_timeout == 0 {
+            errors.push("Invalid setting `batch_timeout`: it must be greater than 0".to_string());
+        }
 
-        let get_optional_env_or_config = |key: &str, env_var: &str| -> Option<String> {
+        if self.enclaves.is_empty() {
+            errors.push("Invalid setting `enclaves`: at least one enclave is required".to_string());
+        } else if !self.enclaves.iter().any(|enclave| enclave.allow_deposit) {
+            errors.push("Invalid setting `enclaves`: at least one enclave must have allow_deposit = true".to_string());
+        }
 
-            let env_var = env::var(env_var);
+        for (index, enclave) in self.enclaves.iter().enumerate() {
+            if let Err(err) = reqwest::Url::parse(&enclave.url) {
+                errors.push(format!("Invalid setting `enclaves[{}].url` = {:?}: {}", index, enclave.url, err));
+            }
+        }
 
-            if env_var.is_ok() {
-                return Some(env_var.unwrap());
+        if let Some(token_server_url) = self.token_server_url.as_ref() {
+            if let Err(err) = reqwest::Url::parse(token_server_url) {
+                errors.push(format!("Invalid setting `token_server_url` = {:?}: {}", token_server_url, err));
             }
+        }
 
-            if settings.as_ref().is_none() {
-                return None
+        if let Some(swagger_ui_url) = self.swagger_ui_url.as_ref() {
+            if let Err(err) = reqwest::Url::parse(swagger_ui_url) {
+                errors.push(format!("Invalid setting `swagger_ui_url` = {:?}: {}", swagger_ui_url, err));
             }
+        }
 
-            let res = settings.as_ref().unwrap().get::<String>(key);
+        if let Some(metrics_token) = self.metrics_token.as_ref() {
+            if metrics_token.is_empty() {
+                errors.push("Invalid setting `metrics_token`: it must not be empty".to_string());
+            }
+        }
 
-            if res.is_ok() {
-                return Some(res.unwrap())
+        if let Some(nostr_info) = self.nostr_info.as_ref() {
+            if let Err(err) = reqwest::Url::parse(&nostr_info.relay_server) {
+                errors.push(format!("Invalid setting `nostr_info.relay_server` = {:?}: {}", nostr_info.relay_server, err));
             }
+            if nostr_info.relay_interval == 0 {
+                errors.push("Invalid setting `nostr_info.relay_interval`: it must be greater than 0".to_string());
+            }
+            if nostr_sdk::Keys::parse(&nostr_info.nostr_privkey).is_err() {
+                errors.push("Invalid setting `nostr_info.nostr_privkey`: not a valid Nostr secret key".to_string());
+            }
+        }
 
-            return None;
-        };
+        if let Some(rate_limits) = self.rate_limits.as_ref() {
+            if rate_limits.window == 0 {
+                errors.push("Invalid setting `rate_limits.window`: it must be greater than 0".to_string());
+            }
+            if let Some(trusted_ip_header) = rate_limits.trusted_ip_header.as_ref() {
+                if trusted_ip_header.trim().is_empty() {
+                    errors.push("Invalid setting `rate_limits.trusted_ip_header`: it must not be empty".to_string());
+                }
+            }
+            for (key, limit) in [("per_ip", rate_limits.per_ip), ("per_statechain", rate_limits.per_statechain), ("per_auth_key", rate_limits.per_auth_key)] {
+                if limit == Some(0) {
+                    errors.push(format!("Invalid setting `rate_limits.{}`: it must be greater than 0", key));
+                }
+            }
+        }
 
-        ServerConfig {
-            network: get_env_or_config("network", "BITCOIN_NETWORK"),
-            lockheight_init: get_env_or_config("lockheight_init", "LOCKHEIGHT_INIT").parse::<u32>().unwrap(),
-            lh_decrement: get_env_or_config("lh_decrement", "LH_DECREMENT").parse::<u32>().unwrap(),
-            batch_timeout: get_env_or_config("batch_timeout", "BATCH_TIMEOUT").parse::<u32>().unwrap(),
-            enclaves: get_env_or_config_enclave("enclaves", "ENCLAVES"),
-            db_user: get_env_or_config("db_user", "DB_USER"),
-            db_password: get_env_or_config("db_password", "DB_PASSWORD"),
-            db_host: get_env_or_config("db_host", "DB_HOST"),
-            db_port: get_env_or_config("db_port", "DB_PORT").parse::<u16>().unwrap(),
-            db_name: get_env_or_config("db_name", "DB_NAME"),
-            nostr_info: get_env_or_config_nostr_info("nostr_info", "NOSTR_INFO"),
-            token_server_url: get_optional_env_or_config("token_server_url", "TOKEN_SERVER_URL"),
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors.join("\n"))
         }
     }
 
+    /// Copies the settings that can change while the server is running.
+    /// The others (network, lock heights, database) only change on restart.
+    fn apply_reloadable(&mut self, new_config: ServerConfig) {
+
+        if self.network != new_config.network || self.lockheight_init != new_config.lockheight_init || self.lh_decrement != new_config.lh_decrement ||
+            self.db_user != new_config.db_user || self.db_password != new_config.db_password || self.db_host != new_config.db_host ||
+            self.db_port != new_config.db_port || self.db_name != new_config.db_name {
+            warn!("Network, lock height and database settings are only applied on restart");
+        }
+
+        self.enclaves = new_config.enclaves;
+        self.batch_timeout = new_config.batch_timeout;
+        self.nostr_info = new_config.nostr_info;
+        self.token_server_url = new_config.token_server_url;
+        self.rate_limits = new_config.rate_limits;
+        self.swagger_ui_url = new_config.swagger_ui_url;
+        self.metrics_token = new_config.metrics_token;
+    }
+
     pub fn build_postgres_connection_string(&self) -> PgConnectOptions {
         PgConnectOptions::new()
             .host(&self.db_host)
@@ -211,3 +296,118 @@ impl ServerConfig {
             .database(&self.db_name)
     }
 }
+
+fn missing_setting(key: &str, env_var: &str) -> String {
+    format!("Missing setting `{}`: set it in Settings.toml or in the {} environment variable", key, env_var)
+}
+
+/// Reads a setting from the environment variable, or from the config file
+fn get_optional_setting<T>(settings: &ConfigRs, key: &str, env_var: &str, errors: &mut Vec<String>) -> Option<Option<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let value = match env::var(env_var) {
+        Ok(value) => value,
+        Err(_) => match settings.get_string(key) {
+            Ok(value) => value,
+            Err(ConfigError::NotFound(_)) => return Some(None),
+            Err(err) => {
+                errors.push(format!("Invalid setting `{}`: {}", key, err));
+                return None;
+            }
+        },
+    };
+
+    match value.trim().parse::<T>() {
+        Ok(value) => Some(Some(value)),
+        Err(err) => {
+            errors.push(format!("Invalid setting `{}` = {:?}: {}", key, value, err));
+            None
+        }
+    }
+}
+
+fn get_setting<T>(settings: &ConfigRs, key: &str, env_var: &str, errors: &mut Vec<String>) -> Option<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    match get_optional_setting::<T>(settings, key, env_var, errors)? {
+        Some(value) => Some(value),
+        None => {
+            errors.push(missing_setting(key, env_var));
+            None
+        }
+    }
+}
+
+/// Reads a structured setting from the environment variable, as JSON, or from the config file
+fn get_optional_json_setting<T: DeserializeOwned>(settings: &ConfigRs, key: &str, env_var: &str, errors: &mut Vec<String>) -> Option<Option<T>> {
+
+    if let Ok(value) = env::var(env_var) {
+        return match serde_json::from_str::<T>(&value) {
+            Ok(value) => Some(Some(value)),
+            Err(err) => {
+                errors.push(format!("Invalid {} environment variable: {}", env_var, err));
+                None
+            }
+        };
+    }
+
+    match settings.get::<T>(key) {
+        Ok(value) => Some(Some(value)),
+        Err(ConfigError::NotFound(_)) => Some(None),
+        Err(err) => {
+            errors.push(format!("Invalid setting `{}`: {}", key, err));
+            None
+        }
+    }
+}
+
+fn get_json_setting<T: DeserializeOwned>(settings: &ConfigRs, key: &str, env_var: &str, errors: &mut Vec<String>) -> Option<T> {
+    match get_optional_json_setting::<T>(settings, key, env_var, errors)? {
+        Some(value) => Some(value),
+        None => {
+            errors.push(missing_setting(key, env_var));
+            None
+        }
+    }
+}
+
+/// Server configuration shared by the handlers, loaded once at startup.
+/// `reload` replaces the reloadable settings, see `ServerConfig::apply_reloadable`.
+#[derive(Clone)]
+pub struct SharedServerConfig(Arc<RwLock<Arc<ServerConfig>>>);
+
+impl SharedServerConfig {
+
+    pub fn new(config: ServerConfig) -> Self {
+        SharedServerConfig(Arc::new(RwLock::new(Arc::new(config))))
+    }
+
+    /// Snapshot of the current configuration. It does not change if the configuration is reloaded meanwhile.
+    pub fn get(&self) -> Arc<ServerConfig> {
+        self.0.read().unwrap().clone()
+    }
+
+    /// Loads the configuration again. The current one is kept if the new one is invalid.
+    pub fn reload(&self) -> Result<(), String> {
+
+        let new_config = ServerConfig::load()?;
+
+        let mut current = self.0.write().unwrap();
+
+        // statechains refer to their enclave by its index in the list
+        if new_config.enclaves.len() < current.enclaves.len() {
+            return Err(format!("Invalid setting `enclaves`: {} enclaves configured, the list cannot shrink below the current {}", new_config.enclaves.len(), current.enclaves.len()));
+        }
+
+        let mut config = (**current).clone();
+        config.apply_reloadable(new_config);
+
+        *current = Arc::new(config);
+
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 
 mod utils;
 
-use mercurylib::{decode_transfer_address, deposit::DepositMsg1Response, transfer::{receiver::{create_transfer_receiver_request_payload, decrypt_transfer_msg, get_new_key_info, StatechainInfo, StatechainInfoResponsePayload}, sender::create_transfer_signature, TransferMsg, TxOutpoint}, utils::ServerConfig, wallet::{Activity, BackupTx, Coin, Settings, Token, Wallet}};
+use mercurylib::{decode_statechain_address_data, deposit::DepositMsg1Response, error::MercuryErrorDetails, transfer::{receiver::{create_transfer_receiver_request_payload, decrypt_transfer_msg, get_new_key_info, StatechainInfo, StatechainInfoResponsePayload}, sender::create_transfer_signature, TransferMsg, TxOutpoint}, transaction::WithdrawalOutput, utils::ServerConfig, wallet::{cpfp_tx::{CpfpExtraInput, CpfpParent}, ownership::{OwnershipProof, StatechainOwnerResponsePayload, OWNERSHIP_PROOF_MAX_AGE}, watchtower::WatchtowerPackage, Activity, DerivationScheme, BackupTx, Coin, Settings, Token, Wallet}, ScAddressOptions};
 use wasm_bindgen::prelude::*;
 use serde::{Serialize, Deserialize};
 use bip39::Mnemonic;
@@ -86,7 +86,7 @@ pub fn getTokens(wallet_json: JsValue) -> JsValue {
 }
 
 #[wasm_bindgen]
-pub fn getBalance(wallet_json: JsValue) -> u32 {
+pub fn getBalance(wallet_json: JsValue) -> u64 {
     let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
     let mut balance = 0;
     for coin in wallet.coins.iter() {
@@ -95,10 +95,12 @@ pub fn getBalance(wallet_json: JsValue) -> u32 {
     balance
 }
 
+/// `address_options` ({server_hint, expiry}) is optional. If it is set, a v1 address is returned.
 #[wasm_bindgen]
-pub fn getSCAddress(wallet_json: JsValue, index: u32, network: String) -> String {
+pub fn getSCAddress(wallet_json: JsValue, index: u32, network: String, address_options: JsValue) -> String {
     let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
-    let address = mercurylib::get_sc_address(&wallet.mnemonic, index, &network).unwrap();
+    let address_options: Option<ScAddressOptions> = serde_wasm_bindgen::from_value(address_options).unwrap();
+    let address = mercurylib::get_sc_address(&wallet.mnemonic, wallet.passphrase.as_deref(), &wallet.derivation_scheme, wallet.account, index, &network, address_options.as_ref()).unwrap();
     address.to_string()
 }
 
@@ -111,7 +113,13 @@ pub fn generateMnemonic() -> String {
 }
 
 #[wasm_bindgen]
-pub fn fromMnemonic(name: String, mnemonic: String) -> JsValue {
+pub fn fromMnemonic(name: String, mnemonic: String, passphrase: Option<String>, derivation_scheme: Option<String>, account: Option<u32>) -> Result<JsValue, JsError> {
+
+    // Wallets created before the derivation scheme was recorded are restored with "legacy" unless "bip44" is passed
+    let derivation_scheme = match derivation_scheme {
+        Some(derivation_scheme) => mercurylib::utils::get_derivation_scheme(&derivation_scheme).map_err(|error| JsError::new(&error.to_string()))?,
+        None => DerivationScheme::Legacy,
+    };
 
     let settings = Settings {
         network: String::from("signet"),
@@ -133,6 +141,9 @@ pub fn fromMnemonic(name: String, mnemonic: String) -> JsValue {
     let wallet = Wallet {
         name,
         mnemonic,
+        passphrase: passphrase.filter(|passphrase| !passphrase.is_empty()),
+        derivation_scheme,
+        account: account.unwrap_or(0),
         version: String::from("0.1.0"),
         state_entity_endpoint: String::from("http://127.0.0.1:8000"),
         electrum_endpoint: String::from("tcp://signet-electrumx.wakiyamap.dev:50001"),
@@ -145,7 +156,7 @@ pub fn fromMnemonic(name: String, mnemonic: String) -> JsValue {
         coins: Vec::new(),
         settings
     };
-    serde_wasm_bindgen::to_value(&wallet).unwrap()
+    Ok(serde_wasm_bindgen::to_value(&wallet).unwrap())
 }
 
 #[wasm_bindgen]
@@ -167,6 +178,63 @@ pub fn getNewCoin(wallet_json: JsValue) -> JsValue {
     serde_wasm_bindgen::to_value(&coin).unwrap()
 }
 
+#[wasm_bindgen]
+pub fn getNewCoinWithAddressOptions(wallet_json: JsValue, address_options: JsValue) -> JsValue {
+    let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
+    let address_options: ScAddressOptions = serde_wasm_bindgen::from_value(address_options).unwrap();
+    let coin = wallet.get_new_coin_with_address_options(&address_options).unwrap();
+    serde_wasm_bindgen::to_value(&coin).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn getCoinAtIndex(wallet_json: JsValue, address_index: u32) -> JsValue {
+    let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).unwrap();
+    let coin = wallet.get_coin_at_index(address_index).unwrap();
+    serde_wasm_bindgen::to_value(&coin).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn createStatechainLookupRequest(coin_json: JsValue, timestamp: u64) -> JsValue {
+    let coin: Coin = serde_wasm_bindgen::from_value(coin_json).unwrap();
+    let statechain_lookup_request = mercurylib::wallet::recovery::create_statechain_lookup_request(&coin, timestamp).unwrap();
+    serde_wasm_bindgen::to_value(&statechain_lookup_request).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn createOwnershipProof(coin_json: JsValue, challenge: String, timestamp: u64) -> Result<JsValue, JsError> {
+    let coin: Coin = serde_wasm_bindgen::from_value(coin_json).map_err(|error| JsError::new(&error.to_string()))?;
+    let ownership_proof = mercurylib::wallet::ownership::create_ownership_proof(&coin, &challenge, timestamp).map_err(|error| JsError::new(&error.to_string()))?;
+    Ok(serde_wasm_bindgen::to_value(&ownership_proof).unwrap())
+}
+
+#[wasm_bindgen]
+pub fn verifyOwnershipProof(ownership_proof_json: JsValue, challenge: String, statechain_owner_json: JsValue, tx0_hex: String, current_time: u64) -> Result<JsValue, JsError> {
+    let ownership_proof: OwnershipProof = serde_wasm_bindgen::from_value(ownership_proof_json).map_err(|error| JsError::new(&error.to_string()))?;
+    let statechain_owner: StatechainOwnerResponsePayload = serde_wasm_bindgen::from_value(statechain_owner_json).map_err(|error| JsError::new(&error.to_string()))?;
+
+    let result = mercurylib::wallet::ownership::verify_ownership_proof(&ownership_proof, &challenge, &statechain_owner, &tx0_hex, current_time, OWNERSHIP_PROOF_MAX_AGE);
+
+    #[derive(Serialize, Deserialize)]
+    struct ValidationResult {
+        result: bool,
+        msg: Option<String>,
+        error: Option<MercuryErrorDetails>,
+    }
+
+    let mut validation_result = ValidationResult {
+        result: result.is_ok(),
+        msg: None,
+        error: None,
+    };
+
+    if let Err(error) = result {
+        validation_result.msg = Some(error.to_string());
+        validation_result.error = Some(error.details());
+    }
+
+    Ok(serde_wasm_bindgen::to_value(&validation_result).unwrap())
+}
+
 #[wasm_bindgen]
 pub fn createDepositMsg1(coin_json: JsValue, token_id: String) -> JsValue {
     let coin: Coin = serde_wasm_bindgen::from_value(coin_json).unwrap();
@@ -213,11 +281,14 @@ pub fn getPartialSigRequest(
     qt_backup_tx: u32,
     to_address: String,
     network: String,
-    is_withdrawal: bool) -> JsValue
+    is_withdrawal: bool,
+    anchor_type: Option<String>) -> JsValue
 {
     let coin: Coin = serde_wasm_bindgen::from_value(coin_json).unwrap();
 
-    let partial_sig_request = mercurylib::transaction::get_partial_sig_request(
+    let anchor_type = anchor_type.map(|anchor_type| mercurylib::utils::get_anchor_output_type(&anchor_type).unwrap());
+
+    let partial_sig_request = mercurylib::transaction::get_partial_sig_request_with_anchor(
         &coin, 
         block_height, 
         initlock, 
@@ -226,7 +297,29 @@ pub fn getPartialSigRequest(
         qt_backup_tx,
         to_address,
         network,
-        is_withdrawal).unwrap();
+        is_withdrawal,
+        anchor_type).unwrap();
+
+    serde_wasm_bindgen::to_value(&partial_sig_request).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn getWithdrawalPartialSigRequest(
+    coin_json: JsValue, 
+    block_height: u32, 
+    fee_rate_sats_per_byte: f32,
+    outputs: JsValue,
+    network: String) -> JsValue
+{
+    let coin: Coin = serde_wasm_bindgen::from_value(coin_json).unwrap();
+    let outputs: Vec<WithdrawalOutput> = serde_wasm_bindgen::from_value(outputs).unwrap();
+
+    let partial_sig_request = mercurylib::transaction::get_withdrawal_partial_sig_request(
+        &coin, 
+        block_height, 
+        fee_rate_sats_per_byte as f64,
+        outputs,
+        network).unwrap();
 
     serde_wasm_bindgen::to_value(&partial_sig_request).unwrap()
 }
@@ -250,6 +343,77 @@ pub fn newBackupTransaction(encoded_unsigned_tx: String, signature_hex: String)
     backup_tx
 }
 
+#[wasm_bindgen]
+pub fn createBackupTxPsbt(
+    coin_json: JsValue, 
+    block_height: u32, 
+    initlock: u32, 
+    interval: u32, 
+    fee_rate_sats_per_byte: f32,
+    qt_backup_tx: u32,
+    to_address: String,
+    network: String,
+    is_withdrawal: bool,
+    anchor_type: Option<String>) -> Result<String, JsError>
+{
+    let coin: Coin = serde_wasm_bindgen::from_value(coin_json).map_err(|error| JsError::new(&error.to_string()))?;
+
+    let anchor_type = match anchor_type {
+        Some(anchor_type) => Some(mercurylib::utils::get_anchor_output_type(&anchor_type).map_err(|error| JsError::new(&error.to_string()))?),
+        None => None,
+    };
+
+    mercurylib::psbt::create_backup_tx_psbt(
+        &coin, 
+        block_height, 
+        initlock, 
+        interval, 
+        fee_rate_sats_per_byte as f64,
+        qt_backup_tx,
+        to_address,
+        network,
+        is_withdrawal,
+        anchor_type).map_err(|error| JsError::new(&error.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn createWithdrawalPsbt(
+    coin_json: JsValue, 
+    block_height: u32, 
+    fee_rate_sats_per_byte: f32,
+    outputs: JsValue,
+    network: String) -> Result<String, JsError>
+{
+    let coin: Coin = serde_wasm_bindgen::from_value(coin_json).map_err(|error| JsError::new(&error.to_string()))?;
+    let outputs: Vec<WithdrawalOutput> = serde_wasm_bindgen::from_value(outputs).map_err(|error| JsError::new(&error.to_string()))?;
+
+    mercurylib::psbt::create_withdrawal_psbt(&coin, block_height, fee_rate_sats_per_byte as f64, outputs, network).map_err(|error| JsError::new(&error.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn signPsbt(coin_json: JsValue, psbt: String) -> Result<String, JsError> {
+    let coin: Coin = serde_wasm_bindgen::from_value(coin_json).map_err(|error| JsError::new(&error.to_string()))?;
+    mercurylib::psbt::sign_psbt(&coin, psbt).map_err(|error| JsError::new(&error.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn getPartialSigRequestFromPsbt(coin_json: JsValue, psbt: String) -> Result<JsValue, JsError> {
+    let coin: Coin = serde_wasm_bindgen::from_value(coin_json).map_err(|error| JsError::new(&error.to_string()))?;
+    let partial_sig_request = mercurylib::psbt::get_partial_sig_request_from_psbt(&coin, psbt).map_err(|error| JsError::new(&error.to_string()))?;
+    Ok(serde_wasm_bindgen::to_value(&partial_sig_request).unwrap())
+}
+
+#[wasm_bindgen]
+pub fn addServerPartialSigToPsbt(coin_json: JsValue, psbt: String, server_partial_sig_hex: String) -> Result<String, JsError> {
+    let coin: Coin = serde_wasm_bindgen::from_value(coin_json).map_err(|error| JsError::new(&error.to_string()))?;
+    mercurylib::psbt::add_server_partial_sig_to_psbt(&coin, psbt, server_partial_sig_hex).map_err(|error| JsError::new(&error.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn finalizePsbt(psbt: String) -> Result<String, JsError> {
+    mercurylib::psbt::finalize_psbt(psbt).map_err(|error| JsError::new(&error.to_string()))
+}
+
 #[wasm_bindgen]
 pub fn createCpfpTx(backup_tx_json: JsValue, coin_json: JsValue, to_address: String, fee_rate_sats_per_byte: f32, network: String) -> String {
     let coin: Coin = serde_wasm_bindgen::from_value(coin_json).unwrap();
@@ -260,6 +424,14 @@ pub fn createCpfpTx(backup_tx_json: JsValue, coin_json: JsValue, to_address: Str
     // "".to_string()
 }
 
+#[wasm_bindgen]
+pub fn createCpfpPackageTx(parents_json: JsValue, extra_inputs_json: JsValue, to_address: String, fee_rate_sats_per_byte: f32, network: String) -> Result<String, JsError> {
+    let parents: Vec<CpfpParent> = serde_wasm_bindgen::from_value(parents_json).map_err(|error| JsError::new(&error.to_string()))?;
+    let extra_inputs: Vec<CpfpExtraInput> = serde_wasm_bindgen::from_value(extra_inputs_json).map_err(|error| JsError::new(&error.to_string()))?;
+
+    mercurylib::wallet::cpfp_tx::create_cpfp_package_tx(&parents, &extra_inputs, &to_address, fee_rate_sats_per_byte as f64, &network).map_err(|error| JsError::new(&error.to_string()))
+}
+
 #[wasm_bindgen]
 pub fn createTransferSignature(recipient_address: String, input_txid: String, input_vout: u32, client_seckey: String) -> String {
     let signature = create_transfer_signature(&recipient_address, &input_txid, input_vout, &client_seckey).unwrap();
@@ -277,30 +449,42 @@ pub fn createTransferUpdateMsg(x1: String, recipient_address: String, coin_json:
 }
 
 #[wasm_bindgen]
-pub fn decodeTransferAddress(sc_address: String) -> JsValue {
+pub fn decodeApiError(status_code: u16, body: String) -> JsValue {
+    let api_error = mercurylib::error::decode_api_error(status_code, &body);
+    serde_wasm_bindgen::to_value(&api_error).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn decodeTransferAddress(sc_address: String) -> Result<JsValue, JsError> {
     
-    let (version, user_pubkey, auth_pubkey) = decode_transfer_address(&sc_address).unwrap();
+    let sc_address = decode_statechain_address_data(&sc_address).map_err(|error| JsError::new(&error.to_string()))?;
 
     #[derive(Serialize, Deserialize)]
     struct DecodedSCAddress {
         version: u8,
         user_pubkey: String,
         auth_pubkey: String,
+        network: Option<String>,
+        server_hint: Option<String>,
+        expiry: Option<u64>,
     }
 
     let decoded_sc_address = DecodedSCAddress {
-        version,
-        user_pubkey: user_pubkey.to_string(),
-        auth_pubkey: auth_pubkey.to_string(),
+        version: sc_address.version,
+        user_pubkey: sc_address.user_pubkey.to_string(),
+        auth_pubkey: sc_address.auth_pubkey.to_string(),
+        network: sc_address.network.map(|network| network.to_string()),
+        server_hint: sc_address.server_hint,
+        expiry: sc_address.expiry,
     };
 
-    serde_wasm_bindgen::to_value(&decoded_sc_address).unwrap()
+    Ok(serde_wasm_bindgen::to_value(&decoded_sc_address).unwrap())
 }
 
 #[wasm_bindgen]
-pub fn decryptTransferMsg(encrypted_message: String, private_key_wif: String) -> JsValue {
-    let transfer_msg = decrypt_transfer_msg(&encrypted_message, &private_key_wif).unwrap();
-    serde_wasm_bindgen::to_value(&transfer_msg).unwrap()
+pub fn decryptTransferMsg(encrypted_message: String, private_key_wif: String) -> Result<JsValue, JsError> {
+    let transfer_msg = decrypt_transfer_msg(&encrypted_message, &private_key_wif).map_err(|error| JsError::new(&error.to_string()))?;
+    Ok(serde_wasm_bindgen::to_value(&transfer_msg).unwrap())
 }
 
 #[wasm_bindgen]
@@ -349,15 +533,18 @@ pub fn verifyTransactionSignature(tx_n_hex: String, tx0_hex: String, fee_rate_to
     struct ValidationResult {
         result: bool,
         msg: Option<String>,
+        error: Option<MercuryErrorDetails>,
     }
 
     let mut validation_result = ValidationResult {
         result: result.is_ok(),
-        msg: None
+        msg: None,
+        error: None,
     };
 
-    if result.is_err() {
-        validation_result.msg = Some(result.err().unwrap().to_string());
+    if let Err(error) = result {
+        validation_result.msg = Some(error.to_string());
+        validation_result.error = Some(error.details());
     }
 
     serde_wasm_bindgen::to_value(&validation_result).unwrap()
@@ -374,15 +561,18 @@ pub fn verifyBlindedMusigScheme(backup_tx: JsValue, tx0_hex: String, statechain_
     struct ValidationResult {
         result: bool,
         msg: Option<String>,
+        error: Option<MercuryErrorDetails>,
     }
 
     let mut validation_result = ValidationResult {
         result: result.is_ok(),
-        msg: None
+        msg: None,
+        error: None,
     };
 
-    if result.is_err() {
-        validation_result.msg = Some(result.err().unwrap().to_string());
+    if let Err(error) = result {
+        validation_result.msg = Some(error.to_string());
+        validation_result.error = Some(error.details());
     }
 
     serde_wasm_bindgen::to_value(&validation_result).unwrap()
@@ -416,10 +606,22 @@ pub fn getNewKeyInfo(server_public_key_hex: String, coin: JsValue, statechain_id
     serde_wasm_bindgen::to_value(&new_key_info).unwrap()
 }
 
+fn get_current_time() -> u64 {
+    (js_sys::Date::now() / 1000.0) as u64
+}
+
 #[wasm_bindgen]
 pub fn validateAddress(address: String, network: String) -> bool {
-    let result = mercurylib::validate_address(&address, &network).unwrap();
-    result
+    // the reason an address is invalid is returned by getAddressValidationError
+    mercurylib::validate_address_at(&address, &network, get_current_time()).unwrap_or(false)
+}
+
+#[wasm_bindgen]
+pub fn getAddressValidationError(address: String, network: String) -> JsValue {
+    match mercurylib::validate_address_at(&address, &network, get_current_time()) {
+        Ok(_) => JsValue::NULL,
+        Err(error) => serde_wasm_bindgen::to_value(&error.details()).unwrap(),
+    }
 }
 
 #[wasm_bindgen]
@@ -479,13 +681,16 @@ pub fn validateSignatureScheme(backup_transactions: JsValue, statechain_info: Js
     struct ValidationResult {
         result: bool,
         msg: Option<String>,
+        error: Option<MercuryErrorDetails>,
         previousLockTime: u32,
     }
 
     if result.is_err() {
+        let error = result.err().unwrap();
         let validation_result = ValidationResult {
             result: false,
-            msg: Some(result.err().unwrap().to_string()),
+            msg: Some(error.to_string()),
+            error: Some(error.details()),
             previousLockTime: 0
         };
         return serde_wasm_bindgen::to_value(&validation_result).unwrap();
@@ -493,12 +698,84 @@ pub fn validateSignatureScheme(backup_transactions: JsValue, statechain_info: Js
         let validation_result = ValidationResult {
             result: true,
             msg: None,
+            error: None,
             previousLockTime: result.unwrap()
         };
         return serde_wasm_bindgen::to_value(&validation_result).unwrap();
     }
 }
 
+#[wasm_bindgen]
+pub fn getSignatureSchemeValidationReport(backup_transactions: JsValue, statechain_info: JsValue, tx0_hex: String, current_blockheight: u32, fee_rate_tolerance: f32, current_fee_rate_sats_per_byte: f32, lockheight_init: u32, interval: u32) -> JsValue {
+
+    let statechain_info: StatechainInfoResponsePayload = serde_wasm_bindgen::from_value(statechain_info).unwrap();
+    let backup_transactions: Vec<BackupTx> = serde_wasm_bindgen::from_value(backup_transactions).unwrap();
+
+    let report = mercurylib::transfer::receiver::get_signature_scheme_validation_report(&backup_transactions, &statechain_info, &tx0_hex, current_blockheight, fee_rate_tolerance as f64, current_fee_rate_sats_per_byte as f64, lockheight_init, interval);
+
+    serde_wasm_bindgen::to_value(&report).unwrap()
+}
+
+#[wasm_bindgen]
+pub fn createWatchtowerPackage(coin: JsValue, backup_transactions: JsValue, network: String) -> Result<JsValue, JsError> {
+    let coin: Coin = serde_wasm_bindgen::from_value(coin).map_err(|error| JsError::new(&error.to_string()))?;
+    let backup_transactions: Vec<BackupTx> = serde_wasm_bindgen::from_value(backup_transactions).map_err(|error| JsError::new(&error.to_string()))?;
+    let package = mercurylib::wallet::watchtower::create_watchtower_package(&coin, &backup_transactions, &network).map_err(|error| JsError::new(&error.to_string()))?;
+    Ok(serde_wasm_bindgen::to_value(&package).unwrap())
+}
+
+#[wasm_bindgen]
+pub fn exportWatchtowerPackage(package: JsValue) -> Result<String, JsError> {
+    let package: WatchtowerPackage = serde_wasm_bindgen::from_value(package).map_err(|error| JsError::new(&error.to_string()))?;
+    mercurylib::wallet::watchtower::export_watchtower_package(&package).map_err(|error| JsError::new(&error.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn importWatchtowerPackage(package_json: String) -> Result<JsValue, JsError> {
+    let package = mercurylib::wallet::watchtower::import_watchtower_package(&package_json).map_err(|error| JsError::new(&error.to_string()))?;
+    Ok(serde_wasm_bindgen::to_value(&package).unwrap())
+}
+
+#[wasm_bindgen]
+pub fn verifyWatchtowerPackage(package: JsValue) -> Result<JsValue, JsError> {
+    let package: WatchtowerPackage = serde_wasm_bindgen::from_value(package).map_err(|error| JsError::new(&error.to_string()))?;
+
+    let result = mercurylib::wallet::watchtower::verify_watchtower_package(&package);
+
+    #[derive(Serialize, Deserialize)]
+    struct ValidationResult {
+        result: bool,
+        msg: Option<String>,
+        error: Option<MercuryErrorDetails>,
+    }
+
+    let mut validation_result = ValidationResult {
+        result: result.is_ok(),
+        msg: None,
+        error: None,
+    };
+
+    if let Err(error) = result {
+        validation_result.msg = Some(error.to_string());
+        validation_result.error = Some(error.details());
+    }
+
+    Ok(serde_wasm_bindgen::to_value(&validation_result).unwrap())
+}
+
+#[wasm_bindgen]
+pub fn getCoinDescriptor(coin: JsValue, network: String) -> Result<String, JsError> {
+    let coin: Coin = serde_wasm_bindgen::from_value(coin).map_err(|error| JsError::new(&error.to_string()))?;
+    mercurylib::wallet::descriptor::get_coin_descriptor(&coin, &network).map_err(|error| JsError::new(&error.to_string()))
+}
+
+#[wasm_bindgen]
+pub fn getWalletDescriptors(wallet_json: JsValue) -> Result<JsValue, JsError> {
+    let wallet: Wallet = serde_wasm_bindgen::from_value(wallet_json).map_err(|error| JsError::new(&error.to_string()))?;
+    let descriptors = mercurylib::wallet::descriptor::get_wallet_descriptors(&wallet).map_err(|error| JsError::new(&error.to_string()))?;
+    Ok(serde_wasm_bindgen::to_value(&descriptors).unwrap())
+}
+
 #[wasm_bindgen]
 pub fn getPreviousOutpoint(backup_tx: JsValue) -> JsValue {
     let backup_tx: BackupTx = serde_wasm_bindgen::from_value(backup_tx).unwrap();
@@ -507,9 +784,9 @@ pub fn getPreviousOutpoint(backup_tx: JsValue) -> JsValue {
 }
 
 #[wasm_bindgen]
-pub fn getAmountFromTx0(tx0_hex: String, tx_outpoint: JsValue) -> u32 {
+pub fn getAmountFromTx0(tx0_hex: String, tx_outpoint: JsValue) -> u64 {
     let tx_outpoint: TxOutpoint = serde_wasm_bindgen::from_value(tx_outpoint).unwrap();
-    mercurylib::transfer::receiver::get_amount_from_tx0(&tx0_hex, &tx_outpoint).unwrap() as u32
+    mercurylib::transfer::receiver::get_amount_from_tx0(&tx0_hex, &tx_outpoint).unwrap()
 }
 
 #[wasm_bindgen]
@@ -578,6 +855,9 @@ pub fn getMockWallet() -> JsValue {
     let wallet = Wallet {
         name: String::from("Mock Wallet"),
         mnemonic: String::from("coil knock parade empower divorce scorpion float force carbon side wonder choice"),
+        passphrase: None,
+        derivation_scheme: DerivationScheme::Legacy,
+        account: 0,
         version: String::from("0.1.0"),
         state_entity_endpoint: String::from("http://127.0.0.1:8000"),
         electrum_endpoint: String::from("tcp://signet-electrumx.wakiyamap.dev:50001"),